@@ -0,0 +1,49 @@
+//! This example demonstrates how to let the client drive the tool call loop with `exec_chat_with_tools`
+
+use genai::chat::{ChatRequest, ToolSchemaRegistry};
+use genai::Client;
+use serde_json::json;
+
+const MODEL: &str = "gpt-4o-mini";
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+	let client = Client::default();
+
+	let mut registry = ToolSchemaRegistry::new();
+	registry.register_from_schema(
+		json!({
+			"type": "function",
+			"function": {
+				"name": "get_weather",
+				"description": "Get the current weather of a city",
+				"parameters": {
+					"type": "object",
+					"properties": { "city": { "type": "string" } },
+					"required": ["city"]
+				}
+			}
+		}),
+		|args| {
+			let city = args
+				.and_then(|args| args.get("city"))
+				.and_then(|c| c.as_str())
+				.unwrap_or("unknown");
+			json!({"city": city, "weather": "sunny", "temperature_c": 22}).to_string()
+		},
+	)?;
+
+	let question = "What is the weather in Paris and in Tokyo?";
+	let chat_req = ChatRequest::from_user(question).with_tools(registry.tool_schemas());
+
+	println!("\n--- Question:\n{question}");
+	// The tool calls are dispatched to the registry, at most 5 rounds.
+	let chat_res = client.exec_chat_with_tools(MODEL, chat_req, None, &registry, 5).await?;
+
+	println!(
+		"\n--- Answer:\n{}",
+		chat_res.content_text_as_str().unwrap_or("NO ANSWER")
+	);
+
+	Ok(())
+}
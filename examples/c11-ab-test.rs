@@ -0,0 +1,43 @@
+//! This example demonstrates how to compare two system prompts with `PromptABTest` (for a classification task)
+
+use genai::chat::ChatRequest;
+use genai::eval::PromptABTest;
+use genai::Client;
+
+const MODEL: &str = "gpt-4o-mini";
+
+const VARIANT_A: &str = "Classify the sentiment of the user message.";
+const VARIANT_B: &str = "Classify the sentiment of the user message. Answer only with 'positive' or 'negative'.";
+
+fn score(text: &str) -> f32 {
+	// The expected label, in the expected format
+	if text.trim().eq_ignore_ascii_case("positive") {
+		1.0
+	} else {
+		0.0
+	}
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+	let client = Client::default();
+
+	let chat_req = ChatRequest::from_user("The delivery was fast and the product works great!");
+	let ab_test = PromptABTest::new(client, chat_req, VARIANT_A, VARIANT_B).with_scorer(score);
+
+	let result = ab_test.run(MODEL, 5).await?;
+
+	for (name, metrics) in [("A", &result.metrics.variant_a), ("B", &result.metrics.variant_b)] {
+		println!(
+			"\n--- Variant {name}\navg tokens: {:.1}\navg latency: {:.0}ms\navg score: {:.2}",
+			metrics.avg_tokens,
+			metrics.avg_latency_ms,
+			metrics.avg_score.unwrap_or_default()
+		);
+	}
+	if let Some(answer) = result.variant_a_responses.first().and_then(|res| res.content_text_as_str()) {
+		println!("\n--- Variant A sample answer:\n{answer}");
+	}
+
+	Ok(())
+}
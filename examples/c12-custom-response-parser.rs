@@ -0,0 +1,58 @@
+//! This example demonstrates how to use a custom ResponseParser for an OpenAI compatible server with a
+//! non-standard response, here a LocalAI server behind a proxy wrapping the responses in a `{"result": ...}`.
+
+use genai::adapter::{AdapterKind, ResponseParser, TextResponseParser};
+use genai::chat::{ChatMessage, ChatRequest, ChatResponse, ChatStreamEvent};
+use genai::resolver::{AuthData, Endpoint, ServiceTargetResolver};
+use genai::{Client, ModelIden, ServiceTarget};
+use serde_json::Value;
+use std::sync::Arc;
+
+const MODEL: &str = "gpt-4";
+const LOCALAI_BASE_URL: &str = "http://localhost:8080/v1/";
+
+/// Unwrap the `result` of the proxy responses, and parse it as an OpenAI response.
+struct LocalAIParser;
+
+impl ResponseParser for LocalAIParser {
+	fn parse_chat_response(&self, model_iden: ModelIden, mut body: Value) -> genai::Result<ChatResponse> {
+		let body = body.get_mut("result").map(Value::take).unwrap_or(body);
+		TextResponseParser::new(AdapterKind::OpenAI).parse_chat_response(model_iden, body)
+	}
+
+	fn parse_stream_event(&self, model_iden: &ModelIden, event: &str) -> genai::Result<Option<ChatStreamEvent>> {
+		// The stream events are not wrapped by the proxy (and only the text chunks are needed)
+		TextResponseParser::new(AdapterKind::OpenAI).parse_stream_event(model_iden, event)
+	}
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+	// -- Point the OpenAI adapter to the LocalAI server
+	let target_resolver = ServiceTargetResolver::from_resolver_fn(
+		|service_target: ServiceTarget| -> Result<ServiceTarget, genai::resolver::Error> {
+			let ServiceTarget { model, .. } = service_target;
+			let endpoint = Endpoint::from_static(LOCALAI_BASE_URL);
+			let auth = AuthData::from_single("localai");
+			Ok(ServiceTarget { endpoint, auth, model })
+		},
+	);
+
+	let client = Client::builder()
+		.with_service_target_resolver(target_resolver)
+		.with_response_parser(AdapterKind::OpenAI, Arc::new(LocalAIParser))
+		.build();
+
+	let question = "Why is the sky blue?";
+	let chat_req = ChatRequest::default()
+		.with_system("Answer in one sentence")
+		.append_message(ChatMessage::user(question));
+
+	println!("\n--- Question:\n{question}");
+	let chat_res = client.exec_chat(MODEL, chat_req, None).await?;
+
+	println!("\n--- Answer: ");
+	println!("{}", chat_res.content_text_as_str().unwrap_or("NO ANSWER"));
+
+	Ok(())
+}
@@ -0,0 +1,69 @@
+//! Procedural macros for `genai`, re-exported by `genai::chat` with the `macros` feature.
+//!
+//! Note: The generated code uses the `::genai` paths, so these macros cannot be used without the `genai` crate.
+
+// region:    --- Modules
+
+mod support;
+mod tool;
+mod tool_doc;
+
+use proc_macro::TokenStream;
+
+// endregion: --- Modules
+
+/// Make a tool function from a plain Rust function, with its schema generated from the parameter types.
+///
+/// - The function doc comment is the tool description, and the parameter doc comments are the parameter descriptions.
+/// - The parameters must be owned types implementing `serde::Deserialize` and `schemars::JsonSchema`.
+/// - The function must return a `Result<R, E>`, with `R: serde::Serialize` (the output is serialized as JSON)
+///   and `E: std::fmt::Display`.
+///
+/// The function is kept as is, and a struct with the same name is generated (in the type namespace),
+/// with the `schema()` and `invoke(args)` functions, and implementing `genai::chat::ToolFn`.
+///
+/// ```ignore
+/// use genai::chat::{tool, ToolSchemaRegistry};
+///
+/// /// Get the current weather of a city.
+/// #[tool]
+/// fn get_current_weather(
+///     /// The city name, e.g., "Paris"
+///     city: String,
+///     /// The temperature unit ("C" or "F"), Celsius by default
+///     unit: Option<String>,
+/// ) -> Result<String, String> {
+///     Ok(format!("21{} in {city}", unit.unwrap_or("C".to_string())))
+/// }
+///
+/// let fn_schema = get_current_weather::schema();
+/// let mut registry = ToolSchemaRegistry::new();
+/// registry.register_tool::<get_current_weather>();
+/// ```
+#[proc_macro_attribute]
+pub fn tool(args: TokenStream, item: TokenStream) -> TokenStream {
+	tool::expand_tool(args.into(), item.into())
+		.unwrap_or_else(syn::Error::into_compile_error)
+		.into()
+}
+
+/// Set the tool function description on a tool parameter struct (as its schema root `description`),
+/// for `genai::chat::schema_for_fn_single_param_from_docs`.
+///
+/// Note: Must be placed above the `#[derive(JsonSchema)]` (it adds a `#[schemars(description = "...")]`).
+///
+/// ```ignore
+/// #[tool_doc("Get the current weather of a city")]
+/// #[derive(Deserialize, JsonSchema)]
+/// struct GetWeatherParams {
+///     city: String,
+/// }
+///
+/// let fn_schema = schema_for_fn_single_param_from_docs::<GetWeatherParams>("get_weather")?;
+/// ```
+#[proc_macro_attribute]
+pub fn tool_doc(args: TokenStream, item: TokenStream) -> TokenStream {
+	tool_doc::expand_tool_doc(args.into(), item.into())
+		.unwrap_or_else(syn::Error::into_compile_error)
+		.into()
+}
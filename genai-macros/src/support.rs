@@ -0,0 +1,28 @@
+use syn::{Attribute, Expr, ExprLit, Lit, Meta};
+
+/// Returns the text of the `///` doc comments (the `#[doc = "..."]` attributes), with the lines trimmed.
+pub fn doc_text(attrs: &[Attribute]) -> Option<String> {
+	let lines: Vec<String> = attrs
+		.iter()
+		.filter_map(|attr| match &attr.meta {
+			Meta::NameValue(meta) if meta.path.is_ident("doc") => match &meta.value {
+				Expr::Lit(ExprLit {
+					lit: Lit::Str(lit_str), ..
+				}) => Some(lit_str.value().trim().to_string()),
+				_ => None,
+			},
+			_ => None,
+		})
+		.collect();
+
+	let text = lines.join("\n").trim().to_string();
+	if text.is_empty() {
+		None
+	} else {
+		Some(text)
+	}
+}
+
+pub fn is_doc_attr(attr: &Attribute) -> bool {
+	attr.path().is_ident("doc")
+}
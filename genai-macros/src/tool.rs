@@ -0,0 +1,118 @@
+use crate::support::{doc_text, is_doc_attr};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Error, FnArg, ItemFn, Pat, Result, Type};
+
+pub fn expand_tool(args: TokenStream, item: TokenStream) -> Result<TokenStream> {
+	if !args.is_empty() {
+		return Err(Error::new_spanned(args, "#[tool] does not take arguments"));
+	}
+	let mut item_fn: ItemFn = syn::parse2(item)?;
+
+	// -- Validate the signature
+	let sig = &item_fn.sig;
+	if let Some(asyncness) = &sig.asyncness {
+		return Err(Error::new_spanned(
+			asyncness,
+			"#[tool] does not support the async functions",
+		));
+	}
+	if !sig.generics.params.is_empty() {
+		return Err(Error::new_spanned(
+			&sig.generics,
+			"#[tool] does not support the generic functions",
+		));
+	}
+	let fn_name = sig.ident.clone();
+	let fn_name_str = fn_name.to_string();
+	let description = doc_text(&item_fn.attrs).ok_or_else(|| {
+		Error::new_spanned(
+			&fn_name,
+			"#[tool] requires a doc comment on the function (the tool description)",
+		)
+	})?;
+
+	// -- Extract the params (and remove their doc comments, not allowed on the function params)
+	let mut fields: Vec<TokenStream> = Vec::new();
+	let mut param_names = Vec::new();
+	for input in item_fn.sig.inputs.iter_mut() {
+		let pat_type = match input {
+			FnArg::Typed(pat_type) => pat_type,
+			FnArg::Receiver(receiver) => {
+				return Err(Error::new_spanned(receiver, "#[tool] does not support the methods"));
+			}
+		};
+		let Pat::Ident(pat_ident) = &*pat_type.pat else {
+			return Err(Error::new_spanned(
+				&pat_type.pat,
+				"#[tool] requires the params to be simple identifiers",
+			));
+		};
+		if let Type::Reference(type_ref) = &*pat_type.ty {
+			return Err(Error::new_spanned(
+				type_ref,
+				"#[tool] requires owned param types (deserialized from the LLM arguments)",
+			));
+		}
+		let name = pat_ident.ident.clone();
+		let ty = pat_type.ty.clone();
+		let docs: Vec<_> = pat_type.attrs.iter().filter(|attr| is_doc_attr(attr)).cloned().collect();
+		pat_type.attrs.retain(|attr| !is_doc_attr(attr));
+
+		fields.push(quote! { #(#docs)* #name: #ty });
+		param_names.push(name);
+	}
+
+	// -- Generate the tool struct and the params struct
+	let vis = &item_fn.vis;
+	let params_ident = format_ident!("__{}_tool_params", fn_name);
+	let tool_struct_doc = format!("The `{fn_name_str}` tool function (see `{fn_name_str}::schema()`).");
+
+	Ok(quote! {
+		#item_fn
+
+		#[doc = #tool_struct_doc]
+		#[allow(non_camel_case_types, dead_code)]
+		#vis struct #fn_name {}
+
+		#[doc(hidden)]
+		#[allow(non_camel_case_types)]
+		#[derive(::genai::__private::serde::Deserialize, ::genai::__private::schemars::JsonSchema)]
+		#[serde(crate = "::genai::__private::serde")]
+		#[schemars(crate = "::genai::__private::schemars")]
+		struct #params_ident {
+			#(#fields),*
+		}
+
+		#[allow(dead_code)]
+		impl #fn_name {
+			/// The tool function schema, in the OpenAI function format (see `genai::chat::schema_for_fn_single_param`).
+			pub fn schema() -> ::genai::__private::serde_json::Value {
+				::genai::chat::schema_for_fn_single_param::<#params_ident>(#fn_name_str, #description)
+			}
+
+			/// Invoke the tool function with the LLM JSON arguments, and returns its output serialized as JSON.
+			pub fn invoke(args: ::genai::__private::serde_json::Value) -> ::genai::Result<String> {
+				// Note: The LLM might send no arguments for the functions without params.
+				let args = if args.is_null() {
+					::genai::__private::serde_json::Value::Object(Default::default())
+				} else {
+					args
+				};
+				::genai::chat::invoke_with_typed_args(|params: #params_ident| #fn_name(#(params.#param_names),*), args)
+			}
+		}
+
+		impl ::genai::chat::ToolFn for #fn_name {
+			const NAME: &'static str = #fn_name_str;
+
+			fn schema() -> ::genai::__private::serde_json::Value {
+				#fn_name::schema()
+			}
+
+			fn invoke(args: ::genai::__private::serde_json::Value) -> ::genai::Result<String> {
+				#fn_name::invoke(args)
+			}
+		}
+	})
+}
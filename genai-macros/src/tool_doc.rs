@@ -0,0 +1,24 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, Error, ItemStruct, LitStr, Result};
+
+pub fn expand_tool_doc(args: TokenStream, item: TokenStream) -> Result<TokenStream> {
+	let description: LitStr = syn::parse2(args).map_err(|err| {
+		Error::new(
+			err.span(),
+			"#[tool_doc] requires the description, e.g., #[tool_doc(\"...\")]",
+		)
+	})?;
+	if description.value().trim().is_empty() {
+		return Err(Error::new_spanned(
+			description,
+			"#[tool_doc] description cannot be empty",
+		));
+	}
+	let mut item_struct: ItemStruct = syn::parse2(item)?;
+
+	// Note: Added last, so it is after the `#[derive(JsonSchema)]` introducing the `schemars` helper attribute.
+	item_struct.attrs.push(parse_quote!(#[schemars(description = #description)]));
+
+	Ok(quote! { #item_struct })
+}
@@ -0,0 +1,50 @@
+use crate::adapter::openai::{has_response_schema, OpenAIResponseSchema};
+use crate::adapter::AdapterKind;
+use crate::{Error, Result};
+
+/// The configuration of an adapter kind for a client (see `ClientConfig::with_adapter_config`).
+#[derive(Debug, Clone, Copy)]
+pub struct AdapterConfig {
+	adapter_kind: AdapterKind,
+	response_schema: Option<OpenAIResponseSchema>,
+}
+
+/// Constructors
+impl AdapterConfig {
+	pub fn new(adapter_kind: AdapterKind) -> Self {
+		Self {
+			adapter_kind,
+			response_schema: None,
+		}
+	}
+}
+
+/// Chainable Setters
+impl AdapterConfig {
+	/// Set the response schema of an OpenAI compatible adapter kind (OpenAI, Ollama, Groq, xAI, DeepSeek),
+	/// e.g., for an OpenAI compatible provider with a different content path.
+	///
+	/// Returns `Error::AdapterConfigInvalid` for the other adapter kinds.
+	pub fn with_response_schema(mut self, schema: OpenAIResponseSchema) -> Result<Self> {
+		if !has_response_schema(self.adapter_kind) {
+			return Err(Error::AdapterConfigInvalid {
+				adapter_kind: self.adapter_kind,
+				cause: "not an OpenAI compatible adapter",
+			});
+		}
+		self.response_schema = Some(schema);
+		Ok(self)
+	}
+}
+
+/// Getters
+impl AdapterConfig {
+	pub fn adapter_kind(&self) -> AdapterKind {
+		self.adapter_kind
+	}
+
+	/// The response schema, if set (otherwise, `OpenAIResponseSchema::OPENAI` for the OpenAI compatible adapters).
+	pub fn response_schema(&self) -> Option<&OpenAIResponseSchema> {
+		self.response_schema.as_ref()
+	}
+}
@@ -0,0 +1,32 @@
+//! The adapter-specific errors, parsed from the provider API error bodies (see `Error::ApiError`).
+
+use crate::adapter::anthropic::AnthropicError;
+use crate::adapter::gemini::GeminiError;
+use crate::adapter::openai::OpenAIError;
+use crate::adapter::AdapterKind;
+use std::any::Any;
+
+/// A structured error of a provider API (e.g., `OpenAIError`), to be matched with `Error::downcast_adapter_error`.
+pub trait AdapterError: std::error::Error + Send + Sync + 'static {
+	/// The provider error type (e.g., `insufficient_quota` for OpenAI, `RESOURCE_EXHAUSTED` for Gemini).
+	fn error_type(&self) -> &str;
+
+	fn message(&self) -> &str;
+
+	/// For `Error::downcast_adapter_error` (returns `self`).
+	fn as_any(&self) -> &dyn Any;
+}
+
+/// Parse the error body of the adapter format, if it is a structured error of this format.
+///
+/// Note: The OpenAI compatible adapters (e.g., Groq, xAI) are parsed as `OpenAIError`.
+pub(crate) fn parse_adapter_error(adapter_kind: AdapterKind, body: &str) -> Option<Box<dyn AdapterError>> {
+	match adapter_kind {
+		AdapterKind::OpenAI | AdapterKind::Groq | AdapterKind::Xai | AdapterKind::DeepSeek | AdapterKind::Ollama => {
+			OpenAIError::from_body(body).map(|err| Box::new(err) as Box<dyn AdapterError>)
+		}
+		AdapterKind::Anthropic => AnthropicError::from_body(body).map(|err| Box::new(err) as Box<dyn AdapterError>),
+		AdapterKind::Gemini => GeminiError::from_body(body).map(|err| Box::new(err) as Box<dyn AdapterError>),
+		AdapterKind::Cohere => None,
+	}
+}
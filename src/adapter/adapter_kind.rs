@@ -1,4 +1,10 @@
+use super::anthropic::MODELS as ANTHROPIC_MODELS;
+use super::cohere::MODELS as COHERE_MODELS;
+use super::deepseek::MODELS as DEEPSEEK_MODELS;
+use super::gemini::MODELS as GEMINI_MODELS;
 use super::groq::MODELS as GROQ_MODELS;
+use super::openai::MODELS as OPENAI_MODELS;
+use super::xai::MODELS as XAI_MODELS;
 use crate::adapter::anthropic::AnthropicAdapter;
 use crate::adapter::cohere::CohereAdapter;
 use crate::adapter::deepseek::DeepSeekAdapter;
@@ -6,9 +12,10 @@ use crate::adapter::gemini::GeminiAdapter;
 use crate::adapter::groq::GroqAdapter;
 use crate::adapter::openai::OpenAIAdapter;
 use crate::adapter::xai::XaiAdapter;
-use crate::Result;
+use crate::{Error, Result};
 use derive_more::Display;
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 
 /// AdapterKind is an enum that represents the different types of adapters that can be used to interact with the API.
 #[derive(Debug, Clone, Copy, Display, Eq, PartialEq, Hash, Serialize, Deserialize)]
@@ -64,8 +71,39 @@ impl AdapterKind {
 	}
 }
 
+/// Parse from the adapter kind name, case-insensitive (e.g., `"openai"`, `"Anthropic"`, `"GEMINI"`).
+impl FromStr for AdapterKind {
+	type Err = Error;
+
+	fn from_str(name: &str) -> Result<Self> {
+		match name.to_lowercase().as_str() {
+			"openai" => Ok(AdapterKind::OpenAI),
+			"ollama" => Ok(AdapterKind::Ollama),
+			"anthropic" => Ok(AdapterKind::Anthropic),
+			"cohere" => Ok(AdapterKind::Cohere),
+			"gemini" => Ok(AdapterKind::Gemini),
+			"groq" => Ok(AdapterKind::Groq),
+			"xai" => Ok(AdapterKind::Xai),
+			"deepseek" => Ok(AdapterKind::DeepSeek),
+			_ => Err(Error::AdapterKindUnknown { name: name.to_string() }),
+		}
+	}
+}
+
 /// Utilities
 impl AdapterKind {
+	/// All of the adapter kinds (e.g., for `Client::diagnose`).
+	pub const ALL: &'static [AdapterKind] = &[
+		AdapterKind::OpenAI,
+		AdapterKind::Ollama,
+		AdapterKind::Anthropic,
+		AdapterKind::Cohere,
+		AdapterKind::Gemini,
+		AdapterKind::Groq,
+		AdapterKind::Xai,
+		AdapterKind::DeepSeek,
+	];
+
 	/// Get the default key environment variable name for the adapter kind.
 	pub fn default_key_env_name(&self) -> Option<&'static str> {
 		match self {
@@ -79,6 +117,22 @@ impl AdapterKind {
 			AdapterKind::Ollama => None,
 		}
 	}
+
+	/// Get the static model names of the adapter kind (empty for Ollama, which has local models).
+	///
+	/// Note: The lists are not exhaustive (see `ModelValidator`).
+	pub fn static_model_names(&self) -> &'static [&'static str] {
+		match self {
+			AdapterKind::OpenAI => OPENAI_MODELS,
+			AdapterKind::Anthropic => ANTHROPIC_MODELS,
+			AdapterKind::Cohere => COHERE_MODELS,
+			AdapterKind::Gemini => GEMINI_MODELS,
+			AdapterKind::Groq => GROQ_MODELS,
+			AdapterKind::Xai => XAI_MODELS,
+			AdapterKind::DeepSeek => DEEPSEEK_MODELS,
+			AdapterKind::Ollama => &[],
+		}
+	}
 }
 
 /// From Model implementations
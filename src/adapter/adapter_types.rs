@@ -1,9 +1,11 @@
 use crate::adapter::AdapterKind;
+use crate::audio::{TranscriptionRequest, TranscriptionResponse};
 use crate::chat::{ChatOptionsSet, ChatRequest, ChatResponse, ChatStreamResponse};
 use crate::resolver::{AuthData, Endpoint};
 use crate::webc::WebResponse;
 use crate::ModelIden;
 use crate::{Result, ServiceTarget};
+use reqwest::multipart::Form;
 use reqwest::RequestBuilder;
 use serde_json::Value;
 
@@ -20,7 +22,8 @@ pub trait Adapter {
 
 	/// The base service URL for this AdapterKind for the given service type.
 	/// NOTE: For some services, the URL will be further updated in the to_web_request_data method.
-	fn get_service_url(model_iden: &ModelIden, service_type: ServiceType, endpoint: Endpoint) -> String;
+	/// Returns `Error::TranscriptionNotSupported` for the `AudioTranscription` of the adapters without transcription.
+	fn get_service_url(model_iden: &ModelIden, service_type: ServiceType, endpoint: Endpoint) -> Result<String>;
 
 	/// To be implemented by Adapters.
 	fn to_web_request_data(
@@ -41,12 +44,23 @@ pub trait Adapter {
 	) -> Result<ChatStreamResponse>;
 }
 
+/// Sub-trait for the adapters supporting the audio transcription (OpenAI compatible `audio/transcriptions`).
+pub trait TranscriptionAdapter: Adapter {
+	fn to_transcription_request_data(
+		service_target: ServiceTarget,
+		transcription_req: TranscriptionRequest,
+	) -> Result<TranscriptionRequestData>;
+
+	fn from_transcription_response(model_iden: ModelIden, web_response: WebResponse) -> Result<TranscriptionResponse>;
+}
+
 // region:    --- ServiceType
 
 #[derive(Debug, Clone, Copy)]
 pub enum ServiceType {
 	Chat,
 	ChatStream,
+	AudioTranscription,
 }
 
 // endregion: --- ServiceType
@@ -61,4 +75,12 @@ pub struct WebRequestData {
 	pub payload: Value,
 }
 
+/// The multipart request data for the audio transcription.
+#[derive(Debug)]
+pub struct TranscriptionRequestData {
+	pub url: String,
+	pub headers: Vec<(String, String)>,
+	pub form: Form,
+}
+
 // endregion: --- WebRequestData
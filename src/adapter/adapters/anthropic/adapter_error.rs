@@ -0,0 +1,42 @@
+use crate::adapter::AdapterError;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::any::Any;
+
+/// The Anthropic API error (`{"type": "error", "error": {"type", "message"}}`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnthropicError {
+	/// e.g., `invalid_request_error`, `rate_limit_error`, `overloaded_error`
+	#[serde(rename = "type")]
+	pub type_: String,
+	pub message: String,
+}
+
+impl AnthropicError {
+	pub(crate) fn from_body(body: &str) -> Option<Self> {
+		let mut body: Value = serde_json::from_str(body).ok()?;
+		serde_json::from_value(body.get_mut("error")?.take()).ok()
+	}
+}
+
+impl AdapterError for AnthropicError {
+	fn error_type(&self) -> &str {
+		&self.type_
+	}
+
+	fn message(&self) -> &str {
+		&self.message
+	}
+
+	fn as_any(&self) -> &dyn Any {
+		self
+	}
+}
+
+impl core::fmt::Display for AnthropicError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "{} ({})", self.message, self.type_)
+	}
+}
+
+impl std::error::Error for AnthropicError {}
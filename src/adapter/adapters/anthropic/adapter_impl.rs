@@ -1,14 +1,14 @@
-use crate::adapter::adapters::support::get_api_key;
+use crate::adapter::adapters::support::{get_api_key, insert_extra_params};
 use crate::adapter::anthropic::AnthropicStreamer;
 use crate::adapter::{Adapter, AdapterKind, ServiceType, WebRequestData};
 use crate::chat::{
-	ChatOptionsSet, ChatRequest, ChatResponse, ChatRole, ChatStream, ChatStreamResponse, ContentPart, ImageSource,
-	MessageContent, MetaUsage, ToolCall,
+	ChatOptionsSet, ChatRequest, ChatResponse, ChatRole, ChatStream, ChatStreamResponse, Citation, ContentPart,
+	ImageSource, MessageContent, MetaUsage, ToolCall, ToolType,
 };
 use crate::resolver::{AuthData, Endpoint};
 use crate::webc::WebResponse;
 use crate::ModelIden;
-use crate::{Result, ServiceTarget};
+use crate::{Error, Result, ServiceTarget};
 use reqwest::RequestBuilder;
 use reqwest_eventsource::EventSource;
 use serde_json::{json, Value};
@@ -23,7 +23,9 @@ const MAX_TOKENS_8K: u32 = 8192;
 const MAX_TOKENS_4K: u32 = 4096;
 
 const ANTHROPIC_VERSION: &str = "2023-06-01";
-const MODELS: &[&str] = &[
+/// The `anthropic-beta` header value for the computer use tools.
+const COMPUTER_USE_BETA: &str = "computer-use-2024-10-22";
+pub(in crate::adapter) const MODELS: &[&str] = &[
 	"claude-3-5-sonnet-20241022",
 	"claude-3-5-haiku-20241022",
 	"claude-3-opus-20240229",
@@ -49,10 +51,13 @@ impl Adapter for AnthropicAdapter {
 		Ok(MODELS.iter().map(|s| s.to_string()).collect())
 	}
 
-	fn get_service_url(_model: &ModelIden, service_type: ServiceType, endpoint: Endpoint) -> String {
+	fn get_service_url(model: &ModelIden, service_type: ServiceType, endpoint: Endpoint) -> Result<String> {
 		let base_url = endpoint.base_url();
 		match service_type {
-			ServiceType::Chat | ServiceType::ChatStream => format!("{base_url}messages"),
+			ServiceType::Chat | ServiceType::ChatStream => Ok(format!("{base_url}messages")),
+			ServiceType::AudioTranscription => Err(Error::TranscriptionNotSupported {
+				model_iden: model.clone(),
+			}),
 		}
 	}
 
@@ -68,14 +73,7 @@ impl Adapter for AnthropicAdapter {
 		let api_key = get_api_key(auth, &model)?;
 
 		// -- url
-		let url = Self::get_service_url(&model, service_type, endpoint);
-
-		// -- headers
-		let headers = vec![
-			// headers
-			("x-api-key".to_string(), api_key),
-			("anthropic-version".to_string(), ANTHROPIC_VERSION.to_string()),
-		];
+		let url = Self::get_service_url(&model, service_type, endpoint)?;
 
 		let model_name = model.model_name.clone();
 
@@ -84,8 +82,19 @@ impl Adapter for AnthropicAdapter {
 			system,
 			messages,
 			tools,
+			has_computer_use,
 		} = Self::into_anthropic_request_parts(model, chat_req)?;
 
+		// -- headers
+		let mut headers = vec![
+			// headers
+			("x-api-key".to_string(), api_key),
+			("anthropic-version".to_string(), ANTHROPIC_VERSION.to_string()),
+		];
+		if has_computer_use {
+			headers.push(("anthropic-beta".to_string(), COMPUTER_USE_BETA.to_string()));
+		}
+
 		// -- Build the basic payload
 
 		let stream = matches!(service_type, ServiceType::ChatStream);
@@ -125,11 +134,16 @@ impl Adapter for AnthropicAdapter {
 			payload.x_insert("top_p", top_p)?;
 		}
 
+		// -- Add the eventual extra params (last, so they can override)
+		insert_extra_params(&mut payload, &options_set)?;
+
 		Ok(WebRequestData { url, headers, payload })
 	}
 
 	fn to_chat_response(model_iden: ModelIden, web_response: WebResponse) -> Result<ChatResponse> {
-		let WebResponse { mut body, .. } = web_response;
+		let WebResponse {
+			mut body, request_id, ..
+		} = web_response;
 
 		// -- Capture the usage
 		let usage = body.x_take("usage").map(Self::into_usage).unwrap_or_default();
@@ -145,23 +159,25 @@ impl Adapter for AnthropicAdapter {
 		let content_items: Vec<Value> = body.x_take("content")?;
 
 		let mut text_content: Vec<String> = Vec::new();
+		let mut citations: Vec<Citation> = Vec::new();
 		// Note: here tool_calls is probably the exception, so not creating the vector if not needed
 		let mut tool_calls: Option<Vec<ToolCall>> = None;
 
 		for mut item in content_items {
 			let typ: &str = item.x_get_as("type")?;
 			if typ == "text" {
-				text_content.push(item.x_take("text")?);
+				let text: String = item.x_take("text")?;
+				// The cited range is the text block, in the "\n" joined content
+				let start_index = text_content.iter().map(|text| text.len() + 1).sum::<usize>();
+				let end_index = start_index + text.len();
+				for citation in item.x_take::<Vec<Value>>("citations").unwrap_or_default() {
+					if let Some(citation) = Self::into_citation(citation, start_index, end_index) {
+						citations.push(citation);
+					}
+				}
+				text_content.push(text);
 			} else if typ == "tool_use" {
-				let call_id = item.x_take::<String>("id")?;
-				let fn_name = item.x_take::<String>("name")?;
-				// if not found, will be Value::Null
-				let fn_arguments = item.x_take::<Value>("input").unwrap_or_default();
-				let tool_call = ToolCall {
-					call_id,
-					fn_name,
-					fn_arguments,
-				};
+				let tool_call = ToolCall::from_anthropic_value(item)?;
 				tool_calls.get_or_insert_with(Vec::new).push(tool_call);
 			}
 		}
@@ -176,6 +192,10 @@ impl Adapter for AnthropicAdapter {
 			content,
 			model_iden,
 			usage,
+			request_id,
+			client_request_id: None,
+			adapter_meta: None,
+			citations,
 		})
 	}
 
@@ -186,10 +206,11 @@ impl Adapter for AnthropicAdapter {
 	) -> Result<ChatStreamResponse> {
 		let event_source = EventSource::new(reqwest_builder)?;
 		let anthropic_stream = AnthropicStreamer::new(event_source, model_iden.clone(), options_set);
-		let chat_stream = ChatStream::from_inter_stream(anthropic_stream);
+		let chat_stream = ChatStream::from_inter_stream(anthropic_stream, model_iden.clone());
 		Ok(ChatStreamResponse {
 			model_iden,
 			stream: chat_stream,
+			client_request_id: None,
 		})
 	}
 }
@@ -197,9 +218,22 @@ impl Adapter for AnthropicAdapter {
 // region:    --- Support
 
 impl AnthropicAdapter {
-	pub(super) fn into_usage(mut usage_value: Value) -> MetaUsage {
+	/// Note: Only the citations with a `url` (e.g., `web_search_result_location`) are captured.
+	fn into_citation(mut citation: Value, start_index: usize, end_index: usize) -> Option<Citation> {
+		Some(Citation {
+			url: citation.x_take("url").ok()?,
+			title: citation.x_take("title").ok(),
+			snippet: citation.x_take("cited_text").ok(),
+			start_index: Some(start_index),
+			end_index: Some(end_index),
+		})
+	}
+
+	pub(crate) fn into_usage(mut usage_value: Value) -> MetaUsage {
 		let input_tokens: Option<i32> = usage_value.x_take("input_tokens").ok();
 		let output_tokens: Option<i32> = usage_value.x_take("output_tokens").ok();
+		let cache_read_input_tokens: Option<i32> = usage_value.x_take("cache_read_input_tokens").ok();
+		let cache_creation_input_tokens: Option<i32> = usage_value.x_take("cache_creation_input_tokens").ok();
 
 		// Compute total_tokens
 		let total_tokens = if input_tokens.is_some() || output_tokens.is_some() {
@@ -212,12 +246,48 @@ impl AnthropicAdapter {
 			input_tokens,
 			output_tokens,
 			total_tokens,
+			cache_read_input_tokens,
+			cache_creation_input_tokens,
+			accepted_prediction_tokens: None,
+			rejected_prediction_tokens: None,
 		}
 	}
 
+	/// The Anthropic content block of a user message part.
+	fn into_anthropic_content_block(model_iden: &ModelIden, part: &ContentPart) -> Result<Value> {
+		let block = match part {
+			ContentPart::Text(text) => json!({"type": "text", "text": text.clone()}),
+			ContentPart::Image {
+				content_type, source, ..
+			} => match source {
+				ImageSource::Url(_) => {
+					return Err(Error::MessageContentTypeNotSupported {
+						model_iden: model_iden.clone(),
+						cause: "Image URL not supported by Anthropic (use a base64 image)",
+					})
+				}
+				ImageSource::Base64(content) => json!({
+					"type": "image",
+					"source": {
+						"type": "base64",
+						"media_type": content_type,
+						"data": content,
+					},
+				}),
+			},
+			// Mark the cacheable blocks (the prompt prefix up to this block is cached)
+			ContentPart::WithCache(part) => {
+				let mut block = Self::into_anthropic_content_block(model_iden, part)?;
+				block["cache_control"] = json!({"type": "ephemeral"});
+				block
+			}
+		};
+		Ok(block)
+	}
+
 	/// Takes the GenAI ChatMessages and constructs the System string and JSON Messages for Anthropic.
 	/// - Will push the `ChatRequest.system` and system message to `AnthropicRequestParts.system`
-	fn into_anthropic_request_parts(_model_iden: ModelIden, chat_req: ChatRequest) -> Result<AnthropicRequestParts> {
+	fn into_anthropic_request_parts(model_iden: ModelIden, chat_req: ChatRequest) -> Result<AnthropicRequestParts> {
 		let mut messages: Vec<Value> = Vec::new();
 		let mut systems: Vec<String> = Vec::new();
 
@@ -238,27 +308,10 @@ impl AnthropicAdapter {
 				ChatRole::User => {
 					let content = match msg.content {
 						MessageContent::Text(content) => json!(content),
-						MessageContent::Parts(parts) => {
-							json!(parts
-								.iter()
-								.map(|part| match part {
-									ContentPart::Text(text) => json!({"type": "text", "text": text.clone()}),
-									ContentPart::Image { content_type, source } => {
-										match source {
-										ImageSource::Url(_) => todo!("Anthropic doesn't support images from URL, need to handle it gracefully"),
-										ImageSource::Base64(content) => json!({
-					                    "type": "image",
-					                    "source": {
-					                        "type": "base64",
-					                        "media_type": content_type,
-					                        "data": content,
-					                    },
-					                }),
-									}
-									}
-								})
-								.collect::<Vec<Value>>())
-						}
+						MessageContent::Parts(parts) => json!(parts
+							.iter()
+							.map(|part| Self::into_anthropic_content_block(&model_iden, part))
+							.collect::<Result<Vec<Value>>>()?),
 						// Use `match` instead of `if let`. This will allow to future-proof this
 						// implementation in case some new message content types would appear,
 						// this way the library would not compile if not all methods are implemented
@@ -266,6 +319,14 @@ impl AnthropicAdapter {
 						// TODO: Probably need to warn if it is a ToolCalls type of content
 						MessageContent::ToolCalls(_) => continue,
 						MessageContent::ToolResponses(_) => continue,
+						// The raw json is used as is
+						MessageContent::Json(content) => content,
+						MessageContent::Audio { .. } => {
+							return Err(Error::MessageContentTypeNotSupported {
+								model_iden,
+								cause: "MessageContent::Audio not supported for this model",
+							})
+						}
 					};
 					messages.push(json! ({"role": "user", "content": content}));
 				}
@@ -275,6 +336,9 @@ impl AnthropicAdapter {
 						MessageContent::Text(content) => {
 							messages.push(json! ({"role": "assistant", "content": content}))
 						}
+						MessageContent::Json(content) => {
+							messages.push(json! ({"role": "assistant", "content": content}))
+						}
 						MessageContent::ToolCalls(tool_calls) => {
 							let tool_calls = tool_calls
 								.into_iter()
@@ -296,6 +360,7 @@ impl AnthropicAdapter {
 						// TODO: Probably need to trace/warn that this will be ignored
 						MessageContent::Parts(_) => (),
 						MessageContent::ToolResponses(_) => (),
+						MessageContent::Audio { .. } => (),
 					}
 				}
 				ChatRole::Tool => {
@@ -331,10 +396,29 @@ impl AnthropicAdapter {
 		};
 
 		// -- Process the tools
+		let has_computer_use = chat_req
+			.tools
+			.as_ref()
+			.is_some_and(|tools| tools.iter().any(|tool| matches!(tool.tool_type, ToolType::ComputerUse(_))));
 		let tools = chat_req.tools.map(|tools| {
 			tools
 				.into_iter()
 				.map(|tool| {
+					// -- The computer use tools have their own type, and no schema
+					if let ToolType::ComputerUse(computer_use_tool) = tool.tool_type {
+						let mut tool_value = json!({
+							"type": computer_use_tool.kind.type_name(),
+							"name": tool.name,
+						});
+						if let Some(width) = computer_use_tool.display_width_px {
+							let _ = tool_value.x_insert("display_width_px", width);
+						}
+						if let Some(height) = computer_use_tool.display_height_px {
+							let _ = tool_value.x_insert("display_height_px", height);
+						}
+						return tool_value;
+					}
+
 					// TODO: Need to handle the error correctly
 					// TODO: Needs to have a custom serializer (tool should not have to match to a provider)
 					// NOTE: Right now, low probability, so we just return null if cannot convert to value.
@@ -356,6 +440,7 @@ impl AnthropicAdapter {
 			system,
 			messages,
 			tools,
+			has_computer_use,
 		})
 	}
 }
@@ -364,6 +449,8 @@ struct AnthropicRequestParts {
 	system: Option<String>,
 	messages: Vec<Value>,
 	tools: Option<Vec<Value>>,
+	/// When true, the computer use beta header is added.
+	has_computer_use: bool,
 }
 
 // endregion: --- Support
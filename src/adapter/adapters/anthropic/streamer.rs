@@ -113,7 +113,7 @@ impl futures::Stream for AnthropicStreamer {
 				}
 				Some(Err(err)) => {
 					println!("Error: {}", err);
-					return Poll::Ready(Some(Err(Error::ReqwestEventSource(err))));
+					return Poll::Ready(Some(Err(Error::ReqwestEventSource(Box::new(err)))));
 				}
 				None => return Poll::Ready(None),
 			}
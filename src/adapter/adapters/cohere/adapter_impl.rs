@@ -1,4 +1,4 @@
-use crate::adapter::adapters::support::get_api_key;
+use crate::adapter::adapters::support::{get_api_key, insert_extra_params};
 use crate::adapter::cohere::CohereStreamer;
 use crate::adapter::{Adapter, AdapterKind, ServiceType, WebRequestData};
 use crate::chat::{
@@ -14,7 +14,7 @@ use value_ext::JsonValueExt;
 
 pub struct CohereAdapter;
 
-const MODELS: &[&str] = &[
+pub(in crate::adapter) const MODELS: &[&str] = &[
 	"command-r-plus",
 	"command-r",
 	"command",
@@ -42,10 +42,13 @@ impl Adapter for CohereAdapter {
 		Ok(MODELS.iter().map(|s| s.to_string()).collect())
 	}
 
-	fn get_service_url(_model: &ModelIden, service_type: ServiceType, endpoint: Endpoint) -> String {
+	fn get_service_url(model: &ModelIden, service_type: ServiceType, endpoint: Endpoint) -> Result<String> {
 		let base_url = endpoint.base_url();
 		match service_type {
-			ServiceType::Chat | ServiceType::ChatStream => format!("{base_url}chat"),
+			ServiceType::Chat | ServiceType::ChatStream => Ok(format!("{base_url}chat")),
+			ServiceType::AudioTranscription => Err(Error::TranscriptionNotSupported {
+				model_iden: model.clone(),
+			}),
 		}
 	}
 
@@ -61,7 +64,7 @@ impl Adapter for CohereAdapter {
 		let api_key = get_api_key(auth, &model)?;
 
 		// -- url
-		let url = Self::get_service_url(&model, service_type, endpoint);
+		let url = Self::get_service_url(&model, service_type, endpoint)?;
 
 		// -- headers
 		let headers = vec![
@@ -110,11 +113,16 @@ impl Adapter for CohereAdapter {
 			payload.x_insert("p", top_p)?;
 		}
 
+		// -- Add the eventual extra params (last, so they can override)
+		insert_extra_params(&mut payload, &options_set)?;
+
 		Ok(WebRequestData { url, headers, payload })
 	}
 
 	fn to_chat_response(model_iden: ModelIden, web_response: WebResponse) -> Result<ChatResponse> {
-		let WebResponse { mut body, .. } = web_response;
+		let WebResponse {
+			mut body, request_id, ..
+		} = web_response;
 
 		// -- Get usage
 		let usage = body.x_take("/meta/tokens").map(Self::into_usage).unwrap_or_default();
@@ -132,6 +140,10 @@ impl Adapter for CohereAdapter {
 			content,
 			model_iden,
 			usage,
+			request_id,
+			client_request_id: None,
+			adapter_meta: None,
+			citations: Vec::new(),
 		})
 	}
 
@@ -142,11 +154,12 @@ impl Adapter for CohereAdapter {
 	) -> Result<ChatStreamResponse> {
 		let web_stream = WebStream::new_with_delimiter(reqwest_builder, "\n");
 		let cohere_stream = CohereStreamer::new(web_stream, model_iden.clone(), options_set);
-		let chat_stream = ChatStream::from_inter_stream(cohere_stream);
+		let chat_stream = ChatStream::from_inter_stream(cohere_stream, model_iden.clone());
 
 		Ok(ChatStreamResponse {
 			model_iden,
 			stream: chat_stream,
+			client_request_id: None,
 		})
 	}
 }
@@ -162,7 +175,7 @@ impl CohereAdapter {
 	///    "output_tokens": 24
 	///  }
 	/// ```
-	pub(super) fn into_usage(mut usage_value: Value) -> MetaUsage {
+	pub(crate) fn into_usage(mut usage_value: Value) -> MetaUsage {
 		let input_tokens: Option<i32> = usage_value.x_take("input_tokens").ok();
 		let output_tokens: Option<i32> = usage_value.x_take("output_tokens").ok();
 
@@ -177,6 +190,10 @@ impl CohereAdapter {
 			input_tokens,
 			output_tokens,
 			total_tokens,
+			cache_read_input_tokens: None,
+			cache_creation_input_tokens: None,
+			accepted_prediction_tokens: None,
+			rejected_prediction_tokens: None,
 		}
 	}
 
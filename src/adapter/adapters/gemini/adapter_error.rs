@@ -0,0 +1,46 @@
+use crate::adapter::AdapterError;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::any::Any;
+
+/// The Gemini API error (`{"error": {"code", "message", "status"}}`, sometimes wrapped in an array).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeminiError {
+	/// The HTTP status code (e.g., `429`).
+	pub code: i32,
+	pub message: String,
+	/// e.g., `INVALID_ARGUMENT`, `RESOURCE_EXHAUSTED`
+	pub status: String,
+}
+
+impl GeminiError {
+	pub(crate) fn from_body(body: &str) -> Option<Self> {
+		let mut body: Value = serde_json::from_str(body).ok()?;
+		if let Value::Array(items) = body {
+			body = items.into_iter().next()?;
+		}
+		serde_json::from_value(body.get_mut("error")?.take()).ok()
+	}
+}
+
+impl AdapterError for GeminiError {
+	fn error_type(&self) -> &str {
+		&self.status
+	}
+
+	fn message(&self) -> &str {
+		&self.message
+	}
+
+	fn as_any(&self) -> &dyn Any {
+		self
+	}
+}
+
+impl core::fmt::Display for GeminiError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "{} ({})", self.message, self.status)
+	}
+}
+
+impl std::error::Error for GeminiError {}
@@ -1,21 +1,22 @@
-use crate::adapter::adapters::support::get_api_key;
+use crate::adapter::adapters::support::{get_api_key, insert_extra_params};
 use crate::adapter::gemini::GeminiStreamer;
 use crate::adapter::{Adapter, AdapterKind, ServiceType, WebRequestData};
 use crate::chat::{
-	ChatOptionsSet, ChatRequest, ChatResponse, ChatResponseFormat, ChatRole, ChatStream, ChatStreamResponse,
-	ContentPart, ImageSource, MessageContent, MetaUsage,
+	ChatOptionsSet, ChatRequest, ChatResponse, ChatResponseFormat, ChatRole, ChatStream, ChatStreamResponse, Citation,
+	ContentPart, ImageSource, MessageContent, MetaUsage, ToolCall,
 };
 use crate::resolver::{AuthData, Endpoint};
 use crate::webc::{WebResponse, WebStream};
 use crate::{Error, Result};
 use crate::{ModelIden, ServiceTarget};
 use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 use value_ext::JsonValueExt;
 
 pub struct GeminiAdapter;
 
-const MODELS: &[&str] = &[
+pub(in crate::adapter) const MODELS: &[&str] = &[
 	"gemini-1.5-pro",
 	"gemini-2.0-flash-exp",
 	"gemini-1.5-flash",
@@ -51,12 +52,15 @@ impl Adapter for GeminiAdapter {
 
 	/// NOTE: As Google Gemini has decided to put their API_KEY in the URL,
 	///       this will return the URL without the API_KEY in it. The API_KEY will need to be added by the caller.
-	fn get_service_url(model: &ModelIden, service_type: ServiceType, endpoint: Endpoint) -> String {
+	fn get_service_url(model: &ModelIden, service_type: ServiceType, endpoint: Endpoint) -> Result<String> {
 		let base_url = endpoint.base_url();
 		let model_name = model.model_name.clone();
 		match service_type {
-			ServiceType::Chat => format!("{base_url}models/{model_name}:generateContent"),
-			ServiceType::ChatStream => format!("{base_url}models/{model_name}:streamGenerateContent"),
+			ServiceType::Chat => Ok(format!("{base_url}models/{model_name}:generateContent")),
+			ServiceType::ChatStream => Ok(format!("{base_url}models/{model_name}:streamGenerateContent")),
+			ServiceType::AudioTranscription => Err(Error::TranscriptionNotSupported {
+				model_iden: model.clone(),
+			}),
 		}
 	}
 
@@ -76,7 +80,7 @@ impl Adapter for GeminiAdapter {
 		//       This should be considered an antipattern from a security point of view
 		//       even if it is done by the well respected Google. Everybody can make mistake once in a while.
 		// e.g., '...models/gemini-1.5-flash-latest:generateContent?key=YOUR_API_KEY'
-		let url = Self::get_service_url(&model, service_type, endpoint);
+		let url = Self::get_service_url(&model, service_type, endpoint)?;
 		let url = format!("{url}?key={api_key}");
 
 		// -- parts
@@ -134,20 +138,39 @@ impl Adapter for GeminiAdapter {
 			payload.x_insert("/generationConfig/topP", top_p)?;
 		}
 
+		// -- Add the eventual extra params (last, so they can override)
+		insert_extra_params(&mut payload, &options_set)?;
+
 		Ok(WebRequestData { url, headers, payload })
 	}
 
 	fn to_chat_response(model_iden: ModelIden, web_response: WebResponse) -> Result<ChatResponse> {
-		let WebResponse { body, .. } = web_response;
+		let WebResponse { body, request_id, .. } = web_response;
 
 		let gemini_response = Self::body_to_gemini_chat_response(&model_iden.clone(), body)?;
-		let GeminiChatResponse { content, usage } = gemini_response;
-		let content = content.map(MessageContent::from);
+		let GeminiChatResponse {
+			content,
+			tool_calls,
+			usage,
+			meta,
+			citations,
+		} = gemini_response;
+
+		// Note: As for the other adapters, the tool calls take precedence over the text content
+		let content = if !tool_calls.is_empty() {
+			Some(MessageContent::from(tool_calls))
+		} else {
+			content.map(MessageContent::from)
+		};
 
 		Ok(ChatResponse {
 			content,
 			model_iden,
 			usage,
+			request_id,
+			client_request_id: None,
+			adapter_meta: Some(serde_json::to_value(meta)?),
+			citations,
 		})
 	}
 
@@ -159,11 +182,12 @@ impl Adapter for GeminiAdapter {
 		let web_stream = WebStream::new_with_pretty_json_array(reqwest_builder);
 
 		let gemini_stream = GeminiStreamer::new(web_stream, model_iden.clone(), options_set);
-		let chat_stream = ChatStream::from_inter_stream(gemini_stream);
+		let chat_stream = ChatStream::from_inter_stream(gemini_stream, model_iden.clone());
 
 		Ok(ChatStreamResponse {
 			model_iden,
 			stream: chat_stream,
+			client_request_id: None,
 		})
 	}
 }
@@ -181,16 +205,85 @@ impl GeminiAdapter {
 			});
 		}
 
-		let content = body.x_take::<Value>("/candidates/0/content/parts/0/text")?;
+		// Note: A candidate blocked by the safety settings has no content (the reason is in the safety ratings).
+		let is_safety_blocked = body.x_get_as::<&str>("/candidates/0/finishReason").is_ok_and(|r| r == "SAFETY");
+		let parts = if is_safety_blocked {
+			body.x_take::<Vec<Value>>("/candidates/0/content/parts").unwrap_or_default()
+		} else {
+			body.x_take::<Vec<Value>>("/candidates/0/content/parts")?
+		};
 		let usage = body.x_take::<Value>("usageMetadata").map(Self::into_usage).unwrap_or_default();
+		let meta = GeminiResponseMeta {
+			model_version: body.x_take("modelVersion").ok(),
+			safety_ratings: body.x_take("/candidates/0/safetyRatings").unwrap_or_default(),
+			block_reason: if is_safety_blocked {
+				body.x_take("/candidates/0/finishReason").ok()
+			} else {
+				None
+			},
+		};
+		let citations = body
+			.x_take::<Value>("/candidates/0/groundingMetadata")
+			.map(Self::into_citations)
+			.unwrap_or_default();
+
+		// -- Capture the text and functionCall parts
+		let mut texts: Vec<String> = Vec::new();
+		let mut tool_calls: Vec<ToolCall> = Vec::new();
+		for mut part in parts {
+			if let Ok(function_call) = part.x_take::<Value>("functionCall") {
+				tool_calls.push(ToolCall::from_gemini_value(function_call)?);
+			} else if let Ok(text) = part.x_take::<String>("text") {
+				texts.push(text);
+			}
+		}
+		let content = if texts.is_empty() { None } else { Some(texts.concat()) };
 
 		Ok(GeminiChatResponse {
-			content: content.as_str().map(String::from),
+			content,
+			tool_calls,
 			usage,
+			meta,
+			citations,
 		})
 	}
 
-	pub(super) fn into_usage(mut usage_value: Value) -> MetaUsage {
+	/// The `groundingChunks` are the sources, and the `groundingSupports` the content segments they support.
+	/// The snippet and range of a citation are from the first segment supported by its chunk.
+	fn into_citations(mut grounding_metadata: Value) -> Vec<Citation> {
+		let chunks: Vec<Value> = grounding_metadata.x_take("groundingChunks").unwrap_or_default();
+		let supports: Vec<Value> = grounding_metadata.x_take("groundingSupports").unwrap_or_default();
+
+		chunks
+			.into_iter()
+			.enumerate()
+			.filter_map(|(idx, mut chunk)| {
+				let mut web: Value = chunk.x_take("web").ok()?;
+				let segment = supports
+					.iter()
+					.find(|support| {
+						support
+							.x_get::<Vec<usize>>("groundingChunkIndices")
+							.is_ok_and(|indices| indices.contains(&idx))
+					})
+					.and_then(|support| support.get("segment"));
+				let segment_value = |name: &str| segment.and_then(|segment| segment.get(name));
+				Some(Citation {
+					url: web.x_take("uri").ok()?,
+					title: web.x_take("title").ok(),
+					snippet: segment_value("text").and_then(Value::as_str).map(String::from),
+					start_index: segment_value("startIndex")
+						.and_then(Value::as_u64)
+						.map(|idx| idx as usize)
+						// Note: The `startIndex` is omitted when 0 (proto3 default value)
+						.or(segment.map(|_| 0)),
+					end_index: segment_value("endIndex").and_then(Value::as_u64).map(|idx| idx as usize),
+				})
+			})
+			.collect()
+	}
+
+	pub(crate) fn into_usage(mut usage_value: Value) -> MetaUsage {
 		let input_tokens: Option<i32> = usage_value.x_take("promptTokenCount").ok();
 		let output_tokens: Option<i32> = usage_value.x_take("candidatesTokenCount").ok();
 		let total_tokens: Option<i32> = usage_value.x_take("totalTokenCount").ok();
@@ -198,6 +291,35 @@ impl GeminiAdapter {
 			input_tokens,
 			output_tokens,
 			total_tokens,
+			cache_read_input_tokens: None,
+			cache_creation_input_tokens: None,
+			accepted_prediction_tokens: None,
+			rejected_prediction_tokens: None,
+		}
+	}
+
+	/// The Gemini content of a user message part.
+	/// Note: The cache marker is not supported (cached contents are a separate API), so the cacheable part is sent as is.
+	fn into_gemini_content_part(part: &ContentPart) -> Value {
+		match part {
+			ContentPart::Text(text) => json!({"text": text.clone()}),
+			ContentPart::Image {
+				content_type, source, ..
+			} => match source {
+				ImageSource::Url(url) => json!({
+					"file_data": {
+						"mime_type": content_type,
+						"file_uri": url
+					}
+				}),
+				ImageSource::Base64(content) => json!({
+					"inline_data": {
+						"mime_type": content_type,
+						"data": content
+					}
+				}),
+			},
+			ContentPart::WithCache(part) => Self::into_gemini_content_part(part),
 		}
 	}
 
@@ -231,28 +353,7 @@ impl GeminiAdapter {
 					let content = match msg.content {
 						MessageContent::Text(content) => json!([{"text": content}]),
 						MessageContent::Parts(parts) => {
-							json!(parts
-								.iter()
-								.map(|part| match part {
-									ContentPart::Text(text) => json!({"text": text.clone()}),
-									ContentPart::Image { content_type, source } => {
-										match source {
-											ImageSource::Url(url) => json!({
-												"file_data": {
-													"mime_type": content_type,
-													"file_uri": url
-												}
-											}),
-											ImageSource::Base64(content) => json!({
-												"inline_data": {
-													"mime_type": content_type,
-													"data": content
-												}
-											}),
-										}
-									}
-								})
-								.collect::<Vec<Value>>())
+							json!(parts.iter().map(Self::into_gemini_content_part).collect::<Vec<Value>>())
 						}
 						// Use `match` instead of `if let`. This will allow to future-proof this
 						// implementation in case some new message content types would appear,
@@ -261,18 +362,30 @@ impl GeminiAdapter {
 						// TODO: Probably need to warn if it is a ToolCalls type of content
 						MessageContent::ToolCalls(_) => continue,
 						MessageContent::ToolResponses(_) => continue,
+						// The raw json is used as is (as the `parts`)
+						MessageContent::Json(content) => content,
+						MessageContent::Audio { .. } => {
+							return Err(Error::MessageContentTypeNotSupported {
+								model_iden,
+								cause: "MessageContent::Audio not supported for this model (for now)",
+							})
+						}
 					};
 
 					contents.push(json!({"role": "user", "parts": content}));
 				}
 				ChatRole::Assistant => {
-					let MessageContent::Text(content) = msg.content else {
-						return Err(Error::MessageContentTypeNotSupported {
-							model_iden,
-							cause: "Only MessageContent::Text supported for this model (for now)",
-						});
+					let parts = match msg.content {
+						MessageContent::Text(content) => json!([{"text": content}]),
+						MessageContent::Json(content) => content,
+						_ => {
+							return Err(Error::MessageContentTypeNotSupported {
+								model_iden,
+								cause: "Only MessageContent::Text or Json supported for this model (for now)",
+							})
+						}
 					};
-					contents.push(json!({"role": "model", "parts": [{"text": content}]}))
+					contents.push(json!({"role": "model", "parts": parts}))
 				}
 				ChatRole::Tool => {
 					return Err(Error::MessageRoleNotSupported {
@@ -297,7 +410,10 @@ impl GeminiAdapter {
 
 pub(super) struct GeminiChatResponse {
 	pub content: Option<String>,
+	pub tool_calls: Vec<ToolCall>,
 	pub usage: MetaUsage,
+	pub meta: GeminiResponseMeta,
+	pub citations: Vec<Citation>,
 }
 
 struct GeminiChatRequestParts {
@@ -307,3 +423,35 @@ struct GeminiChatRequestParts {
 }
 
 // endregion: --- Support
+
+// region:    --- GeminiResponseMeta
+
+/// The Gemini response metadata, available with `ChatResponse::gemini_meta()`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GeminiResponseMeta {
+	/// The `modelVersion` of the response (e.g., `gemini-1.5-flash-002`).
+	pub model_version: Option<String>,
+	/// The `safetyRatings` of the first candidate.
+	pub safety_ratings: Vec<GeminiSafetyRating>,
+	/// The `finishReason` of the first candidate when blocked by the safety settings (i.e., `SAFETY`).
+	#[serde(default)]
+	pub block_reason: Option<String>,
+}
+
+impl GeminiResponseMeta {
+	/// Returns true if the response has a `block_reason` or any safety rating blocked it.
+	pub fn is_blocked(&self) -> bool {
+		self.block_reason.is_some() || self.safety_ratings.iter().any(|rating| rating.blocked)
+	}
+}
+
+/// A Gemini candidate safety rating (e.g., `HARM_CATEGORY_HARASSMENT`, `NEGLIGIBLE`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeminiSafetyRating {
+	pub category: String,
+	pub probability: String,
+	#[serde(default)]
+	pub blocked: bool,
+}
+
+// endregion: --- GeminiResponseMeta
@@ -4,9 +4,11 @@
 
 // region:    --- Modules
 
+mod adapter_error;
 mod adapter_impl;
 mod streamer;
 
+pub use adapter_error::*;
 pub use adapter_impl::*;
 pub use streamer::*;
 
@@ -1,7 +1,7 @@
 use crate::adapter::adapters::support::{StreamerCapturedData, StreamerOptions};
 use crate::adapter::gemini::{GeminiAdapter, GeminiChatResponse};
 use crate::adapter::inter_stream::{InterStreamEnd, InterStreamEvent};
-use crate::chat::ChatOptionsSet;
+use crate::chat::{ChatOptionsSet, HarmBlockMode, MetaUsage};
 use crate::webc::WebStream;
 use crate::{Error, ModelIden, Result};
 use serde_json::Value;
@@ -27,6 +27,18 @@ impl GeminiStreamer {
 			captured_data: Default::default(),
 		}
 	}
+
+	/// Returns the usage of the last chunk having a `usageMetadata` (if `capture_usage`), called at the stream end.
+	///
+	/// Note: The Gemini usage is cumulative, so the last `usageMetadata` is the total (not to be summed).
+	fn finalize_usage(&mut self) -> Option<MetaUsage> {
+		let usage = self.captured_data.usage.take();
+		if self.options.capture_usage {
+			usage
+		} else {
+			None
+		}
+	}
 }
 
 // Implement futures::Stream for InterStream<GeminiStream>
@@ -49,7 +61,7 @@ impl futures::Stream for GeminiStreamer {
 						"[" => InterStreamEvent::Start,
 						"]" => {
 							let inter_stream_end = InterStreamEnd {
-								captured_usage: self.captured_data.usage.take(),
+								captured_usage: self.finalize_usage(),
 								captured_content: self.captured_data.content.take(),
 							};
 
@@ -70,6 +82,9 @@ impl futures::Stream for GeminiStreamer {
 								}
 							};
 
+							// Note: Without `usageMetadata`, the parsed usage is the default (not to be captured).
+							let has_usage = json_block.get("usageMetadata").is_some();
+
 							// -- Extract the Gemini Response
 							let gemini_response =
 								match GeminiAdapter::body_to_gemini_chat_response(&self.options.model_iden, json_block)
@@ -81,7 +96,29 @@ impl futures::Stream for GeminiStreamer {
 									}
 								};
 
-							let GeminiChatResponse { content, usage } = gemini_response;
+							let GeminiChatResponse {
+								content, usage, meta, ..
+							} = gemini_response;
+
+							// -- Fail on the blocked response if requested
+							if self.options.harm_block_mode == HarmBlockMode::Error && meta.is_blocked() {
+								self.done = true;
+								return Poll::Ready(Some(Err(Error::ResponseSafetyBlocked {
+									model_iden: self.options.model_iden.clone(),
+									block_reason: meta.block_reason,
+									safety_ratings: meta.safety_ratings.into_boxed_slice(),
+								})));
+							}
+
+							// -- Capture the usage
+							// NOTE: Apparently in the Gemini API, all events have cumulative usage,
+							//       meaning each message seems to include the tokens for all previous streams.
+							//       Thus, we do not need to add it; we only need to replace captured_data.usage with the latest one
+							//       (including for the last chunk without content).
+							//       See https://twitter.com/jeremychone/status/1813734565967802859 for potential additional information.
+							if has_usage {
+								self.captured_data.usage = Some(usage);
+							}
 
 							// -- Send Chunk event
 							if let Some(content) = content {
@@ -93,14 +130,6 @@ impl futures::Stream for GeminiStreamer {
 									}
 								}
 
-								// NOTE: Apparently in the Gemini API, all events have cumulative usage,
-								//       meaning each message seems to include the tokens for all previous streams.
-								//       Thus, we do not need to add it; we only need to replace captured_data.usage with the latest one.
-								//       See https://twitter.com/jeremychone/status/1813734565967802859 for potential additional information.
-								if self.options.capture_usage {
-									self.captured_data.usage = Some(usage);
-								}
-
 								InterStreamEvent::Chunk(content)
 							} else {
 								continue;
@@ -1,5 +1,8 @@
 use crate::adapter::openai::OpenAIAdapter;
-use crate::adapter::{Adapter, AdapterKind, ServiceType, WebRequestData};
+use crate::adapter::{
+	Adapter, AdapterKind, ServiceType, TranscriptionAdapter, TranscriptionRequestData, WebRequestData,
+};
+use crate::audio::{TranscriptionRequest, TranscriptionResponse};
 use crate::chat::{ChatOptionsSet, ChatRequest, ChatResponse, ChatStreamResponse};
 use crate::resolver::{AuthData, Endpoint};
 use crate::webc::WebResponse;
@@ -46,7 +49,7 @@ impl Adapter for GroqAdapter {
 		Ok(MODELS.iter().map(|s| s.to_string()).collect())
 	}
 
-	fn get_service_url(model: &ModelIden, service_type: ServiceType, endpoint: Endpoint) -> String {
+	fn get_service_url(model: &ModelIden, service_type: ServiceType, endpoint: Endpoint) -> Result<String> {
 		OpenAIAdapter::util_get_service_url(model, service_type, endpoint)
 	}
 
@@ -71,3 +74,17 @@ impl Adapter for GroqAdapter {
 		OpenAIAdapter::to_chat_stream(model_iden, reqwest_builder, options_set)
 	}
 }
+
+/// Groq Whisper transcription (OpenAI compatible, e.g., model "whisper-large-v3")
+impl TranscriptionAdapter for GroqAdapter {
+	fn to_transcription_request_data(
+		target: ServiceTarget,
+		transcription_req: TranscriptionRequest,
+	) -> Result<TranscriptionRequestData> {
+		OpenAIAdapter::util_to_transcription_request_data(target, transcription_req)
+	}
+
+	fn from_transcription_response(model_iden: ModelIden, web_response: WebResponse) -> Result<TranscriptionResponse> {
+		OpenAIAdapter::util_from_transcription_response(model_iden, web_response)
+	}
+}
@@ -0,0 +1,41 @@
+use bytes::{Buf, BufMut, Bytes};
+use tonic::codec::{Codec, DecodeBuf, Decoder, EncodeBuf, Encoder};
+use tonic::Status;
+
+/// A tonic codec passing the message bytes as is (i.e., already encoded by the request transformer).
+#[derive(Debug, Clone, Copy, Default)]
+pub(super) struct BytesCodec;
+
+impl Codec for BytesCodec {
+	type Encode = Bytes;
+	type Decode = Bytes;
+	type Encoder = BytesCodec;
+	type Decoder = BytesCodec;
+
+	fn encoder(&mut self) -> Self::Encoder {
+		BytesCodec
+	}
+
+	fn decoder(&mut self) -> Self::Decoder {
+		BytesCodec
+	}
+}
+
+impl Encoder for BytesCodec {
+	type Item = Bytes;
+	type Error = Status;
+
+	fn encode(&mut self, item: Self::Item, dst: &mut EncodeBuf<'_>) -> Result<(), Self::Error> {
+		dst.put(item);
+		Ok(())
+	}
+}
+
+impl Decoder for BytesCodec {
+	type Item = Bytes;
+	type Error = Status;
+
+	fn decode(&mut self, src: &mut DecodeBuf<'_>) -> Result<Option<Self::Item>, Self::Error> {
+		Ok(Some(src.copy_to_bytes(src.remaining())))
+	}
+}
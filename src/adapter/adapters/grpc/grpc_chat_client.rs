@@ -0,0 +1,106 @@
+use crate::adapter::grpc::bytes_codec::BytesCodec;
+use crate::chat::{ChatRequest, ChatResponse};
+use crate::{Error, Result};
+use bytes::Bytes;
+use tonic::client::Grpc;
+use tonic::codegen::http::uri::PathAndQuery;
+use tonic::transport::Channel;
+
+// region:    --- GrpcChatServiceConfig
+
+/// The unary gRPC chat method of a service (e.g., `inference.GRPCInferenceService/ModelInfer` for Triton).
+///
+/// Note: The transformers encode the `ChatRequest` to the request message (e.g., with prost),
+///       and decode the response message to the `ChatResponse`.
+#[derive(Debug, Clone)]
+pub struct GrpcChatServiceConfig {
+	/// The server URL, e.g., `http://localhost:8001`.
+	pub endpoint: String,
+	/// The fully qualified service name, e.g., `inference.GRPCInferenceService`.
+	pub service_name: String,
+	pub method_name: String,
+	pub request_transformer: fn(ChatRequest) -> Bytes,
+	pub response_transformer: fn(Bytes) -> Result<ChatResponse>,
+}
+
+/// Getters
+impl GrpcChatServiceConfig {
+	/// The gRPC path of the method, e.g., `/inference.GRPCInferenceService/ModelInfer`.
+	pub fn method_path(&self) -> String {
+		format!("/{}/{}", self.service_name, self.method_name)
+	}
+}
+
+// endregion: --- GrpcChatServiceConfig
+
+// region:    --- GrpcChatClient
+
+/// The client of a gRPC chat service (requires the `grpc` feature).
+///
+/// The channel is connected once by `GrpcChatClient::connect`, and reused by all the `exec_chat` calls
+/// (the client is cheap to clone, and the clones share the channel).
+///
+/// Note: The gRPC calls do not go through the web client, so this is not an `AdapterKind` of the `Client`
+///       (no streaming, middlewares, or content filters).
+#[derive(Debug, Clone)]
+pub struct GrpcChatClient {
+	config: GrpcChatServiceConfig,
+	path: PathAndQuery,
+	grpc: Grpc<Channel>,
+}
+
+/// Constructors
+impl GrpcChatClient {
+	/// Connect the channel to the `endpoint` of the service config.
+	pub async fn connect(config: GrpcChatServiceConfig) -> Result<Self> {
+		let path = PathAndQuery::from_maybe_shared(config.method_path()).map_err(grpc_error)?;
+		let channel = Channel::from_shared(config.endpoint.clone())
+			.map_err(grpc_error)?
+			.connect()
+			.await
+			.map_err(grpc_error)?;
+
+		Ok(Self {
+			config,
+			path,
+			grpc: Grpc::new(channel),
+		})
+	}
+}
+
+/// Getters
+impl GrpcChatClient {
+	pub fn config(&self) -> &GrpcChatServiceConfig {
+		&self.config
+	}
+}
+
+/// Exec
+impl GrpcChatClient {
+	/// Execute the chat request with the unary gRPC method of the service config.
+	pub async fn exec_chat(&self, chat_req: ChatRequest) -> Result<ChatResponse> {
+		// Note: The `Grpc` clone is a handle to the same channel.
+		let mut grpc = self.grpc.clone();
+		grpc.ready().await.map_err(grpc_error)?;
+
+		let payload = (self.config.request_transformer)(chat_req);
+		let response = grpc
+			.unary(tonic::Request::new(payload), self.path.clone(), BytesCodec)
+			.await
+			.map_err(|status| Error::Grpc {
+				cause: format!("{:?}: {}", status.code(), status.message()),
+			})?;
+
+		(self.config.response_transformer)(response.into_inner())
+	}
+}
+
+// endregion: --- GrpcChatClient
+
+// region:    --- Support
+
+fn grpc_error(err: impl std::fmt::Display) -> Error {
+	Error::Grpc { cause: err.to_string() }
+}
+
+// endregion: --- Support
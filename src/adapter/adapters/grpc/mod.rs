@@ -0,0 +1,12 @@
+//! gRPC chat services of the local inference servers (e.g., NVIDIA Triton, BentoML), with tonic.
+//! NOTE: The client is protobuf agnostic, the request and response messages are encoded and decoded
+//!       by the `GrpcChatServiceConfig` transformers.
+
+// region:    --- Modules
+
+mod bytes_codec;
+mod grpc_chat_client;
+
+pub use grpc_chat_client::*;
+
+// endregion: --- Modules
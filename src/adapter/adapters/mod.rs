@@ -5,6 +5,8 @@ pub(super) mod cohere;
 pub(super) mod deepseek;
 pub(super) mod gemini;
 pub(super) mod groq;
+#[cfg(feature = "grpc")]
+pub(super) mod grpc;
 pub(super) mod ollama;
 pub(super) mod openai;
 pub(super) mod xai;
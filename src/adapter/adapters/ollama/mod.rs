@@ -5,7 +5,9 @@
 // region:    --- Modules
 
 mod adapter_impl;
+mod ollama_model_manager;
 
 pub use adapter_impl::*;
+pub use ollama_model_manager::*;
 
 // endregion: --- Modules
@@ -0,0 +1,146 @@
+//! Ollama native model management API (pull, delete, show, running models).
+//!
+//! API DOC: https://github.com/ollama/ollama/blob/main/docs/api.md
+
+use crate::adapter::AdapterKind;
+use crate::client::AdapterApiTarget;
+use crate::webc::WebStream;
+use crate::{Client, Error, ModelIden, Result};
+use futures::{Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use value_ext::JsonValueExt;
+
+/// Manage the models of the Ollama server of the client (resolved as the Ollama adapter endpoint).
+#[derive(Debug, Clone)]
+pub struct OllamaModelManager {
+	client: Client,
+}
+
+/// One progress line of an Ollama model pull.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PullProgress {
+	pub status: String,
+	#[serde(default)]
+	pub completed: Option<u64>,
+	#[serde(default)]
+	pub total: Option<u64>,
+}
+
+/// A model currently loaded in memory (from `/api/ps`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunningModel {
+	pub name: String,
+	pub model: String,
+	pub size: u64,
+	#[serde(default)]
+	pub size_vram: Option<u64>,
+	pub digest: String,
+	#[serde(default)]
+	pub expires_at: Option<String>,
+}
+
+// region:    --- Constructors
+
+impl OllamaModelManager {
+	pub fn new(client: Client) -> Self {
+		Self { client }
+	}
+}
+
+impl Client {
+	/// Returns an `OllamaModelManager` using this client's web client and config.
+	pub fn ollama_manager(&self) -> OllamaModelManager {
+		OllamaModelManager::new(self.clone())
+	}
+}
+
+// endregion: --- Constructors
+
+// region:    --- Public Functions
+
+impl OllamaModelManager {
+	/// Pull a model, streaming the pull progress (JSON lines).
+	pub async fn pull_model(&self, name: &str) -> Result<impl Stream<Item = Result<PullProgress>>> {
+		let (url, headers) = self.api_url_and_headers("pull")?;
+		let reqwest_builder = self
+			.client
+			.web_client()
+			.new_req_builder(&url, &headers, json!({ "model": name, "stream": true }))
+			.map_err(|webc_error| Error::from_webc_adapter_call(AdapterKind::Ollama, webc_error))?;
+
+		let model_iden = ModelIden::new(AdapterKind::Ollama, name);
+		let web_stream = WebStream::new_with_delimiter(reqwest_builder, "\n");
+		let progress_stream = web_stream
+			.filter(|line| futures::future::ready(!matches!(line, Ok(line) if line.trim().is_empty())))
+			.map(move |line| {
+				let line = line.map_err(|err| Error::WebStream {
+					model_iden: model_iden.clone(),
+					cause: err.to_string(),
+				})?;
+				let mut value: Value = serde_json::from_str(&line).map_err(|serde_error| Error::StreamParse {
+					model_iden: model_iden.clone(),
+					serde_error,
+				})?;
+				if let Ok(error) = value.x_take::<String>("error") {
+					return Err(Error::WebStream {
+						model_iden: model_iden.clone(),
+						cause: error,
+					});
+				}
+				let progress: PullProgress = serde_json::from_value(value)?;
+				Ok(progress)
+			});
+
+		Ok(progress_stream)
+	}
+
+	pub async fn delete_model(&self, name: &str) -> Result<()> {
+		let (url, headers) = self.api_url_and_headers("delete")?;
+		self.client
+			.web_client()
+			.do_delete(&url, &headers, Some(json!({ "model": name })))
+			.await
+			.map_err(|webc_error| Error::from_webc_adapter_call(AdapterKind::Ollama, webc_error))?;
+		Ok(())
+	}
+
+	/// Returns the raw model information (modelfile, parameters, template, details, ...).
+	pub async fn show_model(&self, name: &str) -> Result<Value> {
+		let (url, headers) = self.api_url_and_headers("show")?;
+		let web_res = self
+			.client
+			.web_client()
+			.do_post(&url, &headers, json!({ "model": name }))
+			.await
+			.map_err(|webc_error| Error::from_webc_adapter_call(AdapterKind::Ollama, webc_error))?;
+		Ok(web_res.body)
+	}
+
+	pub async fn list_running_models(&self) -> Result<Vec<RunningModel>> {
+		let (url, headers) = self.api_url_and_headers("ps")?;
+		let mut web_res = self
+			.client
+			.web_client()
+			.do_get(&url, &headers)
+			.await
+			.map_err(|webc_error| Error::from_webc_adapter_call(AdapterKind::Ollama, webc_error))?;
+		let models: Vec<RunningModel> = web_res.body.x_take("models")?;
+		Ok(models)
+	}
+}
+
+// endregion: --- Public Functions
+
+// region:    --- Support
+
+impl OllamaModelManager {
+	/// The native API url (`.../api/{name}`), from the Ollama adapter endpoint (which is the OpenAI compatible `.../v1/`).
+	fn api_url_and_headers(&self, name: &str) -> Result<(String, Vec<(String, String)>)> {
+		let AdapterApiTarget { base_url, headers } = self.client.adapter_api_target(AdapterKind::Ollama)?;
+		let base_url = base_url.strip_suffix("v1/").unwrap_or(&base_url);
+		Ok((format!("{base_url}api/{name}"), headers))
+	}
+}
+
+// endregion: --- Support
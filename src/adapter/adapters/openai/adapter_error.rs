@@ -0,0 +1,45 @@
+use crate::adapter::AdapterError;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::any::Any;
+
+/// The OpenAI API error (`{"error": {"message", "type", "param", "code"}}`), also used by the OpenAI compatible adapters.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenAIError {
+	/// e.g., `insufficient_quota`, `invalid_api_key`
+	pub code: Option<String>,
+	pub message: String,
+	/// e.g., `invalid_request_error`, `insufficient_quota`
+	#[serde(rename = "type")]
+	pub type_: String,
+	pub param: Option<String>,
+}
+
+impl OpenAIError {
+	pub(crate) fn from_body(body: &str) -> Option<Self> {
+		let mut body: Value = serde_json::from_str(body).ok()?;
+		serde_json::from_value(body.get_mut("error")?.take()).ok()
+	}
+}
+
+impl AdapterError for OpenAIError {
+	fn error_type(&self) -> &str {
+		&self.type_
+	}
+
+	fn message(&self) -> &str {
+		&self.message
+	}
+
+	fn as_any(&self) -> &dyn Any {
+		self
+	}
+}
+
+impl core::fmt::Display for OpenAIError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "{} ({})", self.message, self.type_)
+	}
+}
+
+impl std::error::Error for OpenAIError {}
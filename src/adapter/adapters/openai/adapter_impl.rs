@@ -1,9 +1,9 @@
-use crate::adapter::adapters::support::get_api_key;
-use crate::adapter::openai::OpenAIStreamer;
+use crate::adapter::adapters::support::{get_api_key, insert_extra_params};
+use crate::adapter::openai::{OpenAIResponseSchema, OpenAIStreamer};
 use crate::adapter::{Adapter, AdapterDispatcher, AdapterKind, ServiceType, WebRequestData};
 use crate::chat::{
-	ChatOptionsSet, ChatRequest, ChatResponse, ChatResponseFormat, ChatRole, ChatStream, ChatStreamResponse,
-	ContentPart, ImageSource, MessageContent, MetaUsage, ToolCall,
+	ChatOptionsSet, ChatRequest, ChatResponse, ChatResponseFormat, ChatRole, ChatStream, ChatStreamResponse, Citation,
+	ContentPart, ImageSource, MessageContent, MetaUsage, ToolCall, ToolType,
 };
 use crate::resolver::{AuthData, Endpoint};
 use crate::webc::WebResponse;
@@ -11,14 +11,13 @@ use crate::{Error, Result};
 use crate::{ModelIden, ServiceTarget};
 use reqwest::RequestBuilder;
 use reqwest_eventsource::EventSource;
-use serde::Deserialize;
 use serde_json::{json, Value};
 use value_ext::JsonValueExt;
 
 pub struct OpenAIAdapter;
 
 // Latest models
-const MODELS: &[&str] = &[
+pub(in crate::adapter) const MODELS: &[&str] = &[
 	//
 	"gpt-4o",
 	"gpt-4o-mini",
@@ -45,7 +44,7 @@ impl Adapter for OpenAIAdapter {
 		Ok(MODELS.iter().map(|s| s.to_string()).collect())
 	}
 
-	fn get_service_url(model: &ModelIden, service_type: ServiceType, endpoint: Endpoint) -> String {
+	fn get_service_url(model: &ModelIden, service_type: ServiceType, endpoint: Endpoint) -> Result<String> {
 		Self::util_get_service_url(model, service_type, endpoint)
 	}
 
@@ -59,35 +58,7 @@ impl Adapter for OpenAIAdapter {
 	}
 
 	fn to_chat_response(model_iden: ModelIden, web_response: WebResponse) -> Result<ChatResponse> {
-		let WebResponse { mut body, .. } = web_response;
-
-		// -- Capture the usage
-		let usage = body.x_take("usage").map(OpenAIAdapter::into_usage).unwrap_or_default();
-
-		// -- Capture the content
-		let content = if let Some(mut first_choice) = body.x_take::<Option<Value>>("/choices/0")? {
-			if let Some(content) = first_choice
-				.x_take::<Option<String>>("/message/content")?
-				.map(MessageContent::from)
-			{
-				Some(content)
-			} else {
-				first_choice
-					.x_take("/message/tool_calls")
-					.ok()
-					.map(parse_tool_calls)
-					.transpose()?
-					.map(MessageContent::from_tool_calls)
-			}
-		} else {
-			None
-		};
-
-		Ok(ChatResponse {
-			content,
-			model_iden,
-			usage,
-		})
+		OpenAIAdapter::to_chat_response_with_schema(model_iden, web_response, &OpenAIResponseSchema::OPENAI)
 	}
 
 	fn to_chat_stream(
@@ -97,26 +68,106 @@ impl Adapter for OpenAIAdapter {
 	) -> Result<ChatStreamResponse> {
 		let event_source = EventSource::new(reqwest_builder)?;
 		let openai_stream = OpenAIStreamer::new(event_source, model_iden.clone(), options_sets);
-		let chat_stream = ChatStream::from_inter_stream(openai_stream);
+		let chat_stream = ChatStream::from_inter_stream(openai_stream, model_iden.clone());
 
 		Ok(ChatStreamResponse {
 			model_iden,
 			stream: chat_stream,
+			client_request_id: None,
 		})
 	}
 }
 
 /// Support functions for other adapters that share OpenAI APIs
 impl OpenAIAdapter {
+	/// Parse the chat completion response with the JSON pointer paths of the `schema`
+	/// (see `AdapterConfig::with_response_schema`).
+	pub(in crate::adapter) fn to_chat_response_with_schema(
+		model_iden: ModelIden,
+		web_response: WebResponse,
+		schema: &OpenAIResponseSchema,
+	) -> Result<ChatResponse> {
+		let WebResponse {
+			mut body, request_id, ..
+		} = web_response;
+
+		// -- Capture the usage
+		let usage = body
+			.x_take(schema.usage_path)
+			.map(OpenAIAdapter::into_usage)
+			.unwrap_or_default();
+
+		// -- Capture the content
+		// The eventual audio response (gpt-4o-audio), kept in the `adapter_meta` (see `ChatResponse::audio_bytes`)
+		let audio = body.x_take::<Option<Value>>("/choices/0/message/audio").ok().flatten();
+		let transcript = audio
+			.as_ref()
+			.and_then(|audio| audio.get("transcript"))
+			.and_then(|transcript| transcript.as_str())
+			.map(String::from);
+		// The eventual system fingerprint, kept in the `adapter_meta` (see `ChatResponse::system_fingerprint`)
+		let system_fingerprint = body.x_take::<Option<String>>("system_fingerprint").ok().flatten();
+		// The eventual finish reason, kept in the `adapter_meta` (see `ChatResponse::finish_reason`)
+		let finish_reason = body.x_take::<Option<String>>(schema.finish_reason_path).ok().flatten();
+		let adapter_meta = match (audio, system_fingerprint, finish_reason) {
+			(None, None, None) => None,
+			(audio, system_fingerprint, finish_reason) => {
+				let mut meta = json!({});
+				if let Some(audio) = audio {
+					meta.x_insert("audio", audio)?;
+				}
+				if let Some(system_fingerprint) = system_fingerprint {
+					meta.x_insert("system_fingerprint", system_fingerprint)?;
+				}
+				if let Some(finish_reason) = finish_reason {
+					meta.x_insert("finish_reason", finish_reason)?;
+				}
+				Some(meta)
+			}
+		};
+
+		let text = match body.pointer(schema.content_path) {
+			None | Some(Value::Null) => None,
+			Some(_) => Some(body.x_take::<String>(schema.content_path)?),
+		};
+
+		// The eventual url citations (e.g., the search models), before the text is moved to the content
+		let citations = body
+			.x_take::<Vec<Value>>("/choices/0/message/annotations")
+			.map(|annotations| Self::into_citations(annotations, text.as_deref().unwrap_or_default()))
+			.unwrap_or_default();
+
+		let content = if let Some(content) = text.or(transcript).map(MessageContent::from) {
+			Some(content)
+		} else {
+			body.x_take(schema.tool_calls_path)
+				.ok()
+				.map(parse_tool_calls)
+				.transpose()?
+				.map(MessageContent::from_tool_calls)
+		};
+
+		Ok(ChatResponse {
+			content,
+			model_iden,
+			usage,
+			request_id,
+			client_request_id: None,
+			adapter_meta,
+			citations,
+		})
+	}
+
 	pub(in crate::adapter::adapters) fn util_get_service_url(
 		_model: &ModelIden,
 		service_type: ServiceType,
 		// -- utility arguments
 		default_endpoint: Endpoint,
-	) -> String {
+	) -> Result<String> {
 		let base_url = default_endpoint.base_url();
 		match service_type {
-			ServiceType::Chat | ServiceType::ChatStream => format!("{base_url}chat/completions"),
+			ServiceType::Chat | ServiceType::ChatStream => Ok(format!("{base_url}chat/completions")),
+			ServiceType::AudioTranscription => Ok(format!("{base_url}audio/transcriptions")),
 		}
 	}
 
@@ -132,7 +183,7 @@ impl OpenAIAdapter {
 		let api_key = get_api_key(auth, &model)?;
 
 		// -- url
-		let url = AdapterDispatcher::get_service_url(&model, service_type, endpoint);
+		let url = AdapterDispatcher::get_service_url(&model, service_type, endpoint)?;
 
 		// -- headers
 		let headers = vec![
@@ -144,6 +195,7 @@ impl OpenAIAdapter {
 
 		// -- Build the basic payload
 		let model_name = model.model_name.to_string();
+		let is_reasoning_model = Self::is_reasoning_model(&model_name);
 		let OpenAIRequestParts { messages, tools } = Self::into_openai_request_parts(model, chat_req)?;
 		let mut payload = json!({
 			"model": model_name,
@@ -211,26 +263,107 @@ impl OpenAIAdapter {
 		if let Some(top_p) = options_set.top_p() {
 			payload.x_insert("top_p", top_p)?;
 		}
+		if let Some(prediction) = options_set.prediction() {
+			payload.x_insert("prediction", json!({"type": "content", "content": prediction}))?;
+		}
+		if let Some(seed) = options_set.seed() {
+			payload.x_insert("seed", seed)?;
+		}
+		// Note: Only the reasoning models accept the `reasoning_effort` (ignored for the others)
+		if let Some(reasoning_effort) = options_set.reasoning_effort().filter(|_| is_reasoning_model) {
+			payload.x_insert("reasoning_effort", reasoning_effort.as_str())?;
+		}
+
+		// -- Add the eventual extra params (last, so they can override)
+		insert_extra_params(&mut payload, &options_set)?;
 
 		Ok(WebRequestData { url, headers, payload })
 	}
 
+	/// Returns true for the OpenAI reasoning models (`o1` and `o3` families).
+	fn is_reasoning_model(model_name: &str) -> bool {
+		["o1", "o3"]
+			.iter()
+			.any(|prefix| model_name == *prefix || model_name.starts_with(&format!("{prefix}-")))
+	}
+
+	/// Note: Only the `url_citation` annotations are captured.
+	/// Note: The OpenAI indexes are character offsets in the content `text`, and are converted to byte offsets
+	///       (as for the other providers). The indexes out of the text are dropped.
+	fn into_citations(annotations: Vec<Value>, text: &str) -> Vec<Citation> {
+		let to_byte_index = |char_index: usize| {
+			text.char_indices()
+				.map(|(byte_index, _)| byte_index)
+				.chain(std::iter::once(text.len()))
+				.nth(char_index)
+		};
+
+		annotations
+			.into_iter()
+			.filter(|annotation| annotation.x_get_as::<&str>("type").is_ok_and(|typ| typ == "url_citation"))
+			.filter_map(|mut annotation| {
+				let mut url_citation: Value = annotation.x_take("url_citation").ok()?;
+				Some(Citation {
+					url: url_citation.x_take("url").ok()?,
+					title: url_citation.x_take("title").ok(),
+					snippet: None,
+					start_index: url_citation.x_take("start_index").ok().and_then(to_byte_index),
+					end_index: url_citation.x_take("end_index").ok().and_then(to_byte_index),
+				})
+			})
+			.collect()
+	}
+
 	/// Note: Needs to be called from super::streamer as well
-	pub(super) fn into_usage(mut usage_value: Value) -> MetaUsage {
+	pub(crate) fn into_usage(mut usage_value: Value) -> MetaUsage {
 		let input_tokens: Option<i32> = usage_value.x_take("prompt_tokens").ok();
 		let output_tokens: Option<i32> = usage_value.x_take("completion_tokens").ok();
 		let total_tokens: Option<i32> = usage_value.x_take("total_tokens").ok();
+		let accepted_prediction_tokens: Option<i32> =
+			usage_value.x_take("/completion_tokens_details/accepted_prediction_tokens").ok();
+		let rejected_prediction_tokens: Option<i32> =
+			usage_value.x_take("/completion_tokens_details/rejected_prediction_tokens").ok();
 		MetaUsage {
 			input_tokens,
 			output_tokens,
 			total_tokens,
+			cache_read_input_tokens: None,
+			cache_creation_input_tokens: None,
+			accepted_prediction_tokens,
+			rejected_prediction_tokens,
+		}
+	}
+
+	/// The OpenAI content of a user message part.
+	/// Note: The cache marker is not supported (automatic prompt caching), so the cacheable part is sent as is.
+	fn into_openai_content_part(part: &ContentPart) -> Value {
+		match part {
+			ContentPart::Text(text) => json!({"type": "text", "text": text.clone()}),
+			ContentPart::Image {
+				content_type,
+				source,
+				detail,
+			} => {
+				let mut image_url = match source {
+					ImageSource::Url(url) => json!({"url": url}),
+					ImageSource::Base64(content) => {
+						json!({"url": format!("data:{content_type};base64,{content}")})
+					}
+				};
+				if let Some(detail) = detail {
+					// Note: the image_url is always an object here, so the insert cannot fail
+					let _ = image_url.x_insert("detail", detail);
+				}
+				json!({"type": "image_url", "image_url": image_url})
+			}
+			ContentPart::WithCache(part) => Self::into_openai_content_part(part),
 		}
 	}
 
 	/// Takes the genai ChatMessages and builds the OpenAIChatRequestParts
 	/// - `genai::ChatRequest.system`, if present, is added as the first message with role 'system'.
 	/// - All messages get added with the corresponding roles (tools are not supported for now)
-	fn into_openai_request_parts(_model_iden: ModelIden, chat_req: ChatRequest) -> Result<OpenAIRequestParts> {
+	fn into_openai_request_parts(model_iden: ModelIden, chat_req: ChatRequest) -> Result<OpenAIRequestParts> {
 		let mut messages: Vec<Value> = Vec::new();
 
 		// -- Process the system
@@ -253,23 +386,7 @@ impl OpenAIAdapter {
 					let content = match msg.content {
 						MessageContent::Text(content) => json!(content),
 						MessageContent::Parts(parts) => {
-							json!(parts
-								.iter()
-								.map(|part| match part {
-									ContentPart::Text(text) => json!({"type": "text", "text": text.clone()}),
-									ContentPart::Image { content_type, source } => {
-										match source {
-											ImageSource::Url(url) => {
-												json!({"type": "image_url", "image_url": {"url": url}})
-											}
-											ImageSource::Base64(content) => {
-												let image_url = format!("data:{content_type};base64,{content}");
-												json!({"type": "image_url", "image_url": {"url": image_url}})
-											}
-										}
-									}
-								})
-								.collect::<Vec<Value>>())
+							json!(parts.iter().map(Self::into_openai_content_part).collect::<Vec<Value>>())
 						}
 						// Use `match` instead of `if let`. This will allow to future-proof this
 						// implementation in case some new message content types would appear,
@@ -278,12 +395,19 @@ impl OpenAIAdapter {
 						// TODO: Probably need to warn if it is a ToolCalls type of content
 						MessageContent::ToolCalls(_) => continue,
 						MessageContent::ToolResponses(_) => continue,
+						// The raw json is used as is
+						MessageContent::Json(content) => content,
+						MessageContent::Audio { data_base64, format } => json!([{
+							"type": "input_audio",
+							"input_audio": {"data": data_base64, "format": format.as_str()}
+						}]),
 					};
 					messages.push(json! ({"role": "user", "content": content}));
 				}
 
 				ChatRole::Assistant => match msg.content {
 					MessageContent::Text(content) => messages.push(json! ({"role": "assistant", "content": content})),
+					MessageContent::Json(content) => messages.push(json! ({"role": "assistant", "content": content})),
 					MessageContent::ToolCalls(tool_calls) => {
 						let tool_calls = tool_calls
 							.into_iter()
@@ -303,6 +427,7 @@ impl OpenAIAdapter {
 					// TODO: Probably need to trace/warn that this will be ignored
 					MessageContent::Parts(_) => (),
 					MessageContent::ToolResponses(_) => (),
+					MessageContent::Audio { .. } => (),
 				},
 
 				ChatRole::Tool => {
@@ -321,6 +446,18 @@ impl OpenAIAdapter {
 		}
 
 		// -- Process the tools
+		// Note: Only the function tools are supported (e.g., not the Anthropic computer use tools)
+		if let Some(tool) = chat_req
+			.tools
+			.iter()
+			.flatten()
+			.find(|tool| !matches!(tool.tool_type, ToolType::Function))
+		{
+			return Err(Error::ToolTypeNotSupported {
+				model_iden,
+				tool_name: tool.name.clone(),
+			});
+		}
 		let tools = chat_req.tools.map(|tools| {
 			tools
 				.into_iter()
@@ -367,44 +504,7 @@ fn parse_tool_calls(raw_tool_calls: Value) -> Result<Vec<ToolCall>> {
 }
 
 fn parse_tool_call(raw_tool_call: Value) -> Result<ToolCall> {
-	// Define a helper struct to match the original JSON structure.
-	#[derive(Deserialize)]
-	struct IterimToolFnCall {
-		id: String,
-		#[allow(unused)]
-		#[serde(rename = "type")]
-		r#type: String,
-		function: IterimFunction,
-	}
-
-	#[derive(Deserialize)]
-	struct IterimFunction {
-		name: String,
-		arguments: Value,
-	}
-
-	let iterim = serde_json::from_value::<IterimToolFnCall>(raw_tool_call)?;
-
-	let fn_name = iterim.function.name;
-
-	// For now, support Object only, and parse the eventual string as a json value.
-	// Eventually, we might check pricing
-	let fn_arguments = match iterim.function.arguments {
-		Value::Object(obj) => Value::Object(obj),
-		Value::String(txt) => serde_json::from_str(&txt)?,
-		_ => {
-			return Err(Error::InvalidJsonResponseElement {
-				info: "tool call arguments is not an object",
-			})
-		}
-	};
-
-	// Then, map the fields of the helper struct to the flat structure.
-	Ok(ToolCall {
-		call_id: iterim.id,
-		fn_name,
-		fn_arguments,
-	})
+	ToolCall::from_openai_value(raw_tool_call)
 }
 
 // endregion: --- Support
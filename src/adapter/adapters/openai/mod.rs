@@ -4,10 +4,15 @@
 
 // region:    --- Modules
 
+mod adapter_error;
 mod adapter_impl;
+mod response_schema;
 mod streamer;
+mod transcription_impl;
 
+pub use adapter_error::*;
 pub use adapter_impl::*;
+pub use response_schema::*;
 pub use streamer::*;
 
 // endregion: --- Modules
@@ -0,0 +1,41 @@
+use crate::adapter::AdapterKind;
+
+/// The JSON pointer paths of the OpenAI chat completion response parts, to parse the responses of
+/// the OpenAI compatible providers with minor format differences (see `AdapterConfig::with_response_schema`).
+///
+/// Note: Only for the `exec_chat` responses (the streams have their own chunk format).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OpenAIResponseSchema {
+	/// e.g., `/choices/0/finish_reason` (see `ChatResponse::finish_reason`)
+	pub finish_reason_path: &'static str,
+	/// e.g., `/choices/0/message/content` (or `/choices/0/delta/content` for some providers)
+	pub content_path: &'static str,
+	pub tool_calls_path: &'static str,
+	pub usage_path: &'static str,
+}
+
+impl OpenAIResponseSchema {
+	/// The OpenAI chat completion response schema.
+	pub const OPENAI: OpenAIResponseSchema = OpenAIResponseSchema {
+		finish_reason_path: "/choices/0/finish_reason",
+		content_path: "/choices/0/message/content",
+		tool_calls_path: "/choices/0/message/tool_calls",
+		usage_path: "/usage",
+	};
+}
+
+impl Default for OpenAIResponseSchema {
+	fn default() -> Self {
+		Self::OPENAI
+	}
+}
+
+/// Returns true for the adapter kinds using the OpenAI response parsing (and so, an `OpenAIResponseSchema`).
+pub(crate) fn has_response_schema(adapter_kind: AdapterKind) -> bool {
+	match adapter_kind {
+		AdapterKind::OpenAI | AdapterKind::Ollama | AdapterKind::Groq | AdapterKind::Xai | AdapterKind::DeepSeek => {
+			true
+		}
+		AdapterKind::Anthropic | AdapterKind::Cohere | AdapterKind::Gemini => false,
+	}
+}
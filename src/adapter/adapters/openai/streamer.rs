@@ -77,6 +77,14 @@ impl futures::Stream for OpenAIStreamer {
 
 					let adapter_kind = self.options.model_iden.adapter_kind;
 
+					// NOTE: Groq sends the usage in its `x_groq` extension, usually with the `finish_reason` chunk,
+					//       but possibly in a later chunk without choice, so it is captured from any chunk having it.
+					if matches!(adapter_kind, AdapterKind::Groq) && self.options.capture_usage {
+						if let Ok(usage) = message_data.x_take::<Value>("/x_groq/usage") {
+							self.captured_data.usage = Some(OpenAIAdapter::into_usage(usage));
+						}
+					}
+
 					// If we have a first choice, then it's a normal message
 					if let Some(mut first_choice) = first_choice {
 						// If finish_reason exists, it's the end of this choice.
@@ -84,16 +92,9 @@ impl futures::Stream for OpenAIStreamer {
 						// as there might be other messages, and the last one contains data: `[DONE]`
 						// NOTE: xAI has no `finish_reason` when not finished, so, need to just account for both null/absent
 						if let Ok(_finish_reason) = first_choice.x_take::<String>("finish_reason") {
-							// NOTE: For Groq, the usage is captured when finish_reason indicates stopping, and in the `/x_groq/usage`
+							// NOTE: For Groq, the usage is captured above from the `/x_groq/usage`
 							if self.options.capture_usage {
 								match adapter_kind {
-									AdapterKind::Groq => {
-										let usage = message_data
-											.x_take("/x_groq/usage")
-											.map(OpenAIAdapter::into_usage)
-											.unwrap_or_default(); // permissive for now
-										self.captured_data.usage = Some(usage)
-									}
 									AdapterKind::Xai | AdapterKind::DeepSeek => {
 										let usage = message_data
 											.x_take("usage")
@@ -143,7 +144,7 @@ impl futures::Stream for OpenAIStreamer {
 				}
 				Some(Err(err)) => {
 					println!("Error: {}", err);
-					return Poll::Ready(Some(Err(Error::ReqwestEventSource(err))));
+					return Poll::Ready(Some(Err(Error::ReqwestEventSource(Box::new(err)))));
 				}
 				None => {
 					return Poll::Ready(None);
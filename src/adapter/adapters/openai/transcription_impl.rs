@@ -0,0 +1,76 @@
+//! API Documentation:     https://platform.openai.com/docs/api-reference/audio/createTranscription
+
+use crate::adapter::adapters::support::get_api_key;
+use crate::adapter::openai::OpenAIAdapter;
+use crate::adapter::{Adapter, ServiceType, TranscriptionAdapter, TranscriptionRequestData};
+use crate::audio::{TranscriptionRequest, TranscriptionResponse};
+use crate::webc::WebResponse;
+use crate::{ModelIden, Result, ServiceTarget};
+use reqwest::multipart::{Form, Part};
+use value_ext::JsonValueExt;
+
+impl TranscriptionAdapter for OpenAIAdapter {
+	fn to_transcription_request_data(
+		target: ServiceTarget,
+		transcription_req: TranscriptionRequest,
+	) -> Result<TranscriptionRequestData> {
+		Self::util_to_transcription_request_data(target, transcription_req)
+	}
+
+	fn from_transcription_response(model_iden: ModelIden, web_response: WebResponse) -> Result<TranscriptionResponse> {
+		Self::util_from_transcription_response(model_iden, web_response)
+	}
+}
+
+/// Support functions for other adapters that share the OpenAI audio APIs
+impl OpenAIAdapter {
+	pub(in crate::adapter::adapters) fn util_to_transcription_request_data(
+		target: ServiceTarget,
+		transcription_req: TranscriptionRequest,
+	) -> Result<TranscriptionRequestData> {
+		let ServiceTarget { model, auth, endpoint } = target;
+
+		// -- api_key
+		let api_key = get_api_key(auth, &model)?;
+
+		// -- url & headers
+		let url = Self::get_service_url(&model, ServiceType::AudioTranscription, endpoint)?;
+		let headers = vec![("Authorization".to_string(), format!("Bearer {api_key}"))];
+
+		// -- form
+		let TranscriptionRequest {
+			file_name,
+			audio,
+			language,
+			prompt,
+			temperature,
+			..
+		} = transcription_req;
+
+		let mut form = Form::new()
+			.text("model", model.model_name.to_string())
+			.text("response_format", "json")
+			.part("file", Part::bytes(audio).file_name(file_name));
+		if let Some(language) = language {
+			form = form.text("language", language);
+		}
+		if let Some(prompt) = prompt {
+			form = form.text("prompt", prompt);
+		}
+		if let Some(temperature) = temperature {
+			form = form.text("temperature", temperature.to_string());
+		}
+
+		Ok(TranscriptionRequestData { url, headers, form })
+	}
+
+	pub(in crate::adapter::adapters) fn util_from_transcription_response(
+		model_iden: ModelIden,
+		web_response: WebResponse,
+	) -> Result<TranscriptionResponse> {
+		let WebResponse { mut body, .. } = web_response;
+		let text: String = body.x_take("text")?;
+
+		Ok(TranscriptionResponse { text, model_iden })
+	}
+}
@@ -1,10 +1,12 @@
 //! This support module is for common constructs and utilities for all the adapter implementations.
 //! It should be private to the `crate::adapter::adapters` module.
 
-use crate::chat::{ChatOptionsSet, MetaUsage};
+use crate::chat::{ChatOptionsSet, HarmBlockMode, MetaUsage};
 use crate::resolver::AuthData;
 use crate::ModelIden;
 use crate::{Error, Result};
+use serde_json::Value;
+use value_ext::JsonValueExt;
 
 pub fn get_api_key(auth: AuthData, model: &ModelIden) -> Result<String> {
 	auth.single_key_value().map_err(|resolver_error| Error::Resolver {
@@ -13,12 +15,38 @@ pub fn get_api_key(auth: AuthData, model: &ModelIden) -> Result<String> {
 	})
 }
 
+/// Merge the eventual `extra_params` into the top level of the payload (overriding existing keys).
+///
+/// Returns an `Error::ExtraParamInvalid` for an empty key, or a key starting with `/`
+/// (which would be inserted as a JSON pointer rather than a top-level key).
+pub fn insert_extra_params(payload: &mut Value, options_set: &ChatOptionsSet<'_, '_>) -> Result<()> {
+	if let Some(extra_params) = options_set.extra_params() {
+		for (key, value) in extra_params {
+			if key.is_empty() {
+				return Err(Error::ExtraParamInvalid {
+					key: key.to_string(),
+					cause: "the key is empty",
+				});
+			}
+			if key.starts_with('/') {
+				return Err(Error::ExtraParamInvalid {
+					key: key.to_string(),
+					cause: "the key must be a top-level name, not a JSON pointer",
+				});
+			}
+			payload.x_insert(key, value.clone())?;
+		}
+	}
+	Ok(())
+}
+
 // region:    --- StreamerChatOptions
 
 #[derive(Debug)]
 pub struct StreamerOptions {
 	pub capture_content: bool,
 	pub capture_usage: bool,
+	pub harm_block_mode: HarmBlockMode,
 	pub model_iden: ModelIden,
 }
 
@@ -27,6 +55,7 @@ impl StreamerOptions {
 		Self {
 			capture_content: options_set.capture_content().unwrap_or(false),
 			capture_usage: options_set.capture_usage().unwrap_or(false),
+			harm_block_mode: options_set.harm_block_mode().unwrap_or_default(),
 			model_iden,
 		}
 	}
@@ -2,11 +2,14 @@ use crate::adapter::anthropic::AnthropicAdapter;
 use crate::adapter::cohere::CohereAdapter;
 use crate::adapter::gemini::GeminiAdapter;
 use crate::adapter::ollama::OllamaAdapter;
-use crate::adapter::openai::OpenAIAdapter;
-use crate::adapter::{Adapter, AdapterKind, ServiceType, WebRequestData};
+use crate::adapter::openai::{OpenAIAdapter, OpenAIResponseSchema};
+use crate::adapter::{
+	Adapter, AdapterKind, ServiceType, TranscriptionAdapter, TranscriptionRequestData, WebRequestData,
+};
+use crate::audio::{TranscriptionRequest, TranscriptionResponse};
 use crate::chat::{ChatOptionsSet, ChatRequest, ChatResponse, ChatStreamResponse};
 use crate::webc::WebResponse;
-use crate::ModelIden;
+use crate::{Error, ModelIden};
 use crate::{Result, ServiceTarget};
 use reqwest::RequestBuilder;
 
@@ -62,7 +65,7 @@ impl AdapterDispatcher {
 		}
 	}
 
-	pub fn get_service_url(model: &ModelIden, service_type: ServiceType, endpoint: Endpoint) -> String {
+	pub fn get_service_url(model: &ModelIden, service_type: ServiceType, endpoint: Endpoint) -> Result<String> {
 		match model.adapter_kind {
 			AdapterKind::OpenAI => OpenAIAdapter::get_service_url(model, service_type, endpoint),
 			AdapterKind::Anthropic => AnthropicAdapter::get_service_url(model, service_type, endpoint),
@@ -81,6 +84,8 @@ impl AdapterDispatcher {
 		chat_req: ChatRequest,
 		options_set: ChatOptionsSet<'_, '_>,
 	) -> Result<WebRequestData> {
+		// Note: The dynamic system is evaluated here, just before the payload is built.
+		let chat_req = chat_req.into_resolved_system();
 		let adapter_kind = &target.model.adapter_kind;
 		match adapter_kind {
 			AdapterKind::OpenAI => OpenAIAdapter::to_web_request_data(target, service_type, chat_req, options_set),
@@ -109,6 +114,19 @@ impl AdapterDispatcher {
 		}
 	}
 
+	/// Same as `to_chat_response`, with the eventual response schema of an OpenAI compatible adapter kind
+	/// (see `AdapterConfig::with_response_schema`).
+	pub fn to_chat_response_with_schema(
+		model_iden: ModelIden,
+		web_response: WebResponse,
+		schema: Option<&OpenAIResponseSchema>,
+	) -> Result<ChatResponse> {
+		match schema {
+			Some(schema) => OpenAIAdapter::to_chat_response_with_schema(model_iden, web_response, schema),
+			None => Self::to_chat_response(model_iden, web_response),
+		}
+	}
+
 	pub fn to_chat_stream(
 		model_iden: ModelIden,
 		reqwest_builder: RequestBuilder,
@@ -125,4 +143,29 @@ impl AdapterDispatcher {
 			AdapterKind::DeepSeek => DeepSeekAdapter::to_chat_stream(model_iden, reqwest_builder, options_set),
 		}
 	}
+
+	/// Note: Only the adapters implementing `TranscriptionAdapter` (OpenAI and Groq) support it.
+	pub fn to_transcription_request_data(
+		target: ServiceTarget,
+		transcription_req: TranscriptionRequest,
+	) -> Result<TranscriptionRequestData> {
+		match target.model.adapter_kind {
+			AdapterKind::OpenAI => OpenAIAdapter::to_transcription_request_data(target, transcription_req),
+			AdapterKind::Groq => GroqAdapter::to_transcription_request_data(target, transcription_req),
+			_ => Err(Error::TranscriptionNotSupported {
+				model_iden: target.model,
+			}),
+		}
+	}
+
+	pub fn from_transcription_response(
+		model_iden: ModelIden,
+		web_response: WebResponse,
+	) -> Result<TranscriptionResponse> {
+		match model_iden.adapter_kind {
+			AdapterKind::OpenAI => OpenAIAdapter::from_transcription_response(model_iden, web_response),
+			AdapterKind::Groq => GroqAdapter::from_transcription_response(model_iden, web_response),
+			_ => Err(Error::TranscriptionNotSupported { model_iden }),
+		}
+	}
 }
@@ -5,14 +5,18 @@
 //! Notes:
 //! - All `Adapter` trait methods take the `AdapterKind` as an argument, and for now, the `Adapter` trait functions
 //!   are all static (i.e., no `&self`). This reduces state management and ensures that all states are passed as arguments.
-//! - Only `AdapterKind` from `AdapterConfig` is publicly exported.
+//! - The adapter settings of a client (e.g., the OpenAI compatible response schemas) are in the `AdapterConfig`
+//!   (see `ClientConfig::with_adapter_config`).
 
 // region:    --- Modules
 
+mod adapter_config;
+mod adapter_error;
 mod adapter_kind;
 mod adapter_types;
 mod adapters;
 mod dispatcher;
+mod response_parser;
 
 // -- Flatten (private, crate, public)
 use adapters::*;
@@ -20,7 +24,18 @@ use adapters::*;
 pub(crate) use adapter_types::*;
 pub(crate) use dispatcher::*;
 
+pub use adapter_config::*;
+pub(crate) use adapter_error::parse_adapter_error;
+pub use adapter_error::AdapterError;
 pub use adapter_kind::*;
+pub use adapter_types::WebRequestData;
+pub use adapters::anthropic::AnthropicError;
+pub use adapters::gemini::{GeminiError, GeminiResponseMeta, GeminiSafetyRating};
+#[cfg(feature = "grpc")]
+pub use adapters::grpc::{GrpcChatClient, GrpcChatServiceConfig};
+pub use adapters::ollama::{OllamaModelManager, PullProgress, RunningModel};
+pub use adapters::openai::{OpenAIError, OpenAIResponseSchema};
+pub use response_parser::*;
 
 // -- Crate modules
 pub(crate) mod inter_stream;
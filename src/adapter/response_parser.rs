@@ -0,0 +1,328 @@
+//! The response parsers, to parse the non-standard provider responses (e.g., an OpenAI compatible proxy
+//! with different field names or an extra wrapping), set per adapter kind with `ClientConfig::with_response_parser`.
+
+use crate::adapter::anthropic::AnthropicAdapter;
+use crate::adapter::cohere::CohereAdapter;
+use crate::adapter::gemini::GeminiAdapter;
+use crate::adapter::inter_stream::{InterStreamEnd, InterStreamEvent};
+use crate::adapter::openai::OpenAIAdapter;
+use crate::adapter::{AdapterDispatcher, AdapterKind};
+use crate::chat::{
+	ChatOptionsSet, ChatResponse, ChatStream, ChatStreamEvent, ChatStreamResponse, MetaUsage, StreamChunk,
+};
+use crate::webc::{WebResponse, WebStream};
+use crate::{Error, ModelIden, Result};
+use futures::{Stream, StreamExt};
+use reqwest::{RequestBuilder, StatusCode};
+use reqwest_eventsource::{Event, EventSource};
+use serde_json::Value;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+// region:    --- ResponseParser
+
+/// A parser of the chat responses and of the chat stream events of an adapter kind.
+///
+/// Note: The stream events are the SSE event data (or the ndjson line for Cohere, and the JSON array item for Gemini,
+///       without the array delimiters).
+pub trait ResponseParser: Send + Sync {
+	/// Parse the JSON body of a chat response (the `request_id` and `client_request_id` are set by the client).
+	fn parse_chat_response(&self, model_iden: ModelIden, body: Value) -> Result<ChatResponse>;
+
+	/// Parse a chat stream event, returning `None` for the events to skip.
+	///
+	/// The stream ends on the first `ChatStreamEvent::End` (or at the end of the response).
+	fn parse_stream_event(&self, model_iden: &ModelIden, event: &str) -> Result<Option<ChatStreamEvent>>;
+
+	/// Parse the usage of a chat stream event, if any (called before `parse_stream_event`, with `capture_usage`).
+	///
+	/// The usages of the events are merged (the `Some` values of the last ones win), and are the `captured_usage`
+	/// of the end event, unless `parse_stream_event` returns an end event with its own `captured_usage`.
+	fn parse_stream_usage(&self, _model_iden: &ModelIden, _event: &str) -> Result<Option<MetaUsage>> {
+		Ok(None)
+	}
+}
+
+impl std::fmt::Debug for dyn ResponseParser {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "ResponseParser")
+	}
+}
+
+// endregion: --- ResponseParser
+
+// region:    --- TextResponseParser
+
+/// A text-only parser of an adapter kind, to wrap in a custom `ResponseParser` (e.g., after unwrapping the body).
+///
+/// - `parse_chat_response` is the full adapter parsing (content, tool calls, usage, ...).
+/// - `parse_stream_event` returns the text chunks and the end event only, so the tool call chunks
+///   and the reasoning of the stream events are skipped.
+/// - `parse_stream_usage` returns the usage of the stream events (e.g., for the `CostBudget`).
+///
+/// Note: Without a custom parser, the client uses the adapter streamers, which parse all of the stream events.
+#[derive(Debug, Clone, Copy)]
+pub struct TextResponseParser {
+	adapter_kind: AdapterKind,
+}
+
+impl TextResponseParser {
+	pub fn new(adapter_kind: AdapterKind) -> Self {
+		Self { adapter_kind }
+	}
+}
+
+impl ResponseParser for TextResponseParser {
+	fn parse_chat_response(&self, model_iden: ModelIden, body: Value) -> Result<ChatResponse> {
+		let web_response = WebResponse {
+			status: StatusCode::OK,
+			body,
+			request_id: None,
+		};
+		AdapterDispatcher::to_chat_response(model_iden, web_response)
+	}
+
+	fn parse_stream_event(&self, model_iden: &ModelIden, event: &str) -> Result<Option<ChatStreamEvent>> {
+		// According to the OpenAI spec, this is the end message
+		if event == "[DONE]" {
+			return Ok(Some(ChatStreamEvent::End(Default::default())));
+		}
+		let data: Value = serde_json::from_str(event).map_err(|serde_error| Error::StreamParse {
+			model_iden: model_iden.clone(),
+			serde_error,
+		})?;
+
+		let is_end = match self.adapter_kind {
+			AdapterKind::Anthropic => data.get("type").and_then(Value::as_str) == Some("message_stop"),
+			AdapterKind::Cohere => data.get("is_finished").and_then(Value::as_bool) == Some(true),
+			_ => false,
+		};
+		if is_end {
+			return Ok(Some(ChatStreamEvent::End(Default::default())));
+		}
+
+		let content_path = match self.adapter_kind {
+			AdapterKind::OpenAI
+			| AdapterKind::Ollama
+			| AdapterKind::Groq
+			| AdapterKind::Xai
+			| AdapterKind::DeepSeek => "/choices/0/delta/content",
+			AdapterKind::Anthropic => "/delta/text",
+			AdapterKind::Cohere => "/text",
+			AdapterKind::Gemini => "/candidates/0/content/parts/0/text",
+		};
+		let event = data.pointer(content_path).and_then(Value::as_str).map(|content| {
+			ChatStreamEvent::Chunk(StreamChunk {
+				content: content.to_string(),
+			})
+		});
+
+		Ok(event)
+	}
+
+	fn parse_stream_usage(&self, _model_iden: &ModelIden, event: &str) -> Result<Option<MetaUsage>> {
+		// Note: The events which are not JSON (e.g., the OpenAI `[DONE]`) have no usage.
+		let Ok(mut data) = serde_json::from_str::<Value>(event) else {
+			return Ok(None);
+		};
+
+		let usage_path = match self.adapter_kind {
+			AdapterKind::OpenAI | AdapterKind::Ollama | AdapterKind::Xai | AdapterKind::DeepSeek => "/usage",
+			AdapterKind::Groq => "/x_groq/usage",
+			// The input tokens are in the `message_start`, and the output tokens in the `message_delta`.
+			AdapterKind::Anthropic if data.get("type").and_then(Value::as_str) == Some("message_start") => {
+				"/message/usage"
+			}
+			AdapterKind::Anthropic => "/usage",
+			AdapterKind::Cohere => "/response/meta/tokens",
+			AdapterKind::Gemini => "/usageMetadata",
+		};
+		let Some(usage_value) = data.pointer_mut(usage_path).map(Value::take).filter(|v| v.is_object()) else {
+			return Ok(None);
+		};
+
+		let usage = match self.adapter_kind {
+			AdapterKind::OpenAI
+			| AdapterKind::Ollama
+			| AdapterKind::Groq
+			| AdapterKind::Xai
+			| AdapterKind::DeepSeek => OpenAIAdapter::into_usage(usage_value),
+			// Note: Partial usages, so the total is computed at the end from the merged input and output tokens.
+			AdapterKind::Anthropic => MetaUsage {
+				total_tokens: None,
+				..AnthropicAdapter::into_usage(usage_value)
+			},
+			AdapterKind::Cohere => CohereAdapter::into_usage(usage_value),
+			AdapterKind::Gemini => GeminiAdapter::into_usage(usage_value),
+		};
+
+		Ok(Some(usage))
+	}
+}
+
+// endregion: --- TextResponseParser
+
+// region:    --- ParserStreamer
+
+/// Execute the chat stream request with the response parser, with the transport of the adapter kind.
+pub(crate) fn to_parser_chat_stream(
+	model_iden: ModelIden,
+	reqwest_builder: RequestBuilder,
+	options_set: ChatOptionsSet<'_, '_>,
+	parser: Arc<dyn ResponseParser>,
+) -> Result<ChatStreamResponse> {
+	let stream_model_iden = model_iden.clone();
+	let events: EventStream = match model_iden.adapter_kind {
+		AdapterKind::Cohere | AdapterKind::Gemini => {
+			let web_stream = match model_iden.adapter_kind {
+				AdapterKind::Cohere => WebStream::new_with_delimiter(reqwest_builder, "\n"),
+				_ => WebStream::new_with_pretty_json_array(reqwest_builder),
+			};
+			// Note: The Gemini JSON array delimiters (`[` and `]`) and the empty messages are not parser events.
+			Box::pin(web_stream.filter_map(move |res| {
+				let res = match res {
+					Ok(message) if matches!(message.trim(), "" | "[" | "]") => None,
+					Ok(message) => Some(Ok(message)),
+					Err(err) => Some(Err(Error::WebStream {
+						model_iden: stream_model_iden.clone(),
+						cause: err.to_string(),
+					})),
+				};
+				futures::future::ready(res)
+			}))
+		}
+		_ => {
+			let event_source = EventSource::new(reqwest_builder)?;
+			Box::pin(
+				event_source
+					.take_while(|res| {
+						futures::future::ready(!matches!(res, Err(reqwest_eventsource::Error::StreamEnded)))
+					})
+					.filter_map(|res| async move {
+						match res {
+							Ok(Event::Open) => None,
+							Ok(Event::Message(message)) => Some(Ok(message.data)),
+							Err(err) => Some(Err(Error::ReqwestEventSource(Box::new(err)))),
+						}
+					}),
+			)
+		}
+	};
+
+	let parser_streamer = ParserStreamer {
+		inner: events,
+		parser,
+		model_iden: model_iden.clone(),
+		capture_content: options_set.capture_content().unwrap_or(false),
+		capture_usage: options_set.capture_usage().unwrap_or(false),
+		captured_content: None,
+		captured_usage: None,
+		started: false,
+		done: false,
+	};
+	let chat_stream = ChatStream::from_inter_stream(parser_streamer, model_iden.clone());
+
+	Ok(ChatStreamResponse {
+		model_iden,
+		stream: chat_stream,
+		client_request_id: None,
+	})
+}
+
+type EventStream = Pin<Box<dyn Stream<Item = Result<String>> + Send>>;
+
+struct ParserStreamer {
+	inner: EventStream,
+	parser: Arc<dyn ResponseParser>,
+	model_iden: ModelIden,
+	capture_content: bool,
+	capture_usage: bool,
+	captured_content: Option<String>,
+	captured_usage: Option<MetaUsage>,
+	started: bool,
+	done: bool,
+}
+
+impl ParserStreamer {
+	fn end_event(&mut self, end: InterStreamEnd) -> Poll<Option<Result<InterStreamEvent>>> {
+		self.done = true;
+		let captured_content = end.captured_content.or_else(|| self.captured_content.take());
+		let captured_usage = end.captured_usage.or_else(|| self.captured_usage.take().map(with_total_tokens));
+		Poll::Ready(Some(Ok(InterStreamEvent::End(InterStreamEnd {
+			captured_usage,
+			captured_content,
+		}))))
+	}
+
+	/// Merge the eventual usage of the event into the `captured_usage` (the `Some` values of the event win).
+	fn capture_event_usage(&mut self, data: &str) -> Result<()> {
+		let Some(usage) = self.parser.parse_stream_usage(&self.model_iden, data)? else {
+			return Ok(());
+		};
+		let captured = self.captured_usage.get_or_insert_with(MetaUsage::default);
+		captured.input_tokens = usage.input_tokens.or(captured.input_tokens);
+		captured.output_tokens = usage.output_tokens.or(captured.output_tokens);
+		captured.total_tokens = usage.total_tokens.or(captured.total_tokens);
+		captured.cache_read_input_tokens = usage.cache_read_input_tokens.or(captured.cache_read_input_tokens);
+		captured.cache_creation_input_tokens =
+			usage.cache_creation_input_tokens.or(captured.cache_creation_input_tokens);
+		captured.accepted_prediction_tokens = usage.accepted_prediction_tokens.or(captured.accepted_prediction_tokens);
+		captured.rejected_prediction_tokens = usage.rejected_prediction_tokens.or(captured.rejected_prediction_tokens);
+		Ok(())
+	}
+}
+
+/// The usage with the `total_tokens` computed from the input and output tokens, if not set.
+fn with_total_tokens(mut usage: MetaUsage) -> MetaUsage {
+	if usage.total_tokens.is_none() && (usage.input_tokens.is_some() || usage.output_tokens.is_some()) {
+		usage.total_tokens = Some(usage.input_tokens.unwrap_or(0) + usage.output_tokens.unwrap_or(0));
+	}
+	usage
+}
+
+impl Stream for ParserStreamer {
+	type Item = Result<InterStreamEvent>;
+
+	fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		if self.done {
+			return Poll::Ready(None);
+		}
+		if !self.started {
+			self.started = true;
+			return Poll::Ready(Some(Ok(InterStreamEvent::Start)));
+		}
+		while let Poll::Ready(event) = self.inner.poll_next_unpin(cx) {
+			let data = match event {
+				Some(Ok(data)) => data,
+				Some(Err(err)) => return Poll::Ready(Some(Err(err))),
+				None => return self.end_event(InterStreamEnd::default()),
+			};
+			if self.capture_usage {
+				if let Err(err) = self.capture_event_usage(&data) {
+					return Poll::Ready(Some(Err(err)));
+				}
+			}
+			match self.parser.parse_stream_event(&self.model_iden, &data) {
+				Ok(Some(ChatStreamEvent::Chunk(chunk))) => {
+					if self.capture_content {
+						self.captured_content.get_or_insert_with(String::new).push_str(&chunk.content);
+					}
+					return Poll::Ready(Some(Ok(InterStreamEvent::Chunk(chunk.content))));
+				}
+				Ok(Some(ChatStreamEvent::End(end))) => {
+					let end = InterStreamEnd {
+						captured_usage: end.captured_usage,
+						captured_content: end.captured_content.and_then(|content| content.text_into_string()),
+					};
+					return self.end_event(end);
+				}
+				Ok(Some(ChatStreamEvent::Start) | None) => continue,
+				Err(err) => return Poll::Ready(Some(Err(err))),
+			}
+		}
+		Poll::Pending
+	}
+}
+
+// endregion: --- ParserStreamer
@@ -0,0 +1,221 @@
+use crate::adapter::AdapterKind;
+use crate::assistants::{Assistant, AssistantRequest, Run, RunStatus, Thread, ThreadMessage};
+use crate::chat::{ChatMessage, ChatRole, MessageContent, ToolResponse, ToolSchemaRegistry};
+use crate::{Client, Error, ModelIden, Result};
+use serde_json::{json, Value};
+use std::time::Duration;
+use value_ext::JsonValueExt;
+
+/// The header required by the Assistants API (v2).
+const OPENAI_BETA_HEADER: (&str, &str) = ("OpenAI-Beta", "assistants=v2");
+
+/// Client for the OpenAI Assistants API (v2).
+/// Built with `client.assistant_client()` and shares the client's web client and config.
+#[derive(Debug, Clone)]
+pub struct AssistantClient {
+	client: Client,
+	adapter_kind: AdapterKind,
+}
+
+// region:    --- Constructors
+
+impl AssistantClient {
+	pub fn new(client: Client) -> Self {
+		Self {
+			client,
+			adapter_kind: AdapterKind::OpenAI,
+		}
+	}
+}
+
+impl Client {
+	/// Returns an `AssistantClient` using this client's web client and config.
+	pub fn assistant_client(&self) -> AssistantClient {
+		AssistantClient::new(self.clone())
+	}
+}
+
+// endregion: --- Constructors
+
+// region:    --- Public Assistant Functions
+
+impl AssistantClient {
+	pub async fn create_assistant(&self, assistant_req: AssistantRequest) -> Result<Assistant> {
+		let AssistantRequest {
+			model,
+			name,
+			instructions,
+			tools,
+		} = assistant_req;
+
+		let mut payload = json!({ "model": model });
+		if let Some(name) = name {
+			payload.x_insert("name", name)?;
+		}
+		if let Some(instructions) = instructions {
+			payload.x_insert("instructions", instructions)?;
+		}
+		if !tools.is_empty() {
+			let tools: Vec<Value> = tools
+				.into_iter()
+				.map(|tool| {
+					json!({
+						"type": "function",
+						"function": {
+							"name": tool.name,
+							"description": tool.description,
+							"parameters": tool.schema,
+						}
+					})
+				})
+				.collect();
+			payload.x_insert("tools", tools)?;
+		}
+
+		let assistant_res = self
+			.client
+			.adapter_api_post(self.adapter_kind, "assistants", payload, &[OPENAI_BETA_HEADER])
+			.await?;
+		let assistant: Assistant = serde_json::from_value(assistant_res)?;
+		Ok(assistant)
+	}
+
+	/// Note: Returns the first page of assistants (API default page size).
+	pub async fn list_assistants(&self) -> Result<Vec<Assistant>> {
+		let mut list_res = self
+			.client
+			.adapter_api_get(self.adapter_kind, "assistants", &[OPENAI_BETA_HEADER])
+			.await?;
+		let assistants: Vec<Assistant> = list_res.x_take("data")?;
+		Ok(assistants)
+	}
+
+	pub async fn create_thread(&self) -> Result<Thread> {
+		let thread_res = self
+			.client
+			.adapter_api_post(self.adapter_kind, "threads", json!({}), &[OPENAI_BETA_HEADER])
+			.await?;
+		let thread: Thread = serde_json::from_value(thread_res)?;
+		Ok(thread)
+	}
+
+	/// Add a user or assistant text message to the thread.
+	pub async fn add_message_to_thread(&self, thread_id: &str, msg: ChatMessage) -> Result<ThreadMessage> {
+		let model_iden = ModelIden::new(self.adapter_kind, "");
+		let role = match msg.role {
+			ChatRole::User => "user",
+			ChatRole::Assistant => "assistant",
+			role => return Err(Error::MessageRoleNotSupported { model_iden, role }),
+		};
+		let MessageContent::Text(content) = msg.content else {
+			return Err(Error::MessageContentTypeNotSupported {
+				model_iden,
+				cause: "Only text content is supported for the thread messages",
+			});
+		};
+
+		let path = format!("threads/{thread_id}/messages");
+		let msg_res = self
+			.client
+			.adapter_api_post(
+				self.adapter_kind,
+				&path,
+				json!({"role": role, "content": content}),
+				&[OPENAI_BETA_HEADER],
+			)
+			.await?;
+		let thread_msg: ThreadMessage = serde_json::from_value(msg_res)?;
+		Ok(thread_msg)
+	}
+
+	pub async fn run_thread(&self, thread_id: &str, assistant_id: &str) -> Result<Run> {
+		let path = format!("threads/{thread_id}/runs");
+		let run_res = self
+			.client
+			.adapter_api_post(
+				self.adapter_kind,
+				&path,
+				json!({"assistant_id": assistant_id}),
+				&[OPENAI_BETA_HEADER],
+			)
+			.await?;
+		let run: Run = serde_json::from_value(run_res)?;
+		Ok(run)
+	}
+
+	/// Get the latest state of the run.
+	pub async fn poll_run(&self, run: &Run) -> Result<Run> {
+		let path = format!("threads/{}/runs/{}", run.thread_id, run.id);
+		let run_res = self
+			.client
+			.adapter_api_get(self.adapter_kind, &path, &[OPENAI_BETA_HEADER])
+			.await?;
+		let run: Run = serde_json::from_value(run_res)?;
+		Ok(run)
+	}
+
+	/// Submit the tool responses of a run `RequiresAction` (for the `run.tool_calls()`), and continue the run.
+	pub async fn submit_tool_outputs(&self, run: &Run, tool_responses: Vec<ToolResponse>) -> Result<Run> {
+		let tool_outputs: Vec<Value> = tool_responses
+			.into_iter()
+			.map(|tool_response| json!({"tool_call_id": tool_response.call_id, "output": tool_response.content}))
+			.collect();
+
+		let path = format!("threads/{}/runs/{}/submit_tool_outputs", run.thread_id, run.id);
+		let run_res = self
+			.client
+			.adapter_api_post(
+				self.adapter_kind,
+				&path,
+				json!({"tool_outputs": tool_outputs}),
+				&[OPENAI_BETA_HEADER],
+			)
+			.await?;
+		let run: Run = serde_json::from_value(run_res)?;
+		Ok(run)
+	}
+
+	/// Poll the run every `poll_interval` until it is terminal, dispatching the tool calls of each `RequiresAction`
+	/// to the `registry` (see `ToolSchemaRegistry::dispatch_versioned`) and submitting their outputs.
+	///
+	/// Note: A tool call to an unknown tool (or failing) is answered with the `{"error": ...}` output,
+	///       so the assistant can recover from it.
+	pub async fn run_until_done(
+		&self,
+		run: Run,
+		registry: &ToolSchemaRegistry,
+		poll_interval: Duration,
+	) -> Result<Run> {
+		let mut run = run;
+		while !run.status.is_terminal() {
+			if run.status == RunStatus::RequiresAction {
+				let tool_responses: Vec<ToolResponse> = run
+					.tool_calls()?
+					.iter()
+					.map(|tool_call| {
+						ToolResponse::new(tool_call.call_id.clone(), registry.dispatch_versioned(tool_call))
+					})
+					.collect();
+				run = self.submit_tool_outputs(&run, tool_responses).await?;
+			} else {
+				tokio::time::sleep(poll_interval).await;
+				run = self.poll_run(&run).await?;
+			}
+		}
+
+		Ok(run)
+	}
+
+	/// Note: Returns the first page of messages (API default order, newest first).
+	pub async fn list_thread_messages(&self, thread_id: &str) -> Result<Vec<ThreadMessage>> {
+		let path = format!("threads/{thread_id}/messages");
+		let mut list_res = self
+			.client
+			.adapter_api_get(self.adapter_kind, &path, &[OPENAI_BETA_HEADER])
+			.await?;
+		let messages: Vec<ThreadMessage> = list_res.x_take("data")?;
+		Ok(messages)
+	}
+}
+
+// endregion: --- Public Assistant Functions
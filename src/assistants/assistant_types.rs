@@ -0,0 +1,158 @@
+use crate::chat::{Tool, ToolCall};
+use crate::Result;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+// region:    --- AssistantRequest
+
+/// The assistant creation request for `AssistantClient::create_assistant`.
+#[derive(Debug, Clone)]
+pub struct AssistantRequest {
+	pub model: String,
+	pub name: Option<String>,
+	pub instructions: Option<String>,
+	pub tools: Vec<Tool>,
+}
+
+/// Constructors
+impl AssistantRequest {
+	pub fn new(model: impl Into<String>) -> Self {
+		Self {
+			model: model.into(),
+			name: None,
+			instructions: None,
+			tools: Vec::new(),
+		}
+	}
+}
+
+/// Chainable Setters
+impl AssistantRequest {
+	pub fn with_name(mut self, name: impl Into<String>) -> Self {
+		self.name = Some(name.into());
+		self
+	}
+
+	pub fn with_instructions(mut self, instructions: impl Into<String>) -> Self {
+		self.instructions = Some(instructions.into());
+		self
+	}
+
+	pub fn append_tool(mut self, tool: impl Into<Tool>) -> Self {
+		self.tools.push(tool.into());
+		self
+	}
+}
+
+// endregion: --- AssistantRequest
+
+// region:    --- Assistant
+
+/// The assistant as returned by the `/v1/assistants` API.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Assistant {
+	pub id: String,
+	pub name: Option<String>,
+	pub instructions: Option<String>,
+	/// The assistant tools in the OpenAI format (e.g., `{"type": "function", "function": {...}}`).
+	#[serde(default)]
+	pub tools: Vec<Value>,
+	pub model: String,
+}
+
+// endregion: --- Assistant
+
+// region:    --- Thread
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Thread {
+	pub id: String,
+}
+
+/// A thread message as returned by the `/v1/threads/{thread_id}/messages` API.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThreadMessage {
+	pub id: String,
+	pub thread_id: String,
+	/// `user` or `assistant`
+	pub role: String,
+	/// The content parts in the OpenAI format (e.g., `{"type": "text", "text": {"value": "..."}}`).
+	#[serde(default)]
+	pub content: Vec<Value>,
+	pub created_at: i64,
+}
+
+/// Getters
+impl ThreadMessage {
+	/// Returns the concatenated text content parts (empty if none).
+	pub fn text(&self) -> String {
+		self.content
+			.iter()
+			.filter_map(|part| part.pointer("/text/value").and_then(|v| v.as_str()))
+			.collect::<Vec<_>>()
+			.join("")
+	}
+}
+
+// endregion: --- Thread
+
+// region:    --- Run
+
+/// A thread run as returned by the `/v1/threads/{thread_id}/runs` API.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Run {
+	pub id: String,
+	pub thread_id: String,
+	pub assistant_id: String,
+	pub status: RunStatus,
+	/// Present when the status is `RequiresAction` (see `Run::tool_calls`).
+	pub required_action: Option<Value>,
+}
+
+/// Getters
+impl Run {
+	/// Returns the tool calls to execute when the status is `RequiresAction` (empty otherwise).
+	///
+	/// The tool responses are then sent with `AssistantClient::submit_tool_outputs`.
+	pub fn tool_calls(&self) -> Result<Vec<ToolCall>> {
+		let Some(Value::Array(raw_tool_calls)) = self
+			.required_action
+			.as_ref()
+			.and_then(|action| action.pointer("/submit_tool_outputs/tool_calls"))
+		else {
+			return Ok(Vec::new());
+		};
+
+		raw_tool_calls.iter().cloned().map(ToolCall::from_openai_value).collect()
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RunStatus {
+	Queued,
+	InProgress,
+	RequiresAction,
+	Cancelling,
+	Cancelled,
+	Failed,
+	Completed,
+	Incomplete,
+	Expired,
+}
+
+impl RunStatus {
+	/// Returns true if the run will not change anymore.
+	pub fn is_terminal(&self) -> bool {
+		matches!(
+			self,
+			RunStatus::Cancelled
+				| RunStatus::Failed
+				| RunStatus::Completed
+				| RunStatus::Incomplete
+				| RunStatus::Expired
+		)
+	}
+}
+
+// endregion: --- Run
@@ -0,0 +1,21 @@
+//! The assistants module allows using the OpenAI Assistants API (v2):
+//! create an assistant, create a thread, add messages, run the thread, and poll the run.
+//!
+//! Flow: `create_assistant` -> `create_thread` -> `add_message_to_thread` -> `run_thread` -> `poll_run`
+//! (on `RunStatus::RequiresAction`, execute the `run.tool_calls()` and `submit_tool_outputs`) -> `list_thread_messages`.
+//!
+//! Or, `run_until_done` to poll the run and dispatch its tool calls through a `ToolSchemaRegistry`.
+//!
+//! API DOC: https://platform.openai.com/docs/api-reference/assistants
+//!
+//! Note: Only the OpenAI adapter is supported for now.
+
+// region:    --- Modules
+
+mod assistant_client;
+mod assistant_types;
+
+pub use assistant_client::*;
+pub use assistant_types::*;
+
+// endregion: --- Modules
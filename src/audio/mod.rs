@@ -0,0 +1,9 @@
+//! The genai audio module, with the audio transcription types (see `Client::transcribe_with_adapter`).
+
+// region:    --- Modules
+
+mod transcription_types;
+
+pub use transcription_types::*;
+
+// endregion: --- Modules
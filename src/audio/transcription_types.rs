@@ -0,0 +1,72 @@
+use crate::ModelIden;
+use serde::{Deserialize, Serialize};
+
+// region:    --- TranscriptionRequest
+
+/// An audio transcription request (e.g., OpenAI or Groq Whisper).
+#[derive(Debug, Clone)]
+pub struct TranscriptionRequest {
+	/// The transcription model name (e.g., "whisper-1", "whisper-large-v3").
+	pub model: String,
+
+	/// The audio file name. The extension is used by the providers to detect the audio format (e.g., "audio.mp3").
+	pub file_name: String,
+
+	/// The audio file content.
+	pub audio: Vec<u8>,
+
+	/// The eventual language of the audio (ISO-639-1, e.g., "en").
+	pub language: Option<String>,
+
+	/// The eventual prompt to guide the transcription style or continue a previous segment.
+	pub prompt: Option<String>,
+
+	pub temperature: Option<f64>,
+}
+
+/// Constructors
+impl TranscriptionRequest {
+	pub fn new(model: impl Into<String>, file_name: impl Into<String>, audio: impl Into<Vec<u8>>) -> Self {
+		Self {
+			model: model.into(),
+			file_name: file_name.into(),
+			audio: audio.into(),
+			language: None,
+			prompt: None,
+			temperature: None,
+		}
+	}
+}
+
+/// Chainable Setters
+impl TranscriptionRequest {
+	pub fn with_language(mut self, language: impl Into<String>) -> Self {
+		self.language = Some(language.into());
+		self
+	}
+
+	pub fn with_prompt(mut self, prompt: impl Into<String>) -> Self {
+		self.prompt = Some(prompt.into());
+		self
+	}
+
+	pub fn with_temperature(mut self, temperature: f64) -> Self {
+		self.temperature = Some(temperature);
+		self
+	}
+}
+
+// endregion: --- TranscriptionRequest
+
+// region:    --- TranscriptionResponse
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TranscriptionResponse {
+	/// The transcribed text.
+	pub text: String,
+
+	/// The resolved Model Identifier (AdapterKind/ModelName) used for this request.
+	pub model_iden: ModelIden,
+}
+
+// endregion: --- TranscriptionResponse
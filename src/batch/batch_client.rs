@@ -0,0 +1,196 @@
+use crate::adapter::{AdapterDispatcher, AdapterKind, ServiceType, WebRequestData};
+use crate::batch::{BatchJob, BatchRequest, BatchResult, BatchStatus};
+use crate::chat::ChatOptionsSet;
+use crate::webc::WebResponse;
+use crate::{Client, Error, ModelIden, Result};
+use reqwest::multipart::{Form, Part};
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+use std::time::Duration;
+use value_ext::JsonValueExt;
+
+/// The chat completion endpoint used for each batch line.
+const BATCH_ENDPOINT: &str = "/v1/chat/completions";
+
+/// Client for the OpenAI Batch API.
+/// Built with `client.batch_client()` and shares the client's web client and config (auth, resolvers, chat options).
+#[derive(Debug, Clone)]
+pub struct BatchClient {
+	client: Client,
+	adapter_kind: AdapterKind,
+}
+
+// region:    --- Constructors
+
+impl BatchClient {
+	pub fn new(client: Client) -> Self {
+		Self {
+			client,
+			adapter_kind: AdapterKind::OpenAI,
+		}
+	}
+}
+
+impl Client {
+	/// Returns a `BatchClient` using this client's web client and config.
+	pub fn batch_client(&self) -> BatchClient {
+		BatchClient::new(self.clone())
+	}
+}
+
+// endregion: --- Constructors
+
+// region:    --- Public Batch Functions
+
+impl BatchClient {
+	/// Upload the requests as a JSONL file and create the batch.
+	pub async fn submit_batch(&self, requests: Vec<BatchRequest>) -> Result<BatchJob> {
+		if requests.is_empty() {
+			return Err(Error::BatchHasNoRequests);
+		}
+
+		// -- Build the JSONL content
+		let mut lines: Vec<String> = Vec::with_capacity(requests.len());
+		for request in requests {
+			let line = self.to_batch_line(request)?;
+			lines.push(serde_json::to_string(&line)?);
+		}
+		let content = lines.join("\n");
+
+		// -- Upload the input file
+		let part = Part::bytes(content.into_bytes()).file_name("batch_input.jsonl");
+		let form = Form::new().text("purpose", "batch").part("file", part);
+		let mut file_res = self
+			.client
+			.adapter_api_post_multipart(self.adapter_kind, "files", form, &[])
+			.await?;
+		let input_file_id: String = file_res.x_take("id")?;
+
+		// -- Create the batch
+		let payload = json!({
+			"input_file_id": input_file_id,
+			"endpoint": BATCH_ENDPOINT,
+			"completion_window": "24h",
+		});
+		let batch_res = self.client.adapter_api_post(self.adapter_kind, "batches", payload, &[]).await?;
+		let job: BatchJob = serde_json::from_value(batch_res)?;
+
+		Ok(job)
+	}
+
+	/// Get the latest state of the batch job.
+	pub async fn poll_batch(&self, job: &BatchJob) -> Result<BatchJob> {
+		let path = format!("batches/{}", job.batch_id);
+		let batch_res = self.client.adapter_api_get(self.adapter_kind, &path, &[]).await?;
+		let job: BatchJob = serde_json::from_value(batch_res)?;
+		Ok(job)
+	}
+
+	/// Poll the batch every `poll_interval` until it is terminal, and return the results of all requests
+	/// (succeeded and failed).
+	///
+	/// Returns `Error::BatchNotCompleted` if the batch failed, expired, or was cancelled.
+	pub async fn wait_for_batch(&self, job: BatchJob, poll_interval: Duration) -> Result<Vec<BatchResult>> {
+		let mut job = job;
+		while !job.status.is_terminal() {
+			tokio::time::sleep(poll_interval).await;
+			job = self.poll_batch(&job).await?;
+		}
+
+		self.get_batch_results(&job).await
+	}
+
+	/// Download and parse the output (and error) files of a completed batch.
+	pub async fn get_batch_results(&self, job: &BatchJob) -> Result<Vec<BatchResult>> {
+		if job.status != BatchStatus::Completed {
+			return Err(Error::BatchNotCompleted {
+				batch_id: job.batch_id.clone(),
+				status: job.status,
+			});
+		}
+
+		let mut results = Vec::new();
+		for file_id in [&job.output_file_id, &job.error_file_id].into_iter().flatten() {
+			let path = format!("files/{file_id}/content");
+			let content = self.client.adapter_api_get_text(self.adapter_kind, &path, &[]).await?;
+			for line in content.lines().filter(|line| !line.trim().is_empty()) {
+				results.push(self.parse_result_line(line)?);
+			}
+		}
+
+		Ok(results)
+	}
+}
+
+// endregion: --- Public Batch Functions
+
+// region:    --- Support
+
+impl BatchClient {
+	fn to_batch_line(&self, request: BatchRequest) -> Result<Value> {
+		let BatchRequest {
+			custom_id,
+			chat_req,
+			model,
+			options,
+		} = request;
+
+		let merged_options = chat_req.merged_options(options.as_ref());
+		let options_set = ChatOptionsSet::default()
+			.with_chat_options(merged_options.as_ref().or(options.as_ref()))
+			.with_client_options(self.client.config().chat_options());
+
+		let model = self.client.default_model(&model)?;
+		if model.adapter_kind != self.adapter_kind {
+			return Err(Error::BatchNotSupported { model_iden: model });
+		}
+		let target = self.client.config().resolve_service_target(model)?;
+
+		let WebRequestData { payload, .. } =
+			AdapterDispatcher::to_web_request_data(target, ServiceType::Chat, chat_req, options_set)?;
+
+		Ok(json!({
+			"custom_id": custom_id,
+			"method": "POST",
+			"url": BATCH_ENDPOINT,
+			"body": payload,
+		}))
+	}
+
+	/// Parse a line of the output/error file, for example:
+	/// `{"id": "batch_req_..", "custom_id": "req-1", "response": {"status_code": 200, "body": {..}}, "error": null}`
+	fn parse_result_line(&self, line: &str) -> Result<BatchResult> {
+		let mut line: Value = serde_json::from_str(line)?;
+		let custom_id: String = line.x_take("custom_id")?;
+		let mut error: Option<Value> = line.x_take::<Option<Value>>("error")?.filter(|v| !v.is_null());
+
+		let mut chat_response = None;
+		if let Some(mut response) = line.x_take::<Option<Value>>("response")?.filter(|v| !v.is_null()) {
+			let status_code: u16 = response.x_take("status_code")?;
+			let mut body: Value = response.x_take("body")?;
+			let status = StatusCode::from_u16(status_code).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+
+			if status.is_success() {
+				let model_name: String = body.x_get("model")?;
+				let model_iden = ModelIden::new(self.adapter_kind, model_name);
+				let request_id = response.x_take::<Option<String>>("request_id")?;
+				let web_res = WebResponse {
+					status,
+					body,
+					request_id,
+				};
+				chat_response = Some(AdapterDispatcher::to_chat_response(model_iden, web_res)?);
+			} else if error.is_none() {
+				error = body.x_take::<Option<Value>>("error")?;
+			}
+		}
+
+		Ok(BatchResult {
+			custom_id,
+			chat_response,
+			error,
+		})
+	}
+}
+
+// endregion: --- Support
@@ -0,0 +1,98 @@
+use crate::chat::{ChatOptions, ChatRequest, ChatResponse};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+// region:    --- BatchRequest
+
+/// One chat request of a batch.
+#[derive(Debug, Clone)]
+pub struct BatchRequest {
+	/// The user id of this request, used to match the `BatchResult` (must be unique in the batch).
+	pub custom_id: String,
+	pub chat_req: ChatRequest,
+	pub model: String,
+	pub options: Option<ChatOptions>,
+}
+
+/// Constructors
+impl BatchRequest {
+	pub fn new(custom_id: impl Into<String>, model: impl Into<String>, chat_req: ChatRequest) -> Self {
+		Self {
+			custom_id: custom_id.into(),
+			chat_req,
+			model: model.into(),
+			options: None,
+		}
+	}
+}
+
+/// Chainable Setters
+impl BatchRequest {
+	pub fn with_options(mut self, options: ChatOptions) -> Self {
+		self.options = Some(options);
+		self
+	}
+}
+
+// endregion: --- BatchRequest
+
+// region:    --- BatchJob
+
+/// The batch job as returned by the `/v1/batches` API.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchJob {
+	#[serde(alias = "id")]
+	pub batch_id: String,
+	pub status: BatchStatus,
+	pub output_file_id: Option<String>,
+	pub error_file_id: Option<String>,
+	pub request_counts: Option<BatchRequestCounts>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchStatus {
+	Validating,
+	Failed,
+	InProgress,
+	Finalizing,
+	Completed,
+	Expired,
+	Cancelling,
+	Cancelled,
+}
+
+impl BatchStatus {
+	/// Returns true if the batch will not change status anymore.
+	pub fn is_terminal(&self) -> bool {
+		matches!(
+			self,
+			BatchStatus::Completed | BatchStatus::Failed | BatchStatus::Expired | BatchStatus::Cancelled
+		)
+	}
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BatchRequestCounts {
+	pub total: u32,
+	pub completed: u32,
+	pub failed: u32,
+}
+
+// endregion: --- BatchJob
+
+// region:    --- BatchResult
+
+/// The result of one `BatchRequest`, matched by `custom_id`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchResult {
+	pub custom_id: String,
+
+	/// The chat response if the request succeeded.
+	pub chat_response: Option<ChatResponse>,
+
+	/// The eventual error object returned by the API for this request.
+	pub error: Option<Value>,
+}
+
+// endregion: --- BatchResult
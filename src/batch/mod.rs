@@ -0,0 +1,18 @@
+//! The batch module allows executing many chat requests asynchronously with the OpenAI Batch API
+//! (lower cost, results within the completion window).
+//!
+//! Flow: `submit_batch` (uploads the JSONL file to `/v1/files`, then creates the batch) -> `poll_batch` / `wait_for_batch`.
+//!
+//! API DOC: https://platform.openai.com/docs/guides/batch
+//!
+//! Note: Only the OpenAI adapter is supported for now.
+
+// region:    --- Modules
+
+mod batch_client;
+mod batch_types;
+
+pub use batch_client::*;
+pub use batch_types::*;
+
+// endregion: --- Modules
@@ -0,0 +1,204 @@
+use crate::chat::{ChatRequest, MetaUsage};
+use crate::eval::CostEstimator;
+use crate::{Client, ModelCapabilities};
+use std::collections::HashMap;
+use std::time::Instant;
+
+// region:    --- BenchmarkTask
+
+/// A benchmark task, with its quality scoring.
+///
+/// The quality score of a response is the ratio of the `expected_keywords` found in the response text
+/// (case-insensitive, `1.0` without keywords) multiplied by the `score_fn` score (`1.0` by default).
+#[derive(Debug, Clone)]
+pub struct BenchmarkTask {
+	pub name: String,
+	pub chat_req: ChatRequest,
+	pub expected_keywords: Vec<String>,
+	pub score_fn: fn(&str) -> f64,
+}
+
+/// Constructors
+impl BenchmarkTask {
+	pub fn new(name: impl Into<String>, chat_req: ChatRequest) -> Self {
+		Self {
+			name: name.into(),
+			chat_req,
+			expected_keywords: Vec::new(),
+			score_fn: |_| 1.0,
+		}
+	}
+}
+
+/// Chainable Setters
+impl BenchmarkTask {
+	pub fn with_expected_keywords(mut self, keywords: &[&str]) -> Self {
+		self.expected_keywords = keywords.iter().map(|keyword| keyword.to_string()).collect();
+		self
+	}
+
+	pub fn with_score_fn(mut self, score_fn: fn(&str) -> f64) -> Self {
+		self.score_fn = score_fn;
+		self
+	}
+}
+
+impl BenchmarkTask {
+	/// The quality score of a response text (see `BenchmarkTask`).
+	pub fn score(&self, text: &str) -> f64 {
+		let keywords_ratio = if self.expected_keywords.is_empty() {
+			1.0
+		} else {
+			let text = text.to_lowercase();
+			let found = self
+				.expected_keywords
+				.iter()
+				.filter(|keyword| text.contains(&keyword.to_lowercase()))
+				.count();
+			found as f64 / self.expected_keywords.len() as f64
+		};
+		keywords_ratio * (self.score_fn)(text)
+	}
+}
+
+// endregion: --- BenchmarkTask
+
+// region:    --- BenchmarkRunner
+
+/// Run a suite of `BenchmarkTask` against a list of models.
+///
+/// Note: The tasks are executed one at a time (for comparable latencies), with the model client
+///       (see `with_model_client`) or the default client.
+#[derive(Debug, Clone)]
+pub struct BenchmarkRunner {
+	client: Client,
+	model_clients: HashMap<String, Client>,
+}
+
+/// Constructors
+impl BenchmarkRunner {
+	pub fn new(client: Client) -> Self {
+		Self {
+			client,
+			model_clients: HashMap::new(),
+		}
+	}
+}
+
+/// Chainable Setters
+impl BenchmarkRunner {
+	/// Use a specific client for a model (e.g., with a custom `ServiceTargetResolver`).
+	pub fn with_model_client(mut self, model: &str, client: Client) -> Self {
+		self.model_clients.insert(model.to_string(), client);
+		self
+	}
+}
+
+impl BenchmarkRunner {
+	/// Run all of the tasks for each model, returning a result per model (in the `models` order).
+	///
+	/// Note: The failed requests are counted in the `failed_tasks` (not in the averages, tokens, and cost).
+	pub async fn run(&self, tasks: &[BenchmarkTask], models: &[&str]) -> BenchmarkReport {
+		let mut results = Vec::with_capacity(models.len());
+		for &model in models {
+			results.push(self.run_model(tasks, model).await);
+		}
+		BenchmarkReport { results }
+	}
+
+	async fn run_model(&self, tasks: &[BenchmarkTask], model: &str) -> ModelBenchmarkResult {
+		let client = self.model_clients.get(model).unwrap_or(&self.client);
+		let cost_estimator = ModelCapabilities::for_model(model).map(|caps| CostEstimator::new(caps.price));
+
+		let mut result = ModelBenchmarkResult::new(model);
+		let mut latency_ms_sum = 0.0;
+		let mut score_sum = 0.0;
+		let mut completed = 0;
+
+		for task in tasks {
+			let start = Instant::now();
+			let chat_res = match client.exec_chat(model, task.chat_req.clone(), None).await {
+				Ok(chat_res) => chat_res,
+				Err(err) => {
+					tracing::warn!(model, task = task.name, %err, "benchmark task failed");
+					result.failed_tasks += 1;
+					continue;
+				}
+			};
+			latency_ms_sum += start.elapsed().as_secs_f64() * 1000.0;
+			score_sum += task.score(chat_res.content_text_as_str().unwrap_or_default());
+			completed += 1;
+
+			let MetaUsage {
+				input_tokens,
+				output_tokens,
+				..
+			} = chat_res.usage;
+			result.input_tokens += input_tokens.unwrap_or(0).max(0) as u64;
+			result.output_tokens += output_tokens.unwrap_or(0).max(0) as u64;
+			if let Some(cost_estimator) = cost_estimator {
+				result.total_cost_usd += cost_estimator.estimate_usd(&chat_res.usage);
+			}
+		}
+
+		if completed > 0 {
+			result.avg_latency_ms = latency_ms_sum / completed as f64;
+			result.avg_quality_score = score_sum / completed as f64;
+		}
+		result
+	}
+}
+
+// endregion: --- BenchmarkRunner
+
+// region:    --- BenchmarkReport
+
+#[derive(Debug, Clone)]
+pub struct BenchmarkReport {
+	pub results: Vec<ModelBenchmarkResult>,
+}
+
+impl BenchmarkReport {
+	/// Returns the result of a model, if benchmarked.
+	pub fn result(&self, model: &str) -> Option<&ModelBenchmarkResult> {
+		self.results.iter().find(|result| result.model == model)
+	}
+
+	/// Returns the result with the best average quality score (the lowest cost on a tie).
+	pub fn best_quality(&self) -> Option<&ModelBenchmarkResult> {
+		self.results.iter().max_by(|a, b| {
+			a.avg_quality_score
+				.total_cmp(&b.avg_quality_score)
+				.then(b.total_cost_usd.total_cmp(&a.total_cost_usd))
+		})
+	}
+}
+
+/// The benchmark result of a model (the averages are `0.0` when all of the tasks failed).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModelBenchmarkResult {
+	pub model: String,
+	pub avg_latency_ms: f64,
+	pub avg_quality_score: f64,
+	pub input_tokens: u64,
+	pub output_tokens: u64,
+	/// The estimated cost from the `ModelCapabilities` price (`0.0` for the unknown models).
+	pub total_cost_usd: f64,
+	pub failed_tasks: usize,
+}
+
+impl ModelBenchmarkResult {
+	fn new(model: &str) -> Self {
+		Self {
+			model: model.to_string(),
+			avg_latency_ms: 0.0,
+			avg_quality_score: 0.0,
+			input_tokens: 0,
+			output_tokens: 0,
+			total_cost_usd: 0.0,
+			failed_tasks: 0,
+		}
+	}
+}
+
+// endregion: --- BenchmarkReport
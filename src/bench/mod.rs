@@ -0,0 +1,10 @@
+//! Provider-agnostic model benchmarking, to choose the right model for a specific workload
+//! (latency, quality scores, tokens, and cost of a suite of tasks).
+
+// region:    --- Modules
+
+mod benchmark_runner;
+
+pub use benchmark_runner::*;
+
+// endregion: --- Modules
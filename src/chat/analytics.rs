@@ -0,0 +1,137 @@
+//! Conversation analytics (message counts and sizes, tool usage), e.g., for prompt optimization,
+//! billing estimation, or monitoring.
+
+use crate::chat::{ChatRequest, ChatResponse, ChatRole, ContentPart, MessageContent};
+use std::collections::HashMap;
+
+// region:    --- ConversationStats
+
+/// The statistics of a conversation (see `ChatRequest::stats`).
+///
+/// Note: The char counts are in chars (not bytes), of the text contents only
+///       (text parts included, tool calls and binary contents excluded).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConversationStats {
+	/// The number of turns, a turn being a run of consecutive messages of the same role (system excluded).
+	pub turn_count: usize,
+	pub user_message_count: usize,
+	pub assistant_message_count: usize,
+	/// The number of tool calls of the assistant messages.
+	pub tool_call_count: usize,
+	/// The char count of the `.system`, and of all messages.
+	pub total_char_count: usize,
+	/// The average char count of the messages (system excluded).
+	pub avg_message_len: f64,
+	/// The char count of the `.system` and of the system messages.
+	pub system_char_count: usize,
+}
+
+impl ChatRequest {
+	/// Returns the statistics of this conversation.
+	pub fn stats(&self) -> ConversationStats {
+		let mut stats = ConversationStats {
+			system_char_count: self.system.as_deref().map(|s| s.chars().count()).unwrap_or_default(),
+			..Default::default()
+		};
+
+		let mut message_count = 0;
+		let mut message_char_count = 0;
+		let mut last_role: Option<&ChatRole> = None;
+		for msg in self.messages.iter() {
+			let char_count = text_char_count(&msg.content);
+			if msg.role == ChatRole::System {
+				stats.system_char_count += char_count;
+				continue;
+			}
+
+			message_count += 1;
+			message_char_count += char_count;
+			if last_role != Some(&msg.role) {
+				stats.turn_count += 1;
+				last_role = Some(&msg.role);
+			}
+			match msg.role {
+				ChatRole::User => stats.user_message_count += 1,
+				ChatRole::Assistant => stats.assistant_message_count += 1,
+				_ => (),
+			}
+			if let MessageContent::ToolCalls(tool_calls) = &msg.content {
+				stats.tool_call_count += tool_calls.len();
+			}
+		}
+
+		stats.total_char_count = stats.system_char_count + message_char_count;
+		if message_count > 0 {
+			stats.avg_message_len = message_char_count as f64 / message_count as f64;
+		}
+
+		stats
+	}
+}
+
+// endregion: --- ConversationStats
+
+// region:    --- ToolUsageStats
+
+/// The tool calls statistics of a list of responses (see `analyze_tool_usage` and `ChatRequest::tool_usage`).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ToolUsageStats {
+	pub calls_per_tool: HashMap<String, usize>,
+	pub total_calls: usize,
+	/// The names of the request tools without any call (in the `ChatRequest.tools` order).
+	///
+	/// Note: Only set by `ChatRequest::tool_usage`, since the responses do not have the available tools.
+	pub tools_never_called: Vec<String>,
+}
+
+/// Count the tool calls of the responses, per tool name.
+///
+/// Note: Use `ChatRequest::tool_usage` to also get the `tools_never_called` of the request tools.
+pub fn analyze_tool_usage(history: &[ChatResponse]) -> ToolUsageStats {
+	let mut stats = ToolUsageStats::default();
+
+	for tool_call in history.iter().filter_map(|res| res.tool_calls()).flatten() {
+		*stats.calls_per_tool.entry(tool_call.fn_name.clone()).or_default() += 1;
+		stats.total_calls += 1;
+	}
+
+	stats
+}
+
+impl ChatRequest {
+	/// Returns the tool calls statistics of the responses to this request (see `analyze_tool_usage`),
+	/// with the `tools_never_called` of the `.tools`.
+	pub fn tool_usage(&self, history: &[ChatResponse]) -> ToolUsageStats {
+		let mut stats = analyze_tool_usage(history);
+
+		stats.tools_never_called = self
+			.tools
+			.iter()
+			.flatten()
+			.filter(|tool| !stats.calls_per_tool.contains_key(&tool.name))
+			.map(|tool| tool.name.clone())
+			.collect();
+
+		stats
+	}
+}
+
+// endregion: --- ToolUsageStats
+
+// region:    --- Support
+
+fn text_char_count(content: &MessageContent) -> usize {
+	match content {
+		MessageContent::Text(text) => text.chars().count(),
+		MessageContent::Parts(parts) => parts
+			.iter()
+			.map(|part| match part.without_cache() {
+				ContentPart::Text(text) => text.chars().count(),
+				_ => 0,
+			})
+			.sum(),
+		_ => 0,
+	}
+}
+
+// endregion: --- Support
@@ -1,4 +1,4 @@
-use crate::chat::{MessageContent, ToolCall, ToolResponse};
+use crate::chat::{AudioFormat, ContentPart, MessageContent, ToolCall, ToolResponse};
 use serde::{Deserialize, Serialize};
 
 /// An individual chat message.
@@ -36,10 +36,25 @@ impl ChatMessage {
 			content: content.into(),
 		}
 	}
+
+	/// Create a user message with the audio bytes (base64 encoded), e.g., for the OpenAI `gpt-4o-audio-preview` models.
+	pub fn user_with_audio(audio_bytes: &[u8], format: AudioFormat) -> Self {
+		Self::user(MessageContent::from_audio_bytes(audio_bytes, format))
+	}
+
+	/// Create a user message with a cacheable `document` followed by the `text` question.
+	///
+	/// Note: The document comes first so that it is the cached prompt prefix (see `ContentPart::WithCache`).
+	pub fn user_with_cached_document(text: impl Into<String>, document: impl Into<String>) -> Self {
+		Self::user(MessageContent::from_parts(vec![
+			ContentPart::from_text(document).cacheable(),
+			ContentPart::from_text(text),
+		]))
+	}
 }
 
 /// Chat roles.
-#[derive(Debug, Clone, Serialize, Deserialize, derive_more::Display)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, derive_more::Display)]
 #[allow(missing_docs)]
 pub enum ChatRole {
 	System,
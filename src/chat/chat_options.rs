@@ -6,7 +6,10 @@
 //! Note 2: Extracting it from the `ChatRequest` object allows for better reusability of each component.
 
 use crate::chat::chat_req_response_format::ChatResponseFormat;
+use crate::Result;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
 use std::ops::Deref;
 
 /// Chat Options that are considered for any `Client::exec...` calls.
@@ -40,7 +43,33 @@ pub struct ChatOptions {
 	pub response_format: Option<ChatResponseFormat>,
 
 	/// Specifies sequences used as end markers when generating text
+	#[serde(default)]
 	pub stop_sequences: Vec<String>,
+
+	/// The reasoning effort for the reasoning models (e.g., OpenAI `o1`, `o3`).
+	/// Ignored for the other models.
+	///
+	/// NOTE: For these models, `reasoning_effort` and `temperature` are mutually exclusive (do not set both).
+	pub reasoning_effort: Option<ReasoningEffort>,
+
+	/// What to do when a response is blocked by the provider safety settings (Gemini only for now).
+	/// Defaults to `HarmBlockMode::Ignore` (the response is returned, see `ChatResponse::gemini_meta`).
+	pub harm_block_mode: Option<HarmBlockMode>,
+
+	/// The predicted output content, to reduce the latency when most of the response is known ahead
+	/// (e.g., a code edit). OpenAI only for now (see `MetaUsage::accepted_prediction_tokens`).
+	pub prediction: Option<String>,
+
+	/// The sampling seed, for a (mostly) deterministic response. OpenAI-compatible only for now
+	/// (see `ChatResponse::system_fingerprint` and `ReproducibleChatSession`).
+	pub seed: Option<u64>,
+
+	/// Provider-specific parameters merged as-is into the top level of the request payload
+	/// (e.g., `logit_bias` for OpenAI, `thinking` for Anthropic).
+	///
+	/// IMPORTANT: Use at your own risk. These values are not validated, and they override
+	///            any value genai set for the same top-level key (e.g., `generationConfig` for Gemini).
+	pub extra_params: Option<HashMap<String, Value>>,
 }
 
 /// Chainable Setters
@@ -80,6 +109,30 @@ impl ChatOptions {
 		self
 	}
 
+	/// Set the `reasoning_effort` for this request (reasoning models only).
+	pub fn with_reasoning_effort(mut self, value: ReasoningEffort) -> Self {
+		self.reasoning_effort = Some(value);
+		self
+	}
+
+	/// Set the `harm_block_mode` for this request.
+	pub fn with_harm_block_mode(mut self, value: HarmBlockMode) -> Self {
+		self.harm_block_mode = Some(value);
+		self
+	}
+
+	/// Set the `prediction` (predicted output) for this request.
+	pub fn with_prediction(mut self, text: &str) -> Self {
+		self.prediction = Some(text.to_string());
+		self
+	}
+
+	/// Set the `seed` for this request.
+	pub fn with_seed(mut self, value: u64) -> Self {
+		self.seed = Some(value);
+		self
+	}
+
 	/// Set the `json_mode` for this request.
 	///
 	/// IMPORTANT: This is deprecated now; use `with_response_format(ChatResponseFormat::JsonMode)`
@@ -101,8 +154,119 @@ impl ChatOptions {
 		self.response_format = Some(res_format.into());
 		self
 	}
+
+	/// Add a provider-specific parameter to the `extra_params` (see `ChatOptions::extra_params`).
+	///
+	/// Note: The key is validated at request time (see `Error::ExtraParamInvalid`).
+	pub fn with_extra_param(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+		self.extra_params
+			.get_or_insert_with(HashMap::new)
+			.insert(key.into(), value.into());
+		self
+	}
+}
+
+// region:    --- Merge
+
+impl ChatOptions {
+	/// Returns these options updated with a JSON merge patch (RFC 7396), e.g., loaded from a config file.
+	///
+	/// Only the fields of the `patch` object are changed, and a `null` clears the field
+	/// (e.g., `{"temperature": 0.2, "max_tokens": null}`).
+	pub fn merge_patch(&self, patch: &Value) -> Result<Self> {
+		let mut options = serde_json::to_value(self)?;
+		json_merge_patch(&mut options, patch);
+		Ok(serde_json::from_value(options)?)
+	}
+}
+
+/// Merge two `ChatOptions`, with the right hand side values winning when set
+/// (i.e., `Some`, or a non empty `stop_sequences`).
+impl std::ops::Add for ChatOptions {
+	type Output = ChatOptions;
+
+	fn add(self, rhs: ChatOptions) -> ChatOptions {
+		ChatOptions {
+			temperature: rhs.temperature.or(self.temperature),
+			max_tokens: rhs.max_tokens.or(self.max_tokens),
+			top_p: rhs.top_p.or(self.top_p),
+			capture_usage: rhs.capture_usage.or(self.capture_usage),
+			capture_content: rhs.capture_content.or(self.capture_content),
+			response_format: rhs.response_format.or(self.response_format),
+			stop_sequences: if rhs.stop_sequences.is_empty() {
+				self.stop_sequences
+			} else {
+				rhs.stop_sequences
+			},
+			reasoning_effort: rhs.reasoning_effort.or(self.reasoning_effort),
+			harm_block_mode: rhs.harm_block_mode.or(self.harm_block_mode),
+			prediction: rhs.prediction.or(self.prediction),
+			seed: rhs.seed.or(self.seed),
+			extra_params: rhs.extra_params.or(self.extra_params),
+		}
+	}
 }
 
+/// Apply the JSON merge patch (RFC 7396) to the target.
+fn json_merge_patch(target: &mut Value, patch: &Value) {
+	let Value::Object(patch) = patch else {
+		*target = patch.clone();
+		return;
+	};
+	if !target.is_object() {
+		*target = Value::Object(Default::default());
+	}
+	if let Value::Object(target) = target {
+		for (key, value) in patch {
+			if value.is_null() {
+				target.remove(key);
+			} else {
+				json_merge_patch(target.entry(key.clone()).or_insert(Value::Null), value);
+			}
+		}
+	}
+}
+
+// endregion: --- Merge
+
+// region:    --- ReasoningEffort
+
+/// The amount of chain-of-thought reasoning for the reasoning models.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReasoningEffort {
+	Low,
+	Medium,
+	High,
+}
+
+impl ReasoningEffort {
+	/// The provider value (`"low"`, `"medium"`, `"high"`).
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			ReasoningEffort::Low => "low",
+			ReasoningEffort::Medium => "medium",
+			ReasoningEffort::High => "high",
+		}
+	}
+}
+
+// endregion: --- ReasoningEffort
+
+// region:    --- HarmBlockMode
+
+/// The behavior when a response is blocked by the provider safety settings.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HarmBlockMode {
+	/// Return the response (with no content).
+	#[default]
+	Ignore,
+	/// Fail with `Error::ResponseSafetyBlocked` (for `exec_chat` and as a chat stream event error).
+	Error,
+}
+
+// endregion: --- HarmBlockMode
+
 // region:    --- ChatOptionsSet
 
 /// This is an internal crate struct to resolve the ChatOptions value in a cascading manner.
@@ -170,6 +334,37 @@ impl ChatOptionsSet<'_, '_> {
 			.unwrap_or(&[])
 	}
 
+	pub fn reasoning_effort(&self) -> Option<ReasoningEffort> {
+		self.chat
+			.and_then(|chat| chat.reasoning_effort)
+			.or_else(|| self.client.and_then(|client| client.reasoning_effort))
+	}
+
+	pub fn harm_block_mode(&self) -> Option<HarmBlockMode> {
+		self.chat
+			.and_then(|chat| chat.harm_block_mode)
+			.or_else(|| self.client.and_then(|client| client.harm_block_mode))
+	}
+
+	pub fn prediction(&self) -> Option<&str> {
+		self.chat
+			.and_then(|chat| chat.prediction.as_deref())
+			.or_else(|| self.client.and_then(|client| client.prediction.as_deref()))
+	}
+
+	pub fn seed(&self) -> Option<u64> {
+		self.chat
+			.and_then(|chat| chat.seed)
+			.or_else(|| self.client.and_then(|client| client.seed))
+	}
+
+	/// Note: The chat level `extra_params` replace the client ones (they are not merged).
+	pub fn extra_params(&self) -> Option<&HashMap<String, Value>> {
+		self.chat
+			.and_then(|chat| chat.extra_params.as_ref())
+			.or_else(|| self.client.and_then(|client| client.extra_params.as_ref()))
+	}
+
 	/// Returns true only if there is a ChatResponseFormat::JsonMode
 	#[deprecated(note = "Use .response_format()")]
 	#[allow(unused)]
@@ -0,0 +1,175 @@
+//! The `ChatOptionsBuilder` validates the option ranges when they are set,
+//! and `ChatOptions::validate_for_adapter` validates the options against a given adapter before the network call.
+
+use crate::adapter::AdapterKind;
+use crate::chat::{ChatOptions, ChatResponseFormat};
+
+// region:    --- ChatOptionsBuilder
+
+/// Builder for `ChatOptions` with inline validation of the generic ranges.
+///
+/// Note: The adapter-specific ranges (e.g., Anthropic temperature max of 1.0) are checked by `ChatOptions::validate_for_adapter`.
+#[derive(Debug, Default, Clone)]
+pub struct ChatOptionsBuilder {
+	options: ChatOptions,
+}
+
+impl ChatOptions {
+	/// Create a new ChatOptionsBuilder.
+	pub fn builder() -> ChatOptionsBuilder {
+		ChatOptionsBuilder::default()
+	}
+}
+
+/// Builder methods
+impl ChatOptionsBuilder {
+	/// Set the `temperature` (between 0.0 and 2.0).
+	pub fn temperature(mut self, value: f64) -> Result<Self, ChatOptionsError> {
+		check_range(value, TEMPERATURE_RANGE).map_err(|(min, max)| ChatOptionsError::InvalidTemperature {
+			value,
+			min,
+			max,
+		})?;
+		self.options.temperature = Some(value);
+		Ok(self)
+	}
+
+	/// Set the `top_p` (between 0.0 and 1.0).
+	pub fn top_p(mut self, value: f64) -> Result<Self, ChatOptionsError> {
+		check_range(value, TOP_P_RANGE).map_err(|(min, max)| ChatOptionsError::InvalidTopP { value, min, max })?;
+		self.options.top_p = Some(value);
+		Ok(self)
+	}
+
+	/// Set the `max_tokens` (must be greater than 0).
+	pub fn max_tokens(mut self, value: u32) -> Result<Self, ChatOptionsError> {
+		if value == 0 {
+			return Err(ChatOptionsError::InvalidMaxTokens { value });
+		}
+		self.options.max_tokens = Some(value);
+		Ok(self)
+	}
+
+	pub fn stop_sequences(mut self, values: Vec<String>) -> Self {
+		self.options.stop_sequences = values;
+		self
+	}
+
+	pub fn capture_usage(mut self, value: bool) -> Self {
+		self.options.capture_usage = Some(value);
+		self
+	}
+
+	pub fn capture_content(mut self, value: bool) -> Self {
+		self.options.capture_content = Some(value);
+		self
+	}
+
+	pub fn response_format(mut self, res_format: impl Into<ChatResponseFormat>) -> Self {
+		self.options.response_format = Some(res_format.into());
+		self
+	}
+
+	pub fn build(self) -> ChatOptions {
+		self.options
+	}
+}
+
+// endregion: --- ChatOptionsBuilder
+
+// region:    --- Adapter Validation
+
+impl ChatOptions {
+	/// Validate the options for a given adapter, returning all of the errors found.
+	///
+	/// Note: This is a best-effort validation based on the documented provider ranges and constraints.
+	pub fn validate_for_adapter(&self, adapter_kind: AdapterKind) -> Result<(), Vec<ChatOptionsError>> {
+		let mut errors: Vec<ChatOptionsError> = Vec::new();
+
+		if let Some(value) = self.temperature {
+			let range = match adapter_kind {
+				AdapterKind::Anthropic | AdapterKind::Cohere => (0.0, 1.0),
+				_ => TEMPERATURE_RANGE,
+			};
+			if let Err((min, max)) = check_range(value, range) {
+				errors.push(ChatOptionsError::InvalidTemperature { value, min, max });
+			}
+		}
+
+		if let Some(value) = self.top_p {
+			if let Err((min, max)) = check_range(value, TOP_P_RANGE) {
+				errors.push(ChatOptionsError::InvalidTopP { value, min, max });
+			}
+		}
+
+		if let Some(0) = self.max_tokens {
+			errors.push(ChatOptionsError::InvalidMaxTokens { value: 0 });
+		}
+
+		// Anthropic recommends (and some models require) to alter only one of temperature or top_p.
+		if adapter_kind == AdapterKind::Anthropic && self.temperature.is_some() && self.top_p.is_some() {
+			errors.push(ChatOptionsError::ConflictingOptions {
+				adapter_kind,
+				options: "temperature, top_p",
+			});
+		}
+
+		if errors.is_empty() {
+			Ok(())
+		} else {
+			Err(errors)
+		}
+	}
+}
+
+// endregion: --- Adapter Validation
+
+// region:    --- ChatOptionsError
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChatOptionsError {
+	InvalidTemperature {
+		value: f64,
+		min: f64,
+		max: f64,
+	},
+	InvalidTopP {
+		value: f64,
+		min: f64,
+		max: f64,
+	},
+	InvalidMaxTokens {
+		value: u32,
+	},
+	/// The options cannot be set together for this adapter.
+	ConflictingOptions {
+		adapter_kind: AdapterKind,
+		options: &'static str,
+	},
+}
+
+impl core::fmt::Display for ChatOptionsError {
+	fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::result::Result<(), core::fmt::Error> {
+		write!(fmt, "{self:?}")
+	}
+}
+
+impl std::error::Error for ChatOptionsError {}
+
+// endregion: --- ChatOptionsError
+
+// region:    --- Support
+
+const TEMPERATURE_RANGE: (f64, f64) = (0.0, 2.0);
+const TOP_P_RANGE: (f64, f64) = (0.0, 1.0);
+
+/// Returns the `(min, max)` as error if the value is out of range (or NaN).
+fn check_range(value: f64, (min, max): (f64, f64)) -> Result<(), (f64, f64)> {
+	if (min..=max).contains(&value) {
+		Ok(())
+	} else {
+		Err((min, max))
+	}
+}
+
+// endregion: --- Support
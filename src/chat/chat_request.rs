@@ -1,7 +1,8 @@
 //! This module contains all the types related to a Chat Request (except ChatOptions, which has its own file).
 
-use crate::chat::{ChatMessage, ChatRole, MessageContent, Tool};
+use crate::chat::{ChatMessage, ChatOptions, ChatResponseFormat, ChatRole, ContentPart, MessageContent, Tool};
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 
 // region:    --- ChatRequest
 
@@ -15,6 +16,32 @@ pub struct ChatRequest {
 	pub messages: Vec<ChatMessage>,
 
 	pub tools: Option<Vec<Tool>>,
+
+	// Note: The fields below are private (set with the `with_...` and tool methods, read with the getters),
+	//       so they can evolve without breaking the code using them.
+	/// The tools disabled with `disable_tool` (not sent to the provider, until `enable_tool`).
+	#[serde(default)]
+	disabled_tools: Vec<Tool>,
+
+	/// Request-level JSON mode (takes precedence over the `ChatOptions`).
+	#[serde(default)]
+	json_mode: bool,
+
+	/// Request-level temperature (takes precedence over the `ChatOptions`).
+	#[serde(default)]
+	temperature: Option<f64>,
+
+	/// Request-level max tokens (takes precedence over the `ChatOptions`).
+	#[serde(default)]
+	max_tokens: Option<u32>,
+
+	/// The system content evaluated at request time (appended to the `.system` when both are set).
+	/// Not serialized (see `ChatRequest::with_dynamic_system`).
+	///
+	/// Note: This is a request property (rather than a `ChatMessage` content), as it is resolved into the `.system`
+	///       before the adapters build the payload, so the adapters never see a non-serializable message.
+	#[serde(skip)]
+	dynamic_system: Option<DynamicSystem>,
 }
 
 /// Constructors
@@ -25,6 +52,11 @@ impl ChatRequest {
 			messages,
 			system: None,
 			tools: None,
+			disabled_tools: Vec::new(),
+			json_mode: false,
+			temperature: None,
+			max_tokens: None,
+			dynamic_system: None,
 		}
 	}
 
@@ -34,6 +66,11 @@ impl ChatRequest {
 			system: Some(content.into()),
 			messages: Vec::new(),
 			tools: None,
+			disabled_tools: Vec::new(),
+			json_mode: false,
+			temperature: None,
+			max_tokens: None,
+			dynamic_system: None,
 		}
 	}
 
@@ -43,6 +80,11 @@ impl ChatRequest {
 			system: None,
 			messages: vec![ChatMessage::user(content.into())],
 			tools: None,
+			disabled_tools: Vec::new(),
+			json_mode: false,
+			temperature: None,
+			max_tokens: None,
+			dynamic_system: None,
 		}
 	}
 
@@ -52,6 +94,11 @@ impl ChatRequest {
 			system: None,
 			messages,
 			tools: None,
+			disabled_tools: Vec::new(),
+			json_mode: false,
+			temperature: None,
+			max_tokens: None,
+			dynamic_system: None,
 		}
 	}
 }
@@ -64,6 +111,19 @@ impl ChatRequest {
 		self
 	}
 
+	/// Set a system content function, called just before the request payload is built (for each exec),
+	/// rather than at construction time (e.g., for a system prompt with the current date).
+	///
+	/// Note: The function output is appended to the eventual `.system` content (separated by an empty line),
+	///       so a static and a dynamic system can be combined.
+	pub fn with_dynamic_system<F>(mut self, f: F) -> Self
+	where
+		F: Fn() -> String + Send + Sync + 'static,
+	{
+		self.dynamic_system = Some(DynamicSystem(Arc::new(f)));
+		self
+	}
+
 	/// Append a message to the request.
 	pub fn append_message(mut self, msg: impl Into<ChatMessage>) -> Self {
 		self.messages.push(msg.into());
@@ -80,14 +140,211 @@ impl ChatRequest {
 		self
 	}
 
+	/// Enable the JSON mode for this request (same as `ChatResponseFormat::JsonMode` in the `ChatOptions`).
+	///
+	/// Note: As for the options, the instruction to produce JSON should still be in the system or user messages.
+	pub fn with_json_mode(mut self) -> Self {
+		self.json_mode = true;
+		self
+	}
+
+	pub fn with_temperature(mut self, temperature: f64) -> Self {
+		self.temperature = Some(temperature);
+		self
+	}
+
+	pub fn with_max_tokens(mut self, max_tokens: u32) -> Self {
+		self.max_tokens = Some(max_tokens);
+		self
+	}
+
 	pub fn append_tool(mut self, tool: impl Into<Tool>) -> Self {
 		self.tools.get_or_insert_with(Vec::new).push(tool.into());
 		self
 	}
+
+	/// Insert a message at the given index (appended if the index is past the end).
+	pub fn insert_message(mut self, index: usize, msg: impl Into<ChatMessage>) -> Self {
+		let index = index.min(self.messages.len());
+		self.messages.insert(index, msg.into());
+		self
+	}
+
+	/// Insert a message before the first non-system message (i.e., after the leading system messages).
+	pub fn prepend_message(self, msg: impl Into<ChatMessage>) -> Self {
+		let index = self
+			.messages
+			.iter()
+			.position(|msg| !matches!(msg.role, ChatRole::System))
+			.unwrap_or(self.messages.len());
+		self.insert_message(index, msg)
+	}
+
+	/// Remove the message at the given index (no-op if out of bounds).
+	pub fn remove_message(mut self, index: usize) -> Self {
+		if index < self.messages.len() {
+			self.messages.remove(index);
+		}
+		self
+	}
+
+	/// Replace the message at the given index (no-op if out of bounds).
+	pub fn replace_message(mut self, index: usize, msg: impl Into<ChatMessage>) -> Self {
+		if let Some(current) = self.messages.get_mut(index) {
+			*current = msg.into();
+		}
+		self
+	}
+}
+
+/// Tools
+impl ChatRequest {
+	/// Disable the tool `fn_name`, which is kept in the `disabled_tools`, returning true if it was in the `tools`.
+	///
+	/// Useful in a tool loop, to stop the LLM from calling a tool again (e.g., a `search_web` already done).
+	pub fn disable_tool(&mut self, fn_name: &str) -> bool {
+		let Some(tool) = take_tool(&mut self.tools, fn_name) else {
+			return false;
+		};
+		self.disabled_tools.push(tool);
+		true
+	}
+
+	/// Enable back the tool `fn_name`, appended to the `tools`, returning true if it was disabled.
+	pub fn enable_tool(&mut self, fn_name: &str) -> bool {
+		let Some(index) = self.disabled_tools.iter().position(|tool| tool.name == fn_name) else {
+			return false;
+		};
+		let tool = self.disabled_tools.remove(index);
+		self.tools.get_or_insert_with(Vec::new).push(tool);
+		true
+	}
+
+	/// Remove the tool `fn_name` (enabled or disabled), returning it if found.
+	///
+	/// Note: Returns the `Tool` (rather than its JSON schema `Value`), as the request tools are `Tool`s,
+	///       so it can be added back as is with `append_tool`.
+	/// Note: The `tools` are set to `None` when the last one is removed.
+	pub fn remove_tool(&mut self, fn_name: &str) -> Option<Tool> {
+		take_tool(&mut self.tools, fn_name).or_else(|| {
+			let index = self.disabled_tools.iter().position(|tool| tool.name == fn_name)?;
+			Some(self.disabled_tools.remove(index))
+		})
+	}
+
+	/// Returns true if the tool `fn_name` is in the `tools` (the disabled tools are not included).
+	pub fn has_tool(&self, fn_name: &str) -> bool {
+		self.tools.iter().flatten().any(|tool| tool.name == fn_name)
+	}
 }
 
 /// Getters
 impl ChatRequest {
+	/// The tools disabled with `disable_tool` (not sent to the provider).
+	pub fn disabled_tools(&self) -> &[Tool] {
+		&self.disabled_tools
+	}
+
+	/// Returns true if the JSON mode is enabled for this request (see `with_json_mode`).
+	pub fn json_mode(&self) -> bool {
+		self.json_mode
+	}
+
+	pub fn temperature(&self) -> Option<f64> {
+		self.temperature
+	}
+
+	pub fn max_tokens(&self) -> Option<u32> {
+		self.max_tokens
+	}
+
+	/// The system content function set with `with_dynamic_system`.
+	pub fn dynamic_system(&self) -> Option<&DynamicSystem> {
+		self.dynamic_system.as_ref()
+	}
+
+	/// Iterate through the messages which are not of role System.
+	pub fn messages_excluding_system(&self) -> impl Iterator<Item = &ChatMessage> {
+		self.messages.iter().filter(|msg| !matches!(msg.role, ChatRole::System))
+	}
+
+	pub fn message_count(&self) -> usize {
+		self.messages.len()
+	}
+
+	/// The number of messages of role System (does not include the `.system` property).
+	pub fn system_message_count(&self) -> usize {
+		self.messages.iter().filter(|msg| matches!(msg.role, ChatRole::System)).count()
+	}
+
+	pub fn messages_by_role(&self, role: ChatRole) -> impl Iterator<Item = &ChatMessage> {
+		self.messages.iter().filter(move |msg| msg.role == role)
+	}
+
+	pub fn last_user_message(&self) -> Option<&ChatMessage> {
+		self.messages.iter().rev().find(|msg| msg.role == ChatRole::User)
+	}
+
+	pub fn last_assistant_message(&self) -> Option<&ChatMessage> {
+		self.messages.iter().rev().find(|msg| msg.role == ChatRole::Assistant)
+	}
+
+	/// Iterate through the consecutive (user, assistant) message pairs.
+	pub fn message_pairs(&self) -> impl Iterator<Item = (&ChatMessage, &ChatMessage)> {
+		self.messages
+			.windows(2)
+			.filter_map(|pair| match (&pair[0].role, &pair[1].role) {
+				(ChatRole::User, ChatRole::Assistant) => Some((&pair[0], &pair[1])),
+				_ => None,
+			})
+	}
+
+	/// The sum of the text content lengths (in chars, as the `ConversationStats` counts) of the `.system`
+	/// and all messages, including the text parts of the multi-part contents.
+	pub fn total_text_len(&self) -> usize {
+		let system_len = self.system.as_ref().map(|s| s.chars().count()).unwrap_or_default();
+		let messages_len: usize = self
+			.messages
+			.iter()
+			.map(|msg| match &msg.content {
+				MessageContent::Text(text) => text.chars().count(),
+				MessageContent::Parts(parts) => parts
+					.iter()
+					.map(|part| match part.without_cache() {
+						ContentPart::Text(text) => text.chars().count(),
+						_ => 0,
+					})
+					.sum(),
+				_ => 0,
+			})
+			.sum();
+		system_len + messages_len
+	}
+
+	/// Returns true if this conversation has a tool response for a call of the tool `tool_name`.
+	///
+	/// Note: The tool responses only have the call id, so the tool name is resolved from the assistant tool calls.
+	pub fn contains_tool_response(&self, tool_name: &str) -> bool {
+		let call_ids: Vec<&str> = self
+			.messages
+			.iter()
+			.filter_map(|msg| match &msg.content {
+				MessageContent::ToolCalls(tool_calls) => Some(tool_calls),
+				_ => None,
+			})
+			.flatten()
+			.filter(|tool_call| tool_call.fn_name == tool_name)
+			.map(|tool_call| tool_call.call_id.as_str())
+			.collect();
+
+		self.messages.iter().any(|msg| match &msg.content {
+			MessageContent::ToolResponses(tool_responses) => tool_responses
+				.iter()
+				.any(|tool_response| call_ids.contains(&tool_response.call_id.as_str())),
+			_ => false,
+		})
+	}
+
 	/// Iterate through all of the system content, starting with the eventual
 	/// ChatRequest.system and then the ChatMessage of role System.
 	pub fn iter_systems(&self) -> impl Iterator<Item = &str> {
@@ -130,4 +387,67 @@ impl ChatRequest {
 	}
 }
 
+/// Crate Functions
+impl ChatRequest {
+	/// Evaluate the eventual `dynamic_system`, and append it to the `.system` content.
+	pub(crate) fn into_resolved_system(mut self) -> Self {
+		if let Some(dynamic_system) = self.dynamic_system.take() {
+			let dynamic_content = (dynamic_system.0)();
+			self.system = Some(match self.system.take() {
+				Some(system) if system.ends_with('\n') => format!("{system}\n{dynamic_content}"),
+				Some(system) if !system.is_empty() => format!("{system}\n\n{dynamic_content}"),
+				_ => dynamic_content,
+			});
+		}
+		self
+	}
+
+	/// Returns the `options` with the request-level options applied (request-level wins),
+	/// or `None` if this request has no request-level options (so the `options` can be used as is).
+	pub(crate) fn merged_options(&self, options: Option<&ChatOptions>) -> Option<ChatOptions> {
+		if !self.json_mode && self.temperature.is_none() && self.max_tokens.is_none() {
+			return None;
+		}
+
+		let mut merged = options.cloned().unwrap_or_default();
+		if self.json_mode {
+			merged.response_format = Some(ChatResponseFormat::JsonMode);
+		}
+		if let Some(temperature) = self.temperature {
+			merged.temperature = Some(temperature);
+		}
+		if let Some(max_tokens) = self.max_tokens {
+			merged.max_tokens = Some(max_tokens);
+		}
+
+		Some(merged)
+	}
+}
+
+/// Take the tool `fn_name` out of the `tools`, which are set to `None` when empty
+/// (so no empty tool list is sent to the provider).
+fn take_tool(tools: &mut Option<Vec<Tool>>, fn_name: &str) -> Option<Tool> {
+	let list = tools.as_mut()?;
+	let index = list.iter().position(|tool| tool.name == fn_name)?;
+	let tool = list.remove(index);
+	if list.is_empty() {
+		*tools = None;
+	}
+	Some(tool)
+}
+
 // endregion: --- ChatRequest
+
+// region:    --- DynamicSystem
+
+/// A system content function of a `ChatRequest` (see `ChatRequest::with_dynamic_system`).
+#[derive(Clone)]
+pub struct DynamicSystem(Arc<dyn Fn() -> String + Send + Sync>);
+
+impl std::fmt::Debug for DynamicSystem {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.write_str("DynamicSystem")
+	}
+}
+
+// endregion: --- DynamicSystem
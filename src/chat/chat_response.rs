@@ -1,8 +1,13 @@
 //! This module contains all the types related to a Chat Response (except ChatStream, which has its own file).
 
+use base64::engine::general_purpose;
+use base64::Engine;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
-use crate::chat::{ChatStream, MessageContent, ToolCall};
+use crate::adapter::GeminiResponseMeta;
+use crate::chat::citation::inline_citations;
+use crate::chat::{ChatStream, Citation, MessageContent, ToolCall};
 use crate::ModelIden;
 
 // region:    --- ChatResponse
@@ -19,6 +24,23 @@ pub struct ChatResponse {
 
 	/// The eventual usage of the chat response
 	pub usage: MetaUsage,
+
+	/// The eventual provider request id (from the `x-request-id` or `request-id` response header),
+	/// useful for support tickets and debugging.
+	#[serde(default)]
+	pub request_id: Option<String>,
+
+	/// The id sent by genai in the `x-client-request-id` request header.
+	#[serde(default)]
+	pub client_request_id: Option<String>,
+
+	/// The eventual adapter-specific response metadata (e.g., the Gemini `GeminiResponseMeta`).
+	#[serde(default)]
+	pub adapter_meta: Option<Value>,
+
+	/// The sources cited by the content (Gemini grounding, Anthropic web search, OpenAI url citations).
+	#[serde(default)]
+	pub citations: Vec<Citation>,
 }
 
 // Getters
@@ -35,6 +57,46 @@ impl ChatResponse {
 		self.content.and_then(MessageContent::text_into_string)
 	}
 
+	/// Returns the text content with the citation urls inline (empty if no text content).
+	///
+	/// The `[1]`-style markers are replaced with the `[url]` of the citations, or, without markers,
+	/// a ` [url]` is inserted at the end of each cited segment (see `Citation::end_index`).
+	pub fn content_with_inline_citations(&self) -> String {
+		inline_citations(self.content_text_as_str().unwrap_or_default(), &self.citations)
+	}
+
+	/// Returns the eventual provider request id.
+	pub fn request_id(&self) -> Option<&str> {
+		self.request_id.as_deref()
+	}
+
+	/// Returns the eventual Gemini response metadata (model version and safety ratings).
+	pub fn gemini_meta(&self) -> Option<GeminiResponseMeta> {
+		self.adapter_meta
+			.as_ref()
+			.and_then(|meta| serde_json::from_value(meta.clone()).ok())
+	}
+
+	/// Returns the decoded audio of the response, if any (OpenAI `gpt-4o-audio` models, with the `audio` output modality).
+	///
+	/// Note: The audio transcript is the text content of the response.
+	pub fn audio_bytes(&self) -> Option<Vec<u8>> {
+		let data_base64 = self.adapter_meta.as_ref()?.pointer("/audio/data")?.as_str()?;
+		general_purpose::STANDARD.decode(data_base64).ok()
+	}
+
+	/// Returns the backend configuration fingerprint of the response, if any (OpenAI).
+	///
+	/// Note: With the same `seed`, a different fingerprint means the outputs may not be reproducible.
+	pub fn system_fingerprint(&self) -> Option<&str> {
+		self.adapter_meta.as_ref()?.get("system_fingerprint")?.as_str()
+	}
+
+	/// Returns the reason the model stopped generating, if any (OpenAI compatible adapters, e.g., `"stop"`, `"length"`).
+	pub fn finish_reason(&self) -> Option<&str> {
+		self.adapter_meta.as_ref()?.get("finish_reason")?.as_str()
+	}
+
 	pub fn tool_calls(&self) -> Option<Vec<&ToolCall>> {
 		if let Some(MessageContent::ToolCalls(tool_calls)) = self.content.as_ref() {
 			Some(tool_calls.iter().collect())
@@ -57,12 +119,34 @@ impl ChatResponse {
 // region:    --- ChatStreamResponse
 
 /// The result returned from the chat stream.
+///
+/// Note: Unlike `ChatResponse`, there is no provider `request_id`, as the stream response headers
+///       are not exposed by the event source (use the `client_request_id` to correlate the request).
 pub struct ChatStreamResponse {
 	/// The stream result to iterate through the stream events
 	pub stream: ChatStream,
 
 	/// The Model Identifier (AdapterKind/ModelName) used for this request.
 	pub model_iden: ModelIden,
+
+	/// The id sent by genai in the `x-client-request-id` request header.
+	pub client_request_id: Option<String>,
+}
+
+/// Chainable Setters
+impl ChatStreamResponse {
+	/// Buffer the stream with a bounded channel of `capacity` events (see `ChatStream::with_buffer`).
+	pub fn with_buffer(mut self, capacity: usize) -> Self {
+		self.stream = self.stream.with_buffer(capacity);
+		self
+	}
+}
+
+impl ChatStreamResponse {
+	/// Collect the stream text and final usage (see `ChatStream::collect_with_usage`).
+	pub async fn collect_with_usage(self) -> crate::Result<(String, MetaUsage)> {
+		self.stream.collect_with_usage().await
+	}
 }
 
 // endregion: --- ChatStreamResponse
@@ -79,6 +163,21 @@ pub struct MetaUsage {
 	/// The total number of tokens if returned by the API call.
 	/// This will either be the total_tokens if returned, or the sum of input/output if not specified in the response.
 	pub total_tokens: Option<i32>,
+
+	/// The number of input tokens read from the prompt cache (Anthropic `cache_read_input_tokens`).
+	#[serde(default)]
+	pub cache_read_input_tokens: Option<i32>,
+	/// The number of input tokens written to the prompt cache (Anthropic `cache_creation_input_tokens`).
+	#[serde(default)]
+	pub cache_creation_input_tokens: Option<i32>,
+
+	/// The number of predicted output tokens used in the response (OpenAI `accepted_prediction_tokens`).
+	#[serde(default)]
+	pub accepted_prediction_tokens: Option<i32>,
+	/// The number of predicted output tokens not used in the response, but still billed
+	/// (OpenAI `rejected_prediction_tokens`).
+	#[serde(default)]
+	pub rejected_prediction_tokens: Option<i32>,
 }
 
 // endregion: --- MetaUsage
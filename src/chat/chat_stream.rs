@@ -1,32 +1,174 @@
 use crate::adapter::inter_stream::{InterStreamEnd, InterStreamEvent};
 use crate::chat::{MessageContent, MetaUsage};
+use crate::{Error, ModelIden};
 use derive_more::From;
-use futures::Stream;
+use futures::{Stream, StreamExt};
 use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::io::Write;
 use std::pin::Pin;
 use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+use tokio::time::Sleep;
+use tokio_stream::wrappers::ReceiverStream;
 
 type InterStreamType = Pin<Box<dyn Stream<Item = crate::Result<InterStreamEvent>> + Send>>;
+type TapFn = Box<dyn Fn(&ChatStreamEvent) + Send>;
 
 /// ChatStream is a Rust Future Stream that iterates through the events of a chat stream request.
 pub struct ChatStream {
 	inter_stream: InterStreamType,
+	model_iden: ModelIden,
+	first_chunk_timeout: Option<FirstChunkTimeout>,
+	/// Set when the stream was ended by the first chunk timeout.
+	timed_out: bool,
+	taps: Vec<TapFn>,
+}
+
+struct FirstChunkTimeout {
+	/// Created at the first `poll_next` (not when the timeout is set), so the timer starts when the stream is consumed.
+	sleep: Option<Pin<Box<Sleep>>>,
+	duration: Duration,
 }
 
 impl ChatStream {
-	pub(crate) fn new(inter_stream: InterStreamType) -> Self {
-		ChatStream { inter_stream }
+	pub(crate) fn new(inter_stream: InterStreamType, model_iden: ModelIden) -> Self {
+		ChatStream {
+			inter_stream,
+			model_iden,
+			first_chunk_timeout: None,
+			timed_out: false,
+			taps: Vec::new(),
+		}
 	}
 
-	pub(crate) fn from_inter_stream<T>(inter_stream: T) -> Self
+	pub(crate) fn from_inter_stream<T>(inter_stream: T, model_iden: ModelIden) -> Self
 	where
 		T: Stream<Item = crate::Result<InterStreamEvent>> + Send + Unpin + 'static,
 	{
 		let boxed_stream: InterStreamType = Box::pin(inter_stream);
-		ChatStream::new(boxed_stream)
+		ChatStream::new(boxed_stream, model_iden)
+	}
+}
+
+/// Chainable Setters
+impl ChatStream {
+	/// Fail the stream with `Error::FirstChunkTimeout` if no chunk is received within `duration`.
+	///
+	/// Note: The timer starts at the first poll, and the `Start` event does not count as a chunk
+	///       (it is sent when the connection is established, before the model starts generating).
+	///       Once the first chunk (or end/error) is received, no timeout applies.
+	pub fn timeout_first_chunk(mut self, duration: Duration) -> Self {
+		self.first_chunk_timeout = Some(FirstChunkTimeout { sleep: None, duration });
+		self
+	}
+
+	/// Read the provider stream in a background task, up to `capacity` events ahead of the consumer
+	/// (with a bounded channel), e.g., to smooth a bursty provider stream for a consumer with an uneven pace.
+	///
+	/// Note: Without a buffer, the provider stream is only read when this stream is polled. With a buffer,
+	///       the reader suspends when `capacity` events are pending (relying on the TCP backpressure to the server),
+	///       so the memory stays bounded for a slow consumer.
+	///
+	/// IMPORTANT: Must be called within a Tokio runtime, as the reader task is spawned (a `capacity` of 0 is 1).
+	///            The reader task stops when this stream is dropped.
+	pub fn with_buffer(mut self, capacity: usize) -> Self {
+		let (tx, rx) = tokio::sync::mpsc::channel(capacity.max(1));
+		let mut inter_stream = std::mem::replace(&mut self.inter_stream, Box::pin(futures::stream::empty()));
+		tokio::spawn(async move {
+			while let Some(event) = inter_stream.next().await {
+				// Note: The send fails when the ChatStream (the receiver) was dropped.
+				if tx.send(event).await.is_err() {
+					break;
+				}
+			}
+		});
+		self.inter_stream = Box::pin(ReceiverStream::new(rx));
+		self
+	}
+
+	/// Call `f` on each event, without consuming it (like `Iterator::inspect`), e.g., for debug logging or metrics.
+	///
+	/// Note: The taps are called in the order they are added. See `StreamLogger` to write the events as JSON lines.
+	pub fn tap<F>(mut self, f: F) -> Self
+	where
+		F: Fn(&ChatStreamEvent) + Send + 'static,
+	{
+		self.taps.push(Box::new(f));
+		self
+	}
+
+	/// Same as `tap`, but only called with the text of the `Chunk` events.
+	pub fn tap_text<F>(self, f: F) -> Self
+	where
+		F: Fn(&str) + Send + 'static,
+	{
+		self.tap(move |event| {
+			if let ChatStreamEvent::Chunk(chunk) = event {
+				f(&chunk.content)
+			}
+		})
+	}
+}
+
+// region:    --- Pipes
+
+impl ChatStream {
+	/// Write each text chunk to the `writer` as it arrives, and returns the final `MetaUsage`.
+	///
+	/// Note: The usage is only returned when `ChatOptions::capture_usage` is set (otherwise, `MetaUsage::default()`).
+	pub async fn pipe_to_writer<W: AsyncWrite + Unpin>(mut self, mut writer: W) -> crate::Result<MetaUsage> {
+		let mut usage = MetaUsage::default();
+		while let Some(event) = self.next().await {
+			match event? {
+				ChatStreamEvent::Chunk(chunk) => writer
+					.write_all(chunk.content.as_bytes())
+					.await
+					.map_err(|io_error| self.stream_write_error(io_error))?,
+				ChatStreamEvent::End(end) => usage = end.captured_usage.unwrap_or_default(),
+				ChatStreamEvent::Start => (),
+			}
+		}
+		writer.flush().await.map_err(|io_error| self.stream_write_error(io_error))?;
+		Ok(usage)
+	}
+
+	/// Collect the text chunks, and returns the full text with the final `MetaUsage`.
+	///
+	/// Note: Same as `pipe_to_writer` for the usage (only returned when `ChatOptions::capture_usage` is set).
+	pub async fn collect_with_usage(self) -> crate::Result<(String, MetaUsage)> {
+		let mut content: Vec<u8> = Vec::new();
+		let usage = self.pipe_to_sync_writer(&mut content).await?;
+		Ok((String::from_utf8_lossy(&content).into_owned(), usage))
+	}
+
+	/// Same as `pipe_to_writer` for a `std::io::Write` (e.g., `std::io::stdout()`).
+	pub async fn pipe_to_sync_writer<W: Write>(mut self, mut writer: W) -> crate::Result<MetaUsage> {
+		let mut usage = MetaUsage::default();
+		while let Some(event) = self.next().await {
+			match event? {
+				ChatStreamEvent::Chunk(chunk) => writer
+					.write_all(chunk.content.as_bytes())
+					.map_err(|io_error| self.stream_write_error(io_error))?,
+				ChatStreamEvent::End(end) => usage = end.captured_usage.unwrap_or_default(),
+				ChatStreamEvent::Start => (),
+			}
+		}
+		writer.flush().map_err(|io_error| self.stream_write_error(io_error))?;
+		Ok(usage)
+	}
+
+	fn stream_write_error(&self, io_error: std::io::Error) -> Error {
+		Error::StreamWrite {
+			model_iden: self.model_iden.clone(),
+			cause: io_error.to_string(),
+		}
 	}
 }
 
+// endregion: --- Pipes
+
 // region:    --- Stream Impl
 
 impl Stream for ChatStream {
@@ -35,6 +177,18 @@ impl Stream for ChatStream {
 	fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
 		let this = self.get_mut();
 
+		if this.timed_out {
+			return Poll::Ready(None);
+		}
+
+		// -- Start the eventual first chunk timer at the first poll
+		if let Some(first_chunk_timeout) = this.first_chunk_timeout.as_mut() {
+			let duration = first_chunk_timeout.duration;
+			first_chunk_timeout
+				.sleep
+				.get_or_insert_with(|| Box::pin(tokio::time::sleep(duration)));
+		}
+
 		match Pin::new(&mut this.inter_stream).poll_next(cx) {
 			Poll::Ready(Some(Ok(event))) => {
 				let chat_event = match event {
@@ -42,11 +196,40 @@ impl Stream for ChatStream {
 					InterStreamEvent::Chunk(content) => ChatStreamEvent::Chunk(StreamChunk { content }),
 					InterStreamEvent::End(inter_end) => ChatStreamEvent::End(inter_end.into()),
 				};
+				if !matches!(chat_event, ChatStreamEvent::Start) {
+					this.first_chunk_timeout = None;
+				}
+				for tap in this.taps.iter() {
+					tap(&chat_event);
+				}
 				Poll::Ready(Some(Ok(chat_event)))
 			}
-			Poll::Ready(Some(Err(e))) => Poll::Ready(Some(Err(e))),
+			Poll::Ready(Some(Err(e))) => {
+				this.first_chunk_timeout = None;
+				Poll::Ready(Some(Err(e)))
+			}
 			Poll::Ready(None) => Poll::Ready(None),
-			Poll::Pending => Poll::Pending,
+			Poll::Pending => {
+				// -- Check the eventual first chunk timeout
+				let Some(first_chunk_timeout) = this.first_chunk_timeout.as_mut() else {
+					return Poll::Pending;
+				};
+				let Some(sleep) = first_chunk_timeout.sleep.as_mut() else {
+					return Poll::Pending;
+				};
+				match sleep.as_mut().poll(cx) {
+					Poll::Ready(()) => {
+						let duration_ms = first_chunk_timeout.duration.as_millis() as u64;
+						this.first_chunk_timeout = None;
+						this.timed_out = true;
+						Poll::Ready(Some(Err(Error::FirstChunkTimeout {
+							model_iden: this.model_iden.clone(),
+							duration_ms,
+						})))
+					}
+					Poll::Pending => Poll::Pending,
+				}
+			}
 		}
 	}
 }
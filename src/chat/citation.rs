@@ -0,0 +1,77 @@
+//! The provider citations of a chat response (e.g., the Gemini grounding, the Anthropic web search),
+//! to display the sources to the end users.
+
+use serde::{Deserialize, Serialize};
+
+/// A source cited by the response content.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Citation {
+	pub url: String,
+	pub title: Option<String>,
+	/// The cited text (e.g., the supported content segment, or the source text).
+	pub snippet: Option<String>,
+	/// The range of the cited segment in the content text, in byte offsets
+	/// (Note: The OpenAI character offsets are converted to byte offsets).
+	pub start_index: Option<usize>,
+	pub end_index: Option<usize>,
+}
+
+// region:    --- Inline Citations
+
+/// Replace the `[1]`-style citation markers (1-based citation index) with the `[url]` of the citations.
+///
+/// When the text has no markers, a ` [url]` is inserted at the `end_index` of each citation
+/// (if it is a valid position, and the url is not already in the text).
+pub(crate) fn inline_citations(text: &str, citations: &[Citation]) -> String {
+	if let Some(replaced) = replace_markers(text, citations) {
+		return replaced;
+	}
+
+	let mut inserts: Vec<(usize, &str)> = citations
+		.iter()
+		.filter(|citation| !text.contains(&citation.url))
+		.filter_map(|citation| Some((citation.end_index?, citation.url.as_str())))
+		.filter(|(end_index, _)| text.is_char_boundary(*end_index))
+		.collect();
+	// Insert from the end, so the previous indexes stay valid.
+	inserts.sort_by_key(|(end_index, _)| std::cmp::Reverse(*end_index));
+
+	let mut content = text.to_string();
+	for (end_index, url) in inserts {
+		content.insert_str(end_index, &format!(" [{url}]"));
+	}
+	content
+}
+
+/// Returns None if the text has no citation markers.
+fn replace_markers(text: &str, citations: &[Citation]) -> Option<String> {
+	let mut content = String::with_capacity(text.len());
+	let mut rest = text;
+	let mut replaced = false;
+	while let Some(open) = rest.find('[') {
+		let (before, from_open) = rest.split_at(open);
+		content.push_str(before);
+
+		let citation = from_open.find(']').and_then(|close| {
+			let index: usize = from_open[1..close].parse().ok()?;
+			let citation = citations.get(index.checked_sub(1)?)?;
+			Some((close, citation))
+		});
+		match citation {
+			Some((close, citation)) => {
+				content.push_str(&format!("[{}]", citation.url));
+				rest = &from_open[close + 1..];
+				replaced = true;
+			}
+			None => {
+				content.push('[');
+				rest = &from_open[1..];
+			}
+		}
+	}
+	content.push_str(rest);
+
+	replaced.then_some(content)
+}
+
+// endregion: --- Inline Citations
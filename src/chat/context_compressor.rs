@@ -0,0 +1,163 @@
+//! The `ContextCompressor` keeps a `ChatRequest` within a token budget by summarizing
+//! the oldest messages with an LLM call (rather than truncating them).
+
+use crate::chat::{ChatMessage, ChatRequest, ChatRole, MessageContent};
+use crate::{Client, Error, Result};
+
+// region:    --- TokenCounter
+
+/// Counts the tokens of a text, used to measure the size of a `ChatRequest`.
+pub trait TokenCounter {
+	fn count_tokens(&self, text: &str) -> usize;
+}
+
+/// Approximate token counter based on the number of characters (about 4 characters per token).
+#[derive(Debug, Clone, Default)]
+pub struct CharTokenCounter;
+
+impl TokenCounter for CharTokenCounter {
+	fn count_tokens(&self, text: &str) -> usize {
+		text.chars().count().div_ceil(4)
+	}
+}
+
+// endregion: --- TokenCounter
+
+// region:    --- ContextCompressor
+
+const SUMMARY_PREFIX: &str = "Summary of earlier conversation: ";
+
+#[derive(Debug, Clone)]
+pub struct ContextCompressor {
+	client: Client,
+	model: String,
+	summary_prompt: String,
+}
+
+/// Constructors
+impl ContextCompressor {
+	pub fn new(client: Client, model: &str, summary_prompt: &str) -> Self {
+		Self {
+			client,
+			model: model.to_string(),
+			summary_prompt: summary_prompt.to_string(),
+		}
+	}
+}
+
+impl ContextCompressor {
+	/// Compress the request down to (approximately) `target_tokens`.
+	///
+	/// The oldest non-system messages are summarized with the compressor model and replaced
+	/// with a single system message `Summary of earlier conversation: ...`.
+	/// - The last message is never summarized.
+	/// - A tool calls message and its tool responses are summarized together or kept together
+	///   (providers reject a tool response without its tool call, and the reverse).
+	/// - Does nothing (no LLM call) if the request is already within the budget.
+	pub async fn compress(
+		&self,
+		req: &mut ChatRequest,
+		target_tokens: usize,
+		counter: &dyn TokenCounter,
+	) -> Result<()> {
+		let total_tokens = count_request_tokens(req, counter);
+		if total_tokens <= target_tokens {
+			return Ok(());
+		}
+
+		// -- Select the oldest non-system message groups until the budget is reached
+		let last_idx = req.messages.len().saturating_sub(1);
+		let mut selected_idxs: Vec<usize> = Vec::new();
+		let mut removed_tokens = 0;
+		let mut idx = 0;
+		while idx < last_idx {
+			if total_tokens - removed_tokens <= target_tokens {
+				break;
+			}
+			if matches!(req.messages[idx].role, ChatRole::System) {
+				idx += 1;
+				continue;
+			}
+			let group_end = message_group_end(&req.messages, idx);
+			// Note: The group of the last message is kept whole.
+			if group_end >= last_idx {
+				break;
+			}
+			for group_idx in idx..=group_end {
+				removed_tokens += count_message_tokens(&req.messages[group_idx], counter);
+				selected_idxs.push(group_idx);
+			}
+			idx = group_end + 1;
+		}
+
+		let Some(&first_idx) = selected_idxs.first() else {
+			return Ok(());
+		};
+
+		// -- Summarize the selected messages
+		let transcript = selected_idxs
+			.iter()
+			.map(|&idx| {
+				let msg = &req.messages[idx];
+				format!("{}: {}", msg.role, message_text(msg))
+			})
+			.collect::<Vec<String>>()
+			.join("\n");
+
+		let summary_req = ChatRequest::from_system(&self.summary_prompt).append_message(ChatMessage::user(transcript));
+		let summary_res = self.client.exec_chat(&self.model, summary_req, None).await?;
+		let model_iden = summary_res.model_iden.clone();
+		let summary = summary_res
+			.content_text_into_string()
+			.ok_or(Error::NoChatResponse { model_iden })?;
+
+		// -- Replace the selected messages with the summary
+		let mut compressed_req = std::mem::take(req);
+		for &idx in selected_idxs.iter().rev() {
+			compressed_req = compressed_req.remove_message(idx);
+		}
+		*req = compressed_req.insert_message(first_idx, ChatMessage::system(format!("{SUMMARY_PREFIX}{summary}")));
+
+		Ok(())
+	}
+}
+
+// endregion: --- ContextCompressor
+
+// region:    --- Support
+
+/// Count the tokens of the request system and messages.
+pub fn count_request_tokens(req: &ChatRequest, counter: &dyn TokenCounter) -> usize {
+	let system_tokens = req.system.as_deref().map(|s| counter.count_tokens(s)).unwrap_or_default();
+	let messages_tokens: usize = req.messages.iter().map(|msg| count_message_tokens(msg, counter)).sum();
+	system_tokens + messages_tokens
+}
+
+/// Returns the index of the last message of the group starting at `start_idx`, which is a tool calls message
+/// with its following tool responses (or the message itself).
+fn message_group_end(messages: &[ChatMessage], start_idx: usize) -> usize {
+	let mut end_idx = start_idx;
+	if matches!(messages[start_idx].content, MessageContent::ToolCalls(_)) {
+		while messages
+			.get(end_idx + 1)
+			.is_some_and(|msg| matches!(msg.content, MessageContent::ToolResponses(_)))
+		{
+			end_idx += 1;
+		}
+	}
+	end_idx
+}
+
+fn count_message_tokens(msg: &ChatMessage, counter: &dyn TokenCounter) -> usize {
+	counter.count_tokens(&message_text(msg))
+}
+
+/// The text representation of a message (non-text content is serialized as JSON).
+fn message_text(msg: &ChatMessage) -> String {
+	match &msg.content {
+		MessageContent::Text(text) => text.to_string(),
+		other => serde_json::to_string(other).unwrap_or_default(),
+	}
+}
+
+// endregion: --- Support
@@ -0,0 +1,383 @@
+//! Converters from/to the chat history JSON formats of LangChain and LlamaIndex (requires the `interop` feature).
+//!
+//! - LangChain: `[{"type": "human", "content": "..."}, {"type": "ai", "content": "..."}]`
+//!   (the `messages_to_dict` format, with the fields in `data`, is also accepted).
+//! - LlamaIndex: `[{"role": "user", "content": "..."}, {"role": "assistant", "content": "..."}]`
+//!   (the `blocks` content of the recent versions is also accepted).
+//!
+//! Notes:
+//! - The audio and raw JSON contents have no equivalent, and are skipped by the `to_...` converters,
+//!   as are the tool messages without tool responses (i.e., without a tool call id).
+//! - An assistant message with both a content and tool calls becomes an assistant message followed by
+//!   the tool calls message, which the `to_...` converters merge back into one message.
+
+use crate::chat::{
+	ChatMessage, ChatRequest, ChatRole, ContentPart, ImageSource, MessageContent, ToolCall, ToolResponse,
+};
+use crate::{Error, Result};
+use serde_json::{json, Value};
+
+// region:    --- LangChain
+
+impl ChatRequest {
+	/// Parse the LangChain messages (`"human"`, `"ai"`, `"system"`, and `"tool"` types).
+	///
+	/// The multi-modal content arrays (`text` and `image_url` items) become `MessageContent::Parts`.
+	pub fn from_langchain_messages(json: &Value) -> Result<Self> {
+		let mut messages = Vec::new();
+		for item in as_array(json)? {
+			// Note: The `messages_to_dict` format has the message fields in `data`.
+			let fields = item.get("data").unwrap_or(item);
+			let msg_type = get_str(item, "type")?;
+			let content = fields.get("content").unwrap_or(&Value::Null);
+
+			let msg = match msg_type {
+				"human" => ChatMessage::user(content_from_openai_like(content)?),
+				"system" => ChatMessage::system(content_from_openai_like(content)?),
+				"ai" => {
+					let tool_calls = langchain_tool_calls(fields)?;
+					let content = content_from_openai_like(content)?;
+					if tool_calls.is_empty() {
+						ChatMessage::assistant(content)
+					} else {
+						if !content.is_empty() {
+							messages.push(ChatMessage::assistant(content));
+						}
+						ChatMessage::from(tool_calls)
+					}
+				}
+				"tool" => {
+					let call_id = get_str(fields, "tool_call_id")?;
+					ChatMessage::from(ToolResponse::new(call_id, content_text(content)))
+				}
+				other => return Err(invalid(format!("unknown LangChain message type '{other}'"))),
+			};
+			messages.push(msg);
+		}
+
+		Ok(ChatRequest::new(messages))
+	}
+
+	/// Returns the LangChain messages of this request (the `.system` first, as a `"system"` message).
+	pub fn to_langchain_messages(&self) -> Value {
+		let mut items = Vec::new();
+		if let Some(system) = self.system.as_ref() {
+			items.push(json!({"type": "system", "content": system}));
+		}
+
+		for msg in self.messages.iter() {
+			match &msg.content {
+				MessageContent::ToolCalls(tool_calls) => {
+					let tool_calls: Vec<Value> = tool_calls
+						.iter()
+						.map(|tc| json!({"name": tc.fn_name, "args": tc.fn_arguments, "id": tc.call_id}))
+						.collect();
+					let content = take_last_assistant_content(&mut items, "type", "ai").unwrap_or_else(|| json!(""));
+					items.push(json!({"type": "ai", "content": content, "tool_calls": tool_calls}));
+				}
+				MessageContent::ToolResponses(tool_responses) => {
+					for tr in tool_responses {
+						items.push(json!({"type": "tool", "content": tr.content, "tool_call_id": tr.call_id}));
+					}
+				}
+				content => {
+					let Some(content) = content_to_openai_like(content) else {
+						continue;
+					};
+					let msg_type = match msg.role {
+						ChatRole::System => "system",
+						ChatRole::User => "human",
+						ChatRole::Assistant => "ai",
+						// Note: A tool message requires the `tool_call_id` of a tool response.
+						ChatRole::Tool => continue,
+					};
+					items.push(json!({"type": msg_type, "content": content}));
+				}
+			}
+		}
+
+		Value::Array(items)
+	}
+}
+
+fn langchain_tool_calls(fields: &Value) -> Result<Vec<ToolCall>> {
+	let Some(tool_calls) = fields.get("tool_calls").and_then(|v| v.as_array()) else {
+		return Ok(Vec::new());
+	};
+
+	tool_calls
+		.iter()
+		.map(|tc| {
+			Ok(ToolCall {
+				call_id: get_str(tc, "id")?.to_string(),
+				fn_name: get_str(tc, "name")?.to_string(),
+				fn_arguments: tc.get("args").cloned().unwrap_or_else(|| json!({})),
+			})
+		})
+		.collect()
+}
+
+// endregion: --- LangChain
+
+// region:    --- LlamaIndex
+
+impl ChatRequest {
+	/// Parse the LlamaIndex messages (`"user"`, `"assistant"`, `"system"`, and `"tool"` roles).
+	///
+	/// The assistant tool calls and the tool call id are read from the `additional_kwargs` (OpenAI format),
+	/// and the `blocks` (`text` and `image` blocks) become `MessageContent::Parts`.
+	pub fn from_llamaindex_messages(json: &Value) -> Result<Self> {
+		let mut messages = Vec::new();
+		for item in as_array(json)? {
+			let role = get_str(item, "role")?;
+			let kwargs = item.get("additional_kwargs").unwrap_or(&Value::Null);
+			let content = match item.get("blocks").and_then(|b| b.as_array()) {
+				Some(blocks) => MessageContent::Parts(llamaindex_blocks_to_parts(blocks)?),
+				None => MessageContent::from_text(content_text(item.get("content").unwrap_or(&Value::Null))),
+			};
+
+			let msg = match role {
+				"user" => ChatMessage::user(content),
+				"system" => ChatMessage::system(content),
+				"assistant" => match kwargs.get("tool_calls") {
+					Some(tool_calls) => {
+						let tool_calls = tool_calls
+							.as_array()
+							.ok_or_else(|| invalid("'additional_kwargs.tool_calls' is not an array"))?
+							.iter()
+							.cloned()
+							.map(ToolCall::from_openai_value)
+							.collect::<Result<Vec<_>>>()?;
+						if !content.is_empty() {
+							messages.push(ChatMessage::assistant(content));
+						}
+						ChatMessage::from(tool_calls)
+					}
+					None => ChatMessage::assistant(content),
+				},
+				"tool" => {
+					let call_id = get_str(kwargs, "tool_call_id")?;
+					let text = content.text_into_string().unwrap_or_default();
+					ChatMessage::from(ToolResponse::new(call_id, text))
+				}
+				other => return Err(invalid(format!("unknown LlamaIndex message role '{other}'"))),
+			};
+			messages.push(msg);
+		}
+
+		Ok(ChatRequest::new(messages))
+	}
+
+	/// Returns the LlamaIndex messages of this request (the `.system` first, as a `"system"` message).
+	///
+	/// Note: The `MessageContent::Parts` are returned as `blocks`.
+	pub fn to_llamaindex_messages(&self) -> Value {
+		let mut items = Vec::new();
+		if let Some(system) = self.system.as_ref() {
+			items.push(json!({"role": "system", "content": system, "additional_kwargs": {}}));
+		}
+
+		for msg in self.messages.iter() {
+			match &msg.content {
+				MessageContent::ToolCalls(tool_calls) => {
+					let tool_calls: Vec<Value> = tool_calls
+						.iter()
+						.map(|tc| {
+							json!({
+								"id": tc.call_id,
+								"type": "function",
+								"function": {"name": tc.fn_name, "arguments": tc.fn_arguments.to_string()}
+							})
+						})
+						.collect();
+					let content = take_last_assistant_content(&mut items, "role", "assistant").unwrap_or(Value::Null);
+					items.push(json!({
+						"role": "assistant",
+						"content": content,
+						"additional_kwargs": {"tool_calls": tool_calls}
+					}));
+				}
+				MessageContent::ToolResponses(tool_responses) => {
+					for tr in tool_responses {
+						items.push(json!({
+							"role": "tool",
+							"content": tr.content,
+							"additional_kwargs": {"tool_call_id": tr.call_id}
+						}));
+					}
+				}
+				// Note: A tool message requires the `tool_call_id` of a tool response.
+				_ if msg.role == ChatRole::Tool => (),
+				MessageContent::Text(text) => {
+					items.push(json!({"role": role_name(&msg.role), "content": text, "additional_kwargs": {}}));
+				}
+				MessageContent::Parts(parts) => {
+					items.push(json!({
+						"role": role_name(&msg.role),
+						"blocks": parts_to_llamaindex_blocks(parts),
+						"additional_kwargs": {}
+					}));
+				}
+				MessageContent::Json(_) | MessageContent::Audio { .. } => (),
+			}
+		}
+
+		Value::Array(items)
+	}
+}
+
+fn role_name(role: &ChatRole) -> &'static str {
+	match role {
+		ChatRole::System => "system",
+		ChatRole::User => "user",
+		ChatRole::Assistant => "assistant",
+		ChatRole::Tool => "tool",
+	}
+}
+
+fn llamaindex_blocks_to_parts(blocks: &[Value]) -> Result<Vec<ContentPart>> {
+	blocks
+		.iter()
+		.map(|block| match get_str(block, "block_type")? {
+			"text" => Ok(ContentPart::from_text(get_str(block, "text")?)),
+			"image" => {
+				let content_type = block.get("image_mimetype").and_then(|m| m.as_str()).unwrap_or("image/png");
+				match (
+					block.get("url").and_then(|u| u.as_str()),
+					block.get("image").and_then(|i| i.as_str()),
+				) {
+					(Some(url), _) => Ok(image_part_from_url(url, content_type)),
+					(None, Some(base64)) => Ok(ContentPart::from_image_base64(content_type, base64)),
+					(None, None) => Err(invalid("image block without 'url' or 'image'")),
+				}
+			}
+			other => Err(invalid(format!("unsupported LlamaIndex block type '{other}'"))),
+		})
+		.collect()
+}
+
+fn parts_to_llamaindex_blocks(parts: &[ContentPart]) -> Vec<Value> {
+	parts
+		.iter()
+		.filter_map(|part| match part.without_cache() {
+			ContentPart::Text(text) => Some(json!({"block_type": "text", "text": text})),
+			ContentPart::Image {
+				content_type, source, ..
+			} => Some(match source {
+				ImageSource::Url(url) => json!({"block_type": "image", "url": url, "image_mimetype": content_type}),
+				ImageSource::Base64(data) => {
+					json!({"block_type": "image", "image": data.as_ref(), "image_mimetype": content_type})
+				}
+			}),
+			ContentPart::WithCache(_) => None,
+		})
+		.collect()
+}
+
+// endregion: --- LlamaIndex
+
+// region:    --- Support
+
+/// Parse a string content, or an OpenAI like content array (the LangChain multi-modal format).
+fn content_from_openai_like(content: &Value) -> Result<MessageContent> {
+	let Some(items) = content.as_array() else {
+		return Ok(MessageContent::from_text(content_text(content)));
+	};
+
+	let parts = items
+		.iter()
+		.map(|item| match item {
+			Value::String(text) => Ok(ContentPart::from_text(text)),
+			_ => match get_str(item, "type")? {
+				"text" => Ok(ContentPart::from_text(get_str(item, "text")?)),
+				"image_url" => {
+					// Note: The `image_url` can be the url string, or a `{"url": ..}` object.
+					let image_url = item.get("image_url").unwrap_or(&Value::Null);
+					let url = image_url
+						.as_str()
+						.or_else(|| image_url.get("url").and_then(|u| u.as_str()))
+						.ok_or_else(|| invalid("image_url item without url"))?;
+					Ok(image_part_from_url(url, "image/png"))
+				}
+				other => Err(invalid(format!("unsupported content item type '{other}'"))),
+			},
+		})
+		.collect::<Result<Vec<_>>>()?;
+
+	Ok(MessageContent::Parts(parts))
+}
+
+/// Returns the string or OpenAI like content array of the content (None for the audio and raw JSON contents).
+fn content_to_openai_like(content: &MessageContent) -> Option<Value> {
+	match content {
+		MessageContent::Text(text) => Some(json!(text)),
+		MessageContent::Parts(parts) => {
+			let items: Vec<Value> = parts
+				.iter()
+				.filter_map(|part| match part.without_cache() {
+					ContentPart::Text(text) => Some(json!({"type": "text", "text": text})),
+					ContentPart::Image {
+						content_type, source, ..
+					} => {
+						let url = match source {
+							ImageSource::Url(url) => url.clone(),
+							ImageSource::Base64(data) => format!("data:{content_type};base64,{data}"),
+						};
+						Some(json!({"type": "image_url", "image_url": {"url": url}}))
+					}
+					ContentPart::WithCache(_) => None,
+				})
+				.collect();
+			Some(Value::Array(items))
+		}
+		_ => None,
+	}
+}
+
+/// A `data:{content_type};base64,{data}` url becomes a base64 image part, other urls an url image part.
+fn image_part_from_url(url: &str, default_content_type: &str) -> ContentPart {
+	if let Some((content_type, data)) = url.strip_prefix("data:").and_then(|rest| rest.split_once(";base64,")) {
+		ContentPart::from_image_base64(content_type, data)
+	} else {
+		ContentPart::from_image_url(default_content_type, url)
+	}
+}
+
+/// Removes the last item if it is an assistant message without tool calls, and returns its content
+/// (to merge it with the following tool calls, as in the source format).
+fn take_last_assistant_content(items: &mut Vec<Value>, type_name: &str, assistant_type: &str) -> Option<Value> {
+	let last = items.last()?;
+	let is_assistant_text = last.get(type_name).and_then(|v| v.as_str()) == Some(assistant_type)
+		&& last.get("tool_calls").is_none()
+		&& last.get("blocks").is_none()
+		&& last.pointer("/additional_kwargs/tool_calls").is_none();
+	if !is_assistant_text {
+		return None;
+	}
+	items.pop().and_then(|mut last| last.get_mut("content").map(Value::take))
+}
+
+fn content_text(content: &Value) -> String {
+	match content {
+		Value::String(text) => text.clone(),
+		Value::Null => String::new(),
+		other => other.to_string(),
+	}
+}
+
+fn as_array(json: &Value) -> Result<&Vec<Value>> {
+	json.as_array().ok_or_else(|| invalid("messages are not a JSON array"))
+}
+
+fn get_str<'a>(value: &'a Value, name: &str) -> Result<&'a str> {
+	value
+		.get(name)
+		.and_then(|v| v.as_str())
+		.ok_or_else(|| invalid(format!("missing string property '{name}' in {value}")))
+}
+
+fn invalid(cause: impl Into<String>) -> Error {
+	Error::InteropInvalidMessages { cause: cause.into() }
+}
+
+// endregion: --- Support
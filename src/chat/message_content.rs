@@ -1,6 +1,9 @@
 use crate::chat::{ToolCall, ToolResponse};
+use base64::engine::general_purpose;
+use base64::Engine;
 use derive_more::derive::From;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use std::sync::Arc;
 
 #[derive(Debug, Clone, Serialize, Deserialize, From)]
@@ -18,6 +21,15 @@ pub enum MessageContent {
 	/// Tool call responses
 	#[from]
 	ToolResponses(Vec<ToolResponse>),
+
+	/// Raw JSON content, sent as-is as the adapter message content (e.g., OpenAI `content`, Gemini `parts`).
+	///
+	/// This is a low-level escape hatch for adapter-specific content structures (e.g., Anthropic document blocks).
+	/// No validation is done, and the value must match the target adapter format.
+	Json(Value),
+
+	/// Audio content, base64 encoded (e.g., for the OpenAI `gpt-4o-audio-preview` voice input).
+	Audio { data_base64: String, format: AudioFormat },
 }
 
 /// Constructors
@@ -36,6 +48,19 @@ impl MessageContent {
 	pub fn from_tool_calls(tool_calls: Vec<ToolCall>) -> Self {
 		MessageContent::ToolCalls(tool_calls)
 	}
+
+	/// Create a new MessageContent with the Audio variant, base64 encoding the audio bytes.
+	pub fn from_audio_bytes(audio_bytes: &[u8], format: AudioFormat) -> Self {
+		MessageContent::Audio {
+			data_base64: general_purpose::STANDARD.encode(audio_bytes),
+			format,
+		}
+	}
+
+	/// Create a new MessageContent with the raw Json variant
+	pub fn from_json(value: impl Into<Value>) -> Self {
+		MessageContent::Json(value.into())
+	}
 }
 
 /// Getters
@@ -50,6 +75,8 @@ impl MessageContent {
 			MessageContent::Parts(_) => None,
 			MessageContent::ToolCalls(_) => None,
 			MessageContent::ToolResponses(_) => None,
+			MessageContent::Json(_) => None,
+			MessageContent::Audio { .. } => None,
 		}
 	}
 
@@ -63,6 +90,24 @@ impl MessageContent {
 			MessageContent::Parts(_) => None,
 			MessageContent::ToolCalls(_) => None,
 			MessageContent::ToolResponses(_) => None,
+			MessageContent::Json(_) => None,
+			MessageContent::Audio { .. } => None,
+		}
+	}
+
+	/// Returns the raw JSON value, only if it is MessageContent::Json
+	pub fn try_as_json(&self) -> Option<&Value> {
+		match self {
+			MessageContent::Json(value) => Some(value),
+			_ => None,
+		}
+	}
+
+	/// Consumes the MessageContent and returns the raw JSON value, only if it is MessageContent::Json
+	pub fn into_json(self) -> Option<Value> {
+		match self {
+			MessageContent::Json(value) => Some(value),
+			_ => None,
 		}
 	}
 
@@ -73,6 +118,8 @@ impl MessageContent {
 			MessageContent::Parts(parts) => parts.is_empty(),
 			MessageContent::ToolCalls(tool_calls) => tool_calls.is_empty(),
 			MessageContent::ToolResponses(tool_responses) => tool_responses.is_empty(),
+			MessageContent::Json(value) => value.is_null(),
+			MessageContent::Audio { data_base64, .. } => data_base64.is_empty(),
 		}
 	}
 }
@@ -111,10 +158,45 @@ impl From<Vec<ContentPart>> for MessageContent {
 
 // endregion: --- Froms
 
+// region:    --- AudioFormat
+
+/// The audio format of a `MessageContent::Audio`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AudioFormat {
+	Wav,
+	Mp3,
+	M4a,
+	Ogg,
+}
+
+impl AudioFormat {
+	/// The format name (e.g., `"wav"`), as used by OpenAI `input_audio.format`.
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			AudioFormat::Wav => "wav",
+			AudioFormat::Mp3 => "mp3",
+			AudioFormat::M4a => "m4a",
+			AudioFormat::Ogg => "ogg",
+		}
+	}
+}
+
+// endregion: --- AudioFormat
+
 #[derive(Debug, Clone, Serialize, Deserialize, From)]
 pub enum ContentPart {
 	Text(String),
-	Image { content_type: String, source: ImageSource },
+	Image {
+		content_type: String,
+		source: ImageSource,
+		/// The eventual image detail level (e.g., "low", "high", "auto"), for the services supporting it (OpenAI).
+		#[serde(default, skip_serializing_if = "Option::is_none")]
+		detail: Option<String>,
+	},
+	/// A part marked as cacheable, for the adapters supporting explicit prompt caching (Anthropic `cache_control`).
+	/// The other adapters send the inner part as is.
+	WithCache(Box<ContentPart>),
 }
 
 /// Constructors
@@ -127,6 +209,7 @@ impl ContentPart {
 		ContentPart::Image {
 			content_type: content_type.into(),
 			source: ImageSource::Base64(content.into()),
+			detail: None,
 		}
 	}
 
@@ -134,6 +217,42 @@ impl ContentPart {
 		ContentPart::Image {
 			content_type: content_type.into(),
 			source: ImageSource::Url(url.into()),
+			detail: None,
+		}
+	}
+
+	/// Set the image detail level (no-op for the non image parts).
+	pub fn with_detail(mut self, detail: impl Into<String>) -> ContentPart {
+		if let ContentPart::Image {
+			detail: part_detail, ..
+		} = &mut self
+		{
+			*part_detail = Some(detail.into());
+		}
+		self
+	}
+
+	/// Mark this part as cacheable (see `ContentPart::WithCache`).
+	pub fn cacheable(self) -> ContentPart {
+		match self {
+			ContentPart::WithCache(_) => self,
+			part => ContentPart::WithCache(Box::new(part)),
+		}
+	}
+}
+
+/// Getters
+impl ContentPart {
+	/// Returns true if this part is marked as cacheable.
+	pub fn is_cacheable(&self) -> bool {
+		matches!(self, ContentPart::WithCache(_))
+	}
+
+	/// Returns the part without the eventual cache marker (never a `ContentPart::WithCache`).
+	pub fn without_cache(&self) -> &ContentPart {
+		match self {
+			ContentPart::WithCache(part) => part.without_cache(),
+			part => part,
 		}
 	}
 }
@@ -0,0 +1,149 @@
+//! Schema versions and migrations of the saved `ChatRequest` JSON (see `ChatRequest::load_from_file`).
+//!
+//! The version is in the `"_schema_version"` property of the saved JSON (absent means `V1`).
+
+use crate::chat::ChatRequest;
+use crate::{Error, Result};
+use serde_json::{json, Value};
+use std::path::Path;
+
+/// The property of the saved `ChatRequest` JSON with the schema version.
+pub const CHAT_REQUEST_SCHEMA_VERSION_PROP: &str = "_schema_version";
+
+// region:    --- ChatRequestVersion
+
+/// The schema versions of the saved `ChatRequest` JSON.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ChatRequestVersion {
+	/// The `system`, `messages`, and `tools` properties.
+	V1 = 1,
+	/// Adds the request-level `json_mode`, `temperature`, and `max_tokens`, and the `tools[].tool_type`.
+	V2 = 2,
+	/// Adds the `disabled_tools` (the tools disabled with `ChatRequest::disable_tool`).
+	V3 = 3,
+}
+
+impl ChatRequestVersion {
+	pub const CURRENT: ChatRequestVersion = ChatRequestVersion::V3;
+
+	pub fn from_u32(version: u32) -> Option<Self> {
+		match version {
+			1 => Some(Self::V1),
+			2 => Some(Self::V2),
+			3 => Some(Self::V3),
+			_ => None,
+		}
+	}
+
+	pub fn as_u32(self) -> u32 {
+		self as u32
+	}
+}
+
+// endregion: --- ChatRequestVersion
+
+// region:    --- Migrate
+
+/// Migrate a saved `ChatRequest` JSON object from the schema version `from_version` to `to_version`,
+/// applying each version bump in order, and setting its `"_schema_version"`.
+///
+/// Note: Only the upgrades are supported (`from_version <= to_version`).
+pub fn migrate_chat_request(json: &mut Value, from_version: u32, to_version: u32) -> Result<()> {
+	let from = version_from_u32(from_version)?;
+	let to = version_from_u32(to_version)?;
+	if from > to {
+		return Err(migration_error(format!(
+			"cannot downgrade from version {from_version} to {to_version}"
+		)));
+	}
+	let Some(obj) = json.as_object_mut() else {
+		return Err(migration_error("the chat request is not a JSON object"));
+	};
+
+	for version in from_version..to_version {
+		match version {
+			1 => migrate_v1_to_v2(obj),
+			2 => migrate_v2_to_v3(obj),
+			// Note: Not reachable, the versions were validated above.
+			_ => return Err(migration_error(format!("no migration from version {version}"))),
+		}
+	}
+	obj.insert(CHAT_REQUEST_SCHEMA_VERSION_PROP.to_string(), json!(to_version));
+
+	Ok(())
+}
+
+fn migrate_v1_to_v2(obj: &mut serde_json::Map<String, Value>) {
+	obj.entry("json_mode").or_insert(json!(false));
+	obj.entry("temperature").or_insert(Value::Null);
+	obj.entry("max_tokens").or_insert(Value::Null);
+
+	if let Some(tools) = obj.get_mut("tools").and_then(|tools| tools.as_array_mut()) {
+		for tool in tools.iter_mut().filter_map(|tool| tool.as_object_mut()) {
+			tool.entry("tool_type").or_insert(json!("Function"));
+		}
+	}
+}
+
+fn migrate_v2_to_v3(obj: &mut serde_json::Map<String, Value>) {
+	obj.entry("disabled_tools").or_insert(json!([]));
+}
+
+// endregion: --- Migrate
+
+// region:    --- ChatRequest Load & Save
+
+impl ChatRequest {
+	/// Load a `ChatRequest` saved as JSON, migrating it from its schema version to the current one.
+	pub fn load_from_file(path: impl AsRef<Path>) -> Result<Self> {
+		let path = path.as_ref();
+		let content = std::fs::read_to_string(path).map_err(|io_error| Error::FileRead {
+			path: path.to_string_lossy().to_string(),
+			cause: io_error.to_string(),
+		})?;
+		let mut json: Value = serde_json::from_str(&content)?;
+
+		let from_version = match json.get(CHAT_REQUEST_SCHEMA_VERSION_PROP) {
+			None => ChatRequestVersion::V1.as_u32(),
+			Some(version) => version
+				.as_u64()
+				.map(|v| v as u32)
+				.ok_or_else(|| migration_error(format!("invalid schema version {version}")))?,
+		};
+		migrate_chat_request(&mut json, from_version, ChatRequestVersion::CURRENT.as_u32())?;
+
+		Ok(serde_json::from_value(json)?)
+	}
+
+	/// Save this `ChatRequest` as JSON, with the current schema version (the `dynamic_system` is not saved).
+	pub fn save_to_file(&self, path: impl AsRef<Path>) -> Result<()> {
+		let path = path.as_ref();
+		let mut json = serde_json::to_value(self)?;
+		if let Some(obj) = json.as_object_mut() {
+			obj.insert(
+				CHAT_REQUEST_SCHEMA_VERSION_PROP.to_string(),
+				json!(ChatRequestVersion::CURRENT.as_u32()),
+			);
+		}
+
+		let content = serde_json::to_string_pretty(&json)?;
+		std::fs::write(path, content).map_err(|io_error| Error::FileWrite {
+			path: path.to_string_lossy().to_string(),
+			cause: io_error.to_string(),
+		})
+	}
+}
+
+// endregion: --- ChatRequest Load & Save
+
+// region:    --- Support
+
+fn version_from_u32(version: u32) -> Result<ChatRequestVersion> {
+	ChatRequestVersion::from_u32(version).ok_or_else(|| migration_error(format!("unknown schema version {version}")))
+}
+
+fn migration_error(cause: impl Into<String>) -> Error {
+	Error::ChatRequestMigration { cause: cause.into() }
+}
+
+// endregion: --- Support
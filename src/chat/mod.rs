@@ -1,27 +1,42 @@
 //! The genai chat module contains all of the constructs necessary
 //! to make genai requests with the `genai::Client`.
 
-
 // region:    --- Modules
 
+mod analytics;
 mod chat_message;
 mod chat_options;
+mod chat_options_builder;
 mod chat_req_response_format;
 mod chat_request;
 mod chat_response;
 mod chat_stream;
+mod citation;
+mod context_compressor;
+#[cfg(feature = "interop")]
+mod interop;
 mod message_content;
+mod migrate;
+mod stream_logger;
 mod tool;
+mod user_message_builder;
 
 // -- Flatten
+pub use analytics::*;
 pub use chat_message::*;
 pub use chat_options::*;
+pub use chat_options_builder::*;
 pub use chat_req_response_format::*;
 pub use chat_request::*;
 pub use chat_response::*;
 pub use chat_stream::*;
+pub use citation::Citation;
+pub use context_compressor::*;
 pub use message_content::*;
+pub use migrate::*;
+pub use stream_logger::*;
 pub use tool::*;
+pub use user_message_builder::*;
 
 pub mod printer;
 
@@ -1,5 +1,16 @@
 //! Printer utility to help print a chat stream
 //! > Note: This is primarily for quick testing and temporary debugging
+//! > (see the `TerminalStreamPrinter`, with the `terminal` feature, for a styled output)
+
+// region:    --- Modules
+
+#[cfg(feature = "terminal")]
+mod terminal;
+
+#[cfg(feature = "terminal")]
+pub use terminal::*;
+
+// endregion: --- Modules
 
 use crate::chat::{ChatStreamEvent, ChatStreamResponse, StreamChunk};
 use futures::StreamExt;
@@ -0,0 +1,218 @@
+//! Styled chat stream terminal output, with crossterm (`terminal` feature).
+
+use crate::chat::{ChatStreamEvent, ChatStreamResponse, MetaUsage};
+use crate::{Error, ModelIden, Result};
+use crossterm::cursor::{DisableBlinking, EnableBlinking, MoveToColumn, Show};
+use crossterm::queue;
+use crossterm::style::{Attribute, Color, Print, ResetColor, SetAttribute, SetForegroundColor};
+use crossterm::terminal::{Clear, ClearType};
+use futures::StreamExt;
+use std::io::Write;
+
+// region:    --- ColorScheme
+
+/// The colors of the `TerminalStreamPrinter` output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColorScheme {
+	/// The model header and usage footer color (also dimmed).
+	pub meta: Color,
+	pub content: Color,
+	/// The `TerminalStreamPrinter::interrupt` message color (e.g., a tool call).
+	pub interrupt: Color,
+}
+
+impl Default for ColorScheme {
+	fn default() -> Self {
+		Self {
+			meta: Color::DarkGrey,
+			content: Color::Reset,
+			interrupt: Color::Yellow,
+		}
+	}
+}
+
+impl ColorScheme {
+	/// The terminal default color for everything (the header and footer are still dimmed).
+	pub fn monochrome() -> Self {
+		Self {
+			meta: Color::Reset,
+			content: Color::Reset,
+			interrupt: Color::Reset,
+		}
+	}
+}
+
+// endregion: --- ColorScheme
+
+// region:    --- TerminalStreamPrinter
+
+/// Print a chat stream incrementally to the terminal, with a `[Model: ..]` header, a blinking cursor while
+/// streaming, and a `[Tokens: .. in / .. out]` footer (all on by default).
+///
+/// Note: The footer is printed only when the usage is captured (see `ChatOptions::with_capture_usage`).
+#[derive(Debug, Clone)]
+pub struct TerminalStreamPrinter {
+	show_model_header: bool,
+	show_usage_footer: bool,
+	typing_indicator: bool,
+	color_scheme: ColorScheme,
+}
+
+impl Default for TerminalStreamPrinter {
+	fn default() -> Self {
+		Self {
+			show_model_header: true,
+			show_usage_footer: true,
+			typing_indicator: true,
+			color_scheme: ColorScheme::default(),
+		}
+	}
+}
+
+/// Constructors
+impl TerminalStreamPrinter {
+	pub fn builder() -> TerminalStreamPrinterBuilder {
+		TerminalStreamPrinterBuilder::default()
+	}
+}
+
+impl TerminalStreamPrinter {
+	/// Print the chat stream to the stdout, and returns the final `MetaUsage`.
+	pub async fn print(&self, chat_res: ChatStreamResponse) -> Result<MetaUsage> {
+		self.print_to(std::io::stdout(), chat_res).await
+	}
+
+	/// Same as `print`, with a given writer (e.g., `std::io::stderr()`).
+	pub async fn print_to<W: Write>(&self, mut writer: W, chat_res: ChatStreamResponse) -> Result<MetaUsage> {
+		let ChatStreamResponse {
+			model_iden, mut stream, ..
+		} = chat_res;
+		let write_error = |io_error: std::io::Error| Error::StreamWrite {
+			model_iden: model_iden.clone(),
+			cause: io_error.to_string(),
+		};
+
+		let mut usage = MetaUsage::default();
+		let print_res: Result<()> = async {
+			self.write_header(&mut writer, &model_iden).map_err(write_error)?;
+			while let Some(event) = stream.next().await {
+				match event? {
+					ChatStreamEvent::Chunk(chunk) => {
+						queue!(writer, Print(chunk.content)).map_err(write_error)?;
+						writer.flush().map_err(write_error)?;
+					}
+					ChatStreamEvent::End(end) => usage = end.captured_usage.unwrap_or_default(),
+					ChatStreamEvent::Start => (),
+				}
+			}
+			Ok(())
+		}
+		.await;
+
+		// Note: The terminal style is restored on all of the exit paths (even on a stream or write error),
+		//       and the first error is returned.
+		let footer_res = self.write_footer(&mut writer, &usage).map_err(write_error);
+		print_res?;
+		footer_res?;
+		Ok(usage)
+	}
+
+	/// Clear the current line and print a message (e.g., `[Tool call: get_weather]`), for the interruptions
+	/// of the printed stream (e.g., between the rounds of a tool call loop).
+	pub fn interrupt<W: Write>(&self, writer: &mut W, message: &str) -> std::io::Result<()> {
+		queue!(
+			writer,
+			Clear(ClearType::CurrentLine),
+			MoveToColumn(0),
+			SetForegroundColor(self.color_scheme.interrupt),
+			Print(message),
+			ResetColor,
+			Print("\n"),
+			SetForegroundColor(self.color_scheme.content),
+		)?;
+		writer.flush()
+	}
+}
+
+// -- Support
+impl TerminalStreamPrinter {
+	fn write_header<W: Write>(&self, writer: &mut W, model_iden: &ModelIden) -> std::io::Result<()> {
+		if self.show_model_header {
+			queue!(
+				writer,
+				SetAttribute(Attribute::Dim),
+				SetForegroundColor(self.color_scheme.meta),
+				Print(format!("[Model: {}]\n", model_iden.model_name)),
+				SetAttribute(Attribute::Reset),
+				ResetColor,
+			)?;
+		}
+		if self.typing_indicator {
+			queue!(writer, Show, EnableBlinking)?;
+		}
+		queue!(writer, SetForegroundColor(self.color_scheme.content))?;
+		writer.flush()
+	}
+
+	fn write_footer<W: Write>(&self, writer: &mut W, usage: &MetaUsage) -> std::io::Result<()> {
+		queue!(writer, ResetColor, Print("\n"))?;
+		if self.typing_indicator {
+			queue!(writer, DisableBlinking)?;
+		}
+		if self.show_usage_footer && (usage.input_tokens.is_some() || usage.output_tokens.is_some()) {
+			queue!(
+				writer,
+				SetAttribute(Attribute::Dim),
+				SetForegroundColor(self.color_scheme.meta),
+				Print(format!(
+					"[Tokens: {} in / {} out]\n",
+					usage.input_tokens.unwrap_or(0),
+					usage.output_tokens.unwrap_or(0)
+				)),
+				SetAttribute(Attribute::Reset),
+				ResetColor,
+			)?;
+		}
+		writer.flush()
+	}
+}
+
+// endregion: --- TerminalStreamPrinter
+
+// region:    --- TerminalStreamPrinterBuilder
+
+#[derive(Debug, Clone, Default)]
+pub struct TerminalStreamPrinterBuilder {
+	printer: TerminalStreamPrinter,
+}
+
+impl TerminalStreamPrinterBuilder {
+	/// Print the `[Model: ..]` header in a dim style (default `true`).
+	pub fn show_model_header(mut self, show: bool) -> Self {
+		self.printer.show_model_header = show;
+		self
+	}
+
+	/// Print the `[Tokens: .. in / .. out]` footer in a dim style (default `true`).
+	pub fn show_usage_footer(mut self, show: bool) -> Self {
+		self.printer.show_usage_footer = show;
+		self
+	}
+
+	/// Show a blinking cursor while streaming (default `true`).
+	pub fn typing_indicator(mut self, enabled: bool) -> Self {
+		self.printer.typing_indicator = enabled;
+		self
+	}
+
+	pub fn color_scheme(mut self, color_scheme: ColorScheme) -> Self {
+		self.printer.color_scheme = color_scheme;
+		self
+	}
+
+	pub fn build(self) -> TerminalStreamPrinter {
+		self.printer
+	}
+}
+
+// endregion: --- TerminalStreamPrinterBuilder
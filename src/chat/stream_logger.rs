@@ -0,0 +1,39 @@
+use crate::chat::ChatStreamEvent;
+use std::io::Write;
+use std::sync::Mutex;
+
+/// A built-in `ChatStream` tap writing each event as a JSON line to a writer (e.g., a file).
+///
+/// ```ignore
+/// let file = std::fs::File::create("stream-events.jsonl")?;
+/// let stream = chat_res.stream.tap(StreamLogger::new(file).into_tap());
+/// ```
+pub struct StreamLogger {
+	writer: Mutex<Box<dyn Write + Send>>,
+}
+
+impl StreamLogger {
+	pub fn new(writer: impl Write + Send + 'static) -> Self {
+		Self {
+			writer: Mutex::new(Box::new(writer)),
+		}
+	}
+
+	/// Write the event as a JSON line.
+	///
+	/// Note: The write errors are ignored, so that the logging never disrupts the stream.
+	pub fn log(&self, event: &ChatStreamEvent) {
+		let Ok(line) = serde_json::to_string(event) else {
+			return;
+		};
+		if let Ok(mut writer) = self.writer.lock() {
+			let _ = writeln!(writer, "{line}");
+			let _ = writer.flush();
+		}
+	}
+
+	/// Returns the tap function for `ChatStream::tap`.
+	pub fn into_tap(self) -> impl Fn(&ChatStreamEvent) + Send + 'static {
+		move |event| self.log(event)
+	}
+}
@@ -2,10 +2,19 @@
 
 mod tool_base;
 mod tool_call;
+mod tool_fn;
+mod tool_registry;
 mod tool_response;
+mod tool_schema;
 
 pub use tool_base::*;
 pub use tool_call::*;
+pub use tool_fn::*;
+pub use tool_registry::*;
 pub use tool_response::*;
+pub use tool_schema::*;
+
+#[cfg(feature = "macros")]
+pub use genai_macros::{tool, tool_doc};
 
 // endregion: --- Modules
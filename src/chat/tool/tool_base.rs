@@ -34,6 +34,10 @@ pub struct Tool {
 	/// })
 	/// ```
 	pub schema: Option<Value>,
+
+	/// The tool type, `ToolType::Function` (with the `schema` above) by default.
+	#[serde(default)]
+	pub tool_type: ToolType,
 }
 
 /// Constructor
@@ -43,6 +47,17 @@ impl Tool {
 			name: name.into(),
 			description: None,
 			schema: None,
+			tool_type: ToolType::Function,
+		}
+	}
+
+	/// Create an Anthropic computer use tool (named after its kind, e.g., `computer`).
+	pub fn computer_use(computer_use_tool: ComputerUseTool) -> Self {
+		Self {
+			name: computer_use_tool.kind.tool_name().to_string(),
+			description: None,
+			schema: None,
+			tool_type: ToolType::ComputerUse(computer_use_tool),
 		}
 	}
 }
@@ -62,3 +77,80 @@ impl Tool {
 }
 
 // endregion: --- Setters
+
+// region:    --- ToolType
+
+/// The type of a `Tool`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub enum ToolType {
+	/// A function tool, described by the `Tool` name, description, and schema.
+	#[default]
+	Function,
+	/// An Anthropic computer use tool (beta, Anthropic only).
+	ComputerUse(ComputerUseTool),
+}
+
+/// An Anthropic computer use tool (see https://docs.anthropic.com/en/docs/build-with-claude/computer-use).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComputerUseTool {
+	pub kind: ComputerToolKind,
+	/// The display width (required for the `ComputerToolKind::Computer`).
+	pub display_width_px: Option<u32>,
+	/// The display height (required for the `ComputerToolKind::Computer`).
+	pub display_height_px: Option<u32>,
+}
+
+impl ComputerUseTool {
+	pub fn new(kind: ComputerToolKind) -> Self {
+		Self {
+			kind,
+			display_width_px: None,
+			display_height_px: None,
+		}
+	}
+
+	pub fn with_display_size(mut self, width_px: u32, height_px: u32) -> Self {
+		self.display_width_px = Some(width_px);
+		self.display_height_px = Some(height_px);
+		self
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ComputerToolKind {
+	Computer,
+	Bash,
+	TextEditor,
+}
+
+impl ComputerToolKind {
+	/// The Anthropic tool `type` (e.g., `computer_20241022`).
+	pub fn type_name(&self) -> &'static str {
+		match self {
+			ComputerToolKind::Computer => "computer_20241022",
+			ComputerToolKind::Bash => "bash_20241022",
+			ComputerToolKind::TextEditor => "text_editor_20241022",
+		}
+	}
+
+	/// The fixed tool name, as used in the `ToolCall::fn_name` of the responses.
+	pub fn tool_name(&self) -> &'static str {
+		match self {
+			ComputerToolKind::Computer => "computer",
+			ComputerToolKind::Bash => "bash",
+			ComputerToolKind::TextEditor => "str_replace_editor",
+		}
+	}
+
+	/// Returns the kind for a computer use tool name (e.g., from a `ToolCall::fn_name`).
+	pub fn from_tool_name(name: &str) -> Option<Self> {
+		match name {
+			"computer" => Some(ComputerToolKind::Computer),
+			"bash" => Some(ComputerToolKind::Bash),
+			"str_replace_editor" => Some(ComputerToolKind::TextEditor),
+			_ => None,
+		}
+	}
+}
+
+// endregion: --- ToolType
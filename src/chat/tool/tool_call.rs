@@ -1,5 +1,7 @@
+use crate::{Error, Result};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use value_ext::JsonValueExt;
 
 /// The tool call function name and arguments sent back by the LLM.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -8,3 +10,86 @@ pub struct ToolCall {
 	pub fn_name: String,
 	pub fn_arguments: Value,
 }
+
+/// Constructors from the adapter formats
+impl ToolCall {
+	/// Create a ToolCall from an OpenAI `tool_calls` item (also used by the OpenAI Assistants API).
+	///
+	/// ```json
+	/// {"id": "call_abc123", "type": "function", "function": {"name": "get_weather", "arguments": "{\"location\": \"Paris\"}"}}
+	/// ```
+	///
+	/// Note: The `arguments` string is parsed as a JSON value (only objects are supported).
+	pub fn from_openai_value(raw_tool_call: Value) -> Result<Self> {
+		// Define a helper struct to match the original JSON structure.
+		#[derive(Deserialize)]
+		struct IterimToolFnCall {
+			id: String,
+			#[allow(unused)]
+			#[serde(rename = "type")]
+			r#type: String,
+			function: IterimFunction,
+		}
+
+		#[derive(Deserialize)]
+		struct IterimFunction {
+			name: String,
+			arguments: Value,
+		}
+
+		let iterim = serde_json::from_value::<IterimToolFnCall>(raw_tool_call)?;
+
+		let fn_arguments = match iterim.function.arguments {
+			Value::Object(obj) => Value::Object(obj),
+			Value::String(txt) => serde_json::from_str(&txt)?,
+			_ => {
+				return Err(Error::InvalidJsonResponseElement {
+					info: "tool call arguments is not an object",
+				})
+			}
+		};
+
+		Ok(ToolCall {
+			call_id: iterim.id,
+			fn_name: iterim.function.name,
+			fn_arguments,
+		})
+	}
+
+	/// Create a ToolCall from an Anthropic `tool_use` content block
+	/// (`input` is already a JSON object, not a string as for OpenAI).
+	///
+	/// ```json
+	/// {"type": "tool_use", "id": "toolu_01A09q90qw90lq917835lq9", "name": "get_weather", "input": {"location": "Paris"}}
+	/// ```
+	pub fn from_anthropic_value(mut value: Value) -> Result<Self> {
+		let call_id = value.x_take::<String>("id")?;
+		let fn_name = value.x_take::<String>("name")?;
+		// if not found, will be Value::Null
+		let fn_arguments = value.x_take::<Value>("input").unwrap_or_default();
+
+		Ok(ToolCall {
+			call_id,
+			fn_name,
+			fn_arguments,
+		})
+	}
+
+	/// Create a ToolCall from a Gemini `functionCall` part value.
+	///
+	/// ```json
+	/// {"name": "get_weather", "args": {"location": "Paris"}}
+	/// ```
+	///
+	/// Note: Gemini does not provide a call id, so the function name is used as the `call_id`.
+	pub fn from_gemini_value(mut value: Value) -> Result<Self> {
+		let fn_name = value.x_take::<String>("name")?;
+		let fn_arguments = value.x_take::<Value>("args").unwrap_or_default();
+
+		Ok(ToolCall {
+			call_id: fn_name.clone(),
+			fn_name,
+			fn_arguments,
+		})
+	}
+}
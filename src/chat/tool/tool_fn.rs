@@ -0,0 +1,17 @@
+use crate::Result;
+use serde_json::Value;
+
+/// A tool function with its schema, to be invoked with the LLM JSON arguments.
+///
+/// Implemented by the `#[tool]` attribute (with the `macros` feature) for the struct generated with the function name,
+/// so it can be registered with `registry.register_tool::<get_weather>()` (see `ToolSchemaRegistry::register_tool`).
+pub trait ToolFn {
+	/// The tool function name.
+	const NAME: &'static str;
+
+	/// The tool function schema, in the OpenAI function format (see `schema_for_fn_single_param`).
+	fn schema() -> Value;
+
+	/// Invoke the tool function with the LLM JSON arguments, and returns its output (the `ToolResponse` content).
+	fn invoke(args: Value) -> Result<String>;
+}
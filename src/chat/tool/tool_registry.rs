@@ -0,0 +1,388 @@
+//! Registry of versioned tool schemas and handlers, to dispatch the LLM tool calls
+//! and to detect the breaking schema changes against a previously saved snapshot.
+
+use crate::chat::{Tool, ToolCall, ToolFn};
+use crate::{Error, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::any::Any;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::Path;
+use std::sync::Mutex;
+
+type ToolHandler = Box<dyn Fn(Value) -> Result<String> + Send + Sync>;
+
+// region:    --- ToolSchemaRegistry
+
+/// Registry of the tools by name and version.
+///
+/// Each version is exposed to the LLM as `{name}_{version}` (e.g., `get_weather_v2`),
+/// with the `.` of the version replaced by `_` (e.g., `1.2` gives `get_weather_1_2`).
+#[derive(Default)]
+pub struct ToolSchemaRegistry {
+	tools: Vec<VersionedTool>,
+	/// The disabled tool names or versioned names (see `disable`).
+	disabled: HashSet<String>,
+	/// The typed results stored by tool call id (see `store_result`).
+	results: Mutex<HashMap<String, Box<dyn Any + Send + Sync>>>,
+}
+
+struct VersionedTool {
+	name: String,
+	version: String,
+	versioned_name: String,
+	description: Option<String>,
+	parameters: Value,
+	handler: ToolHandler,
+}
+
+/// Constructors
+impl ToolSchemaRegistry {
+	pub fn new() -> Self {
+		Self::default()
+	}
+}
+
+impl ToolSchemaRegistry {
+	/// Register (or replace) the `version` of the tool `name`.
+	///
+	/// The `schema` can be the parameters schema, or the function format from `schema_for_fn_single_param`
+	/// (the `function.description` and `function.parameters` are then used).
+	pub fn register_versioned<F>(&mut self, name: &str, version: &str, schema: Value, handler: F) -> &mut Self
+	where
+		F: Fn(Value) -> Result<String> + Send + Sync + 'static,
+	{
+		let versioned_name = versioned_name(name, version);
+		let (description, parameters) = match schema.get("function") {
+			Some(function) => (
+				function.get("description").and_then(|d| d.as_str()).map(String::from),
+				function.get("parameters").cloned().unwrap_or_default(),
+			),
+			None => (None, schema),
+		};
+
+		self.tools.retain(|tool| tool.versioned_name != versioned_name);
+		self.tools.push(VersionedTool {
+			name: name.to_string(),
+			version: version.to_string(),
+			versioned_name,
+			description,
+			parameters,
+			handler: Box::new(handler),
+		});
+		self
+	}
+
+	/// Register (or replace) an unversioned tool from its full OpenAI format schema
+	/// (`{"type": "function", "function": {"name", "description", "parameters"}}`),
+	/// e.g., loaded from a JSON or YAML config file.
+	///
+	/// The tool is exposed with its `function.name` as is (and an empty version), and the `handler`
+	/// receives the call arguments (`None` if the LLM sent none).
+	pub fn register_from_schema<F>(&mut self, schema: Value, handler: F) -> Result<&mut Self>
+	where
+		F: Fn(Option<&Value>) -> String + Send + Sync + 'static,
+	{
+		let name = validate_tool_schema(&schema).map_err(|cause| Error::ToolInvalidSchema { cause })?;
+		let description = schema
+			.pointer("/function/description")
+			.and_then(|d| d.as_str())
+			.map(String::from);
+		let parameters = schema.pointer("/function/parameters").cloned().unwrap_or_default();
+
+		self.tools.retain(|tool| tool.versioned_name != name);
+		self.tools.push(VersionedTool {
+			name: name.clone(),
+			version: String::new(),
+			versioned_name: name,
+			description,
+			parameters,
+			handler: Box::new(move |args| Ok(handler(Some(&args).filter(|args| !args.is_null())))),
+		});
+		Ok(self)
+	}
+
+	/// Register (or replace) an unversioned `ToolFn`, e.g., a function with the `#[tool]` attribute
+	/// (with the `macros` feature): `registry.register_tool::<get_weather>()`.
+	///
+	/// The tool is exposed with its `ToolFn::NAME` (and an empty version).
+	pub fn register_tool<T: ToolFn + 'static>(&mut self) -> &mut Self {
+		let schema = T::schema();
+		let description = schema
+			.pointer("/function/description")
+			.and_then(|d| d.as_str())
+			.map(String::from);
+		let parameters = schema.pointer("/function/parameters").cloned().unwrap_or_default();
+
+		self.tools.retain(|tool| tool.versioned_name != T::NAME);
+		self.tools.push(VersionedTool {
+			name: T::NAME.to_string(),
+			version: String::new(),
+			versioned_name: T::NAME.to_string(),
+			description,
+			parameters,
+			handler: Box::new(T::invoke),
+		});
+		self
+	}
+
+	/// Disable the tool `name` (all of its versions), or a single version with its versioned name
+	/// (e.g., `get_weather_v1`), so it is not in the `tool_schemas()` anymore.
+	///
+	/// Note: The disabled tools can still be dispatched (e.g., for a call from a previous response).
+	pub fn disable(&mut self, name: &str) -> &mut Self {
+		self.disabled.insert(name.to_string());
+		self
+	}
+
+	/// Enable back a tool disabled with `disable` (with the same name).
+	pub fn enable(&mut self, name: &str) -> &mut Self {
+		self.disabled.remove(name);
+		self
+	}
+
+	/// Dispatch the tool call to the handler of its versioned name.
+	/// A call to the unversioned name (e.g., `get_weather`) goes to the last registered version.
+	///
+	/// Returns the handler output, or a `{"error": ...}` JSON string to be sent back to the LLM
+	/// (unknown tool or handler error).
+	pub fn dispatch_versioned(&self, call: &ToolCall) -> String {
+		let tool = self
+			.tools
+			.iter()
+			.find(|tool| tool.versioned_name == call.fn_name)
+			.or_else(|| self.tools.iter().rev().find(|tool| tool.name == call.fn_name));
+
+		let Some(tool) = tool else {
+			return json!({"error": format!("Unknown tool '{}'", call.fn_name)}).to_string();
+		};
+
+		match (tool.handler)(call.fn_arguments.clone()) {
+			Ok(output) => output,
+			Err(err) => json!({"error": err.to_string()}).to_string(),
+		}
+	}
+}
+
+/// Getters
+impl ToolSchemaRegistry {
+	/// Returns the `Tool` of each registered version, with its versioned name (for the `ChatRequest`).
+	///
+	/// Note: The disabled tools are not included (see `disable`).
+	pub fn tool_schemas(&self) -> Vec<Tool> {
+		self.tools
+			.iter()
+			.filter(|tool| self.is_enabled(tool))
+			.map(|tool| {
+				let mut chat_tool = Tool::new(&tool.versioned_name).with_schema(tool.parameters.clone());
+				chat_tool.description = tool.description.clone();
+				chat_tool
+			})
+			.collect()
+	}
+
+	fn is_enabled(&self, tool: &VersionedTool) -> bool {
+		!self.disabled.contains(&tool.name) && !self.disabled.contains(&tool.versioned_name)
+	}
+
+	/// Returns the registered versions of the tool `name`, in the registration order.
+	pub fn versions(&self, name: &str) -> Vec<&str> {
+		self.tools
+			.iter()
+			.filter(|tool| tool.name == name)
+			.map(|tool| tool.version.as_str())
+			.collect()
+	}
+}
+
+/// Typed Results
+impl ToolSchemaRegistry {
+	/// Store the typed result of a tool call (e.g., the `TypedToolResult.value`), for a later `take_result`.
+	pub fn store_result<T: Any + Send + Sync>(&self, tool_call_id: &str, value: T) {
+		self.lock_results().insert(tool_call_id.to_string(), Box::new(value));
+	}
+
+	/// Take the typed result of the tool call, if stored with this type (otherwise, it is kept).
+	pub fn take_result<T: Any>(&self, tool_call_id: &str) -> Option<T> {
+		let mut results = self.lock_results();
+		let result = results.remove(tool_call_id)?;
+		match result.downcast::<T>() {
+			Ok(value) => Some(*value),
+			Err(result) => {
+				results.insert(tool_call_id.to_string(), result);
+				None
+			}
+		}
+	}
+
+	fn lock_results(&self) -> std::sync::MutexGuard<'_, HashMap<String, Box<dyn Any + Send + Sync>>> {
+		// Note: The results map is always consistent, so a poisoned lock can be recovered.
+		self.results.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+	}
+}
+
+// endregion: --- ToolSchemaRegistry
+
+// region:    --- Snapshot & Compatibility
+
+/// The saved parameters schemas, by versioned name.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ToolSchemaSnapshot {
+	tools: BTreeMap<String, Value>,
+}
+
+/// A breaking change between a saved snapshot and the currently registered schemas.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum CompatibilityWarning {
+	/// The versioned tool is no longer registered.
+	ToolRemoved { tool_name: String },
+	/// A required parameter was removed from the properties.
+	RequiredParamRemoved { tool_name: String, param: String },
+	/// A parameter became required (the previous calls may not have it).
+	RequiredParamAdded { tool_name: String, param: String },
+	/// The `type` of a parameter changed.
+	ParamTypeChanged {
+		tool_name: String,
+		param: String,
+		old_type: Value,
+		new_type: Value,
+	},
+}
+
+impl ToolSchemaRegistry {
+	/// Save the parameters schemas of the registered tools, to be compared later with `compatibility_report`.
+	pub fn save_snapshot(&self, path: &Path) -> Result<()> {
+		let content = serde_json::to_string_pretty(&self.snapshot())?;
+		std::fs::write(path, content).map_err(|io_error| Error::FileWrite {
+			path: path.to_string_lossy().to_string(),
+			cause: io_error.to_string(),
+		})
+	}
+
+	/// Compare the registered schemas against the snapshot saved at `snapshot_path`,
+	/// and returns the breaking changes (removed tools, removed or added required params, changed types).
+	///
+	/// Note: New tools and new optional parameters are compatible, so not reported.
+	pub fn compatibility_report(&self, snapshot_path: &Path) -> Result<Vec<CompatibilityWarning>> {
+		let content = std::fs::read_to_string(snapshot_path).map_err(|io_error| Error::FileRead {
+			path: snapshot_path.to_string_lossy().to_string(),
+			cause: io_error.to_string(),
+		})?;
+		let saved: ToolSchemaSnapshot = serde_json::from_str(&content)?;
+		let current = self.snapshot();
+
+		let mut warnings = Vec::new();
+		for (tool_name, old_params) in saved.tools.iter() {
+			let Some(new_params) = current.tools.get(tool_name) else {
+				warnings.push(CompatibilityWarning::ToolRemoved {
+					tool_name: tool_name.to_string(),
+				});
+				continue;
+			};
+			compare_params(tool_name, old_params, new_params, &mut warnings);
+		}
+
+		Ok(warnings)
+	}
+
+	fn snapshot(&self) -> ToolSchemaSnapshot {
+		let tools = self
+			.tools
+			.iter()
+			.map(|tool| (tool.versioned_name.clone(), tool.parameters.clone()))
+			.collect();
+		ToolSchemaSnapshot { tools }
+	}
+}
+
+fn compare_params(tool_name: &str, old_params: &Value, new_params: &Value, warnings: &mut Vec<CompatibilityWarning>) {
+	let old_required = required_names(old_params);
+	let new_required = required_names(new_params);
+
+	for param in old_required.iter() {
+		if new_params.pointer(&format!("/properties/{param}")).is_none() {
+			warnings.push(CompatibilityWarning::RequiredParamRemoved {
+				tool_name: tool_name.to_string(),
+				param: param.to_string(),
+			});
+		}
+	}
+
+	for param in new_required.iter().filter(|param| !old_required.contains(param)) {
+		warnings.push(CompatibilityWarning::RequiredParamAdded {
+			tool_name: tool_name.to_string(),
+			param: param.to_string(),
+		});
+	}
+
+	let Some(old_properties) = old_params.get("properties").and_then(|p| p.as_object()) else {
+		return;
+	};
+	for (param, old_schema) in old_properties {
+		let Some(new_schema) = new_params.pointer(&format!("/properties/{param}")) else {
+			continue;
+		};
+		let old_type = old_schema.get("type").cloned().unwrap_or_default();
+		let new_type = new_schema.get("type").cloned().unwrap_or_default();
+		if old_type != new_type {
+			warnings.push(CompatibilityWarning::ParamTypeChanged {
+				tool_name: tool_name.to_string(),
+				param: param.to_string(),
+				old_type,
+				new_type,
+			});
+		}
+	}
+}
+
+// endregion: --- Snapshot & Compatibility
+
+// region:    --- Support
+
+fn versioned_name(name: &str, version: &str) -> String {
+	format!("{name}_{}", version.replace('.', "_"))
+}
+
+/// Validate the structure of an OpenAI format tool schema, and returns its function name.
+fn validate_tool_schema(schema: &Value) -> core::result::Result<String, String> {
+	if schema.get("type").and_then(|t| t.as_str()) != Some("function") {
+		return Err("'type' should be \"function\"".to_string());
+	}
+	let function = schema
+		.get("function")
+		.and_then(|f| f.as_object())
+		.ok_or("'function' should be an object")?;
+
+	let name = function
+		.get("name")
+		.and_then(|n| n.as_str())
+		.ok_or("'function.name' should be a string")?;
+	let is_valid_name =
+		!name.is_empty() && name.len() <= 64 && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+	if !is_valid_name {
+		return Err(format!(
+			"'function.name' should be 1 to 64 letters, digits, '_', or '-' (was '{name}')"
+		));
+	}
+
+	if function.get("description").is_some_and(|d| !d.is_string()) {
+		return Err("'function.description' should be a string".to_string());
+	}
+	if let Some(parameters) = function.get("parameters") {
+		if parameters.get("type").and_then(|t| t.as_str()) != Some("object") {
+			return Err("'function.parameters' should be an object schema (with \"type\": \"object\")".to_string());
+		}
+	}
+
+	Ok(name.to_string())
+}
+
+fn required_names(params: &Value) -> Vec<&str> {
+	params
+		.get("required")
+		.and_then(|r| r.as_array())
+		.map(|required| required.iter().filter_map(|name| name.as_str()).collect())
+		.unwrap_or_default()
+}
+
+// endregion: --- Support
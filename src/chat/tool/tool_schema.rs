@@ -0,0 +1,402 @@
+//! Tool function schema generation (from `schemars::JsonSchema` types) and tool function invocation
+//! from the LLM JSON arguments.
+
+use crate::chat::ToolResponse;
+use crate::{Error, Result};
+use schemars::gen::SchemaSettings;
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+
+// region:    --- Schema Generation
+
+/// Options for the generated tool parameter schemas.
+///
+/// Note: All default to `true`, since some providers (e.g., Groq) reject the `$schema` and `title` root fields.
+#[derive(Debug, Clone)]
+pub struct SchemaOptions {
+	/// Remove the root `title` (the Rust type name).
+	pub strip_title: bool,
+	/// Remove the root `$schema` (the JSON Schema draft URL).
+	pub strip_schema_field: bool,
+	/// Set `additionalProperties: false` on the object schemas which do not define it.
+	pub additional_properties_false: bool,
+}
+
+impl Default for SchemaOptions {
+	fn default() -> Self {
+		Self {
+			strip_title: true,
+			strip_schema_field: true,
+			additional_properties_false: true,
+		}
+	}
+}
+
+/// Generate the function schema for a tool function taking a single parameter struct `T`.
+///
+/// Returns the OpenAI function format `{"type": "function", "function": {"name", "description", "parameters"}}`.
+pub fn schema_for_fn_single_param<T: JsonSchema>(fn_name: &str, fn_description: &str) -> Value {
+	schema_for_fn_single_param_with_options::<T>(fn_name, fn_description, &SchemaOptions::default())
+}
+
+pub fn schema_for_fn_single_param_with_options<T: JsonSchema>(
+	fn_name: &str,
+	fn_description: &str,
+	options: &SchemaOptions,
+) -> Value {
+	json!({
+		"type": "function",
+		"function": {
+			"name": fn_name,
+			"description": fn_description,
+			"parameters": normalize_schema(schema_for_type::<T>(), options),
+		}
+	})
+}
+
+/// Same as `schema_for_fn_single_param`, with an explicit `required` list for the parameters.
+pub fn schema_for_fn_single_param_with_required<T: JsonSchema>(
+	fn_name: &str,
+	fn_description: &str,
+	overrides: &[&str],
+) -> Value {
+	let mut fn_schema = schema_for_fn_single_param::<T>(fn_name, fn_description);
+	if let Some(parameters) = fn_schema.pointer_mut("/function/parameters").and_then(|p| p.as_object_mut()) {
+		parameters.insert("required".to_string(), json!(overrides));
+	}
+	fn_schema
+}
+
+/// Same as `schema_for_fn_single_param`, with the `function.output_schema` describing the return type `TReturn`.
+pub fn schema_for_fn_with_return<TParam: JsonSchema, TReturn: JsonSchema + Serialize>(
+	fn_name: &str,
+	fn_description: &str,
+) -> Value {
+	let mut fn_schema = schema_for_fn_single_param::<TParam>(fn_name, fn_description);
+	let output_schema = normalize_schema(schema_for_type::<TReturn>(), &SchemaOptions::default());
+	if let Some(function) = fn_schema.get_mut("function").and_then(|f| f.as_object_mut()) {
+		function.insert("output_schema".to_string(), output_schema);
+	}
+	fn_schema
+}
+
+/// Same as `schema_for_fn_single_param`, with the function description from the root `description` of the `TParam`
+/// schema, i.e., the param struct doc comment (or its `#[schemars(description = "...")]`).
+///
+/// Note: The root `description` is moved from the `parameters` to the `function.description`.
+///
+/// Returns `Error::ToolSchemaNoDescription` if the `TParam` schema has no root description.
+pub fn schema_for_fn_single_param_from_docs<TParam: JsonSchema>(fn_name: &str) -> Result<Value> {
+	let mut parameters = normalize_schema(schema_for_type::<TParam>(), &SchemaOptions::default());
+	let description = parameters
+		.as_object_mut()
+		.and_then(|root| root.remove("description"))
+		.and_then(|description| description.as_str().map(String::from))
+		.ok_or_else(|| Error::ToolSchemaNoDescription {
+			fn_name: fn_name.to_string(),
+		})?;
+
+	Ok(json!({
+		"type": "function",
+		"function": {
+			"name": fn_name,
+			"description": description,
+			"parameters": parameters,
+		}
+	}))
+}
+
+/// Normalize a generated root schema for the tool `parameters`.
+/// - Always removes the root `definitions` (the sub schemas are inlined at generation).
+/// - Always removes the optional (nullable) properties from the `required` lists.
+/// - Removes the root `$schema` and `title`, and sets `additionalProperties: false`, per the `options`.
+pub fn normalize_schema(mut schema: Value, options: &SchemaOptions) -> Value {
+	if let Some(root) = schema.as_object_mut() {
+		root.remove("definitions");
+		if options.strip_schema_field {
+			root.remove("$schema");
+		}
+		if options.strip_title {
+			root.remove("title");
+		}
+	}
+	remove_optional_from_required(&mut schema);
+	if options.additional_properties_false {
+		set_additional_properties_false(&mut schema);
+	}
+	schema
+}
+
+// endregion: --- Schema Generation
+
+// region:    --- Invoke
+
+/// Invoke a tool function with the LLM JSON arguments.
+///
+/// When `output_schema` is given, the returned `String` must be a JSON string matching this schema.
+pub fn invoke_with_args<F, A, E>(f: F, args: Value, output_schema: Option<&Value>) -> Result<String>
+where
+	F: FnOnce(A) -> core::result::Result<String, E>,
+	A: DeserializeOwned,
+	E: std::fmt::Display,
+{
+	let args: A = serde_json::from_value(args).map_err(|err| Error::ToolInvalidArgs { cause: err.to_string() })?;
+	let output = f(args).map_err(|err| Error::ToolFnFailed { cause: err.to_string() })?;
+
+	if let Some(output_schema) = output_schema {
+		let output_value: Value =
+			serde_json::from_str(&output).map_err(|err| Error::ToolInvalidOutput { cause: err.to_string() })?;
+		validate_json_value(&output_value, output_schema).map_err(|cause| Error::ToolInvalidOutput { cause })?;
+	}
+
+	Ok(output)
+}
+
+/// Invoke a tool function returning a typed value, serialized to a JSON string.
+pub fn invoke_with_typed_args<F, A, R, E>(f: F, args: Value) -> Result<String>
+where
+	F: FnOnce(A) -> core::result::Result<R, E>,
+	A: DeserializeOwned,
+	R: Serialize,
+	E: std::fmt::Display,
+{
+	let args: A = serde_json::from_value(args).map_err(|err| Error::ToolInvalidArgs { cause: err.to_string() })?;
+	let output = f(args).map_err(|err| Error::ToolFnFailed { cause: err.to_string() })?;
+	let output = serde_json::to_string(&output)?;
+
+	Ok(output)
+}
+
+/// Invoke a tool function returning a typed value, keeping both the value and its JSON string (for the LLM).
+///
+/// Note: Without `args` (the LLM sent none), the function is called with the `{}` arguments.
+pub fn invoke_typed_with_args<F, A, R, E>(f: F, args: Option<&Value>) -> Result<TypedToolResult<R>>
+where
+	F: FnOnce(A) -> core::result::Result<R, E>,
+	A: DeserializeOwned,
+	R: Serialize + DeserializeOwned,
+	E: std::fmt::Display,
+{
+	let args = args.filter(|args| !args.is_null()).cloned().unwrap_or_else(|| json!({}));
+	let args: A = serde_json::from_value(args).map_err(|err| Error::ToolInvalidArgs { cause: err.to_string() })?;
+	let value = f(args).map_err(|err| Error::ToolFnFailed { cause: err.to_string() })?;
+	let output = serde_json::to_string(&value)?;
+
+	Ok(TypedToolResult { value, output })
+}
+
+/// A tool result, with its typed value and its JSON string serialization (the `ToolResponse` content).
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypedToolResult<T: DeserializeOwned> {
+	pub value: T,
+	pub output: String,
+}
+
+impl<T: DeserializeOwned> TypedToolResult<T> {
+	/// Deserialize a tool result string (e.g., a `ToolResponse.content`) back to its typed value.
+	pub fn from_output(output: impl Into<String>) -> Result<Self> {
+		let output = output.into();
+		let value = serde_json::from_str(&output).map_err(|err| Error::ToolInvalidOutput { cause: err.to_string() })?;
+		Ok(Self { value, output })
+	}
+
+	/// The `ToolResponse` of the tool call `tool_call_id`, with the JSON string output.
+	pub fn to_tool_response(&self, tool_call_id: impl Into<String>) -> ToolResponse {
+		ToolResponse::new(tool_call_id, self.output.clone())
+	}
+}
+
+// endregion: --- Invoke
+
+// region:    --- Validation
+
+/// Validate a JSON value against a JSON schema.
+///
+/// Note: This is a minimal validator supporting `type`, `enum`, `const`, `properties`, `required`,
+///       `additionalProperties: false`, `items`, `anyOf`, `oneOf`, and `allOf` (enough for the generated tool schemas).
+///       Returns the validation error message with the JSON pointer of the invalid value.
+pub fn validate_json_value(value: &Value, schema: &Value) -> core::result::Result<(), String> {
+	validate_at(value, schema, "")
+}
+
+fn validate_at(value: &Value, schema: &Value, path: &str) -> core::result::Result<(), String> {
+	let Some(schema) = schema.as_object() else {
+		// `true` or missing schema accepts all
+		return Ok(());
+	};
+
+	// -- type
+	if let Some(typ) = schema.get("type") {
+		let types: Vec<&str> = match typ {
+			Value::String(typ) => vec![typ.as_str()],
+			Value::Array(types) => types.iter().filter_map(|t| t.as_str()).collect(),
+			_ => Vec::new(),
+		};
+		if !types.is_empty() && !types.iter().any(|typ| is_of_type(value, typ)) {
+			return Err(format!("'{path}' should be of type {}", types.join(" | ")));
+		}
+	}
+
+	// -- enum & const
+	if let Some(Value::Array(variants)) = schema.get("enum") {
+		if !variants.contains(value) {
+			return Err(format!("'{path}' should be one of {}", Value::Array(variants.clone())));
+		}
+	}
+	if let Some(expected) = schema.get("const") {
+		if expected != value {
+			return Err(format!("'{path}' should be {expected}"));
+		}
+	}
+
+	// -- object
+	if let Value::Object(obj) = value {
+		if let Some(Value::Array(required)) = schema.get("required") {
+			for name in required.iter().filter_map(|n| n.as_str()) {
+				if !obj.contains_key(name) {
+					return Err(format!("'{path}/{name}' is required"));
+				}
+			}
+		}
+		let properties = schema.get("properties").and_then(|p| p.as_object());
+		for (name, prop_value) in obj {
+			match properties.and_then(|p| p.get(name)) {
+				Some(prop_schema) => validate_at(prop_value, prop_schema, &format!("{path}/{name}"))?,
+				None if schema.get("additionalProperties") == Some(&Value::Bool(false)) => {
+					return Err(format!("'{path}/{name}' is not an allowed property"));
+				}
+				None => (),
+			}
+		}
+	}
+
+	// -- array
+	if let (Value::Array(items), Some(items_schema)) = (value, schema.get("items")) {
+		for (idx, item) in items.iter().enumerate() {
+			validate_at(item, items_schema, &format!("{path}/{idx}"))?;
+		}
+	}
+
+	// -- combinators
+	if let Some(Value::Array(sub_schemas)) = schema.get("allOf") {
+		for sub_schema in sub_schemas {
+			validate_at(value, sub_schema, path)?;
+		}
+	}
+	for key in ["anyOf", "oneOf"] {
+		if let Some(Value::Array(sub_schemas)) = schema.get(key) {
+			if !sub_schemas
+				.iter()
+				.any(|sub_schema| validate_at(value, sub_schema, path).is_ok())
+			{
+				return Err(format!("'{path}' does not match any of the '{key}' schemas"));
+			}
+		}
+	}
+
+	Ok(())
+}
+
+fn is_of_type(value: &Value, typ: &str) -> bool {
+	match typ {
+		"object" => value.is_object(),
+		"array" => value.is_array(),
+		"string" => value.is_string(),
+		"number" => value.is_number(),
+		"integer" => value.is_i64() || value.is_u64(),
+		"boolean" => value.is_boolean(),
+		"null" => value.is_null(),
+		_ => true,
+	}
+}
+
+// endregion: --- Validation
+
+// region:    --- Support
+
+/// Recursively remove from the `required` lists the properties which are nullable
+/// (i.e., `Option<T>`, as `"type": [.., "null"]` or `anyOf`/`oneOf` with a `{"type": "null"}`),
+/// or which are not defined in the `properties`.
+fn remove_optional_from_required(schema: &mut Value) {
+	match schema {
+		Value::Object(obj) => {
+			let properties = obj.get("properties").and_then(|p| p.as_object());
+			let required = obj.get("required").and_then(|r| r.as_array()).map(|required| {
+				required
+					.iter()
+					.filter(|name| {
+						let prop_schema = name.as_str().and_then(|name| properties.and_then(|p| p.get(name)));
+						prop_schema.is_some_and(|prop_schema| !is_nullable_schema(prop_schema))
+					})
+					.cloned()
+					.collect::<Vec<Value>>()
+			});
+			if let Some(required) = required {
+				obj.insert("required".to_string(), Value::Array(required));
+			}
+			for_each_sub_schema(obj, &mut remove_optional_from_required);
+		}
+		Value::Array(items) => items.iter_mut().for_each(remove_optional_from_required),
+		_ => (),
+	}
+}
+
+fn is_nullable_schema(schema: &Value) -> bool {
+	let is_null_type = |typ: &Value| match typ {
+		Value::String(typ) => typ == "null",
+		Value::Array(types) => types.iter().any(|t| t == "null"),
+		_ => false,
+	};
+	if schema.get("type").is_some_and(is_null_type) || schema.get("nullable") == Some(&Value::Bool(true)) {
+		return true;
+	}
+	["anyOf", "oneOf"].iter().any(|key| {
+		schema
+			.get(key)
+			.and_then(|s| s.as_array())
+			.is_some_and(|sub_schemas| sub_schemas.iter().any(is_nullable_schema))
+	})
+}
+
+/// Recursively set `additionalProperties: false` on the schemas with `properties`.
+fn set_additional_properties_false(schema: &mut Value) {
+	match schema {
+		Value::Object(obj) => {
+			if obj.contains_key("properties") && !obj.contains_key("additionalProperties") {
+				obj.insert("additionalProperties".to_string(), Value::Bool(false));
+			}
+			for_each_sub_schema(obj, &mut set_additional_properties_false);
+		}
+		Value::Array(items) => items.iter_mut().for_each(set_additional_properties_false),
+		_ => (),
+	}
+}
+
+/// Call `f` on the sub schemas of a schema object (the `properties` values, `items`, `anyOf`/`oneOf`/`allOf`,
+/// and an object `additionalProperties`), but not on the data (e.g., `enum`, `default`, `examples`).
+fn for_each_sub_schema(obj: &mut Map<String, Value>, f: &mut impl FnMut(&mut Value)) {
+	for (key, value) in obj.iter_mut() {
+		match (key.as_str(), value) {
+			("properties", Value::Object(properties)) => properties.values_mut().for_each(&mut *f),
+			("items", Value::Array(items)) => items.iter_mut().for_each(&mut *f),
+			("items", items @ Value::Object(_)) => f(items),
+			("anyOf" | "oneOf" | "allOf", Value::Array(sub_schemas)) => sub_schemas.iter_mut().for_each(&mut *f),
+			("additionalProperties", additional @ Value::Object(_)) => f(additional),
+			_ => (),
+		}
+	}
+}
+
+/// Generate the JSON schema of a type, with the sub schemas inlined (no `definitions`).
+fn schema_for_type<T: JsonSchema>() -> Value {
+	let generator = SchemaSettings::draft07()
+		.with(|settings| settings.inline_subschemas = true)
+		.into_generator();
+	let root_schema = generator.into_root_schema_for::<T>();
+	// Note: The RootSchema serialization cannot fail (plain JSON data).
+	serde_json::to_value(root_schema).unwrap_or_default()
+}
+
+// endregion: --- Support
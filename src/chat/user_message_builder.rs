@@ -0,0 +1,105 @@
+//! The `UserMessageBuilder` builds a multi-part (text and images) user `ChatMessage`.
+
+use crate::chat::{ChatMessage, ChatRequest, ContentPart, MessageContent};
+use base64::engine::general_purpose;
+use base64::Engine;
+
+/// Builder for a multi-part user message.
+///
+/// Note: When created from `ChatRequest::append_user_message_builder`, `.end_message()` appends the message
+///       to the request and returns it.
+#[derive(Debug, Default, Clone)]
+pub struct UserMessageBuilder {
+	parts: Vec<ContentPart>,
+	chat_req: Option<ChatRequest>,
+}
+
+/// Constructors
+impl UserMessageBuilder {
+	pub fn new() -> Self {
+		Self::default()
+	}
+}
+
+impl ChatRequest {
+	/// Start a `UserMessageBuilder` which will be appended to this request with `.end_message()`.
+	pub fn append_user_message_builder(self) -> UserMessageBuilder {
+		UserMessageBuilder {
+			parts: Vec::new(),
+			chat_req: Some(self),
+		}
+	}
+}
+
+/// Builder methods
+impl UserMessageBuilder {
+	pub fn text(mut self, s: &str) -> Self {
+		self.parts.push(ContentPart::from_text(s));
+		self
+	}
+
+	/// Add an image from a URL (the content type is inferred from the URL extension, default "image/jpeg").
+	pub fn image_url(mut self, url: &str) -> Self {
+		self.parts.push(ContentPart::from_image_url(media_type_from_url(url), url));
+		self
+	}
+
+	/// Add an image from a URL with a detail level (e.g., "low", "high", "auto").
+	pub fn image_url_with_detail(mut self, url: &str, detail: &str) -> Self {
+		self.parts
+			.push(ContentPart::from_image_url(media_type_from_url(url), url).with_detail(detail));
+		self
+	}
+
+	/// Add an inline image, base64 encoded.
+	///
+	/// Note: The media type is detected from the magic bytes (JPEG, PNG, GIF, WebP),
+	///       and the given `media_type` is used only if it cannot be detected.
+	pub fn image_bytes(mut self, bytes: &[u8], media_type: &str) -> Self {
+		let media_type = media_type_from_bytes(bytes).unwrap_or(media_type);
+		let content = general_purpose::STANDARD.encode(bytes);
+		self.parts.push(ContentPart::from_image_base64(media_type, content));
+		self
+	}
+
+	pub fn build(self) -> ChatMessage {
+		ChatMessage::user(MessageContent::from_parts(self.parts))
+	}
+
+	/// Build the message and append it to the originating request (or a new request if none).
+	pub fn end_message(mut self) -> ChatRequest {
+		let chat_req = self.chat_req.take().unwrap_or_default();
+		chat_req.append_message(self.build())
+	}
+}
+
+// region:    --- Support
+
+fn media_type_from_bytes(bytes: &[u8]) -> Option<&'static str> {
+	if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
+		Some("image/jpeg")
+	} else if bytes.starts_with(&[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A]) {
+		Some("image/png")
+	} else if bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a") {
+		Some("image/gif")
+	} else if bytes.len() >= 12 && bytes.starts_with(b"RIFF") && &bytes[8..12] == b"WEBP" {
+		Some("image/webp")
+	} else {
+		None
+	}
+}
+
+fn media_type_from_url(url: &str) -> &'static str {
+	let path = url.split(['?', '#']).next().unwrap_or(url).to_lowercase();
+	if path.ends_with(".png") {
+		"image/png"
+	} else if path.ends_with(".gif") {
+		"image/gif"
+	} else if path.ends_with(".webp") {
+		"image/webp"
+	} else {
+		"image/jpeg"
+	}
+}
+
+// endregion: --- Support
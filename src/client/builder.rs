@@ -1,11 +1,14 @@
+use crate::adapter::{AdapterConfig, AdapterKind, ResponseParser};
 use crate::chat::ChatOptions;
+use crate::middleware::{ContentFilter, Middleware};
 use crate::resolver::{
 	AuthResolver, IntoAuthResolverFn, IntoModelMapperFn, IntoServiceTargetResolverFn, ModelMapper,
 	ServiceTargetResolver,
 };
 use crate::webc::WebClient;
-use crate::{Client, ClientConfig};
+use crate::{Client, ClientConfig, CostBudget, Result};
 use std::sync::Arc;
+use std::time::Duration;
 
 /// The builder for the `Client` structure.
 ///
@@ -86,14 +89,89 @@ impl ClientBuilder {
 		client_config.model_mapper = Some(model_mapper);
 		self
 	}
+
+	/// Add a middleware to the ClientConfig of this ClientBuilder.
+	pub fn with_middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+		let client_config = self.config.take().unwrap_or_default();
+		self.config = Some(client_config.with_middleware(middleware));
+		self
+	}
+
+	/// Set the HTTP/HTTPS proxy url of the ClientConfig of this ClientBuilder (see `ClientConfig::with_proxy`).
+	pub fn with_proxy(mut self, url: &str) -> Result<Self> {
+		let client_config = self.config.take().unwrap_or_default();
+		self.config = Some(client_config.with_proxy(url)?);
+		Ok(self)
+	}
+
+	/// Set the request timeout of the ClientConfig of this ClientBuilder (see `ClientConfig::with_timeout`).
+	pub fn with_timeout(mut self, timeout: Duration) -> Self {
+		let client_config = self.config.take().unwrap_or_default();
+		self.config = Some(client_config.with_timeout(timeout));
+		self
+	}
+
+	/// Set the request compression of the ClientConfig of this ClientBuilder
+	/// (see `ClientConfig::with_request_compression`).
+	pub fn with_request_compression(mut self, enabled: bool) -> Self {
+		let client_config = self.config.take().unwrap_or_default();
+		self.config = Some(client_config.with_request_compression(enabled));
+		self
+	}
+
+	/// Set the strict model validation of the ClientConfig of this ClientBuilder
+	/// (see `ClientConfig::with_strict_model_validation`).
+	pub fn with_strict_model_validation(mut self, strict: bool) -> Self {
+		let client_config = self.config.take().unwrap_or_default();
+		self.config = Some(client_config.with_strict_model_validation(strict));
+		self
+	}
+
+	/// Set the cost budget of the ClientConfig of this ClientBuilder (see `ClientConfig::with_cost_budget`).
+	pub fn with_cost_budget(mut self, budget: CostBudget) -> Self {
+		let client_config = self.config.take().unwrap_or_default();
+		self.config = Some(client_config.with_cost_budget(budget));
+		self
+	}
+
+	/// Set the response parser of an adapter kind to the ClientConfig of this ClientBuilder
+	/// (see `ClientConfig::with_response_parser`).
+	pub fn with_response_parser(mut self, kind: AdapterKind, parser: Arc<dyn ResponseParser>) -> Self {
+		let client_config = self.config.take().unwrap_or_default();
+		self.config = Some(client_config.with_response_parser(kind, parser));
+		self
+	}
+
+	/// Set the configuration of an adapter kind to the ClientConfig of this ClientBuilder
+	/// (see `ClientConfig::with_adapter_config`).
+	pub fn with_adapter_config(mut self, adapter_config: AdapterConfig) -> Self {
+		let client_config = self.config.take().unwrap_or_default();
+		self.config = Some(client_config.with_adapter_config(adapter_config));
+		self
+	}
+
+	/// Add a content filter to the ClientConfig of this ClientBuilder.
+	pub fn with_content_filter(mut self, content_filter: impl ContentFilter + 'static) -> Self {
+		let client_config = self.config.take().unwrap_or_default();
+		self.config = Some(client_config.with_content_filter(content_filter));
+		self
+	}
 }
 
 impl ClientBuilder {
 	/// Build a new immutable GenAI client.
 	pub fn build(self) -> Client {
+		let config = self.config.unwrap_or_default();
+		// Note: The `with_reqwest` client takes precedence over the config one.
+		let web_client = self
+			.web_client
+			.or_else(|| config.build_reqwest_client().map(WebClient::from_reqwest_client))
+			.unwrap_or_default()
+			.with_request_compression(config.request_compression());
 		let inner = super::ClientInner {
-			web_client: self.web_client.unwrap_or_default(),
-			config: self.config.unwrap_or_default(),
+			web_client,
+			config,
+			state: Default::default(),
 		};
 		Client { inner: Arc::new(inner) }
 	}
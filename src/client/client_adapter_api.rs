@@ -0,0 +1,139 @@
+//! Crate support for the adapter-level web APIs that are not chat requests (e.g., OpenAI files and batches).
+//!
+//! Note: For now, these calls use the `Authorization: Bearer` header, as for the OpenAI-compatible APIs,
+//!       with the eventual `extra_headers` of the API (e.g., the `OpenAI-Beta` header of the Assistants API).
+
+use crate::adapter::AdapterKind;
+use crate::{Client, Error, Result};
+use reqwest::multipart::Form;
+use serde_json::Value;
+
+/// The resolved base url and headers for an adapter API call.
+pub(crate) struct AdapterApiTarget {
+	pub base_url: String,
+	pub headers: Vec<(String, String)>,
+}
+
+impl AdapterApiTarget {
+	/// Append the `extra_headers` to the auth headers.
+	fn with_extra_headers(mut self, extra_headers: &[(&str, &str)]) -> Self {
+		self.headers
+			.extend(extra_headers.iter().map(|(name, value)| (name.to_string(), value.to_string())));
+		self
+	}
+}
+
+/// Crate Adapter API Functions
+impl Client {
+	pub(crate) fn adapter_api_target(&self, adapter_kind: AdapterKind) -> Result<AdapterApiTarget> {
+		let target = self.config().resolve_adapter_service_target(adapter_kind)?;
+		let api_key = target.auth.single_key_value().map_err(|resolver_error| Error::Resolver {
+			model_iden: target.model.clone(),
+			resolver_error,
+		})?;
+
+		Ok(AdapterApiTarget {
+			base_url: target.endpoint.base_url().to_string(),
+			headers: vec![("Authorization".to_string(), format!("Bearer {api_key}"))],
+		})
+	}
+
+	/// GET `{base_url}{path}` and return the JSON body.
+	pub(crate) async fn adapter_api_get(
+		&self,
+		adapter_kind: AdapterKind,
+		path: &str,
+		extra_headers: &[(&str, &str)],
+	) -> Result<Value> {
+		let AdapterApiTarget { base_url, headers } =
+			self.adapter_api_target(adapter_kind)?.with_extra_headers(extra_headers);
+		let url = format!("{base_url}{path}");
+
+		let web_res = self
+			.web_client()
+			.do_get(&url, &headers)
+			.await
+			.map_err(|webc_error| Error::from_webc_adapter_call(adapter_kind, webc_error))?;
+
+		Ok(web_res.body)
+	}
+
+	/// GET `{base_url}{path}` and return the raw text body (e.g., file contents).
+	pub(crate) async fn adapter_api_get_text(
+		&self,
+		adapter_kind: AdapterKind,
+		path: &str,
+		extra_headers: &[(&str, &str)],
+	) -> Result<String> {
+		let AdapterApiTarget { base_url, headers } =
+			self.adapter_api_target(adapter_kind)?.with_extra_headers(extra_headers);
+		let url = format!("{base_url}{path}");
+
+		let text = self
+			.web_client()
+			.do_get_text(&url, &headers)
+			.await
+			.map_err(|webc_error| Error::from_webc_adapter_call(adapter_kind, webc_error))?;
+
+		Ok(text)
+	}
+
+	/// POST the JSON `payload` to `{base_url}{path}` and return the JSON body.
+	pub(crate) async fn adapter_api_post(
+		&self,
+		adapter_kind: AdapterKind,
+		path: &str,
+		payload: Value,
+		extra_headers: &[(&str, &str)],
+	) -> Result<Value> {
+		let AdapterApiTarget { base_url, headers } =
+			self.adapter_api_target(adapter_kind)?.with_extra_headers(extra_headers);
+		let url = format!("{base_url}{path}");
+
+		let web_res = self
+			.web_client()
+			.do_post(&url, &headers, payload)
+			.await
+			.map_err(|webc_error| Error::from_webc_adapter_call(adapter_kind, webc_error))?;
+
+		Ok(web_res.body)
+	}
+
+	/// POST the multipart `form` to `{base_url}{path}` and return the JSON body.
+	pub(crate) async fn adapter_api_post_multipart(
+		&self,
+		adapter_kind: AdapterKind,
+		path: &str,
+		form: Form,
+		extra_headers: &[(&str, &str)],
+	) -> Result<Value> {
+		let AdapterApiTarget { base_url, headers } =
+			self.adapter_api_target(adapter_kind)?.with_extra_headers(extra_headers);
+		let url = format!("{base_url}{path}");
+
+		let web_res = self
+			.web_client()
+			.do_post_multipart(&url, &headers, form)
+			.await
+			.map_err(|webc_error| Error::from_webc_adapter_call(adapter_kind, webc_error))?;
+
+		Ok(web_res.body)
+	}
+
+	/// DELETE `{base_url}{path}` (the response body is ignored).
+	pub(crate) async fn adapter_api_delete(
+		&self,
+		adapter_kind: AdapterKind,
+		path: &str,
+		extra_headers: &[(&str, &str)],
+	) -> Result<()> {
+		let AdapterApiTarget { base_url, headers } =
+			self.adapter_api_target(adapter_kind)?.with_extra_headers(extra_headers);
+		let url = format!("{base_url}{path}");
+
+		self.web_client()
+			.do_delete(&url, &headers, None)
+			.await
+			.map_err(|webc_error| Error::from_webc_adapter_call(adapter_kind, webc_error))
+	}
+}
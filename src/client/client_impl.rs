@@ -1,6 +1,28 @@
-use crate::adapter::{AdapterDispatcher, AdapterKind, ServiceType, WebRequestData};
-use crate::chat::{ChatOptions, ChatOptionsSet, ChatRequest, ChatResponse, ChatStreamResponse};
-use crate::{Client, Error, ModelIden, Result, ServiceTarget};
+use crate::adapter::{
+	to_parser_chat_stream, AdapterDispatcher, AdapterKind, ServiceType, TranscriptionRequestData, WebRequestData,
+};
+use crate::audio::{TranscriptionRequest, TranscriptionResponse};
+use crate::chat::{
+	ChatOptions, ChatOptionsSet, ChatRequest, ChatResponse, ChatStreamEvent, ChatStreamResponse, HarmBlockMode,
+	MessageContent, MetaUsage, StreamEnd, ToolResponse, ToolSchemaRegistry,
+};
+use crate::middleware::FilterAction;
+use crate::resolver::AuthData;
+use crate::{
+	Client, Error, ModelCapabilities, ModelIden, ModelNormalizer, ModelSelector, ModelValidation, ModelValidator,
+	Result, ServiceTarget, TaskRequirements,
+};
+use futures::future::BoxFuture;
+use futures::ready;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Instant;
+use tokio::io::AsyncWrite;
+use tracing::{field, Instrument};
+
+/// The request header with the genai generated id, which can be correlated with the provider request id.
+const CLIENT_REQUEST_ID_HEADER: &str = "x-client-request-id";
 
 /// Public AI Functions
 impl Client {
@@ -22,7 +44,13 @@ impl Client {
 
 	/// Return the default model for a model_name str.
 	/// This is used before
+	///
+	/// Note: The logical model names (e.g., `"sonnet-3-5"`) are resolved with `ModelNormalizer::resolve`.
 	pub fn default_model(&self, model_name: &str) -> Result<ModelIden> {
+		if let Some(model_iden) = ModelNormalizer::resolve(model_name) {
+			return Ok(model_iden);
+		}
+
 		// -- First get the default ModelInfo
 		let adapter_kind = AdapterKind::from_model(model_name)?;
 		let model_iden = ModelIden::new(adapter_kind, model_name);
@@ -42,6 +70,11 @@ impl Client {
 	}
 
 	/// Executes a chat.
+	///
+	/// Note: The call is instrumented with an `exec_chat` tracing span recording the model, usage, latency,
+	///       request id, and whether the response had tool calls.
+	/// Note: The client config content filters are run on the input messages and on the response.
+	/// Note: With a `CostBudget`, returns `Error::CostBudgetExceeded` once the budget is spent (see `Client::accumulated_cost`).
 	pub async fn exec_chat(
 		&self,
 		model: &str,
@@ -49,49 +82,110 @@ impl Client {
 		// options not implemented yet
 		options: Option<&ChatOptions>,
 	) -> Result<ChatResponse> {
-		let options_set = ChatOptionsSet::default()
-			.with_chat_options(options)
-			.with_client_options(self.config().chat_options());
-
-		let model = self.default_model(model)?;
-		let target = self.config().resolve_service_target(model)?;
-		let model = target.model.clone();
+		let span = tracing::info_span!(
+			"exec_chat",
+			model_name = model,
+			adapter_kind = field::Empty,
+			input_tokens = field::Empty,
+			output_tokens = field::Empty,
+			latency_ms = field::Empty,
+			request_id = field::Empty,
+			had_tool_calls = field::Empty,
+		);
 
-		let WebRequestData { headers, payload, url } =
-			AdapterDispatcher::to_web_request_data(target, ServiceType::Chat, chat_req, options_set)?;
+		self.exec_chat_traced(model, chat_req, options).instrument(span).await
+	}
 
-		let web_res =
-			self.web_client()
-				.do_post(&url, &headers, payload)
-				.await
-				.map_err(|webc_error| Error::WebModelCall {
-					model_iden: model.clone(),
-					webc_error,
-				})?;
+	/// Executes a chat, dispatching the tool calls of the responses to the `registry`, until a response without tool calls.
+	///
+	/// Each round appends the assistant tool calls and the tool responses to the `chat_req`, and executes it again.
+	/// Returns `Error::ToolCallLoopExceeded` if the LLM still calls tools after `max_rounds` rounds.
+	///
+	/// Note: The `chat_req.tools` are used as is (e.g., `.with_tools(registry.tool_schemas())`).
+	pub async fn exec_chat_with_tools(
+		&self,
+		model: &str,
+		mut chat_req: ChatRequest,
+		options: Option<&ChatOptions>,
+		registry: &ToolSchemaRegistry,
+		max_rounds: u32,
+	) -> Result<ChatResponse> {
+		let mut rounds = 0;
+		loop {
+			let chat_res = self.exec_chat(model, chat_req.clone(), options).await?;
+			if chat_res.tool_calls().is_none() {
+				return Ok(chat_res);
+			}
+			if rounds >= max_rounds {
+				return Err(Error::ToolCallLoopExceeded { rounds });
+			}
 
-		let chat_res = AdapterDispatcher::to_chat_response(model, web_res)?;
+			let tool_calls = chat_res.into_tool_calls().unwrap_or_default();
+			let tool_responses: Vec<ToolResponse> = tool_calls
+				.iter()
+				.map(|tool_call| ToolResponse::new(tool_call.call_id.clone(), registry.dispatch_versioned(tool_call)))
+				.collect();
+			chat_req = chat_req.append_message(tool_calls);
+			for tool_response in tool_responses {
+				chat_req = chat_req.append_message(tool_response);
+			}
+			rounds += 1;
+		}
+	}
 
-		Ok(chat_res)
+	/// Executes a chat with the best model for the requirements (see `ModelSelector::select`).
+	///
+	/// Note: The candidates are the `ModelCapabilities::known()` models with a resolved auth for this client
+	///       (e.g., the models of a provider without its API key environment variable are skipped).
+	pub async fn exec_chat_auto(&self, chat_req: ChatRequest, requirements: TaskRequirements) -> Result<ChatResponse> {
+		let candidates = ModelCapabilities::known()
+			.into_iter()
+			.filter(|caps| self.is_auth_resolved(ModelIden::new(caps.adapter_kind, caps.model_name)))
+			.collect();
+		let Some(model) = ModelSelector::from_candidates(candidates).select(&requirements) else {
+			return Err(Error::NoModelForRequirements {
+				requirements: Box::new(requirements),
+			});
+		};
+		self.exec_chat(model, chat_req, None).await
 	}
 
 	/// Executes a chat stream response.
+	///
+	/// Note: The cost of the stream is added at its end, when the usage is captured (see `Client::accumulated_cost`).
+	///       With a `CostBudget`, returns `Error::CostBudgetExceeded` once the budget is spent, and the usage
+	///       is always captured (`capture_usage` is forced).
 	pub async fn exec_chat_stream(
 		&self,
 		model: &str,
 		chat_req: ChatRequest, // options not implemented yet
 		options: Option<&ChatOptions>,
 	) -> Result<ChatStreamResponse> {
+		let budget_options = self.config().cost_budget().map(|_| with_forced_capture_usage(options));
+		let options = budget_options.as_ref().or(options);
+		let merged_options = chat_req.merged_options(options);
 		let options_set = ChatOptionsSet::default()
-			.with_chat_options(options)
+			.with_chat_options(merged_options.as_ref().or(options))
 			.with_client_options(self.config().chat_options());
 
 		let model = self.default_model(model)?;
 		let target = self.config().resolve_service_target(model)?;
+		self.validate_model_name(&target)?;
 		let model = target.model.clone();
+		if let Some(budget) = self.config().cost_budget() {
+			self.state().check_budget(budget)?;
+		}
 
-		let WebRequestData { url, headers, payload } =
+		let mut request_data =
 			AdapterDispatcher::to_web_request_data(target, ServiceType::ChatStream, chat_req, options_set.clone())?;
 
+		let client_request_id = new_client_request_id();
+		request_data
+			.headers
+			.push((CLIENT_REQUEST_ID_HEADER.to_string(), client_request_id.clone()));
+		self.run_middlewares(&model, &mut request_data)?;
+		let WebRequestData { url, headers, payload } = request_data;
+
 		let reqwest_builder = self
 			.web_client()
 			.new_req_builder(&url, &headers, payload)
@@ -100,8 +194,405 @@ impl Client {
 				webc_error,
 			})?;
 
-		let res = AdapterDispatcher::to_chat_stream(model, reqwest_builder, options_set)?;
+		let mut res = match self.config().response_parser(model.adapter_kind) {
+			Some(parser) => to_parser_chat_stream(model.clone(), reqwest_builder, options_set, parser.clone())?,
+			None => AdapterDispatcher::to_chat_stream(model.clone(), reqwest_builder, options_set)?,
+		};
+		res.client_request_id = Some(client_request_id);
+
+		// -- Add the stream cost at its end (with the captured usage)
+		let client = self.clone();
+		res.stream = res.stream.tap(move |event| {
+			if let ChatStreamEvent::End(StreamEnd {
+				captured_usage: Some(usage),
+				..
+			}) = event
+			{
+				client.state().add_usage_cost(&model, usage, client.config().cost_budget());
+			}
+		});
 
 		Ok(res)
 	}
+
+	/// Executes a chat stream and writes the text chunks to the file at `path` (created or truncated),
+	/// returning the final `MetaUsage` (as `ChatStream::pipe_to_writer`).
+	///
+	/// Note: The usage is always captured (`capture_usage` is forced), and the file is only created at the first
+	///       chunk (or at the end of the stream), so a failed request does not create or truncate it.
+	pub async fn exec_chat_stream_to_file(
+		&self,
+		model: &str,
+		chat_req: ChatRequest,
+		options: Option<&ChatOptions>,
+		path: &Path,
+	) -> Result<MetaUsage> {
+		let options = with_forced_capture_usage(options);
+		let chat_res = self.exec_chat_stream(model, chat_req, Some(&options)).await?;
+
+		chat_res.stream.pipe_to_writer(LazyFileWriter::new(path)).await
+	}
+
+	/// Executes a chat stream and prints it to the terminal with the `printer` (see `TerminalStreamPrinter`),
+	/// returning the final `MetaUsage`.
+	///
+	/// Note: The usage is always captured (`capture_usage` is forced), for the usage footer.
+	#[cfg(feature = "terminal")]
+	pub async fn exec_chat_stream_to_terminal(
+		&self,
+		model: &str,
+		chat_req: ChatRequest,
+		options: Option<&ChatOptions>,
+		printer: &crate::chat::printer::TerminalStreamPrinter,
+	) -> Result<MetaUsage> {
+		let options = with_forced_capture_usage(options);
+		let chat_res = self.exec_chat_stream(model, chat_req, Some(&options)).await?;
+		printer.print(chat_res).await
+	}
+
+	/// Transcribe an audio with the given adapter (OpenAI or Groq), for the `transcription_req.model`.
+	pub async fn transcribe_with_adapter(
+		&self,
+		adapter_kind: AdapterKind,
+		transcription_req: TranscriptionRequest,
+	) -> Result<TranscriptionResponse> {
+		let model = ModelIden::new(adapter_kind, transcription_req.model.as_str());
+		let target = self.config().resolve_service_target(model)?;
+		let model = target.model.clone();
+
+		let TranscriptionRequestData { url, headers, form } =
+			AdapterDispatcher::to_transcription_request_data(target, transcription_req)?;
+
+		let web_res = self
+			.web_client()
+			.do_post_multipart(&url, &headers, form)
+			.await
+			.map_err(|webc_error| Error::from_webc_model_call(model.clone(), webc_error))?;
+
+		AdapterDispatcher::from_transcription_response(model, web_res)
+	}
+}
+
+// region:    --- Support
+
+impl Client {
+	async fn exec_chat_traced(
+		&self,
+		model: &str,
+		chat_req: ChatRequest,
+		options: Option<&ChatOptions>,
+	) -> Result<ChatResponse> {
+		let merged_options = chat_req.merged_options(options);
+		let options_set = ChatOptionsSet::default()
+			.with_chat_options(merged_options.as_ref().or(options))
+			.with_client_options(self.config().chat_options());
+
+		let model = self.default_model(model)?;
+		let target = self.config().resolve_service_target(model)?;
+		self.validate_model_name(&target)?;
+		let model = target.model.clone();
+		if let Some(budget) = self.config().cost_budget() {
+			self.state().check_budget(budget)?;
+		}
+
+		let span = tracing::Span::current();
+		span.record("model_name", &*model.model_name);
+		span.record("adapter_kind", model.adapter_kind.as_str());
+
+		// -- Run the input content filters
+		let mut chat_req = chat_req;
+		if let Some(reason) = self.run_input_filters(&mut chat_req).await? {
+			return self.content_blocked(model, reason);
+		}
+
+		let harm_block_mode = options_set.harm_block_mode().unwrap_or_default();
+		let mut request_data =
+			AdapterDispatcher::to_web_request_data(target, ServiceType::Chat, chat_req, options_set)?;
+
+		let client_request_id = new_client_request_id();
+		request_data
+			.headers
+			.push((CLIENT_REQUEST_ID_HEADER.to_string(), client_request_id.clone()));
+		self.run_middlewares(&model, &mut request_data)?;
+
+		// Note: A cached response (e.g., `IdempotencyMiddleware`) is not a provider call, so it is not added to the cost.
+		let start = Instant::now();
+		let (mut chat_res, is_cached) = self
+			.cached_or_provider_response(&model, &request_data, client_request_id)
+			.await?;
+		let latency_ms = start.elapsed().as_millis() as u64;
+
+		if harm_block_mode == HarmBlockMode::Error {
+			if let Some(meta) = chat_res.gemini_meta().filter(|meta| meta.is_blocked()) {
+				return Err(Error::ResponseSafetyBlocked {
+					model_iden: model,
+					block_reason: meta.block_reason,
+					safety_ratings: meta.safety_ratings.into_boxed_slice(),
+				});
+			}
+		}
+
+		// -- Run the output content filters
+		// Note: Also run on the cached responses, which are cached before the filters.
+		if let Some(reason) = self.run_output_filters(&mut chat_res).await? {
+			return self.content_blocked(model, reason);
+		}
+
+		// -- Record the response data
+		span.record("latency_ms", latency_ms);
+		if let Some(input_tokens) = chat_res.usage.input_tokens {
+			span.record("input_tokens", input_tokens);
+		}
+		if let Some(output_tokens) = chat_res.usage.output_tokens {
+			span.record("output_tokens", output_tokens);
+		}
+		if let Some(request_id) = chat_res.request_id() {
+			span.record("request_id", request_id);
+		}
+		span.record("had_tool_calls", chat_res.tool_calls().is_some());
+		tracing::debug!(latency_ms, is_cached, "response_received");
+
+		Ok(chat_res)
+	}
+
+	/// The response of the first middleware with a cached response for the request data (with `true`),
+	/// or the provider response.
+	///
+	/// The middlewares before the one with the cached response (or all of them for a provider call)
+	/// are called with `after_response`, or `after_error` if the call failed.
+	async fn cached_or_provider_response(
+		&self,
+		model: &ModelIden,
+		request_data: &WebRequestData,
+		client_request_id: String,
+	) -> Result<(ChatResponse, bool)> {
+		let middlewares = self.config().middlewares();
+		let mut missed_count = 0;
+		let mut cached_res = None;
+		for middleware in middlewares {
+			match middleware.cached_response(model, request_data).await {
+				Ok(None) => missed_count += 1,
+				Ok(Some(chat_res)) => {
+					cached_res = Some(Ok(chat_res));
+					break;
+				}
+				Err(err) => {
+					cached_res = Some(Err(err));
+					break;
+				}
+			}
+		}
+
+		let is_cached = cached_res.is_some();
+		let res = match cached_res {
+			Some(res) => res,
+			None => self.exec_provider_call(model, request_data, client_request_id).await,
+		};
+
+		let missed_middlewares = &middlewares[..missed_count];
+		match res {
+			Ok(chat_res) => {
+				for middleware in missed_middlewares {
+					middleware.after_response(model, request_data, &chat_res)?;
+				}
+				Ok((chat_res, is_cached))
+			}
+			Err(err) => {
+				for middleware in missed_middlewares {
+					middleware.after_error(model, request_data, &err);
+				}
+				Err(err)
+			}
+		}
+	}
+
+	/// Send the request data to the provider, and parse the chat response (adding its usage to the cost).
+	async fn exec_provider_call(
+		&self,
+		model: &ModelIden,
+		request_data: &WebRequestData,
+		client_request_id: String,
+	) -> Result<ChatResponse> {
+		let WebRequestData { url, headers, payload } = request_data.clone();
+
+		// Note: The field values are evaluated only if the event is enabled.
+		tracing::debug!(payload_bytes = payload.to_string().len(), "request_sent");
+
+		let web_res = self
+			.web_client()
+			.do_post(&url, &headers, payload)
+			.await
+			.map_err(|webc_error| Error::from_webc_model_call(model.clone(), webc_error))?;
+
+		let mut chat_res = match self.config().response_parser(model.adapter_kind) {
+			Some(parser) => {
+				let mut chat_res = parser.parse_chat_response(model.clone(), web_res.body)?;
+				chat_res.request_id = web_res.request_id;
+				chat_res
+			}
+			None => {
+				let schema = self
+					.config()
+					.adapter_config(model.adapter_kind)
+					.and_then(|adapter_config| adapter_config.response_schema());
+				AdapterDispatcher::to_chat_response_with_schema(model.clone(), web_res, schema)?
+			}
+		};
+		chat_res.client_request_id = Some(client_request_id);
+		self.state().add_usage_cost(model, &chat_res.usage, self.config().cost_budget());
+
+		Ok(chat_res)
+	}
+
+	/// Run the input content filters on each message, returning the eventual block reason.
+	async fn run_input_filters(&self, chat_req: &mut ChatRequest) -> Result<Option<String>> {
+		for content_filter in self.config().content_filters() {
+			for msg in chat_req.messages.iter_mut() {
+				if let FilterAction::Block(reason) = content_filter.filter_input(msg).await? {
+					return Ok(Some(reason));
+				}
+			}
+		}
+		Ok(None)
+	}
+
+	/// Run the output content filters on the chat response, returning the eventual block reason.
+	async fn run_output_filters(&self, chat_res: &mut ChatResponse) -> Result<Option<String>> {
+		for content_filter in self.config().content_filters() {
+			if let FilterAction::Block(reason) = content_filter.filter_output(chat_res).await? {
+				return Ok(Some(reason));
+			}
+		}
+		Ok(None)
+	}
+
+	/// The `content_blocked_response` if set, otherwise `Error::ContentBlocked`.
+	fn content_blocked(&self, model_iden: ModelIden, reason: String) -> Result<ChatResponse> {
+		match self.config().content_blocked_response() {
+			Some(text) => Ok(ChatResponse {
+				content: Some(MessageContent::from_text(text)),
+				model_iden,
+				usage: MetaUsage::default(),
+				request_id: None,
+				client_request_id: None,
+				adapter_meta: None,
+				citations: Vec::new(),
+			}),
+			None => Err(Error::ContentBlocked { model_iden, reason }),
+		}
+	}
+
+	/// Warn once per model on the unknown model names (or return an error with the strict model validation).
+	///
+	/// Note: The model names of a custom endpoint (e.g., a proxy or a compatible provider) are not validated,
+	///       since the static model lists are the ones of the adapter default endpoint.
+	fn validate_model_name(&self, target: &ServiceTarget) -> Result<()> {
+		let model = &target.model;
+		if target.endpoint.base_url() != AdapterDispatcher::default_endpoint(model.adapter_kind).base_url() {
+			return Ok(());
+		}
+		let suggestion = match ModelValidator::validate_model_name(&model.model_name, model.adapter_kind) {
+			ModelValidation::Known => return Ok(()),
+			ModelValidation::Unknown => None,
+			ModelValidation::SimilarExists(suggestion) => Some(suggestion),
+		};
+		if self.config().strict_model_validation() {
+			return Err(Error::ModelNameUnknown {
+				model_iden: model.clone(),
+				suggestion,
+			});
+		}
+		if self.state().mark_model_warned(model) {
+			tracing::warn!(model = %model, ?suggestion, "unknown model name");
+		}
+		Ok(())
+	}
+
+	/// Run the client config middlewares (in order) on the request data.
+	fn run_middlewares(&self, model: &ModelIden, request_data: &mut WebRequestData) -> Result<()> {
+		for middleware in self.config().middlewares() {
+			middleware.before_request(model, request_data)?;
+		}
+		Ok(())
+	}
+}
+
+impl Client {
+	/// Returns true if the service target of the model resolves with a usable auth
+	/// (i.e., a `FromEnv` auth with its environment variable set and not empty).
+	fn is_auth_resolved(&self, model: ModelIden) -> bool {
+		match self.config().resolve_service_target(model) {
+			Ok(ServiceTarget {
+				auth: AuthData::FromEnv(env_name),
+				..
+			}) => std::env::var(env_name).is_ok_and(|value| !value.trim().is_empty()),
+			Ok(_) => true,
+			Err(_) => false,
+		}
+	}
+}
+
+fn new_client_request_id() -> String {
+	uuid::Uuid::new_v4().to_string()
+}
+
+/// An `AsyncWrite` to a file created (or truncated) at the first write or flush.
+struct LazyFileWriter {
+	path: PathBuf,
+	file: Option<tokio::fs::File>,
+	creating: Option<BoxFuture<'static, std::io::Result<tokio::fs::File>>>,
 }
+
+impl LazyFileWriter {
+	fn new(path: &Path) -> Self {
+		Self {
+			path: path.to_path_buf(),
+			file: None,
+			creating: None,
+		}
+	}
+
+	fn poll_file(&mut self, cx: &mut Context<'_>) -> Poll<std::io::Result<&mut tokio::fs::File>> {
+		let file = match self.file.take() {
+			Some(file) => file,
+			None => {
+				let path = self.path.clone();
+				let creating = self.creating.get_or_insert_with(|| {
+					Box::pin(async move {
+						tokio::fs::File::create(&path).await.map_err(|io_error| {
+							std::io::Error::new(io_error.kind(), format!("{}: {io_error}", path.to_string_lossy()))
+						})
+					})
+				});
+				let file = ready!(creating.as_mut().poll(cx))?;
+				self.creating = None;
+				file
+			}
+		};
+		Poll::Ready(Ok(self.file.insert(file)))
+	}
+}
+
+impl AsyncWrite for LazyFileWriter {
+	fn poll_write(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<std::io::Result<usize>> {
+		let file = ready!(self.get_mut().poll_file(cx))?;
+		Pin::new(file).poll_write(cx, buf)
+	}
+
+	fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+		let file = ready!(self.get_mut().poll_file(cx))?;
+		Pin::new(file).poll_flush(cx)
+	}
+
+	fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+		let file = ready!(self.get_mut().poll_file(cx))?;
+		Pin::new(file).poll_shutdown(cx)
+	}
+}
+
+/// The `options` with `capture_usage` forced (e.g., for the functions returning the final `MetaUsage`).
+fn with_forced_capture_usage(options: Option<&ChatOptions>) -> ChatOptions {
+	options.cloned().unwrap_or_default().with_capture_usage(true)
+}
+
+// endregion: --- Support
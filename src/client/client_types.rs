@@ -1,4 +1,5 @@
-use crate::client::ClientConfig;
+use crate::client::{ClientConfig, ClientState};
+use crate::middleware::Middleware;
 use crate::webc::WebClient;
 use crate::ClientBuilder;
 use std::sync::Arc;
@@ -28,6 +29,18 @@ impl Client {
 	}
 }
 
+impl Client {
+	/// Returns a new client with the same web client and config, plus the given middleware.
+	pub(crate) fn with_added_middleware(&self, middleware: impl Middleware + 'static) -> Client {
+		let inner = ClientInner {
+			web_client: self.inner.web_client.clone(),
+			config: self.inner.config.clone().with_middleware(middleware),
+			state: self.inner.state.clone(),
+		};
+		Client { inner: Arc::new(inner) }
+	}
+}
+
 // endregion: --- Client Constructors
 
 // region:    --- Client Getters
@@ -40,6 +53,16 @@ impl Client {
 	pub(crate) fn config(&self) -> &ClientConfig {
 		&self.inner.config
 	}
+
+	pub(super) fn state(&self) -> &ClientState {
+		&self.inner.state
+	}
+
+	/// The USD cost accumulated by the `exec_chat` and `exec_chat_stream` calls of this client (and its clones),
+	/// estimated with the `ModelCapabilities` prices (see `CostBudget`).
+	pub fn accumulated_cost(&self) -> f64 {
+		self.state().accumulated_cost_usd()
+	}
 }
 
 // endregion: --- Client Getters
@@ -51,6 +74,9 @@ pub(super) struct ClientInner {
 	pub(super) web_client: WebClient,
 
 	pub(super) config: ClientConfig,
+
+	/// Shared with the clients derived from this one (e.g., `with_added_middleware`).
+	pub(super) state: Arc<ClientState>,
 }
 
 // endregion: --- ClientInner
@@ -1,8 +1,12 @@
-use crate::adapter::AdapterDispatcher;
+use crate::adapter::{AdapterConfig, AdapterDispatcher, AdapterKind, ResponseParser};
 use crate::chat::ChatOptions;
-use crate::client::ServiceTarget;
+use crate::client::{CostBudget, ServiceTarget};
+use crate::middleware::{ContentFilter, Middleware};
 use crate::resolver::{AuthResolver, ModelMapper, ServiceTargetResolver};
 use crate::{Error, ModelIden, Result};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
 
 /// The Client configuration used in the configuration builder stage.
 #[derive(Debug, Default, Clone)]
@@ -11,6 +15,17 @@ pub struct ClientConfig {
 	pub(super) service_target_resolver: Option<ServiceTargetResolver>,
 	pub(super) model_mapper: Option<ModelMapper>,
 	pub(super) chat_options: Option<ChatOptions>,
+	pub(super) middlewares: Vec<Arc<dyn Middleware>>,
+	pub(super) content_filters: Vec<Arc<dyn ContentFilter>>,
+	pub(super) content_blocked_response: Option<String>,
+	pub(super) reqwest_client: Option<reqwest::Client>,
+	pub(super) proxy: Option<reqwest::Proxy>,
+	pub(super) timeout: Option<Duration>,
+	pub(super) request_compression: bool,
+	pub(super) strict_model_validation: bool,
+	pub(super) response_parsers: HashMap<AdapterKind, Arc<dyn ResponseParser>>,
+	pub(super) adapter_configs: HashMap<AdapterKind, AdapterConfig>,
+	pub(super) cost_budget: Option<CostBudget>,
 }
 
 /// Chainable setters related to the ClientConfig.
@@ -45,6 +60,94 @@ impl ClientConfig {
 		self.chat_options = Some(options);
 		self
 	}
+
+	/// Add a middleware for this client config (called in the order they are added).
+	pub fn with_middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+		self.middlewares.push(Arc::new(middleware));
+		self
+	}
+
+	/// Add a content filter for this client config (called in the order they are added).
+	pub fn with_content_filter(mut self, content_filter: impl ContentFilter + 'static) -> Self {
+		self.content_filters.push(Arc::new(content_filter));
+		self
+	}
+
+	/// Set the `reqwest::Client` used by the client (e.g., with a custom TLS or connection pool configuration).
+	///
+	/// Note: When set, the `with_proxy` and `with_timeout` shortcuts are ignored.
+	pub fn with_reqwest_client(mut self, reqwest_client: reqwest::Client) -> Self {
+		self.reqwest_client = Some(reqwest_client);
+		self
+	}
+
+	/// Set the HTTP/HTTPS proxy url for all of the requests (shortcut for a custom `reqwest::Client`).
+	///
+	/// Returns `Error::ProxyUrlInvalid` if the url cannot be used as a proxy.
+	pub fn with_proxy(mut self, url: &str) -> Result<Self> {
+		let proxy = reqwest::Proxy::all(url).map_err(|err| Error::ProxyUrlInvalid {
+			url: url.to_string(),
+			cause: err.to_string(),
+		})?;
+		self.proxy = Some(proxy);
+		Ok(self)
+	}
+
+	/// Set the total timeout of each request (shortcut for a custom `reqwest::Client`).
+	pub fn with_timeout(mut self, timeout: Duration) -> Self {
+		self.timeout = Some(timeout);
+		self
+	}
+
+	/// Gzip compress the request bodies (with the `content-encoding: gzip` header), which can reduce the latency
+	/// of the large requests (e.g., many few-shot examples or tool schemas).
+	///
+	/// Note: OpenAI, Anthropic, and Gemini accept the compressed requests, but some servers might not
+	///       (e.g., a local or proxied OpenAI compatible server). See the `RequestCompressor` middleware
+	///       to compress only the large requests.
+	pub fn with_request_compression(mut self, enabled: bool) -> Self {
+		self.request_compression = enabled;
+		self
+	}
+
+	/// Return an `Error::ModelNameUnknown` for the model names not in the static model lists
+	/// (by default, `exec_chat` and `exec_chat_stream` only log a warning, since the lists are not exhaustive).
+	///
+	/// Note: The Ollama model names, and the model names of a custom endpoint, are not validated.
+	pub fn with_strict_model_validation(mut self, strict: bool) -> Self {
+		self.strict_model_validation = strict;
+		self
+	}
+
+	/// Set the response parser of an adapter kind, used by `exec_chat` and `exec_chat_stream` instead of the
+	/// adapter response parsing (e.g., for an OpenAI compatible proxy with a non-standard response).
+	///
+	/// Note: See `TextResponseParser` to wrap the adapter parsing (text-only for the stream events).
+	pub fn with_response_parser(mut self, kind: AdapterKind, parser: Arc<dyn ResponseParser>) -> Self {
+		self.response_parsers.insert(kind, parser);
+		self
+	}
+
+	/// Set the configuration of the `adapter_config.adapter_kind()` (e.g., an OpenAI compatible response schema),
+	/// replacing the eventual previous one of this adapter kind.
+	pub fn with_adapter_config(mut self, adapter_config: AdapterConfig) -> Self {
+		self.adapter_configs.insert(adapter_config.adapter_kind(), adapter_config);
+		self
+	}
+
+	/// Set the cost budget of the `Client::exec_chat` and `Client::exec_chat_stream` calls.
+	/// Once `max_total_usd` is reached, the next calls return `Error::CostBudgetExceeded`.
+	pub fn with_cost_budget(mut self, budget: CostBudget) -> Self {
+		self.cost_budget = Some(budget);
+		self
+	}
+
+	/// Set the response text returned by `exec_chat` when a content filter blocks the content
+	/// (by default, `exec_chat` returns an `Error::ContentBlocked`).
+	pub fn with_content_blocked_response(mut self, text: impl Into<String>) -> Self {
+		self.content_blocked_response = Some(text.into());
+		self
+	}
 }
 
 /// Getters for the fields of ClientConfig (as references).
@@ -67,6 +170,76 @@ impl ClientConfig {
 	pub fn chat_options(&self) -> Option<&ChatOptions> {
 		self.chat_options.as_ref()
 	}
+
+	pub fn middlewares(&self) -> &[Arc<dyn Middleware>] {
+		&self.middlewares
+	}
+
+	pub fn content_filters(&self) -> &[Arc<dyn ContentFilter>] {
+		&self.content_filters
+	}
+
+	pub fn content_blocked_response(&self) -> Option<&str> {
+		self.content_blocked_response.as_deref()
+	}
+
+	pub fn reqwest_client(&self) -> Option<&reqwest::Client> {
+		self.reqwest_client.as_ref()
+	}
+
+	pub fn timeout(&self) -> Option<Duration> {
+		self.timeout
+	}
+
+	pub fn request_compression(&self) -> bool {
+		self.request_compression
+	}
+
+	pub fn strict_model_validation(&self) -> bool {
+		self.strict_model_validation
+	}
+
+	/// Get the custom response parser of the adapter kind, if set.
+	pub fn response_parser(&self, kind: AdapterKind) -> Option<&Arc<dyn ResponseParser>> {
+		self.response_parsers.get(&kind)
+	}
+
+	/// Get the configuration of the adapter kind, if set.
+	pub fn adapter_config(&self, kind: AdapterKind) -> Option<&AdapterConfig> {
+		self.adapter_configs.get(&kind)
+	}
+
+	pub fn cost_budget(&self) -> Option<&CostBudget> {
+		self.cost_budget.as_ref()
+	}
+}
+
+/// Crate Functions
+impl ClientConfig {
+	/// Build the `reqwest::Client` for this config, if it has a custom one or the proxy/timeout shortcuts.
+	pub(crate) fn build_reqwest_client(&self) -> Option<reqwest::Client> {
+		if let Some(reqwest_client) = self.reqwest_client.clone() {
+			return Some(reqwest_client);
+		}
+		if self.proxy.is_none() && self.timeout.is_none() {
+			return None;
+		}
+
+		let mut builder = reqwest::Client::builder();
+		if let Some(proxy) = self.proxy.clone() {
+			builder = builder.proxy(proxy);
+		}
+		if let Some(timeout) = self.timeout {
+			builder = builder.timeout(timeout);
+		}
+		match builder.build() {
+			Ok(reqwest_client) => Some(reqwest_client),
+			Err(err) => {
+				tracing::warn!(%err, "cannot build the configured reqwest client, using the default one");
+				None
+			}
+		}
+	}
 }
 
 /// Resolvers
@@ -82,6 +255,18 @@ impl ClientConfig {
 			resolver_error,
 		})?;
 
+		self.resolve_target_for_model(model)
+	}
+
+	/// Resolve the ServiceTarget for the adapter-level APIs that are not bound to a model (e.g., files, batches).
+	///
+	/// Note: The ModelIden has an empty model name, so the `model_mapper` is not applied,
+	///       but the `auth_resolver` and `service_target_resolver` are.
+	pub(crate) fn resolve_adapter_service_target(&self, adapter_kind: AdapterKind) -> Result<ServiceTarget> {
+		self.resolve_target_for_model(ModelIden::new(adapter_kind, ""))
+	}
+
+	fn resolve_target_for_model(&self, model: ModelIden) -> Result<ServiceTarget> {
 		// -- Get the auth
 		let auth = self
 			.auth_resolver()
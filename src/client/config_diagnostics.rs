@@ -0,0 +1,284 @@
+//! The configuration diagnostics, to report the common misconfigurations (e.g., a missing API key environment variable)
+//! before the first request, with a fix hint.
+
+use crate::adapter::{AdapterDispatcher, AdapterKind};
+use crate::resolver::AuthData;
+use crate::{Client, ModelIden, ServiceTarget};
+use derive_more::Display;
+use std::time::Duration;
+use tokio::net::TcpStream;
+
+/// The connect timeout of the local servers reachability check (e.g., Ollama).
+const LOCAL_CONNECT_TIMEOUT: Duration = Duration::from_millis(300);
+
+// region:    --- Types
+
+#[derive(Debug, Clone, Copy, Display, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+	Info,
+	Warning,
+	/// The requests of this adapter will fail.
+	Error,
+}
+
+/// A configuration issue of an adapter, reported by `check_adapter_config`, `check_adapter_reachability`,
+/// or `Client::diagnose`.
+#[derive(Debug, Clone)]
+pub struct ConfigWarning {
+	pub adapter_kind: AdapterKind,
+	pub severity: Severity,
+	pub message: String,
+	pub fix_hint: String,
+}
+
+impl ConfigWarning {
+	fn new(
+		adapter_kind: AdapterKind,
+		severity: Severity,
+		message: impl Into<String>,
+		fix_hint: impl Into<String>,
+	) -> Self {
+		Self {
+			adapter_kind,
+			severity,
+			message: message.into(),
+			fix_hint: fix_hint.into(),
+		}
+	}
+}
+
+impl core::fmt::Display for ConfigWarning {
+	fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(
+			fmt,
+			"[{}] {}: {} {}",
+			self.severity, self.adapter_kind, self.message, self.fix_hint
+		)
+	}
+}
+
+// endregion: --- Types
+
+// region:    --- Checks
+
+/// Check the default configuration of an adapter kind (i.e., the default auth and endpoint):
+/// - The API key environment variable (set, empty, quoted, or another provider key).
+/// - The base URL (valid, ending with `/`, and with `/v1/` for the OpenAI compatible adapters).
+///
+/// Note: No network calls (see `check_adapter_reachability` for the local servers).
+/// Note: Use `Client::diagnose` to check the client resolved configuration (e.g., with a custom `AuthResolver`).
+pub fn check_adapter_config(kind: AdapterKind) -> Vec<ConfigWarning> {
+	check_service_target(&default_service_target(kind))
+}
+
+/// Check that the local server of an adapter kind default endpoint (e.g., Ollama) is reachable,
+/// with a non-blocking connect of at most 300ms.
+///
+/// Note: The endpoints which are not local (i.e., the providers) are not checked, so return no warnings.
+pub async fn check_adapter_reachability(kind: AdapterKind) -> Vec<ConfigWarning> {
+	check_local_reachability(&default_service_target(kind))
+		.await
+		.into_iter()
+		.collect()
+}
+
+impl Client {
+	/// Run the `check_adapter_config` and `check_adapter_reachability` checks for all of the adapter kinds,
+	/// with the client resolved auth and endpoint (i.e., after the `AuthResolver` and `ServiceTargetResolver`).
+	///
+	/// Note: The warnings are for all of the adapter kinds, so the ones of the unused adapters can be ignored.
+	pub async fn diagnose(&self) -> Vec<ConfigWarning> {
+		let mut warnings = Vec::new();
+		for &kind in AdapterKind::ALL {
+			match self.config().resolve_adapter_service_target(kind) {
+				Ok(target) => {
+					warnings.extend(check_service_target(&target));
+					warnings.extend(check_local_reachability(&target).await);
+				}
+				Err(err) => {
+					// Note: The resolver error text is the source, not in the Display.
+					let cause = std::error::Error::source(&err)
+						.map(|source| format!(" ({source})"))
+						.unwrap_or_default();
+					warnings.push(ConfigWarning::new(
+						kind,
+						Severity::Error,
+						format!("The service target cannot be resolved: {err}{cause}."),
+						"Check the client `AuthResolver` and `ServiceTargetResolver`.",
+					))
+				}
+			}
+		}
+		warnings
+	}
+}
+
+fn check_service_target(target: &ServiceTarget) -> Vec<ConfigWarning> {
+	let kind = target.model.adapter_kind;
+	let mut warnings = Vec::new();
+	check_auth(kind, &target.auth, &mut warnings);
+	check_base_url(kind, target.endpoint.base_url(), &mut warnings);
+	warnings
+}
+
+fn check_auth(kind: AdapterKind, auth: &AuthData, warnings: &mut Vec<ConfigWarning>) {
+	match auth {
+		AuthData::FromEnv(env_name) => match std::env::var(env_name) {
+			Ok(value) => check_key_value(kind, env_name, &value, warnings),
+			Err(_) => warnings.push(ConfigWarning::new(
+				kind,
+				Severity::Error,
+				format!("{env_name} is not set."),
+				format!("Set it with `export {env_name}={}...`", key_prefix(kind)),
+			)),
+		},
+		AuthData::Key(value) => check_key_value(kind, "The API key", value, warnings),
+		AuthData::MultiKeys(_) => (),
+	}
+}
+
+fn check_key_value(kind: AdapterKind, name: &str, value: &str, warnings: &mut Vec<ConfigWarning>) {
+	let trimmed = value.trim();
+	if trimmed.is_empty() {
+		warnings.push(ConfigWarning::new(
+			kind,
+			Severity::Error,
+			format!("{name} is empty."),
+			"Set it to the API key of the provider console.",
+		));
+		return;
+	}
+	if trimmed.len() != value.len() {
+		warnings.push(ConfigWarning::new(
+			kind,
+			Severity::Warning,
+			format!("{name} has leading or trailing whitespace."),
+			"Remove the whitespace (e.g., a trailing newline from a file).",
+		));
+	}
+	if trimmed.starts_with(['"', '\'']) || trimmed.ends_with(['"', '\'']) {
+		warnings.push(ConfigWarning::new(
+			kind,
+			Severity::Warning,
+			format!("{name} is quoted."),
+			"Remove the quotes around the key value.",
+		));
+	}
+	if kind != AdapterKind::Anthropic && trimmed.starts_with("sk-ant-") {
+		warnings.push(ConfigWarning::new(
+			kind,
+			Severity::Warning,
+			format!("{name} looks like an Anthropic API key."),
+			format!("Use a {kind} API key."),
+		));
+	}
+}
+
+fn check_base_url(kind: AdapterKind, base_url: &str, warnings: &mut Vec<ConfigWarning>) {
+	let default_url = AdapterDispatcher::default_endpoint(kind);
+	let default_url = default_url.base_url();
+
+	let is_http_url = reqwest::Url::parse(base_url).is_ok_and(|url| matches!(url.scheme(), "http" | "https"));
+	if !is_http_url {
+		warnings.push(ConfigWarning::new(
+			kind,
+			Severity::Error,
+			format!("Base URL '{base_url}' is not a valid http(s) URL."),
+			format!("Use a full URL (e.g., `{default_url}`)."),
+		));
+		return;
+	}
+
+	// The service paths are appended to the base URL (e.g., `{base_url}chat/completions`)
+	if !base_url.ends_with('/') {
+		warnings.push(ConfigWarning::new(
+			kind,
+			Severity::Error,
+			format!("Base URL '{base_url}' does not end with '/'."),
+			format!("Add the trailing slash (e.g., `{base_url}/`)."),
+		));
+	} else if is_openai_compatible(kind) && !base_url.ends_with("/v1/") {
+		warnings.push(ConfigWarning::new(
+			kind,
+			Severity::Warning,
+			format!("Base URL '{base_url}' does not end with /v1/ for OpenAI-compatible adapters."),
+			format!("Add the /v1/ suffix (e.g., `{default_url}`)."),
+		));
+	}
+}
+
+/// The unreachable warning of a local server (e.g., Ollama), if the base URL is local and cannot be connected.
+///
+/// Note: Only the local servers are checked, to avoid any network calls to the providers.
+async fn check_local_reachability(target: &ServiceTarget) -> Option<ConfigWarning> {
+	let kind = target.model.adapter_kind;
+	let base_url = target.endpoint.base_url();
+	// Note: An invalid URL is reported by `check_base_url`.
+	let url = reqwest::Url::parse(base_url).ok()?;
+	let is_local = matches!(url.host_str(), Some("localhost" | "127.0.0.1" | "[::1]"));
+	if !is_local || is_reachable(&url).await {
+		return None;
+	}
+
+	let fix_hint = match kind {
+		AdapterKind::Ollama => "Start it with `ollama serve`.",
+		_ => "Start the local server, or fix the base URL.",
+	};
+	Some(ConfigWarning::new(
+		kind,
+		Severity::Error,
+		format!("The server is not reachable at '{base_url}'."),
+		fix_hint,
+	))
+}
+
+// endregion: --- Checks
+
+// region:    --- Support
+
+/// The service target of the adapter kind default auth and endpoint.
+fn default_service_target(kind: AdapterKind) -> ServiceTarget {
+	ServiceTarget {
+		model: ModelIden::new(kind, ""),
+		auth: AdapterDispatcher::default_auth(kind),
+		endpoint: AdapterDispatcher::default_endpoint(kind),
+	}
+}
+
+fn is_openai_compatible(kind: AdapterKind) -> bool {
+	matches!(
+		kind,
+		AdapterKind::OpenAI | AdapterKind::Ollama | AdapterKind::Groq | AdapterKind::Xai | AdapterKind::DeepSeek
+	)
+}
+
+fn key_prefix(kind: AdapterKind) -> &'static str {
+	match kind {
+		AdapterKind::OpenAI | AdapterKind::DeepSeek => "sk-",
+		AdapterKind::Anthropic => "sk-ant-",
+		AdapterKind::Groq => "gsk_",
+		AdapterKind::Xai => "xai-",
+		AdapterKind::Ollama | AdapterKind::Cohere | AdapterKind::Gemini => "",
+	}
+}
+
+/// Returns true if a TCP connection to the url host can be opened within the `LOCAL_CONNECT_TIMEOUT`.
+async fn is_reachable(url: &reqwest::Url) -> bool {
+	let (Some(host), Some(port)) = (url.host_str(), url.port_or_known_default()) else {
+		return false;
+	};
+	// Note: The IPv6 host of the url is in brackets (e.g., `[::1]`).
+	let host = host.trim_start_matches('[').trim_end_matches(']');
+	let connect = async {
+		let addrs = tokio::net::lookup_host((host, port)).await.ok()?;
+		for addr in addrs {
+			if TcpStream::connect(addr).await.is_ok() {
+				return Some(());
+			}
+		}
+		None
+	};
+	matches!(tokio::time::timeout(LOCAL_CONNECT_TIMEOUT, connect).await, Ok(Some(())))
+}
+
+// endregion: --- Support
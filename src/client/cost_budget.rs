@@ -0,0 +1,96 @@
+use crate::chat::MetaUsage;
+use crate::client::ModelCapabilities;
+use crate::eval::CostEstimator;
+use crate::{Error, ModelIden, Result};
+use std::collections::HashSet;
+use std::sync::Mutex;
+
+/// The USD cost limits of the `Client::exec_chat` and `Client::exec_chat_stream` calls
+/// (see `ClientConfig::with_cost_budget`).
+///
+/// Note: The cost is estimated with the `ModelCapabilities` prices (`0.0` for the unknown models).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CostBudget {
+	/// Once reached, the next calls return `Error::CostBudgetExceeded` (without calling the API).
+	pub max_total_usd: f64,
+	/// A warning is logged when a single call costs more.
+	pub max_per_request_usd: f64,
+	/// A warning is logged when the accumulated cost reaches it.
+	pub alert_at_usd: f64,
+}
+
+impl CostBudget {
+	pub fn new(max_total_usd: f64, max_per_request_usd: f64, alert_at_usd: f64) -> Self {
+		Self {
+			max_total_usd,
+			max_per_request_usd,
+			alert_at_usd,
+		}
+	}
+}
+
+// region:    --- ClientState
+
+/// The mutable state of a Client, shared by its clones.
+#[derive(Debug, Default)]
+pub(super) struct ClientState {
+	accumulated_cost_usd: Mutex<f64>,
+	/// The unknown model names already warned about (see `ClientConfig::with_strict_model_validation`).
+	warned_model_names: Mutex<HashSet<String>>,
+}
+
+impl ClientState {
+	/// Returns true the first time it is called for the model (i.e., to warn only once per model).
+	pub(super) fn mark_model_warned(&self, model: &ModelIden) -> bool {
+		let mut warned = self.warned_model_names.lock().unwrap_or_else(|err| err.into_inner());
+		warned.insert(model.to_string())
+	}
+
+	pub(super) fn accumulated_cost_usd(&self) -> f64 {
+		*self.accumulated_cost_usd.lock().unwrap_or_else(|err| err.into_inner())
+	}
+
+	/// Returns `Error::CostBudgetExceeded` if the accumulated cost reached the budget `max_total_usd`.
+	pub(super) fn check_budget(&self, budget: &CostBudget) -> Result<()> {
+		let spent_usd = self.accumulated_cost_usd();
+		if spent_usd >= budget.max_total_usd {
+			return Err(Error::CostBudgetExceeded {
+				spent_usd,
+				limit_usd: budget.max_total_usd,
+			});
+		}
+		Ok(())
+	}
+
+	/// Add the estimated cost of the usage, warning on the budget per request and alert limits.
+	pub(super) fn add_usage_cost(&self, model: &ModelIden, usage: &MetaUsage, budget: Option<&CostBudget>) {
+		let Some(caps) = ModelCapabilities::for_model(&model.model_name) else {
+			return;
+		};
+		let cost_usd = CostEstimator::new(caps.price).estimate_usd(usage);
+
+		let (before_usd, spent_usd) = {
+			let mut accumulated = self.accumulated_cost_usd.lock().unwrap_or_else(|err| err.into_inner());
+			let before_usd = *accumulated;
+			*accumulated += cost_usd;
+			(before_usd, *accumulated)
+		};
+
+		let Some(budget) = budget else {
+			return;
+		};
+		if cost_usd > budget.max_per_request_usd {
+			tracing::warn!(model = %model, cost_usd, limit_usd = budget.max_per_request_usd, "request cost over budget");
+		}
+		// Note: Only once, when the accumulated cost crosses the alert limit.
+		if before_usd < budget.alert_at_usd && spent_usd >= budget.alert_at_usd {
+			tracing::warn!(
+				spent_usd,
+				alert_at_usd = budget.alert_at_usd,
+				"cost budget alert reached"
+			);
+		}
+	}
+}
+
+// endregion: --- ClientState
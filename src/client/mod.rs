@@ -1,14 +1,25 @@
 // region:    --- Modules
 
 mod builder;
+mod client_adapter_api;
 mod client_impl;
 mod client_types;
 mod config;
+mod config_diagnostics;
+mod cost_budget;
+mod model_selector;
+mod reproducible_session;
 mod service_target;
 
 pub use builder::*;
+pub(crate) use client_adapter_api::AdapterApiTarget;
 pub use client_types::*;
 pub use config::*;
+pub use config_diagnostics::*;
+use cost_budget::ClientState;
+pub use cost_budget::CostBudget;
+pub use model_selector::*;
+pub use reproducible_session::*;
 pub use service_target::*;
 
 // endregion: --- Modules
@@ -0,0 +1,179 @@
+//! Model selection from the task requirements (vision, tools, context size, cost, and latency),
+//! based on the static `ModelCapabilities` of the known models.
+
+use crate::adapter::AdapterKind;
+use crate::eval::{CostEstimator, TokenPrice};
+
+// region:    --- ModelCapabilities
+
+/// The capabilities and price of a model, as supported by genai (e.g., `supports_tools` is false
+/// if the adapter does not send the tools yet).
+///
+/// Note: These are static values (prices in USD as of late 2024), see `ModelCapabilities::known()`.
+#[derive(Debug, Clone)]
+pub struct ModelCapabilities {
+	pub model_name: &'static str,
+	pub adapter_kind: AdapterKind,
+	pub supports_vision: bool,
+	pub supports_tools: bool,
+	pub context_window_tokens: u32,
+	pub price: TokenPrice,
+	/// True for the low latency models (e.g., `gpt-4o-mini`, the Groq models).
+	pub is_fast: bool,
+}
+
+impl ModelCapabilities {
+	/// The capabilities of the main known models.
+	pub fn known() -> Vec<ModelCapabilities> {
+		KNOWN_MODELS
+			.iter()
+			.map(|row| ModelCapabilities {
+				model_name: row.0,
+				adapter_kind: row.1,
+				supports_vision: row.2,
+				supports_tools: row.3,
+				context_window_tokens: row.4,
+				price: TokenPrice::new(row.5, row.6),
+				is_fast: row.7,
+			})
+			.collect()
+	}
+
+	/// Returns the capabilities of a known model.
+	pub fn for_model(model_name: &str) -> Option<ModelCapabilities> {
+		Self::known().into_iter().find(|caps| caps.model_name == model_name)
+	}
+}
+
+/// (model_name, adapter_kind, vision, tools, context, input price, output price, fast)
+type KnownModelRow = (&'static str, AdapterKind, bool, bool, u32, f64, f64, bool);
+
+#[rustfmt::skip]
+const KNOWN_MODELS: &[KnownModelRow] = &[
+	("gpt-4o", AdapterKind::OpenAI, true, true, 128_000, 2.5, 10.0, false),
+	("gpt-4o-mini", AdapterKind::OpenAI, true, true, 128_000, 0.15, 0.6, true),
+	("o1-preview", AdapterKind::OpenAI, false, false, 128_000, 15.0, 60.0, false),
+	("o1-mini", AdapterKind::OpenAI, false, false, 128_000, 3.0, 12.0, false),
+	("claude-3-5-sonnet-20241022", AdapterKind::Anthropic, true, true, 200_000, 3.0, 15.0, false),
+	("claude-3-5-haiku-20241022", AdapterKind::Anthropic, false, true, 200_000, 0.8, 4.0, true),
+	("claude-3-opus-20240229", AdapterKind::Anthropic, true, true, 200_000, 15.0, 75.0, false),
+	("claude-3-haiku-20240307", AdapterKind::Anthropic, true, true, 200_000, 0.25, 1.25, true),
+	("gemini-1.5-pro", AdapterKind::Gemini, true, false, 2_000_000, 1.25, 5.0, false),
+	("gemini-1.5-flash", AdapterKind::Gemini, true, false, 1_000_000, 0.075, 0.3, true),
+	("gemini-1.5-flash-8b", AdapterKind::Gemini, true, false, 1_000_000, 0.0375, 0.15, true),
+	("command-r-plus", AdapterKind::Cohere, false, false, 128_000, 2.5, 10.0, false),
+	("command-r", AdapterKind::Cohere, false, false, 128_000, 0.15, 0.6, false),
+	("llama-3.1-70b-versatile", AdapterKind::Groq, false, true, 128_000, 0.59, 0.79, true),
+	("llama-3.1-8b-instant", AdapterKind::Groq, false, true, 128_000, 0.05, 0.08, true),
+	("deepseek-chat", AdapterKind::DeepSeek, false, true, 64_000, 0.14, 0.28, false),
+	("grok-beta", AdapterKind::Xai, false, true, 131_072, 5.0, 15.0, false),
+];
+
+// endregion: --- ModelCapabilities
+
+// region:    --- TaskRequirements
+
+/// How much the latency matters for the task.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LatencyPriority {
+	/// The cheapest model, regardless of its latency.
+	Low,
+	/// The cheapest model, with the fast models first at an equal cost.
+	#[default]
+	Normal,
+	/// The fast models first, then the cheapest.
+	High,
+}
+
+/// The requirements of a task for `ModelSelector::select`.
+#[derive(Debug, Clone)]
+pub struct TaskRequirements {
+	pub needs_vision: bool,
+	pub needs_tools: bool,
+	pub min_context_tokens: u32,
+	/// The max blended cost (average of the input and output prices) per 1k tokens, in USD.
+	pub max_cost_per_1k_tokens: f64,
+	/// The providers to select first (in order), when they have a matching model.
+	pub preferred_providers: Vec<AdapterKind>,
+	pub latency_priority: LatencyPriority,
+}
+
+impl Default for TaskRequirements {
+	fn default() -> Self {
+		Self {
+			needs_vision: false,
+			needs_tools: false,
+			min_context_tokens: 0,
+			max_cost_per_1k_tokens: f64::INFINITY,
+			preferred_providers: Vec::new(),
+			latency_priority: LatencyPriority::default(),
+		}
+	}
+}
+
+// endregion: --- TaskRequirements
+
+// region:    --- ModelSelector
+
+/// Select the best matching model for some `TaskRequirements`.
+#[derive(Debug, Clone)]
+pub struct ModelSelector {
+	candidates: Vec<ModelCapabilities>,
+}
+
+impl Default for ModelSelector {
+	fn default() -> Self {
+		Self {
+			candidates: ModelCapabilities::known(),
+		}
+	}
+}
+
+/// Constructors
+impl ModelSelector {
+	/// A selector with the given candidate models (instead of `ModelCapabilities::known()`).
+	pub fn from_candidates(candidates: Vec<ModelCapabilities>) -> Self {
+		Self { candidates }
+	}
+}
+
+impl ModelSelector {
+	/// Returns the name of the best model matching the requirements, if any.
+	///
+	/// The matching models are ranked by preferred provider, then by cost and latency (per the `latency_priority`).
+	pub fn select(&self, requirements: &TaskRequirements) -> Option<&'static str> {
+		let mut matches: Vec<(&ModelCapabilities, f64)> = self
+			.candidates
+			.iter()
+			.filter(|caps| !requirements.needs_vision || caps.supports_vision)
+			.filter(|caps| !requirements.needs_tools || caps.supports_tools)
+			.filter(|caps| caps.context_window_tokens >= requirements.min_context_tokens)
+			.map(|caps| (caps, CostEstimator::new(caps.price).cost_per_1k_tokens()))
+			.filter(|(_, cost)| *cost <= requirements.max_cost_per_1k_tokens)
+			.collect();
+
+		let preferred_rank = |caps: &ModelCapabilities| {
+			requirements
+				.preferred_providers
+				.iter()
+				.position(|kind| *kind == caps.adapter_kind)
+				.unwrap_or(usize::MAX)
+		};
+
+		matches.sort_by(|(a, a_cost), (b, b_cost)| {
+			let by_preferred = preferred_rank(a).cmp(&preferred_rank(b));
+			let by_cost = a_cost.total_cmp(b_cost);
+			// Note: `false < true`, so comparing `b` to `a` puts the fast models first.
+			let by_fast = b.is_fast.cmp(&a.is_fast);
+			match requirements.latency_priority {
+				LatencyPriority::Low => by_preferred.then(by_cost),
+				LatencyPriority::Normal => by_preferred.then(by_cost).then(by_fast),
+				LatencyPriority::High => by_preferred.then(by_fast).then(by_cost),
+			}
+		});
+
+		matches.first().map(|(caps, _)| caps.model_name)
+	}
+}
+
+// endregion: --- ModelSelector
@@ -0,0 +1,90 @@
+use crate::chat::{ChatOptions, ChatRequest, ChatResponse};
+use crate::{Client, Result};
+use serde::{Deserialize, Serialize};
+
+// region:    --- ReproducibilityRecord
+
+/// The reproducibility metadata of a `ReproducibleChatSession`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReproducibilityRecord {
+	pub seed: u64,
+	/// The distinct `system_fingerprint` values of the responses, in order of appearance.
+	pub system_fingerprints: Vec<String>,
+	/// The number of successful calls.
+	pub call_count: usize,
+}
+
+// endregion: --- ReproducibilityRecord
+
+// region:    --- ReproducibleChatSession
+
+/// A `Client` wrapper executing the chats with a fixed `seed`, and tracking the `system_fingerprint`
+/// of the responses to detect a provider configuration change (which breaks the reproducibility).
+///
+/// Note: The `seed` and `system_fingerprint` are OpenAI-compatible only for now.
+#[derive(Debug, Clone)]
+pub struct ReproducibleChatSession {
+	client: Client,
+	record: ReproducibilityRecord,
+}
+
+/// Constructors
+impl ReproducibleChatSession {
+	pub fn new(client: Client, seed: u64) -> Self {
+		Self {
+			client,
+			record: ReproducibilityRecord {
+				seed,
+				system_fingerprints: Vec::new(),
+				call_count: 0,
+			},
+		}
+	}
+}
+
+/// Getters
+impl ReproducibleChatSession {
+	pub fn record(&self) -> &ReproducibilityRecord {
+		&self.record
+	}
+
+	/// Returns false if the responses had different `system_fingerprint` values.
+	pub fn verify_reproducibility(&self) -> bool {
+		self.record.system_fingerprints.len() <= 1
+	}
+}
+
+impl ReproducibleChatSession {
+	/// Executes a chat with the session `seed` (overriding the eventual `options` seed).
+	///
+	/// A warning is logged when the response `system_fingerprint` differs from the first one.
+	pub async fn exec_chat(
+		&mut self,
+		model: &str,
+		chat_req: ChatRequest,
+		options: Option<&ChatOptions>,
+	) -> Result<ChatResponse> {
+		let options = options.cloned().unwrap_or_default().with_seed(self.record.seed);
+		let chat_res = self.client.exec_chat(model, chat_req, Some(&options)).await?;
+		self.record.call_count += 1;
+
+		if let Some(system_fingerprint) = chat_res.system_fingerprint() {
+			let fingerprints = &mut self.record.system_fingerprints;
+			if let Some(first) = fingerprints.first().filter(|first| *first != system_fingerprint) {
+				tracing::warn!(
+					model,
+					first_fingerprint = first,
+					system_fingerprint,
+					"system fingerprint changed, the outputs may not be reproducible"
+				);
+			}
+			if !fingerprints.iter().any(|fingerprint| fingerprint == system_fingerprint) {
+				fingerprints.push(system_fingerprint.to_string());
+			}
+		}
+
+		Ok(chat_res)
+	}
+}
+
+// endregion: --- ReproducibleChatSession
@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use value_ext::JsonValueExt;
+
+/// The structured error returned by the provider API when a call fails (HTTP 4xx/5xx).
+///
+/// The `error` object is extracted from the response body for the main formats:
+/// - OpenAI (and compatible): `{"error": {"message", "type", "code"}}`
+/// - Anthropic: `{"type": "error", "error": {"type", "message"}}`
+/// - Gemini: `{"error": {"code", "message", "status"}}` (sometimes wrapped in an array)
+/// - Cohere: `{"message"}`
+///
+/// If the body is not a known JSON format, `error_message` is the raw body.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiError {
+	pub status_code: u16,
+	pub error_type: Option<String>,
+	pub error_message: String,
+	pub error_code: Option<String>,
+}
+
+/// Constructors
+impl ApiError {
+	pub fn from_status_body(status_code: u16, body: &str) -> Self {
+		let raw_error = || ApiError {
+			status_code,
+			error_type: None,
+			error_message: body.to_string(),
+			error_code: None,
+		};
+
+		let Ok(mut body_value) = serde_json::from_str::<Value>(body) else {
+			return raw_error();
+		};
+
+		// Gemini can return the error object in an array
+		if let Value::Array(items) = body_value {
+			let Some(first) = items.into_iter().next() else {
+				return raw_error();
+			};
+			body_value = first;
+		}
+
+		// -- Error object (OpenAI, Anthropic, Gemini)
+		if let Ok(mut error) = body_value.x_take::<Value>("error") {
+			let error_message = error.x_take::<String>("message").unwrap_or_else(|_| body.to_string());
+			// Anthropic/OpenAI have `type`, Gemini has `status`
+			let error_type = error
+				.x_take::<String>("type")
+				.or_else(|_| error.x_take::<String>("status"))
+				.ok();
+			// OpenAI code is a string, Gemini code is a number
+			let error_code = match error.x_take::<Value>("code") {
+				Ok(Value::String(code)) => Some(code),
+				Ok(Value::Number(code)) => Some(code.to_string()),
+				_ => None,
+			};
+			return ApiError {
+				status_code,
+				error_type,
+				error_message,
+				error_code,
+			};
+		}
+
+		// -- Top message (Cohere)
+		if let Ok(error_message) = body_value.x_take::<String>("message") {
+			return ApiError {
+				status_code,
+				error_type: None,
+				error_message,
+				error_code: None,
+			};
+		}
+
+		raw_error()
+	}
+}
+
+impl core::fmt::Display for ApiError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "HTTP {}: {}", self.status_code, self.error_message)
+	}
+}
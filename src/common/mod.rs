@@ -1,9 +1,13 @@
 // region:    --- Modules
 
+mod api_error;
 mod model_iden;
 mod model_name;
+mod model_validator;
 
+pub use api_error::*;
 pub use model_iden::*;
 pub use model_name::*;
+pub use model_validator::*;
 
 // endregion: --- Modules
@@ -1,7 +1,8 @@
 use serde::{Deserialize, Serialize};
 
 use crate::adapter::AdapterKind;
-use crate::ModelName;
+use crate::{Error, ModelName, Result};
+use std::str::FromStr;
 
 /// Holds the adapter kind and model name in an efficient, clonable way.
 ///
@@ -25,6 +26,32 @@ impl ModelIden {
 	}
 }
 
+/// Formats as `"adapter_kind/model_name"` (e.g., `"openai/gpt-4o"`), the format parsed by `FromStr`.
+impl core::fmt::Display for ModelIden {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "{}/{}", self.adapter_kind.as_lower_str(), self.model_name)
+	}
+}
+
+/// Parse a `"adapter_kind/model_name"` string (e.g., `"openai/gpt-4o"`, `"anthropic/claude-3-5-sonnet-latest"`).
+///
+/// If the prefix before the first `/` is not an adapter kind (e.g., `"hf.co/some/model"` for Ollama),
+/// or if there is no `/`, the adapter kind is inferred with `AdapterKind::from_model` from the whole string.
+impl FromStr for ModelIden {
+	type Err = Error;
+
+	fn from_str(model_string: &str) -> Result<Self> {
+		if let Some((prefix, model_name)) = model_string.split_once('/') {
+			if let Ok(adapter_kind) = AdapterKind::from_str(prefix) {
+				return Ok(ModelIden::new(adapter_kind, model_name));
+			}
+		}
+
+		let adapter_kind = AdapterKind::from_model(model_string)?;
+		Ok(ModelIden::new(adapter_kind, model_string))
+	}
+}
+
 impl<T> From<(AdapterKind, T)> for ModelIden
 where
 	T: Into<ModelName>,
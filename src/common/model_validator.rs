@@ -0,0 +1,67 @@
+//! Validation of the model names against the static model lists of the adapters, to catch the typos
+//! (e.g., `"gpt-4o-mni"`) before the provider returns a confusing 404 or 400.
+
+use crate::adapter::AdapterKind;
+
+/// The max Levenshtein distance for a known model name to be suggested.
+const SUGGESTION_MAX_DISTANCE: usize = 3;
+
+/// The result of `ModelValidator::validate_model_name`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ModelValidation {
+	Known,
+	Unknown,
+	/// The model name is unknown, but a close known model name exists (e.g., a typo).
+	SimilarExists(String),
+}
+
+/// Validate the model names with the `AdapterKind::static_model_names`.
+///
+/// Note: The static lists are not exhaustive, so an unknown name might still be valid
+///       (see `ClientConfig::with_strict_model_validation`).
+pub struct ModelValidator;
+
+impl ModelValidator {
+	/// Validate the model name for the adapter kind.
+	///
+	/// Note: The adapter kinds without a static model list (i.e., Ollama) are always `Known`.
+	pub fn validate_model_name(name: &str, adapter_kind: AdapterKind) -> ModelValidation {
+		let names = adapter_kind.static_model_names();
+		if names.is_empty() || names.contains(&name) {
+			return ModelValidation::Known;
+		}
+		match Self::suggest(name, adapter_kind) {
+			Some(suggestion) => ModelValidation::SimilarExists(suggestion),
+			None => ModelValidation::Unknown,
+		}
+	}
+
+	/// Returns the closest known model name of the adapter kind (within a small edit distance).
+	pub fn suggest(name: &str, adapter_kind: AdapterKind) -> Option<String> {
+		adapter_kind
+			.static_model_names()
+			.iter()
+			.map(|known| (levenshtein(name, known), *known))
+			.filter(|(distance, _)| *distance <= SUGGESTION_MAX_DISTANCE)
+			.min_by_key(|(distance, _)| *distance)
+			.map(|(_, known)| known.to_string())
+	}
+}
+
+// region:    --- Support
+
+fn levenshtein(a: &str, b: &str) -> usize {
+	let b: Vec<char> = b.chars().collect();
+	let mut prev: Vec<usize> = (0..=b.len()).collect();
+	for (i, a_char) in a.chars().enumerate() {
+		let mut curr = vec![i + 1; b.len() + 1];
+		for (j, b_char) in b.iter().enumerate() {
+			let cost = usize::from(a_char != *b_char);
+			curr[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(curr[j] + 1);
+		}
+		prev = curr;
+	}
+	prev[b.len()]
+}
+
+// endregion: --- Support
@@ -1,6 +1,7 @@
-use crate::adapter::AdapterKind;
+use crate::adapter::{parse_adapter_error, AdapterError, AdapterKind, GeminiSafetyRating};
+use crate::batch::BatchStatus;
 use crate::chat::ChatRole;
-use crate::{resolver, webc, ModelIden};
+use crate::{resolver, webc, ApiError, ModelIden, TaskRequirements};
 use derive_more::From;
 use value_ext::JsonValueExtError;
 
@@ -8,6 +9,8 @@ use value_ext::JsonValueExtError;
 pub type Result<T> = core::result::Result<T, Error>;
 
 /// Main GenAI error
+///
+/// Note: The large payloads (e.g., `ApiError`, `TaskRequirements`) are boxed to keep the `Result` small.
 #[derive(Debug, From)]
 #[allow(missing_docs)]
 pub enum Error {
@@ -28,6 +31,20 @@ pub enum Error {
 		cause: &'static str,
 	},
 	JsonModeWithoutInstruction,
+	/// The `ChatOptions::extra_params` key cannot be merged into the payload (see `ChatOptions::with_extra_param`).
+	ExtraParamInvalid {
+		key: String,
+		cause: &'static str,
+	},
+	/// The LangChain/LlamaIndex messages are not in the expected format (see `ChatRequest::from_langchain_messages`).
+	InteropInvalidMessages {
+		cause: String,
+	},
+	/// The tool type (e.g., `ToolType::ComputerUse`) is not supported by the adapter.
+	ToolTypeNotSupported {
+		model_iden: ModelIden,
+		tool_name: String,
+	},
 
 	// -- Chat Output
 	NoChatResponse {
@@ -36,6 +53,52 @@ pub enum Error {
 	InvalidJsonResponseElement {
 		info: &'static str,
 	},
+	/// The response was blocked by the provider safety settings, with `HarmBlockMode::Error` (Gemini only for now).
+	ResponseSafetyBlocked {
+		model_iden: ModelIden,
+		block_reason: Option<String>,
+		safety_ratings: Box<[GeminiSafetyRating]>,
+	},
+
+	// -- Content Filter
+	ContentBlocked {
+		model_iden: ModelIden,
+		reason: String,
+	},
+
+	// -- Client Config
+	/// The proxy url cannot be used (see `ClientConfig::with_proxy`).
+	ProxyUrlInvalid {
+		url: String,
+		cause: String,
+	},
+
+	// -- Model
+	AdapterKindUnknown {
+		name: String,
+	},
+	/// The `AdapterConfig` value is not supported by its adapter kind (see `AdapterConfig::with_response_schema`).
+	AdapterConfigInvalid {
+		adapter_kind: AdapterKind,
+		cause: &'static str,
+	},
+	/// The model name is not a known model name, with strict model validation
+	/// (see `ClientConfig::with_strict_model_validation`).
+	ModelNameUnknown {
+		model_iden: ModelIden,
+		suggestion: Option<String>,
+	},
+	/// No model matches the requirements of `Client::exec_chat_auto`.
+	NoModelForRequirements {
+		requirements: Box<TaskRequirements>,
+	},
+
+	// -- Cost Budget
+	/// The accumulated cost reached the `CostBudget::max_total_usd` (see `ClientConfig::with_cost_budget`).
+	CostBudgetExceeded {
+		spent_usd: f64,
+		limit_usd: f64,
+	},
 
 	// -- Auth
 	RequiresApiKey {
@@ -63,6 +126,15 @@ pub enum Error {
 		model_iden: ModelIden,
 		webc_error: webc::Error,
 	},
+	/// The provider API returned an error status (with the parsed error body).
+	///
+	/// The `adapter_error` is set when the error body is a structured error of the adapter format
+	/// (e.g., `OpenAIError`, see `Error::downcast_adapter_error`).
+	ApiError {
+		model_iden: ModelIden,
+		api_error: Box<ApiError>,
+		adapter_error: Option<Box<dyn AdapterError>>,
+	},
 
 	// -- Chat Stream
 	StreamParse {
@@ -77,6 +149,86 @@ pub enum Error {
 		model_iden: ModelIden,
 		cause: String,
 	},
+	/// The chunk write failed in `ChatStream::pipe_to_writer` (or `pipe_to_sync_writer`).
+	StreamWrite {
+		model_iden: ModelIden,
+		cause: String,
+	},
+	/// No chunk received within the `ChatStream::timeout_first_chunk` duration.
+	FirstChunkTimeout {
+		model_iden: ModelIden,
+		duration_ms: u64,
+	},
+
+	// -- Audio
+	TranscriptionNotSupported {
+		model_iden: ModelIden,
+	},
+
+	// -- Tool
+	ToolInvalidArgs {
+		cause: String,
+	},
+	ToolFnFailed {
+		cause: String,
+	},
+	/// The tool output does not match its output schema.
+	ToolInvalidOutput {
+		cause: String,
+	},
+	/// The tool schema is not a valid OpenAI format tool (see `ToolSchemaRegistry::register_from_schema`).
+	ToolInvalidSchema {
+		cause: String,
+	},
+	/// The tool param type schema has no root description (see `schema_for_fn_single_param_from_docs`).
+	ToolSchemaNoDescription {
+		fn_name: String,
+	},
+	/// The LLM still requested tool calls after `rounds` dispatch rounds (see `Client::exec_chat_with_tools`).
+	ToolCallLoopExceeded {
+		rounds: u32,
+	},
+
+	// -- Batch
+	BatchHasNoRequests,
+	BatchNotSupported {
+		model_iden: ModelIden,
+	},
+	BatchNotCompleted {
+		batch_id: String,
+		status: BatchStatus,
+	},
+
+	// -- Realtime
+	Realtime {
+		cause: String,
+	},
+
+	// -- gRPC
+	/// The gRPC call failed (see `GrpcChatClient`, with the `grpc` feature).
+	Grpc {
+		cause: String,
+	},
+
+	// -- Files
+	FileRead {
+		path: String,
+		cause: String,
+	},
+	FileWrite {
+		path: String,
+		cause: String,
+	},
+	/// The saved `ChatRequest` JSON cannot be migrated (see `migrate_chat_request`).
+	ChatRequestMigration {
+		cause: String,
+	},
+
+	// -- Replay
+	/// No recorded response matches the request (see `ConversationReplayer`).
+	NoRecordedResponse {
+		model_iden: ModelIden,
+	},
 
 	// -- Modules
 	Resolver {
@@ -89,20 +241,268 @@ pub enum Error {
 	EventSourceClone(reqwest_eventsource::CannotCloneRequestError),
 	#[from]
 	JsonValueExt(JsonValueExtError),
-	ReqwestEventSource(reqwest_eventsource::Error),
+	ReqwestEventSource(Box<reqwest_eventsource::Error>),
 	// Note: will probably need to remove this one to provide more context
 	#[from]
 	SerdeJson(serde_json::Error),
 }
 
+// region:    --- Crate Constructors
+
+impl Error {
+	/// Map a web call error of a model call, extracting the `ApiError` when the API returned an error status
+	/// (and the eventual adapter-specific error).
+	pub(crate) fn from_webc_model_call(model_iden: ModelIden, webc_error: webc::Error) -> Error {
+		match webc_error {
+			webc::Error::ResponseFailedStatus { status, body } => Error::ApiError {
+				api_error: Box::new(ApiError::from_status_body(status.as_u16(), &body)),
+				adapter_error: parse_adapter_error(model_iden.adapter_kind, &body),
+				model_iden,
+			},
+			webc_error => Error::WebModelCall { model_iden, webc_error },
+		}
+	}
+
+	/// Same as `from_webc_model_call` for the adapter-level calls (the `ApiError` model name is empty).
+	pub(crate) fn from_webc_adapter_call(adapter_kind: AdapterKind, webc_error: webc::Error) -> Error {
+		match webc_error {
+			webc::Error::ResponseFailedStatus { .. } => {
+				Error::from_webc_model_call(ModelIden::new(adapter_kind, ""), webc_error)
+			}
+			webc_error => Error::WebAdapterCall {
+				adapter_kind,
+				webc_error,
+			},
+		}
+	}
+}
+
+// endregion: --- Crate Constructors
+
+// region:    --- Error Properties
+
+impl Error {
+	/// Returns the `ApiError` of an `Error::ApiError`.
+	pub fn api_error(&self) -> Option<&ApiError> {
+		match self {
+			Error::ApiError { api_error, .. } => Some(api_error),
+			_ => None,
+		}
+	}
+
+	/// Returns the adapter-specific error of an `Error::ApiError`, if it is of the type `T`
+	/// (e.g., `OpenAIError` to match its `code`).
+	pub fn downcast_adapter_error<T: AdapterError>(&self) -> Option<&T> {
+		match self {
+			Error::ApiError {
+				adapter_error: Some(adapter_error),
+				..
+			} => adapter_error.as_any().downcast_ref::<T>(),
+			_ => None,
+		}
+	}
+
+	/// Returns true if the error is likely transient (rate limit, network, or server error),
+	/// so the same request might succeed on retry.
+	pub fn is_transient(&self) -> bool {
+		match self {
+			Error::ApiError { api_error, .. } => is_transient_status(api_error.status_code),
+			Error::WebAdapterCall { webc_error, .. } | Error::WebModelCall { webc_error, .. } => {
+				webc_error.is_transient()
+			}
+			Error::WebStream { .. } | Error::FirstChunkTimeout { .. } => true,
+			Error::ReqwestEventSource(err) => match err.as_ref() {
+				reqwest_eventsource::Error::Transport(_) => true,
+				reqwest_eventsource::Error::InvalidStatusCode(status, _) => is_transient_status(status.as_u16()),
+				_ => false,
+			},
+			_ => false,
+		}
+	}
+}
+
+/// Rate limit (429), request timeout (408), and server errors (5xx).
+pub(crate) fn is_transient_status(status_code: u16) -> bool {
+	status_code == 408 || status_code == 429 || status_code >= 500
+}
+
+// endregion: --- Error Properties
+
 // region:    --- Error Boilerplate
 
+// Note: The Display of the variants wrapping an error does not include the inner error text,
+//       which is the `source()` (e.g., for the `anyhow` or `color-eyre` reports).
 impl core::fmt::Display for Error {
 	fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::result::Result<(), core::fmt::Error> {
-		write!(fmt, "{self:?}")
+		match self {
+			// -- Chat Input
+			Error::ChatReqHasNoMessages { model_iden } => write!(fmt, "Chat request for {model_iden} has no messages"),
+			Error::LastChatMessageIsNotUser {
+				model_iden,
+				actual_role,
+			} => write!(
+				fmt,
+				"Last chat message for {model_iden} must be a user message (was {actual_role:?})"
+			),
+			Error::MessageRoleNotSupported { model_iden, role } => {
+				write!(fmt, "Message role {role:?} not supported by {model_iden}")
+			}
+			Error::MessageContentTypeNotSupported { model_iden, cause } => {
+				write!(fmt, "Message content type not supported by {model_iden}: {cause}")
+			}
+			Error::JsonModeWithoutInstruction => {
+				write!(
+					fmt,
+					"JSON mode requires an instruction mentioning JSON in the chat request"
+				)
+			}
+			Error::ExtraParamInvalid { key, cause } => write!(fmt, "Invalid extra param '{key}': {cause}"),
+			Error::InteropInvalidMessages { cause } => write!(fmt, "Invalid interop messages: {cause}"),
+			Error::ToolTypeNotSupported { model_iden, tool_name } => {
+				write!(fmt, "Tool type of '{tool_name}' not supported by {model_iden}")
+			}
+
+			// -- Chat Output
+			Error::NoChatResponse { model_iden } => write!(fmt, "No chat response from {model_iden}"),
+			Error::InvalidJsonResponseElement { info } => write!(fmt, "Invalid JSON response element: {info}"),
+			Error::ResponseSafetyBlocked {
+				model_iden,
+				block_reason,
+				..
+			} => write!(
+				fmt,
+				"Response of {model_iden} blocked by the safety settings (reason: {})",
+				block_reason.as_deref().unwrap_or("unknown")
+			),
+
+			// -- Content Filter
+			Error::ContentBlocked { model_iden, reason } => {
+				write!(fmt, "Content blocked for {model_iden}: {reason}")
+			}
+
+			// -- Client Config
+			Error::ProxyUrlInvalid { url, cause } => write!(fmt, "Invalid proxy url '{url}': {cause}"),
+
+			// -- Model
+			Error::AdapterKindUnknown { name } => write!(fmt, "Unknown adapter kind '{name}'"),
+			Error::AdapterConfigInvalid { adapter_kind, cause } => {
+				write!(fmt, "Invalid adapter config for {adapter_kind}: {cause}")
+			}
+			Error::ModelNameUnknown { model_iden, suggestion } => match suggestion {
+				Some(suggestion) => write!(
+					fmt,
+					"Unknown model name for {model_iden} (did you mean '{suggestion}'?)"
+				),
+				None => write!(fmt, "Unknown model name for {model_iden}"),
+			},
+			Error::NoModelForRequirements { requirements } => {
+				write!(fmt, "No model matches the requirements: {requirements:?}")
+			}
+
+			// -- Cost Budget
+			Error::CostBudgetExceeded { spent_usd, limit_usd } => {
+				write!(fmt, "Cost budget exceeded: ${spent_usd:.4} spent of ${limit_usd:.4}")
+			}
+
+			// -- Auth
+			Error::RequiresApiKey { model_iden } => write!(fmt, "{model_iden} requires an API key"),
+			Error::NoAuthResolver { model_iden } => write!(fmt, "No auth resolver for {model_iden}"),
+			Error::NoAuthData { model_iden } => write!(fmt, "No auth data for {model_iden}"),
+
+			// -- ModelMapper
+			Error::ModelMapperFailed { model_iden, .. } => write!(fmt, "Model mapper failed for {model_iden}"),
+
+			// -- Web Call error
+			Error::WebAdapterCall { adapter_kind, .. } => {
+				write!(fmt, "Web call failed for adapter {}", adapter_kind.as_str())
+			}
+			Error::WebModelCall { model_iden, .. } => write!(fmt, "Web call failed for {model_iden}"),
+			Error::ApiError {
+				model_iden, api_error, ..
+			} => write!(fmt, "API error from {model_iden}: {api_error}"),
+
+			// -- Chat Stream
+			Error::StreamParse { model_iden, .. } => write!(fmt, "Cannot parse the stream event of {model_iden}"),
+			Error::StreamEventError { model_iden, body } => write!(fmt, "Stream error event from {model_iden}: {body}"),
+			Error::WebStream { model_iden, cause } => write!(fmt, "Web stream failed for {model_iden}: {cause}"),
+			Error::StreamWrite { model_iden, cause } => {
+				write!(fmt, "Cannot write the stream of {model_iden}: {cause}")
+			}
+			Error::FirstChunkTimeout {
+				model_iden,
+				duration_ms,
+			} => {
+				write!(fmt, "No first chunk from {model_iden} within {duration_ms}ms")
+			}
+
+			// -- Audio
+			Error::TranscriptionNotSupported { model_iden } => {
+				write!(fmt, "Transcription not supported by {model_iden}")
+			}
+
+			// -- Tool
+			Error::ToolInvalidArgs { cause } => write!(fmt, "Invalid tool arguments: {cause}"),
+			Error::ToolFnFailed { cause } => write!(fmt, "Tool function failed: {cause}"),
+			Error::ToolInvalidOutput { cause } => write!(fmt, "Invalid tool output: {cause}"),
+			Error::ToolInvalidSchema { cause } => write!(fmt, "Invalid tool schema: {cause}"),
+			Error::ToolSchemaNoDescription { fn_name } => write!(
+				fmt,
+				"Tool '{fn_name}' param type has no description (add a doc comment or `#[schemars(description = \"..\")]`)"
+			),
+			Error::ToolCallLoopExceeded { rounds } => {
+				write!(
+					fmt,
+					"Tool call loop exceeded (still calling tools after {rounds} rounds)"
+				)
+			}
+
+			// -- Batch
+			Error::BatchHasNoRequests => write!(fmt, "Batch has no requests"),
+			Error::BatchNotSupported { model_iden } => write!(fmt, "Batch not supported by {model_iden}"),
+			Error::BatchNotCompleted { batch_id, status } => {
+				write!(fmt, "Batch '{batch_id}' not completed (status: {status:?})")
+			}
+
+			// -- Realtime
+			Error::Realtime { cause } => write!(fmt, "Realtime session failed: {cause}"),
+
+			// -- gRPC
+			Error::Grpc { cause } => write!(fmt, "gRPC call failed: {cause}"),
+
+			// -- Files
+			Error::FileRead { path, cause } => write!(fmt, "Cannot read file '{path}': {cause}"),
+			Error::FileWrite { path, cause } => write!(fmt, "Cannot write file '{path}': {cause}"),
+			Error::ChatRequestMigration { cause } => write!(fmt, "Cannot migrate the chat request: {cause}"),
+
+			// -- Replay
+			Error::NoRecordedResponse { model_iden } => write!(fmt, "No recorded response for {model_iden}"),
+
+			// -- Modules
+			Error::Resolver { model_iden, .. } => write!(fmt, "Resolver failed for {model_iden}"),
+
+			// -- Externals
+			Error::EventSourceClone(_) => write!(fmt, "Cannot clone the event source request"),
+			Error::JsonValueExt(_) => write!(fmt, "JSON value error"),
+			Error::ReqwestEventSource(_) => write!(fmt, "Event source error"),
+			Error::SerdeJson(_) => write!(fmt, "JSON error"),
+		}
 	}
 }
 
-impl std::error::Error for Error {}
+impl std::error::Error for Error {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match self {
+			Error::ModelMapperFailed { cause, .. } => Some(cause),
+			Error::WebAdapterCall { webc_error, .. } | Error::WebModelCall { webc_error, .. } => Some(webc_error),
+			Error::StreamParse { serde_error, .. } => Some(serde_error),
+			Error::Resolver { resolver_error, .. } => Some(resolver_error),
+			Error::EventSourceClone(err) => Some(err),
+			Error::JsonValueExt(err) => Some(err),
+			Error::ReqwestEventSource(err) => Some(err.as_ref()),
+			Error::SerdeJson(err) => Some(err),
+			_ => None,
+		}
+	}
+}
 
 // endregion: --- Error Boilerplate
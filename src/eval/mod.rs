@@ -0,0 +1,13 @@
+//! Evaluation utilities to compare chat responses (e.g., for A/B prompt experiments).
+
+// region:    --- Modules
+
+mod prompt_ab_test;
+mod response_diff;
+mod usage_comparison;
+
+pub use prompt_ab_test::*;
+pub use response_diff::*;
+pub use usage_comparison::*;
+
+// endregion: --- Modules
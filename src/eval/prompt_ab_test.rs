@@ -0,0 +1,142 @@
+use crate::chat::{ChatRequest, ChatResponse, MetaUsage};
+use crate::{Client, Result};
+use futures::future::try_join_all;
+use std::time::Instant;
+
+// region:    --- PromptABTest
+
+/// Run a base `ChatRequest` with two system prompt variants, to compare their responses and metrics.
+///
+/// Note: The variant replaces the `.system` of the base request (the system messages are kept).
+#[derive(Debug, Clone)]
+pub struct PromptABTest {
+	client: Client,
+	base_req: ChatRequest,
+	variant_a: String,
+	variant_b: String,
+	scorer: Option<fn(&str) -> f32>,
+}
+
+/// Constructors
+impl PromptABTest {
+	pub fn new(client: Client, base_req: ChatRequest, variant_a: &str, variant_b: &str) -> Self {
+		Self {
+			client,
+			base_req,
+			variant_a: variant_a.to_string(),
+			variant_b: variant_b.to_string(),
+			scorer: None,
+		}
+	}
+}
+
+/// Chainable Setters
+impl PromptABTest {
+	/// Set the quality scorer of the response texts (e.g., `1.0` for an expected label, `0.0` otherwise).
+	pub fn with_scorer(mut self, scorer: fn(&str) -> f32) -> Self {
+		self.scorer = Some(scorer);
+		self
+	}
+}
+
+impl PromptABTest {
+	/// Execute each variant `n_samples` times, all of the requests concurrently.
+	///
+	/// Returns the first error if any request fails.
+	pub async fn run(&self, model: &str, n_samples: usize) -> Result<ABTestResult> {
+		let req_a = self.base_req.clone().with_system(&self.variant_a);
+		let req_b = self.base_req.clone().with_system(&self.variant_b);
+
+		let samples = (0..n_samples)
+			.map(|_| self.exec_timed(model, req_a.clone()))
+			.chain((0..n_samples).map(|_| self.exec_timed(model, req_b.clone())));
+		let mut samples = try_join_all(samples).await?;
+		let samples_b = samples.split_off(n_samples);
+
+		let metrics = ABMetrics {
+			variant_a: VariantMetrics::from_samples(&samples, self.scorer),
+			variant_b: VariantMetrics::from_samples(&samples_b, self.scorer),
+		};
+
+		Ok(ABTestResult {
+			variant_a_responses: samples.into_iter().map(|(chat_res, _)| chat_res).collect(),
+			variant_b_responses: samples_b.into_iter().map(|(chat_res, _)| chat_res).collect(),
+			metrics,
+		})
+	}
+
+	/// Returns the response and its latency in milliseconds.
+	async fn exec_timed(&self, model: &str, chat_req: ChatRequest) -> Result<(ChatResponse, f64)> {
+		let start = Instant::now();
+		let chat_res = self.client.exec_chat(model, chat_req, None).await?;
+		Ok((chat_res, start.elapsed().as_secs_f64() * 1000.0))
+	}
+}
+
+// endregion: --- PromptABTest
+
+// region:    --- ABTestResult
+
+#[derive(Debug, Clone)]
+pub struct ABTestResult {
+	pub variant_a_responses: Vec<ChatResponse>,
+	pub variant_b_responses: Vec<ChatResponse>,
+	pub metrics: ABMetrics,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ABMetrics {
+	pub variant_a: VariantMetrics,
+	pub variant_b: VariantMetrics,
+}
+
+/// The averages of the samples of a variant (`0.0` when there are no samples).
+#[derive(Debug, Clone, PartialEq)]
+pub struct VariantMetrics {
+	/// The average total tokens (or input + output tokens when the total is not present).
+	pub avg_tokens: f64,
+	pub avg_latency_ms: f64,
+	/// The average score of the response texts (`None` without scorer, a response without text scores `0.0`).
+	pub avg_score: Option<f32>,
+}
+
+impl VariantMetrics {
+	fn from_samples(samples: &[(ChatResponse, f64)], scorer: Option<fn(&str) -> f32>) -> Self {
+		if samples.is_empty() {
+			return Self {
+				avg_tokens: 0.0,
+				avg_latency_ms: 0.0,
+				avg_score: scorer.map(|_| 0.0),
+			};
+		}
+
+		let count = samples.len() as f64;
+		let tokens: f64 = samples.iter().map(|(chat_res, _)| total_tokens(&chat_res.usage) as f64).sum();
+		let latency_ms: f64 = samples.iter().map(|(_, latency_ms)| latency_ms).sum();
+		let avg_score = scorer.map(|scorer| {
+			let score: f32 = samples
+				.iter()
+				.map(|(chat_res, _)| chat_res.content_text_as_str().map(scorer).unwrap_or(0.0))
+				.sum();
+			score / samples.len() as f32
+		});
+
+		Self {
+			avg_tokens: tokens / count,
+			avg_latency_ms: latency_ms / count,
+			avg_score,
+		}
+	}
+}
+
+// endregion: --- ABTestResult
+
+// region:    --- Support
+
+fn total_tokens(usage: &MetaUsage) -> i32 {
+	usage
+		.total_tokens
+		.unwrap_or_else(|| usage.input_tokens.unwrap_or(0) + usage.output_tokens.unwrap_or(0))
+}
+
+// endregion: --- Support
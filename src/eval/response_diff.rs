@@ -0,0 +1,92 @@
+use crate::chat::ChatResponse;
+use std::collections::HashSet;
+
+/// The line diff and similarity between two chat response texts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChatResponseDiff {
+	/// The lines in `b` which are not in `a` (in `b` order).
+	pub added_lines: Vec<String>,
+	/// The lines in `a` which are not in `b` (in `a` order).
+	pub removed_lines: Vec<String>,
+	/// The Jaccard index of the lowercased whitespace tokens (1.0 when both texts have no tokens).
+	pub similarity: f32,
+}
+
+/// Diff the text content of two chat responses (the non text content is ignored).
+///
+/// Note: The line diff is based on the longest common subsequence of lines.
+pub fn diff_responses(a: &ChatResponse, b: &ChatResponse) -> ChatResponseDiff {
+	let a_text = a.content_text_as_str().unwrap_or_default();
+	let b_text = b.content_text_as_str().unwrap_or_default();
+
+	let a_lines: Vec<&str> = a_text.lines().collect();
+	let b_lines: Vec<&str> = b_text.lines().collect();
+	let (a_common, b_common) = lcs_line_flags(&a_lines, &b_lines);
+
+	let removed_lines = a_lines
+		.iter()
+		.zip(a_common)
+		.filter(|(_, common)| !common)
+		.map(|(line, _)| line.to_string())
+		.collect();
+	let added_lines = b_lines
+		.iter()
+		.zip(b_common)
+		.filter(|(_, common)| !common)
+		.map(|(line, _)| line.to_string())
+		.collect();
+
+	ChatResponseDiff {
+		added_lines,
+		removed_lines,
+		similarity: jaccard_similarity(a_text, b_text),
+	}
+}
+
+// region:    --- Support
+
+/// Returns, for each line of `a` and `b`, whether it is part of their longest common subsequence.
+fn lcs_line_flags(a: &[&str], b: &[&str]) -> (Vec<bool>, Vec<bool>) {
+	// lengths[i][j] = LCS length of a[i..] and b[j..]
+	let mut lengths = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+	for i in (0..a.len()).rev() {
+		for j in (0..b.len()).rev() {
+			lengths[i][j] = if a[i] == b[j] {
+				lengths[i + 1][j + 1] + 1
+			} else {
+				lengths[i + 1][j].max(lengths[i][j + 1])
+			};
+		}
+	}
+
+	let mut a_common = vec![false; a.len()];
+	let mut b_common = vec![false; b.len()];
+	let (mut i, mut j) = (0, 0);
+	while i < a.len() && j < b.len() {
+		if a[i] == b[j] {
+			a_common[i] = true;
+			b_common[j] = true;
+			i += 1;
+			j += 1;
+		} else if lengths[i + 1][j] >= lengths[i][j + 1] {
+			i += 1;
+		} else {
+			j += 1;
+		}
+	}
+
+	(a_common, b_common)
+}
+
+fn jaccard_similarity(a: &str, b: &str) -> f32 {
+	let a_tokens: HashSet<String> = a.split_whitespace().map(|t| t.to_lowercase()).collect();
+	let b_tokens: HashSet<String> = b.split_whitespace().map(|t| t.to_lowercase()).collect();
+	let union = a_tokens.union(&b_tokens).count();
+	if union == 0 {
+		return 1.0;
+	}
+	let intersection = a_tokens.intersection(&b_tokens).count();
+	intersection as f32 / union as f32
+}
+
+// endregion: --- Support
@@ -0,0 +1,76 @@
+use crate::chat::MetaUsage;
+
+/// The token and cost differences from a usage `a` to a usage `b` (positive when `b` uses or costs more).
+#[derive(Debug, Clone, PartialEq)]
+pub struct UsageComparison {
+	pub token_delta: i32,
+	/// The cost difference at the `compare_usages` price (`0.0` without a price).
+	pub cost_delta_usd: f64,
+}
+
+/// The USD price per million tokens, for the input and output tokens.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TokenPrice {
+	pub input_per_million_usd: f64,
+	pub output_per_million_usd: f64,
+}
+
+impl TokenPrice {
+	pub fn new(input_per_million_usd: f64, output_per_million_usd: f64) -> Self {
+		Self {
+			input_per_million_usd,
+			output_per_million_usd,
+		}
+	}
+}
+
+/// Estimate the USD cost of the usages for a given token price.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CostEstimator {
+	price: TokenPrice,
+}
+
+impl CostEstimator {
+	pub fn new(price: TokenPrice) -> Self {
+		Self { price }
+	}
+
+	/// The USD cost of the input and output tokens of the usage.
+	pub fn estimate_usd(&self, usage: &MetaUsage) -> f64 {
+		let input = usage.input_tokens.unwrap_or(0) as f64 * self.price.input_per_million_usd;
+		let output = usage.output_tokens.unwrap_or(0) as f64 * self.price.output_per_million_usd;
+		(input + output) / 1_000_000.0
+	}
+
+	/// The blended USD cost per 1k tokens (the average of the input and output prices).
+	pub fn cost_per_1k_tokens(&self) -> f64 {
+		(self.price.input_per_million_usd + self.price.output_per_million_usd) / 2.0 / 1_000.0
+	}
+}
+
+/// Compare the total tokens of two usages, and their cost at the `price`.
+///
+/// Note: The `MetaUsage` has no model, so the cost difference needs the price of the compared runs model
+///       (e.g., `ModelCapabilities::for_model(model).map(|caps| caps.price)`).
+pub fn compare_usages(a: &MetaUsage, b: &MetaUsage, price: Option<TokenPrice>) -> UsageComparison {
+	let cost_delta_usd = price
+		.map(CostEstimator::new)
+		.map(|estimator| estimator.estimate_usd(b) - estimator.estimate_usd(a))
+		.unwrap_or_default();
+
+	UsageComparison {
+		token_delta: total_tokens(b) - total_tokens(a),
+		cost_delta_usd,
+	}
+}
+
+// region:    --- Support
+
+/// The `total_tokens`, or the sum of the input and output tokens if not present.
+fn total_tokens(usage: &MetaUsage) -> i32 {
+	usage
+		.total_tokens
+		.unwrap_or_else(|| usage.input_tokens.unwrap_or(0) + usage.output_tokens.unwrap_or(0))
+}
+
+// endregion: --- Support
@@ -0,0 +1,85 @@
+use crate::adapter::AdapterKind;
+use crate::files::{FilePurpose, UploadedFile};
+use crate::{Client, Error, Result};
+use reqwest::multipart::{Form, Part};
+use std::path::Path;
+use value_ext::JsonValueExt;
+
+/// Client for the OpenAI files API.
+/// Built with `client.file_client()` and shares the client's web client and config.
+#[derive(Debug, Clone)]
+pub struct FileClient {
+	client: Client,
+	adapter_kind: AdapterKind,
+}
+
+// region:    --- Constructors
+
+impl FileClient {
+	pub fn new(client: Client) -> Self {
+		Self {
+			client,
+			adapter_kind: AdapterKind::OpenAI,
+		}
+	}
+}
+
+impl Client {
+	/// Returns a `FileClient` using this client's web client and config.
+	pub fn file_client(&self) -> FileClient {
+		FileClient::new(self.clone())
+	}
+}
+
+// endregion: --- Constructors
+
+// region:    --- Public File Functions
+
+impl FileClient {
+	/// Upload the file at `path` (multipart form upload) with the given purpose.
+	pub async fn upload_file(&self, path: &Path, purpose: FilePurpose) -> Result<UploadedFile> {
+		let content = tokio::fs::read(path).await.map_err(|io_error| Error::FileRead {
+			path: path.to_string_lossy().to_string(),
+			cause: io_error.to_string(),
+		})?;
+		let file_name = path
+			.file_name()
+			.map(|name| name.to_string_lossy().to_string())
+			.unwrap_or_else(|| "file".to_string());
+
+		let part = Part::bytes(content).file_name(file_name);
+		let form = Form::new().text("purpose", purpose.as_str()).part("file", part);
+		let file_res = self
+			.client
+			.adapter_api_post_multipart(self.adapter_kind, "files", form, &[])
+			.await?;
+		let file: UploadedFile = serde_json::from_value(file_res)?;
+
+		Ok(file)
+	}
+
+	/// List the files, optionally only the ones with the given purpose.
+	pub async fn list_files(&self, purpose: Option<FilePurpose>) -> Result<Vec<UploadedFile>> {
+		let path = match purpose {
+			Some(purpose) => format!("files?purpose={}", purpose.as_str()),
+			None => "files".to_string(),
+		};
+		let mut list_res = self.client.adapter_api_get(self.adapter_kind, &path, &[]).await?;
+		let files: Vec<UploadedFile> = list_res.x_take("data")?;
+		Ok(files)
+	}
+
+	pub async fn get_file(&self, id: &str) -> Result<UploadedFile> {
+		let path = format!("files/{id}");
+		let file_res = self.client.adapter_api_get(self.adapter_kind, &path, &[]).await?;
+		let file: UploadedFile = serde_json::from_value(file_res)?;
+		Ok(file)
+	}
+
+	pub async fn delete_file(&self, id: &str) -> Result<()> {
+		let path = format!("files/{id}");
+		self.client.adapter_api_delete(self.adapter_kind, &path, &[]).await
+	}
+}
+
+// endregion: --- Public File Functions
@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+
+// region:    --- FilePurpose
+
+/// The intended purpose of an uploaded file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FilePurpose {
+	#[serde(rename = "assistants")]
+	Assistants,
+	#[serde(rename = "fine-tune")]
+	FineTune,
+	#[serde(rename = "batch")]
+	Batch,
+}
+
+impl FilePurpose {
+	/// The API `purpose` value.
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			FilePurpose::Assistants => "assistants",
+			FilePurpose::FineTune => "fine-tune",
+			FilePurpose::Batch => "batch",
+		}
+	}
+}
+
+// endregion: --- FilePurpose
+
+// region:    --- UploadedFile
+
+/// The file object as returned by the `/v1/files` API.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UploadedFile {
+	/// The file id (e.g., the `training_file_id` of the fine-tuning jobs).
+	pub id: String,
+	pub filename: String,
+	pub bytes: u64,
+	pub created_at: u64,
+	/// Note: Kept as `String` since the API can return other purposes (e.g., `batch_output`).
+	pub purpose: String,
+}
+
+// endregion: --- UploadedFile
@@ -0,0 +1,16 @@
+//! The files module allows managing the OpenAI files (upload, list, get, delete),
+//! e.g., to upload the batch input or fine-tuning training files.
+//!
+//! API DOC: https://platform.openai.com/docs/api-reference/files
+//!
+//! Note: Only the OpenAI adapter is supported for now.
+
+// region:    --- Modules
+
+mod file_client;
+mod file_types;
+
+pub use file_client::*;
+pub use file_types::*;
+
+// endregion: --- Modules
@@ -0,0 +1,146 @@
+use crate::adapter::{AdapterDispatcher, AdapterKind, ServiceType, WebRequestData};
+use crate::chat::{ChatOptionsSet, ChatRequest};
+use crate::finetune::{FineTuneJob, FineTuneOptions, TrainingSample};
+use crate::{Client, Result};
+use reqwest::multipart::{Form, Part};
+use serde_json::{json, Value};
+use value_ext::JsonValueExt;
+
+/// Client for the OpenAI fine-tuning API.
+/// Built with `client.finetune_client()` and shares the client's web client and config.
+#[derive(Debug, Clone)]
+pub struct FineTuneClient {
+	client: Client,
+	adapter_kind: AdapterKind,
+}
+
+// region:    --- Constructors
+
+impl FineTuneClient {
+	pub fn new(client: Client) -> Self {
+		Self {
+			client,
+			adapter_kind: AdapterKind::OpenAI,
+		}
+	}
+}
+
+impl Client {
+	/// Returns a `FineTuneClient` using this client's web client and config.
+	pub fn finetune_client(&self) -> FineTuneClient {
+		FineTuneClient::new(self.clone())
+	}
+}
+
+// endregion: --- Constructors
+
+// region:    --- Public FineTune Functions
+
+impl FineTuneClient {
+	/// Upload the training samples as a JSONL file (purpose `fine-tune`) and return the file id.
+	///
+	/// Each sample's messages are serialized the same way as for an OpenAI chat request.
+	pub async fn upload_training_file(&self, samples: Vec<TrainingSample>) -> Result<String> {
+		let mut lines: Vec<String> = Vec::with_capacity(samples.len());
+		for sample in samples {
+			let messages = self.to_openai_messages(sample)?;
+			lines.push(serde_json::to_string(&json!({ "messages": messages }))?);
+		}
+		let content = lines.join("\n");
+
+		let part = Part::bytes(content.into_bytes()).file_name("training_data.jsonl");
+		let form = Form::new().text("purpose", "fine-tune").part("file", part);
+		let mut file_res = self
+			.client
+			.adapter_api_post_multipart(self.adapter_kind, "files", form, &[])
+			.await?;
+		let file_id: String = file_res.x_take("id")?;
+
+		Ok(file_id)
+	}
+
+	pub async fn create_fine_tuning_job(
+		&self,
+		model: &str,
+		training_file_id: &str,
+		options: FineTuneOptions,
+	) -> Result<FineTuneJob> {
+		let FineTuneOptions {
+			n_epochs,
+			batch_size,
+			learning_rate_multiplier,
+			suffix,
+		} = options;
+
+		let mut payload = json!({
+			"model": model,
+			"training_file": training_file_id,
+		});
+
+		let mut hyperparameters = json!({});
+		if let Some(n_epochs) = n_epochs {
+			hyperparameters.x_insert("n_epochs", n_epochs)?;
+		}
+		if let Some(batch_size) = batch_size {
+			hyperparameters.x_insert("batch_size", batch_size)?;
+		}
+		if let Some(learning_rate_multiplier) = learning_rate_multiplier {
+			hyperparameters.x_insert("learning_rate_multiplier", learning_rate_multiplier)?;
+		}
+		if hyperparameters.as_object().is_some_and(|obj| !obj.is_empty()) {
+			payload.x_insert("hyperparameters", hyperparameters)?;
+		}
+		if let Some(suffix) = suffix {
+			payload.x_insert("suffix", suffix)?;
+		}
+
+		let job_res = self
+			.client
+			.adapter_api_post(self.adapter_kind, "fine_tuning/jobs", payload, &[])
+			.await?;
+		let job: FineTuneJob = serde_json::from_value(job_res)?;
+
+		Ok(job)
+	}
+
+	/// Note: Returns the first page of jobs (API default page size).
+	pub async fn list_fine_tuning_jobs(&self) -> Result<Vec<FineTuneJob>> {
+		let mut list_res = self.client.adapter_api_get(self.adapter_kind, "fine_tuning/jobs", &[]).await?;
+		let jobs: Vec<FineTuneJob> = list_res.x_take("data")?;
+		Ok(jobs)
+	}
+
+	pub async fn get_fine_tuning_job(&self, id: &str) -> Result<FineTuneJob> {
+		let path = format!("fine_tuning/jobs/{id}");
+		let job_res = self.client.adapter_api_get(self.adapter_kind, &path, &[]).await?;
+		let job: FineTuneJob = serde_json::from_value(job_res)?;
+		Ok(job)
+	}
+
+	pub async fn cancel_fine_tuning_job(&self, id: &str) -> Result<FineTuneJob> {
+		let path = format!("fine_tuning/jobs/{id}/cancel");
+		let job_res = self.client.adapter_api_post(self.adapter_kind, &path, json!({}), &[]).await?;
+		let job: FineTuneJob = serde_json::from_value(job_res)?;
+		Ok(job)
+	}
+}
+
+// endregion: --- Public FineTune Functions
+
+// region:    --- Support
+
+impl FineTuneClient {
+	/// Reuse the adapter chat request serialization to get the OpenAI `messages` of a sample.
+	fn to_openai_messages(&self, sample: TrainingSample) -> Result<Value> {
+		let target = self.client.config().resolve_adapter_service_target(self.adapter_kind)?;
+		let chat_req = ChatRequest::from_messages(sample.messages);
+
+		let WebRequestData { mut payload, .. } =
+			AdapterDispatcher::to_web_request_data(target, ServiceType::Chat, chat_req, ChatOptionsSet::default())?;
+		let messages: Value = payload.x_take("messages")?;
+
+		Ok(messages)
+	}
+}
+
+// endregion: --- Support
@@ -0,0 +1,87 @@
+use crate::chat::ChatMessage;
+use serde::{Deserialize, Serialize};
+
+// region:    --- TrainingSample
+
+/// One training conversation of the fine-tuning file (one JSONL line).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrainingSample {
+	pub messages: Vec<ChatMessage>,
+}
+
+impl From<Vec<ChatMessage>> for TrainingSample {
+	fn from(messages: Vec<ChatMessage>) -> Self {
+		Self { messages }
+	}
+}
+
+// endregion: --- TrainingSample
+
+// region:    --- FineTuneOptions
+
+/// The optional hyperparameters and suffix of a fine-tuning job.
+/// When not set, the API will choose the value (e.g., `auto`).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FineTuneOptions {
+	pub n_epochs: Option<u32>,
+	pub batch_size: Option<u32>,
+	pub learning_rate_multiplier: Option<f64>,
+
+	/// The suffix added to the fine-tuned model name (e.g., `ft:gpt-4o-mini:my-org:{suffix}:id`)
+	pub suffix: Option<String>,
+}
+
+/// Chainable Setters
+impl FineTuneOptions {
+	pub fn with_n_epochs(mut self, value: u32) -> Self {
+		self.n_epochs = Some(value);
+		self
+	}
+
+	pub fn with_batch_size(mut self, value: u32) -> Self {
+		self.batch_size = Some(value);
+		self
+	}
+
+	pub fn with_learning_rate_multiplier(mut self, value: f64) -> Self {
+		self.learning_rate_multiplier = Some(value);
+		self
+	}
+
+	pub fn with_suffix(mut self, value: impl Into<String>) -> Self {
+		self.suffix = Some(value.into());
+		self
+	}
+}
+
+// endregion: --- FineTuneOptions
+
+// region:    --- FineTuneJob
+
+/// The fine-tuning job as returned by the `/v1/fine_tuning/jobs` API.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FineTuneJob {
+	pub id: String,
+	/// The base model being fine-tuned.
+	pub model: String,
+	pub status: FineTuneStatus,
+	pub training_file: String,
+	/// The name of the resulting model, once the job succeeded.
+	pub fine_tuned_model: Option<String>,
+	pub trained_tokens: Option<u64>,
+	pub created_at: i64,
+	pub finished_at: Option<i64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FineTuneStatus {
+	ValidatingFiles,
+	Queued,
+	Running,
+	Succeeded,
+	Failed,
+	Cancelled,
+}
+
+// endregion: --- FineTuneJob
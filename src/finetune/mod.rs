@@ -0,0 +1,15 @@
+//! The finetune module allows managing OpenAI fine-tuning jobs (upload the training file, create, list, get, cancel).
+//!
+//! API DOC: https://platform.openai.com/docs/api-reference/fine-tuning
+//!
+//! Note: Only the OpenAI adapter is supported for now.
+
+// region:    --- Modules
+
+mod finetune_client;
+mod finetune_types;
+
+pub use finetune_client::*;
+pub use finetune_types::*;
+
+// endregion: --- Modules
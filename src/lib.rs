@@ -6,16 +6,41 @@
 mod client;
 mod common;
 mod error;
+mod support;
 
 // -- Flatten
 pub use client::*;
 pub use common::*;
 pub use error::{Error, Result};
+pub use support::*;
 
 // -- Public Modules
 pub mod adapter;
+pub mod assistants;
+pub mod audio;
+pub mod batch;
+pub mod bench;
 pub mod chat;
+pub mod eval;
+pub mod files;
+pub mod finetune;
+pub mod middleware;
+#[cfg(feature = "realtime")]
+pub mod realtime;
 pub mod resolver;
 pub mod webc;
 
 // endregion: --- Modules
+
+// region:    --- Macro Support
+
+/// The crates used by the `genai_macros` generated code (not a public API).
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+pub mod __private {
+	pub use schemars;
+	pub use serde;
+	pub use serde_json;
+}
+
+// endregion: --- Macro Support
@@ -0,0 +1,112 @@
+//! Content filters, called by `Client::exec_chat` on the input messages before sending,
+//! and on the chat response before returning (e.g., for custom safety layers, PII scrubbing, or post-processing).
+
+use crate::chat::{ChatMessage, ChatResponse, ContentPart, MessageContent};
+use crate::Result;
+use futures::future::BoxFuture;
+
+// region:    --- ContentFilter
+
+/// The action returned by a `ContentFilter`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FilterAction {
+	/// The content is allowed as is.
+	Allow,
+	/// The content is blocked, with the reason.
+	Block(String),
+	/// The content was modified by the filter (and is allowed).
+	Modify,
+}
+
+/// A content filter on the chat input messages and output response.
+///
+/// Note: When a filter returns `FilterAction::Block`, `exec_chat` returns an `Error::ContentBlocked`,
+///       or the `ClientConfig::with_content_blocked_response(..)` response if set.
+pub trait ContentFilter: Send + Sync {
+	/// Called for each message of the chat request before it is sent.
+	fn filter_input<'a>(&'a self, msg: &'a mut ChatMessage) -> BoxFuture<'a, Result<FilterAction>>;
+
+	/// Called on the chat response before it is returned.
+	fn filter_output<'a>(&'a self, response: &'a mut ChatResponse) -> BoxFuture<'a, Result<FilterAction>>;
+}
+
+impl std::fmt::Debug for dyn ContentFilter {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "ContentFilter")
+	}
+}
+
+// endregion: --- ContentFilter
+
+// region:    --- ProfanityFilter
+
+/// A trivial built-in filter masking the given words (ASCII case-insensitive) with `*` in the input and output texts.
+#[derive(Debug, Clone)]
+pub struct ProfanityFilter {
+	words: Vec<String>,
+}
+
+impl ProfanityFilter {
+	pub fn new(words: &[&str]) -> Self {
+		Self {
+			words: words.iter().filter(|w| !w.is_empty()).map(|w| w.to_ascii_lowercase()).collect(),
+		}
+	}
+
+	fn mask_content(&self, content: &mut MessageContent) -> FilterAction {
+		let mut modified = false;
+		match content {
+			MessageContent::Text(text) => modified |= self.mask_text(text),
+			MessageContent::Parts(parts) => {
+				for part in parts.iter_mut() {
+					let mut part = part;
+					while let ContentPart::WithCache(inner) = part {
+						part = inner.as_mut();
+					}
+					if let ContentPart::Text(text) = part {
+						modified |= self.mask_text(text);
+					}
+				}
+			}
+			_ => (),
+		}
+		if modified {
+			FilterAction::Modify
+		} else {
+			FilterAction::Allow
+		}
+	}
+
+	/// Returns true if the text was modified.
+	fn mask_text(&self, text: &mut String) -> bool {
+		let mut modified = false;
+		for word in &self.words {
+			// Note: ASCII lowercase keeps the byte positions.
+			let lower = text.to_ascii_lowercase();
+			let positions: Vec<usize> = lower.match_indices(word.as_str()).map(|(idx, _)| idx).collect();
+			for idx in positions {
+				text.replace_range(idx..idx + word.len(), &"*".repeat(word.len()));
+				modified = true;
+			}
+		}
+		modified
+	}
+}
+
+impl ContentFilter for ProfanityFilter {
+	fn filter_input<'a>(&'a self, msg: &'a mut ChatMessage) -> BoxFuture<'a, Result<FilterAction>> {
+		Box::pin(async move { Ok(self.mask_content(&mut msg.content)) })
+	}
+
+	fn filter_output<'a>(&'a self, response: &'a mut ChatResponse) -> BoxFuture<'a, Result<FilterAction>> {
+		Box::pin(async move {
+			let action = match response.content.as_mut() {
+				Some(content) => self.mask_content(content),
+				None => FilterAction::Allow,
+			};
+			Ok(action)
+		})
+	}
+}
+
+// endregion: --- ProfanityFilter
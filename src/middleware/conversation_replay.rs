@@ -0,0 +1,172 @@
+//! Record the `exec_chat` requests and responses to a JSON lines file, and replay them without network access
+//! (e.g., as test fixtures, or for CI tests without API keys).
+//!
+//! The requests are matched by a stable hash of the model and the provider payload (same key as the
+//! `IdempotencyMiddleware`).
+
+use crate::adapter::WebRequestData;
+use crate::chat::{ChatOptions, ChatRequest, ChatResponse};
+use crate::middleware::support::request_key;
+use crate::middleware::Middleware;
+use crate::resolver::{AuthData, AuthResolver};
+use crate::{Client, Error, ModelIden, Result};
+use futures::future::BoxFuture;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+// region:    --- ConversationRecorder
+
+/// Wraps a `Client` to record all of the `exec_chat` requests and responses to a JSON lines file.
+///
+/// Note: The file is created (or truncated) at the first response, and each response is then appended as a line
+///       (the file is not rewritten).
+#[derive(Debug, Clone)]
+pub struct ConversationRecorder {
+	client: Client,
+}
+
+impl ConversationRecorder {
+	pub fn new(client: Client, output_path: &Path) -> Self {
+		let recording = RecordingMiddleware {
+			output_path: output_path.to_path_buf(),
+			file: Mutex::new(None),
+		};
+		Self {
+			client: client.with_added_middleware(recording),
+		}
+	}
+
+	/// The recording client (e.g., to pass to the application code).
+	pub fn client(&self) -> &Client {
+		&self.client
+	}
+
+	pub async fn exec_chat(
+		&self,
+		model: &str,
+		chat_req: ChatRequest,
+		options: Option<&ChatOptions>,
+	) -> Result<ChatResponse> {
+		self.client.exec_chat(model, chat_req, options).await
+	}
+}
+
+struct RecordingMiddleware {
+	output_path: PathBuf,
+	/// The output file, opened at the first response.
+	file: Mutex<Option<File>>,
+}
+
+impl RecordingMiddleware {
+	fn file_write_error(&self, cause: impl ToString) -> Error {
+		Error::FileWrite {
+			path: self.output_path.to_string_lossy().to_string(),
+			cause: cause.to_string(),
+		}
+	}
+}
+
+impl Middleware for RecordingMiddleware {
+	fn after_response(
+		&self,
+		model_iden: &ModelIden,
+		request_data: &WebRequestData,
+		chat_res: &ChatResponse,
+	) -> Result<()> {
+		let entry = RecordedEntry {
+			key: request_key(model_iden, &request_data.payload),
+			model_iden: model_iden.clone(),
+			request: request_data.payload.clone(),
+			response: chat_res.clone(),
+		};
+		let mut line = serde_json::to_string(&entry)?;
+		line.push('\n');
+
+		let mut file_guard = self.file.lock().map_err(|err| self.file_write_error(err))?;
+		let file = match &mut *file_guard {
+			Some(file) => file,
+			None => {
+				let file = File::create(&self.output_path).map_err(|io_error| self.file_write_error(io_error))?;
+				file_guard.insert(file)
+			}
+		};
+		file.write_all(line.as_bytes())
+			.map_err(|io_error| self.file_write_error(io_error))
+	}
+}
+
+// endregion: --- ConversationRecorder
+
+// region:    --- ConversationReplayer
+
+/// Build a `Client` answering the `exec_chat` requests with the responses of a `ConversationRecorder` file
+/// (one JSON entry per line).
+pub struct ConversationReplayer;
+
+impl ConversationReplayer {
+	/// Returns a client which never calls the providers, and returns `Error::NoRecordedResponse`
+	/// for the requests not in the recording.
+	///
+	/// Note: A fixed auth is set, so no API keys are needed.
+	pub fn from_file(path: &Path) -> Result<Client> {
+		let content = std::fs::read_to_string(path).map_err(|io_error| Error::FileRead {
+			path: path.to_string_lossy().to_string(),
+			cause: io_error.to_string(),
+		})?;
+		let entries = content
+			.lines()
+			.filter(|line| !line.trim().is_empty())
+			.map(serde_json::from_str)
+			.collect::<serde_json::Result<Vec<RecordedEntry>>>()?;
+
+		let auth_resolver =
+			AuthResolver::from_resolver_fn(|_model_iden: ModelIden| Ok(Some(AuthData::from_single("genai-replay"))));
+		let client = Client::builder()
+			.with_auth_resolver(auth_resolver)
+			.with_middleware(ReplayMiddleware { entries })
+			.build();
+
+		Ok(client)
+	}
+}
+
+struct ReplayMiddleware {
+	entries: Vec<RecordedEntry>,
+}
+
+impl Middleware for ReplayMiddleware {
+	fn cached_response<'a>(
+		&'a self,
+		model_iden: &'a ModelIden,
+		request_data: &'a WebRequestData,
+	) -> BoxFuture<'a, Result<Option<ChatResponse>>> {
+		let key = request_key(model_iden, &request_data.payload);
+		let res = match self.entries.iter().find(|entry| entry.key == key) {
+			Some(entry) => Ok(Some(entry.response.clone())),
+			None => Err(Error::NoRecordedResponse {
+				model_iden: model_iden.clone(),
+			}),
+		};
+		Box::pin(futures::future::ready(res))
+	}
+}
+
+// endregion: --- ConversationReplayer
+
+// region:    --- Support
+
+/// A recorded request and response (a line of the recording file).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RecordedEntry {
+	key: String,
+	model_iden: ModelIden,
+	/// The provider payload (for information, the match is on the `key`).
+	request: Value,
+	response: ChatResponse,
+}
+
+// endregion: --- Support
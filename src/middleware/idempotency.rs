@@ -0,0 +1,144 @@
+//! Request deduplication middleware, to not pay twice for the same `exec_chat` request
+//! (e.g., on the retries of a load balancer or of the application).
+
+use crate::adapter::WebRequestData;
+use crate::chat::ChatResponse;
+use crate::middleware::support::request_key;
+use crate::middleware::Middleware;
+use crate::{Error, ModelIden, Result};
+use futures::future::BoxFuture;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tokio::sync::watch;
+
+/// Returns the cached response of an identical request (same model and provider payload)
+/// received less than `ttl` ago, without calling the provider.
+///
+/// The identical requests received while the first one is in flight wait for its response
+/// (or make the call if it fails, or is not done within the `ttl`).
+///
+/// The idempotency key is the SHA256 of the model and the payload (the headers, e.g., the client request id,
+/// are ignored). The expired entries are evicted on each request.
+///
+/// Note: Unlike a response cache, the `ttl` is meant to be short (e.g., the retry window of the callers).
+/// Note: The response is cached as received (before the output content filters, which run on each call).
+#[derive(Debug)]
+pub struct IdempotencyMiddleware {
+	ttl: Duration,
+	entries: Mutex<HashMap<String, IdempotencyEntry>>,
+}
+
+#[derive(Debug)]
+enum IdempotencyEntry {
+	/// The first request is in flight. The waiters are released when the sender is dropped
+	/// (i.e., when the entry is replaced by its response, or removed on error).
+	InFlight {
+		started: Instant,
+		done_tx: watch::Sender<()>,
+	},
+	Done {
+		created: Instant,
+		chat_res: Box<ChatResponse>,
+	},
+}
+
+impl IdempotencyEntry {
+	fn created(&self) -> Instant {
+		match self {
+			IdempotencyEntry::InFlight { started, .. } => *started,
+			IdempotencyEntry::Done { created, .. } => *created,
+		}
+	}
+}
+
+impl IdempotencyMiddleware {
+	pub fn new(ttl: Duration) -> Self {
+		Self {
+			ttl,
+			entries: Mutex::new(HashMap::new()),
+		}
+	}
+}
+
+impl IdempotencyMiddleware {
+	/// The idempotency key of a request (hex SHA256 of the model and the provider payload).
+	pub fn idempotency_key(model_iden: &ModelIden, request_data: &WebRequestData) -> String {
+		request_key(model_iden, &request_data.payload)
+	}
+
+	/// The number of cached (possibly expired) responses (the requests in flight are not included).
+	pub fn len(&self) -> usize {
+		self.lock_entries()
+			.values()
+			.filter(|entry| matches!(entry, IdempotencyEntry::Done { .. }))
+			.count()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	fn lock_entries(&self) -> std::sync::MutexGuard<'_, HashMap<String, IdempotencyEntry>> {
+		// Note: The entries are always consistent, so a poisoned lock can be recovered.
+		self.entries.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+	}
+}
+
+impl Middleware for IdempotencyMiddleware {
+	fn cached_response<'a>(
+		&'a self,
+		model_iden: &'a ModelIden,
+		request_data: &'a WebRequestData,
+	) -> BoxFuture<'a, Result<Option<ChatResponse>>> {
+		let key = Self::idempotency_key(model_iden, request_data);
+		Box::pin(async move {
+			loop {
+				// -- Return the response, or subscribe to the request in flight (or become it)
+				// Note: The receiver is created under the lock, so the release cannot be missed.
+				let (mut done_rx, remaining) = {
+					let mut entries = self.lock_entries();
+					entries.retain(|_, entry| entry.created().elapsed() < self.ttl);
+					match entries.get(&key) {
+						Some(IdempotencyEntry::Done { chat_res, .. }) => return Ok(Some(chat_res.as_ref().clone())),
+						Some(IdempotencyEntry::InFlight { started, done_tx }) => {
+							(done_tx.subscribe(), self.ttl.saturating_sub(started.elapsed()))
+						}
+						None => {
+							let (done_tx, _) = watch::channel(());
+							let started = Instant::now();
+							entries.insert(key, IdempotencyEntry::InFlight { started, done_tx });
+							return Ok(None);
+						}
+					}
+				};
+
+				// -- Wait for the release (or the expiration), and check again
+				let _ = tokio::time::timeout(remaining, done_rx.changed()).await;
+			}
+		})
+	}
+
+	fn after_response(
+		&self,
+		model_iden: &ModelIden,
+		request_data: &WebRequestData,
+		chat_res: &ChatResponse,
+	) -> Result<()> {
+		let key = Self::idempotency_key(model_iden, request_data);
+		let entry = IdempotencyEntry::Done {
+			created: Instant::now(),
+			chat_res: Box::new(chat_res.clone()),
+		};
+		self.lock_entries().insert(key, entry);
+		Ok(())
+	}
+
+	fn after_error(&self, model_iden: &ModelIden, request_data: &WebRequestData, _error: &Error) {
+		let key = Self::idempotency_key(model_iden, request_data);
+		let mut entries = self.lock_entries();
+		if matches!(entries.get(&key), Some(IdempotencyEntry::InFlight { .. })) {
+			entries.remove(&key);
+		}
+	}
+}
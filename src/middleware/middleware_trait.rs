@@ -0,0 +1,50 @@
+use crate::adapter::WebRequestData;
+use crate::chat::ChatResponse;
+use crate::{Error, ModelIden, Result};
+use futures::future::BoxFuture;
+
+/// A middleware called by the `Client` on each chat request (`exec_chat` and `exec_chat_stream`).
+///
+/// All of the hooks default to no-op.
+pub trait Middleware: Send + Sync {
+	/// Called with the provider web request (url, headers, and serialized payload) before it is sent.
+	///
+	/// Note: The payload is in the provider format of the `model_iden.adapter_kind`.
+	fn before_request(&self, _model_iden: &ModelIden, _request_data: &mut WebRequestData) -> Result<()> {
+		Ok(())
+	}
+
+	/// Called after `before_request` (`exec_chat` only). Returning a response skips the provider call
+	/// (e.g., for a cache or a replay), and the next middlewares.
+	///
+	/// Note: Async, so an identical request in flight can be awaited (see `IdempotencyMiddleware`).
+	fn cached_response<'a>(
+		&'a self,
+		_model_iden: &'a ModelIden,
+		_request_data: &'a WebRequestData,
+	) -> BoxFuture<'a, Result<Option<ChatResponse>>> {
+		Box::pin(async { Ok(None) })
+	}
+
+	/// Called with the chat response as received, before the safety check and the output content filters
+	/// (`exec_chat` only), so a response returned later by `cached_response` is filtered only once.
+	///
+	/// Note: Only called for the middlewares before the one which returned a `cached_response` (if any).
+	fn after_response(
+		&self,
+		_model_iden: &ModelIden,
+		_request_data: &WebRequestData,
+		_chat_res: &ChatResponse,
+	) -> Result<()> {
+		Ok(())
+	}
+
+	/// Called instead of `after_response` when the provider call (or a `cached_response`) failed (`exec_chat` only).
+	fn after_error(&self, _model_iden: &ModelIden, _request_data: &WebRequestData, _error: &Error) {}
+}
+
+impl std::fmt::Debug for dyn Middleware {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "Middleware")
+	}
+}
@@ -0,0 +1,26 @@
+//! Client middlewares and content filters, called on the chat requests around the provider call.
+//!
+//! Middlewares are registered in the `ClientConfig` (or `ClientBuilder`) with `with_middleware(..)`,
+//! and are called in the order they were added.
+
+// region:    --- Modules
+
+mod support;
+
+mod content_filter;
+mod conversation_replay;
+mod idempotency;
+mod middleware_trait;
+mod request_compressor;
+mod system_prompt;
+mod trace_context;
+
+pub use content_filter::*;
+pub use conversation_replay::*;
+pub use idempotency::*;
+pub use middleware_trait::*;
+pub use request_compressor::*;
+pub use system_prompt::*;
+pub use trace_context::*;
+
+// endregion: --- Modules
@@ -0,0 +1,38 @@
+//! Request compression middleware, an alternative to `ClientConfig::with_request_compression`
+//! to gzip only the large requests.
+
+use crate::adapter::WebRequestData;
+use crate::middleware::Middleware;
+use crate::{ModelIden, Result};
+
+/// Gzip compresses the requests with a serialized payload of at least `min_payload_bytes`,
+/// by adding the `content-encoding: gzip` header (the body is compressed by the web client).
+#[derive(Debug, Clone)]
+pub struct RequestCompressor {
+	min_payload_bytes: usize,
+}
+
+impl RequestCompressor {
+	pub fn new(min_payload_bytes: usize) -> Self {
+		Self { min_payload_bytes }
+	}
+}
+
+impl Middleware for RequestCompressor {
+	fn before_request(&self, _model_iden: &ModelIden, request_data: &mut WebRequestData) -> Result<()> {
+		let has_encoding = request_data
+			.headers
+			.iter()
+			.any(|(k, _)| k.eq_ignore_ascii_case("content-encoding"));
+		if has_encoding {
+			return Ok(());
+		}
+
+		let payload_bytes = serde_json::to_vec(&request_data.payload)?.len();
+		if payload_bytes >= self.min_payload_bytes {
+			request_data.headers.push(("content-encoding".to_string(), "gzip".to_string()));
+		}
+
+		Ok(())
+	}
+}
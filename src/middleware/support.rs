@@ -0,0 +1,16 @@
+//! This support module is for common constructs and utilities for the middlewares.
+//! It should be private to the `crate::middleware` module.
+
+use crate::ModelIden;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+/// The stable key of a request (hex SHA256 of the model and the provider payload),
+/// shared by the middlewares matching the identical requests (see `IdempotencyMiddleware` and `ConversationReplayer`).
+///
+/// Note: The `serde_json` objects are sorted by key, so the payload string is deterministic.
+pub fn request_key(model_iden: &ModelIden, payload: &Value) -> String {
+	let mut hasher = Sha256::new();
+	hasher.update(format!("{model_iden}|{payload}"));
+	format!("{:x}", hasher.finalize())
+}
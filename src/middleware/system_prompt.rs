@@ -0,0 +1,140 @@
+//! System prompt middlewares, adding a mandatory (e.g., safety or persona) system prompt to every request.
+//!
+//! Note: These work on the serialized payload, so the user `ChatRequest` is never mutated.
+
+use crate::adapter::{AdapterKind, WebRequestData};
+use crate::middleware::Middleware;
+use crate::{ModelIden, Result};
+use serde_json::{json, Value};
+
+// region:    --- SystemPromptInjector
+
+/// Prepends a prefix to the system content of each request (or sets it if there is none).
+#[derive(Debug, Clone)]
+pub struct SystemPromptInjector {
+	prefix: String,
+}
+
+impl SystemPromptInjector {
+	pub fn new(prefix: &str) -> Self {
+		Self {
+			prefix: prefix.to_string(),
+		}
+	}
+}
+
+impl Middleware for SystemPromptInjector {
+	fn before_request(&self, model_iden: &ModelIden, request_data: &mut WebRequestData) -> Result<()> {
+		wrap_payload_system(model_iden.adapter_kind, &mut request_data.payload, &self.prefix, "");
+		Ok(())
+	}
+}
+
+// endregion: --- SystemPromptInjector
+
+// region:    --- SystemPromptWrapper
+
+/// Wraps the system content of each request with a prefix and a suffix.
+#[derive(Debug, Clone)]
+pub struct SystemPromptWrapper {
+	prefix: String,
+	suffix: String,
+}
+
+impl SystemPromptWrapper {
+	pub fn new(prefix: &str, suffix: &str) -> Self {
+		Self {
+			prefix: prefix.to_string(),
+			suffix: suffix.to_string(),
+		}
+	}
+}
+
+impl Middleware for SystemPromptWrapper {
+	fn before_request(&self, model_iden: &ModelIden, request_data: &mut WebRequestData) -> Result<()> {
+		wrap_payload_system(
+			model_iden.adapter_kind,
+			&mut request_data.payload,
+			&self.prefix,
+			&self.suffix,
+		);
+		Ok(())
+	}
+}
+
+// endregion: --- SystemPromptWrapper
+
+// region:    --- Support
+
+/// Wrap the system content of the provider payload with the prefix and suffix (joined with a new line).
+/// - Anthropic: `system` (a string, or an array of text blocks)
+/// - Cohere: `preamble`
+/// - Gemini: `systemInstruction.parts[0].text`
+/// - Others (OpenAI compatible): the leading `"role": "system"` messages (one is inserted if none)
+fn wrap_payload_system(adapter_kind: AdapterKind, payload: &mut Value, prefix: &str, suffix: &str) {
+	let system_value = match adapter_kind {
+		AdapterKind::Anthropic => Some(&mut payload["system"]),
+		AdapterKind::Cohere => Some(&mut payload["preamble"]),
+		AdapterKind::Gemini => {
+			if payload.pointer("/systemInstruction/parts/0").is_none() {
+				payload["systemInstruction"] = json!({"parts": [{"text": ""}]});
+			}
+			payload.pointer_mut("/systemInstruction/parts/0/text")
+		}
+		_ => None,
+	};
+
+	// -- Single system value
+	if let Some(system_value) = system_value {
+		wrap_content(system_value, prefix, suffix);
+		return;
+	}
+
+	// -- OpenAI compatible messages
+	let Some(messages) = payload.get_mut("messages").and_then(|m| m.as_array_mut()) else {
+		return;
+	};
+	let system_count = messages
+		.iter()
+		.take_while(|msg| msg.get("role").and_then(|r| r.as_str()) == Some("system"))
+		.count();
+	if system_count == 0 {
+		let content = join_non_empty(&[prefix, suffix]);
+		messages.insert(0, json!({"role": "system", "content": content}));
+		return;
+	}
+	if let Some(first_content) = messages[0].get_mut("content") {
+		wrap_content(first_content, prefix, "");
+	}
+	if let Some(last_content) = messages[system_count - 1].get_mut("content") {
+		wrap_content(last_content, "", suffix);
+	}
+}
+
+/// Wrap a string content, or add the prefix and suffix as text blocks to an array content
+/// (e.g., the Anthropic `system` blocks with `cache_control`, or the OpenAI content parts), keeping its blocks as-is.
+fn wrap_content(content: &mut Value, prefix: &str, suffix: &str) {
+	if let Value::Array(blocks) = content {
+		if !prefix.is_empty() {
+			blocks.insert(0, json!({"type": "text", "text": prefix}));
+		}
+		if !suffix.is_empty() {
+			blocks.push(json!({"type": "text", "text": suffix}));
+		}
+		return;
+	}
+
+	let text = content.as_str().unwrap_or_default();
+	*content = Value::String(join_non_empty(&[prefix, text, suffix]));
+}
+
+fn join_non_empty(parts: &[&str]) -> String {
+	parts
+		.iter()
+		.filter(|part| !part.is_empty())
+		.copied()
+		.collect::<Vec<_>>()
+		.join("\n")
+}
+
+// endregion: --- Support
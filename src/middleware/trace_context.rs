@@ -0,0 +1,134 @@
+//! Correlation id middlewares, adding a request header to correlate the provider calls
+//! with the distributed traces or the application logs.
+
+use crate::adapter::WebRequestData;
+use crate::middleware::Middleware;
+use crate::{ModelIden, Result};
+
+// region:    --- TraceContextInjector
+
+/// Add a `header_name` header with a new id from `id_generator` to each request
+/// (e.g., an `x-request-id` with the application id scheme).
+///
+/// Note: An already present header (same name, case insensitive) is kept as is.
+#[derive(Debug, Clone)]
+pub struct TraceContextInjector {
+	header_name: String,
+	id_generator: fn() -> String,
+}
+
+impl TraceContextInjector {
+	pub fn new(header_name: &str, id_generator: fn() -> String) -> Self {
+		Self {
+			header_name: header_name.to_string(),
+			id_generator,
+		}
+	}
+}
+
+impl Middleware for TraceContextInjector {
+	fn before_request(&self, _model_iden: &ModelIden, request_data: &mut WebRequestData) -> Result<()> {
+		if !has_header(request_data, &self.header_name) {
+			request_data.headers.push((self.header_name.clone(), (self.id_generator)()));
+		}
+		Ok(())
+	}
+}
+
+// endregion: --- TraceContextInjector
+
+// region:    --- UuidCorrelationMiddleware
+
+/// Add a `x-correlation-id` header with a new UUID (v4) to each request.
+#[derive(Debug, Clone)]
+pub struct UuidCorrelationMiddleware {
+	injector: TraceContextInjector,
+}
+
+impl UuidCorrelationMiddleware {
+	pub const HEADER_NAME: &'static str = "x-correlation-id";
+
+	pub fn new() -> Self {
+		Self {
+			injector: TraceContextInjector::new(Self::HEADER_NAME, || uuid::Uuid::new_v4().to_string()),
+		}
+	}
+}
+
+impl Default for UuidCorrelationMiddleware {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl Middleware for UuidCorrelationMiddleware {
+	fn before_request(&self, model_iden: &ModelIden, request_data: &mut WebRequestData) -> Result<()> {
+		self.injector.before_request(model_iden, request_data)
+	}
+}
+
+// endregion: --- UuidCorrelationMiddleware
+
+// region:    --- TracingMiddleware
+
+/// Add the W3C Trace Context `traceparent` header of the current `tracing` span (requires the `opentelemetry` feature).
+///
+/// The trace context is read with `tracing-opentelemetry`, so the application must have the
+/// `tracing_opentelemetry` layer in its subscriber. Without a valid trace context, no header is added.
+///
+/// Note: The current span is the `exec_chat` span, a child of the caller span.
+#[cfg(feature = "opentelemetry")]
+#[derive(Debug, Clone, Default)]
+pub struct TracingMiddleware;
+
+#[cfg(feature = "opentelemetry")]
+impl TracingMiddleware {
+	pub const HEADER_NAME: &'static str = "traceparent";
+
+	pub fn new() -> Self {
+		Self
+	}
+
+	/// The `traceparent` value of the current span (`00-{trace_id}-{span_id}-{flags}`), if it has a valid trace context.
+	pub fn current_traceparent() -> Option<String> {
+		use opentelemetry::trace::TraceContextExt;
+		use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+		let context = tracing::Span::current().context();
+		let span = context.span();
+		let span_context = span.span_context();
+		if !span_context.is_valid() {
+			return None;
+		}
+
+		Some(format!(
+			"00-{:032x}-{:016x}-{:02x}",
+			span_context.trace_id(),
+			span_context.span_id(),
+			span_context.trace_flags().to_u8()
+		))
+	}
+}
+
+#[cfg(feature = "opentelemetry")]
+impl Middleware for TracingMiddleware {
+	fn before_request(&self, _model_iden: &ModelIden, request_data: &mut WebRequestData) -> Result<()> {
+		if has_header(request_data, Self::HEADER_NAME) {
+			return Ok(());
+		}
+		if let Some(traceparent) = Self::current_traceparent() {
+			request_data.headers.push((Self::HEADER_NAME.to_string(), traceparent));
+		}
+		Ok(())
+	}
+}
+
+// endregion: --- TracingMiddleware
+
+// region:    --- Support
+
+fn has_header(request_data: &WebRequestData, name: &str) -> bool {
+	request_data.headers.iter().any(|(k, _)| k.eq_ignore_ascii_case(name))
+}
+
+// endregion: --- Support
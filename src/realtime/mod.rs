@@ -0,0 +1,19 @@
+//! The realtime module allows using the OpenAI Realtime API over a WebSocket connection
+//! (text and audio in, text and audio deltas out).
+//!
+//! Flow: `RealtimeClient::connect(model, api_key)` -> `session.send_text(..)` / `session.send_audio(..)`
+//! -> consume `session.events()`.
+//!
+//! API DOC: https://platform.openai.com/docs/api-reference/realtime
+//!
+//! Note: Requires the `realtime` feature (which adds the `tokio-tungstenite` dependency).
+
+// region:    --- Modules
+
+mod realtime_client;
+mod realtime_event;
+
+pub use realtime_client::*;
+pub use realtime_event::*;
+
+// endregion: --- Modules
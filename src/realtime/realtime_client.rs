@@ -0,0 +1,102 @@
+use crate::realtime::RealtimeEvent;
+use crate::{Error, Result};
+use base64::engine::general_purpose;
+use base64::Engine;
+use futures::stream::{SplitSink, SplitStream};
+use futures::{SinkExt, Stream, StreamExt};
+use serde_json::{json, Value};
+use tokio::net::TcpStream;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::http::HeaderValue;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+
+const REALTIME_BASE_URL: &str = "wss://api.openai.com/v1/realtime";
+
+type WsStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+// region:    --- RealtimeClient
+
+/// Client for the OpenAI Realtime API (WebSocket transport).
+pub struct RealtimeClient;
+
+impl RealtimeClient {
+	/// Open a realtime session for the model (e.g., `gpt-4o-realtime-preview`).
+	pub async fn connect(model: &str, api_key: &str) -> Result<RealtimeSession> {
+		let mut url = reqwest::Url::parse(REALTIME_BASE_URL).map_err(realtime_error)?;
+		url.query_pairs_mut().append_pair("model", model);
+		let mut request = url.as_str().into_client_request().map_err(realtime_error)?;
+		let headers = request.headers_mut();
+		headers.insert(
+			"Authorization",
+			HeaderValue::from_str(&format!("Bearer {api_key}")).map_err(realtime_error)?,
+		);
+		headers.insert("OpenAI-Beta", HeaderValue::from_static("realtime=v1"));
+
+		let (ws_stream, _) = tokio_tungstenite::connect_async(request).await.map_err(realtime_error)?;
+		let (sink, stream) = ws_stream.split();
+
+		Ok(RealtimeSession { sink, stream })
+	}
+}
+
+// endregion: --- RealtimeClient
+
+// region:    --- RealtimeSession
+
+/// An open realtime session, to send the text and audio inputs and consume the server events.
+pub struct RealtimeSession {
+	sink: SplitSink<WsStream, Message>,
+	stream: SplitStream<WsStream>,
+}
+
+impl RealtimeSession {
+	/// Add a user text message to the conversation, and request a response.
+	pub async fn send_text(&mut self, text: &str) -> Result<()> {
+		self.send_event(json!({
+			"type": "conversation.item.create",
+			"item": {
+				"type": "message",
+				"role": "user",
+				"content": [{"type": "input_text", "text": text}]
+			}
+		}))
+		.await?;
+		self.send_event(json!({"type": "response.create"})).await
+	}
+
+	/// Append an audio chunk (in the session input audio format, PCM16 by default) to the input audio buffer.
+	///
+	/// Note: With the server voice activity detection (default), the server commits the buffer and responds.
+	pub async fn send_audio(&mut self, bytes: &[u8]) -> Result<()> {
+		let audio = general_purpose::STANDARD.encode(bytes);
+		self.send_event(json!({"type": "input_audio_buffer.append", "audio": audio}))
+			.await
+	}
+
+	/// Send a raw client event (e.g., `session.update`).
+	pub async fn send_event(&mut self, event: Value) -> Result<()> {
+		self.sink.send(Message::Text(event.to_string())).await.map_err(realtime_error)
+	}
+
+	/// The server events (the non text messages, e.g., pings, are skipped).
+	pub fn events(&mut self) -> impl Stream<Item = Result<RealtimeEvent>> + '_ {
+		(&mut self.stream).filter_map(|message| async move {
+			match message {
+				Ok(Message::Text(text)) => Some(RealtimeEvent::from_json_str(&text).map_err(Error::from)),
+				Ok(_) => None,
+				Err(err) => Some(Err(realtime_error(err))),
+			}
+		})
+	}
+}
+
+// endregion: --- RealtimeSession
+
+// region:    --- Support
+
+fn realtime_error(err: impl std::fmt::Display) -> Error {
+	Error::Realtime { cause: err.to_string() }
+}
+
+// endregion: --- Support
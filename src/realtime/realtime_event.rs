@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// The OpenAI Realtime API server events (the `type` field).
+///
+/// Note: The nested objects (session, item, response, ...) are kept as JSON values,
+///       and the event types not listed here are `RealtimeEvent::Other`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum RealtimeEvent {
+	// -- Session
+	#[serde(rename = "session.created")]
+	SessionCreated { session: Value },
+	#[serde(rename = "session.updated")]
+	SessionUpdated { session: Value },
+
+	// -- Conversation
+	#[serde(rename = "conversation.created")]
+	ConversationCreated { conversation: Value },
+	#[serde(rename = "conversation.item.created")]
+	ConversationItemCreated { item: Value },
+	#[serde(rename = "conversation.item.input_audio_transcription.completed")]
+	InputAudioTranscriptionCompleted { item_id: String, transcript: String },
+
+	// -- Input Audio Buffer
+	#[serde(rename = "input_audio_buffer.committed")]
+	InputAudioBufferCommitted { item_id: String },
+	#[serde(rename = "input_audio_buffer.cleared")]
+	InputAudioBufferCleared,
+	#[serde(rename = "input_audio_buffer.speech_started")]
+	InputAudioBufferSpeechStarted { audio_start_ms: u64, item_id: String },
+	#[serde(rename = "input_audio_buffer.speech_stopped")]
+	InputAudioBufferSpeechStopped { audio_end_ms: u64, item_id: String },
+
+	// -- Response
+	#[serde(rename = "response.created")]
+	ResponseCreated { response: Value },
+	#[serde(rename = "response.done")]
+	ResponseDone { response: Value },
+	#[serde(rename = "response.output_item.added")]
+	ResponseOutputItemAdded { item: Value },
+	#[serde(rename = "response.output_item.done")]
+	ResponseOutputItemDone { item: Value },
+	#[serde(rename = "response.text.delta")]
+	ResponseTextDelta { item_id: String, delta: String },
+	#[serde(rename = "response.text.done")]
+	ResponseTextDone { item_id: String, text: String },
+	/// The `delta` is the base64 encoded audio chunk.
+	#[serde(rename = "response.audio.delta")]
+	ResponseAudioDelta { item_id: String, delta: String },
+	#[serde(rename = "response.audio.done")]
+	ResponseAudioDone { item_id: String },
+	#[serde(rename = "response.audio_transcript.delta")]
+	ResponseAudioTranscriptDelta { item_id: String, delta: String },
+	#[serde(rename = "response.audio_transcript.done")]
+	ResponseAudioTranscriptDone { item_id: String, transcript: String },
+	#[serde(rename = "response.function_call_arguments.done")]
+	ResponseFunctionCallArgumentsDone {
+		call_id: String,
+		name: String,
+		arguments: String,
+	},
+
+	// -- Others
+	#[serde(rename = "rate_limits.updated")]
+	RateLimitsUpdated { rate_limits: Value },
+	/// The server error event (e.g., invalid client event).
+	#[serde(rename = "error")]
+	Error { error: Value },
+	/// Any other event type.
+	#[serde(other)]
+	Other,
+}
+
+impl RealtimeEvent {
+	/// Parse a server event JSON text message.
+	pub fn from_json_str(json: &str) -> serde_json::Result<Self> {
+		serde_json::from_str(json)
+	}
+}
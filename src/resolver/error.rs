@@ -24,7 +24,14 @@ pub enum Error {
 
 impl core::fmt::Display for Error {
 	fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::result::Result<(), core::fmt::Error> {
-		write!(fmt, "{self:?}")
+		match self {
+			Error::ApiKeyEnvNotFound { env_name } => write!(
+				fmt,
+				"API key environment variable '{env_name}' not found (set it with `export {env_name}=...`)"
+			),
+			Error::ResolverAuthDataNotSingleValue => write!(fmt, "Auth data is not a single value"),
+			Error::Custom(message) => write!(fmt, "{message}"),
+		}
 	}
 }
 
@@ -0,0 +1,7 @@
+// region:    --- Modules
+
+mod model_normalizer;
+
+pub use model_normalizer::*;
+
+// endregion: --- Modules
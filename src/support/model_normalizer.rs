@@ -0,0 +1,61 @@
+//! Mapping between the cross-adapter logical model names (e.g., `"sonnet-3-5"`) and the provider model names
+//! (e.g., `"claude-3-5-sonnet-20241022"`).
+
+use crate::adapter::AdapterKind;
+use crate::ModelIden;
+
+/// Normalize the logical model names to the provider model names (and back), from a built-in mapping table.
+///
+/// Note: `Client::exec_chat` (and the other model resolutions) resolve a logical name to the first adapter
+///       of the table having it (e.g., `"llama-3-1-8b"` resolves to Groq rather than Ollama).
+pub struct ModelNormalizer;
+
+impl ModelNormalizer {
+	/// Returns the provider model name of a logical name (or of an already provider name) for the adapter kind.
+	pub fn normalize(name: &str, adapter_kind: AdapterKind) -> Option<&'static str> {
+		MODEL_NAMES
+			.iter()
+			.find(|(logical_name, kind, provider_name)| {
+				*kind == adapter_kind && (*logical_name == name || *provider_name == name)
+			})
+			.map(|(_, _, provider_name)| *provider_name)
+	}
+
+	/// Returns the logical name of a provider model name (e.g., for display).
+	pub fn canonical_name(provider_name: &str, adapter_kind: AdapterKind) -> Option<&'static str> {
+		MODEL_NAMES
+			.iter()
+			.find(|(_, kind, name)| *kind == adapter_kind && *name == provider_name)
+			.map(|(logical_name, _, _)| *logical_name)
+	}
+
+	/// Returns the model iden of a logical name, with the first adapter kind of the table having it.
+	pub fn resolve(logical_name: &str) -> Option<ModelIden> {
+		MODEL_NAMES
+			.iter()
+			.find(|(name, _, _)| *name == logical_name)
+			.map(|(_, adapter_kind, provider_name)| ModelIden::new(*adapter_kind, *provider_name))
+	}
+}
+
+/// (logical_name, adapter_kind, provider_name)
+#[rustfmt::skip]
+const MODEL_NAMES: &[(&str, AdapterKind, &str)] = &[
+	("gpt-4o", AdapterKind::OpenAI, "gpt-4o"),
+	("gpt-4o-mini", AdapterKind::OpenAI, "gpt-4o-mini"),
+	("sonnet-3-5", AdapterKind::Anthropic, "claude-3-5-sonnet-20241022"),
+	("haiku-3-5", AdapterKind::Anthropic, "claude-3-5-haiku-20241022"),
+	("opus-3", AdapterKind::Anthropic, "claude-3-opus-20240229"),
+	("haiku-3", AdapterKind::Anthropic, "claude-3-haiku-20240307"),
+	("gemini-pro-1-5", AdapterKind::Gemini, "gemini-1.5-pro"),
+	("gemini-flash-1-5", AdapterKind::Gemini, "gemini-1.5-flash"),
+	("gemini-flash-8b-1-5", AdapterKind::Gemini, "gemini-1.5-flash-8b"),
+	("command-r-plus", AdapterKind::Cohere, "command-r-plus"),
+	("command-r", AdapterKind::Cohere, "command-r"),
+	("llama-3-1-70b", AdapterKind::Groq, "llama-3.1-70b-versatile"),
+	("llama-3-1-70b", AdapterKind::Ollama, "llama3.1:70b"),
+	("llama-3-1-8b", AdapterKind::Groq, "llama-3.1-8b-instant"),
+	("llama-3-1-8b", AdapterKind::Ollama, "llama3.1:8b"),
+	("deepseek-chat", AdapterKind::DeepSeek, "deepseek-chat"),
+	("grok-beta", AdapterKind::Xai, "grok-beta"),
+];
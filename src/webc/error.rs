@@ -17,6 +17,11 @@ pub enum Error {
 		body: String,
 	},
 
+	// -- Request
+	RequestCompression {
+		cause: String,
+	},
+
 	// -- Utils
 	#[from]
 	JsonValueExt(JsonValueExtError),
@@ -28,14 +33,48 @@ pub enum Error {
 	EventSourceClone(reqwest_eventsource::CannotCloneRequestError),
 }
 
+// region:    --- Error Properties
+
+impl Error {
+	/// Returns true for the timeout and connect errors, and the rate limit or server error statuses.
+	pub fn is_transient(&self) -> bool {
+		match self {
+			Error::ResponseFailedStatus { status, .. } => crate::error::is_transient_status(status.as_u16()),
+			Error::Reqwest(err) => err.is_timeout() || err.is_connect(),
+			_ => false,
+		}
+	}
+}
+
+// endregion: --- Error Properties
+
 // region:    --- Error Boilerplate
 
+// Note: The inner error text is in the `source()`, not in the Display.
 impl core::fmt::Display for Error {
 	fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::result::Result<(), core::fmt::Error> {
-		write!(fmt, "{self:?}")
+		match self {
+			Error::ResponseFailedNotJson { content_type } => {
+				write!(fmt, "Response is not JSON (content-type: '{content_type}')")
+			}
+			Error::ResponseFailedStatus { status, body } => write!(fmt, "Response failed with status {status}: {body}"),
+			Error::RequestCompression { cause } => write!(fmt, "Cannot gzip the request body: {cause}"),
+			Error::JsonValueExt(_) => write!(fmt, "JSON value error"),
+			Error::Reqwest(_) => write!(fmt, "HTTP request failed"),
+			Error::EventSourceClone(_) => write!(fmt, "Cannot clone the event source request"),
+		}
 	}
 }
 
-impl std::error::Error for Error {}
+impl std::error::Error for Error {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match self {
+			Error::JsonValueExt(err) => Some(err),
+			Error::Reqwest(err) => Some(err),
+			Error::EventSourceClone(err) => Some(err),
+			_ => None,
+		}
+	}
+}
 
 // endregion: --- Error Boilerplate
@@ -1,12 +1,18 @@
 use crate::webc::{Error, Result};
-use reqwest::header::HeaderMap;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use reqwest::header::{HeaderMap, CONTENT_ENCODING, CONTENT_TYPE};
+use reqwest::multipart::Form;
 use reqwest::{Method, RequestBuilder, StatusCode};
 use serde_json::Value;
+use std::io::Write;
 
 /// A simple reqwest client wrapper for this library.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct WebClient {
 	reqwest_client: reqwest::Client,
+	/// When true, the JSON request bodies are gzip compressed (see `new_req_builder`).
+	request_compression: bool,
 }
 
 // Implements Default
@@ -14,6 +20,7 @@ impl Default for WebClient {
 	fn default() -> Self {
 		WebClient {
 			reqwest_client: reqwest::Client::new(),
+			request_compression: false,
 		}
 	}
 }
@@ -22,7 +29,16 @@ impl Default for WebClient {
 
 impl WebClient {
 	pub fn from_reqwest_client(reqwest_client: reqwest::Client) -> Self {
-		WebClient { reqwest_client }
+		WebClient {
+			reqwest_client,
+			request_compression: false,
+		}
+	}
+
+	/// Gzip compress all of the JSON request bodies (with the `content-encoding: gzip` header).
+	pub fn with_request_compression(mut self, enabled: bool) -> Self {
+		self.request_compression = enabled;
+		self
 	}
 }
 
@@ -54,6 +70,57 @@ impl WebClient {
 		Ok(response)
 	}
 
+	/// Post a multipart form (e.g., for file uploads) and return the JSON response.
+	pub async fn do_post_multipart(&self, url: &str, headers: &[(String, String)], form: Form) -> Result<WebResponse> {
+		let mut reqwest_builder = self.reqwest_client.request(Method::POST, url);
+		for (k, v) in headers.iter() {
+			reqwest_builder = reqwest_builder.header(k, v);
+		}
+		let reqwest_res = reqwest_builder.multipart(form).send().await?;
+
+		let response = WebResponse::from_reqwest_response(reqwest_res).await?;
+
+		Ok(response)
+	}
+
+	/// Send a DELETE, with a JSON content if any, only checking the response status (the body is ignored).
+	pub async fn do_delete(&self, url: &str, headers: &[(String, String)], content: Option<Value>) -> Result<()> {
+		let mut reqwest_builder = self.reqwest_client.request(Method::DELETE, url);
+		for (k, v) in headers.iter() {
+			reqwest_builder = reqwest_builder.header(k, v);
+		}
+		if let Some(content) = content {
+			reqwest_builder = reqwest_builder.json(&content);
+		}
+		let reqwest_res = reqwest_builder.send().await?;
+
+		let status = reqwest_res.status();
+		if !status.is_success() {
+			let body = reqwest_res.text().await?;
+			return Err(Error::ResponseFailedStatus { status, body });
+		}
+
+		Ok(())
+	}
+
+	/// Get the raw text content of a response (e.g., for JSONL file contents).
+	/// Note: Unlike `do_get`, the content type is not checked.
+	pub async fn do_get_text(&self, url: &str, headers: &[(String, String)]) -> Result<String> {
+		let mut reqwest_builder = self.reqwest_client.request(Method::GET, url);
+		for (k, v) in headers.iter() {
+			reqwest_builder = reqwest_builder.header(k, v);
+		}
+		let reqwest_res = reqwest_builder.send().await?;
+
+		let status = reqwest_res.status();
+		let body = reqwest_res.text().await?;
+		if !status.is_success() {
+			return Err(Error::ResponseFailedStatus { status, body });
+		}
+
+		Ok(body)
+	}
+
 	pub fn new_req_builder(&self, url: &str, headers: &[(String, String)], content: Value) -> Result<RequestBuilder> {
 		let method = Method::POST;
 
@@ -61,13 +128,43 @@ impl WebClient {
 		for (k, v) in headers.iter() {
 			reqwest_builder = reqwest_builder.header(k, v);
 		}
-		reqwest_builder = reqwest_builder.json(&content);
+
+		// Note: A `content-encoding: gzip` request header (e.g., from the `RequestCompressor` middleware)
+		//       enables the compression for this request only.
+		let has_gzip_header = headers
+			.iter()
+			.any(|(k, v)| k.eq_ignore_ascii_case(CONTENT_ENCODING.as_str()) && v.eq_ignore_ascii_case("gzip"));
+		if self.request_compression || has_gzip_header {
+			if !has_gzip_header {
+				reqwest_builder = reqwest_builder.header(CONTENT_ENCODING, "gzip");
+			}
+			reqwest_builder = reqwest_builder
+				.header(CONTENT_TYPE, "application/json")
+				.body(gzip_json(&content)?);
+		} else {
+			reqwest_builder = reqwest_builder.json(&content);
+		}
 
 		Ok(reqwest_builder)
 	}
 }
 // endregion: --- Web Method Implementation
 
+// region:    --- Support
+
+fn gzip_json(content: &Value) -> Result<Vec<u8>> {
+	let json_bytes = serde_json::to_vec(content).map_err(|err| Error::RequestCompression { cause: err.to_string() })?;
+	let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+	encoder
+		.write_all(&json_bytes)
+		.map_err(|err| Error::RequestCompression { cause: err.to_string() })?;
+	encoder
+		.finish()
+		.map_err(|err| Error::RequestCompression { cause: err.to_string() })
+}
+
+// endregion: --- Support
+
 // region:    --- WebResponse
 
 // NOTE: This is not a non-streaming web response (assumed to be JSON for this library).
@@ -78,6 +175,8 @@ pub struct WebResponse {
 	#[allow(unused)]
 	pub status: StatusCode,
 	pub body: Value,
+	/// The eventual `x-request-id` (OpenAI, Groq, ...) or `request-id` (Anthropic) response header.
+	pub request_id: Option<String>,
 }
 
 impl WebResponse {
@@ -97,6 +196,12 @@ impl WebResponse {
 		let headers = res.headers_mut().drain().filter_map(|(n, v)| n.map(|n| (n, v)));
 		let header_map = HeaderMap::from_iter(headers);
 
+		// Capture the request id
+		let request_id = ["x-request-id", "request-id"]
+			.iter()
+			.find_map(|name| header_map.get(*name).and_then(|v| v.to_str().ok()))
+			.map(|v| v.to_string());
+
 		// Capture the body
 		let ct = header_map.get("content-type").and_then(|v| v.to_str().ok()).unwrap_or_default();
 		let body = if ct.starts_with("application/json") {
@@ -107,7 +212,11 @@ impl WebResponse {
 			});
 		};
 
-		Ok(WebResponse { status, body })
+		Ok(WebResponse {
+			status,
+			body,
+			request_id,
+		})
 	}
 }
 
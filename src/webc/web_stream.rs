@@ -239,26 +239,22 @@ fn process_buff_string_delimited(
 	let parts = buff_string.split(delimiter);
 
 	for part in parts {
-		// If we already have a candidate, the candidate becomes the message
+		// If we already have a candidate, the candidate becomes the message (skipped if empty)
 		if let Some(candidate_message) = candidate_message.take() {
-			// If candidate is empty, we skip
 			if !candidate_message.is_empty() {
-				let message = candidate_message.to_string();
 				if first_message.is_none() {
-					first_message = Some(message);
+					first_message = Some(candidate_message);
 				} else {
-					next_messages.get_or_insert_with(Vec::new).push(message);
+					next_messages.get_or_insert_with(Vec::new).push(candidate_message);
 				}
-			} else {
-				continue;
 			}
+		}
+
+		// And then, this part becomes the candidate (prefixed by the eventual partial message for the first part)
+		if let Some(partial) = partial_message.take() {
+			candidate_message = Some(format!("{partial}{part}"));
 		} else {
-			// And then, this part becomes the candidate
-			if let Some(partial) = partial_message.take() {
-				candidate_message = Some(format!("{partial}{part}"));
-			} else {
-				candidate_message = Some(part.to_string());
-			}
+			candidate_message = Some(part.to_string());
 		}
 	}
 
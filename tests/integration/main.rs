@@ -0,0 +1,22 @@
+//! Live API integration tests, one module per adapter (cheap/fast model for each).
+//!
+//! Gated by the `integration-tests` feature, and require the provider API keys. e.g., CI step:
+//!
+//! ```sh
+//! if [ -n "$OPENAI_API_KEY" ] && [ -n "$ANTHROPIC_API_KEY" ] && [ -n "$GEMINI_API_KEY" ] && [ -n "$GROQ_API_KEY" ]; then
+//!   cargo test --features integration-tests --test integration
+//! else
+//!   echo "Skipping the integration tests (missing API keys)"
+//! fi
+//! ```
+
+#![cfg(feature = "integration-tests")]
+
+#[path = "../support/mod.rs"]
+mod support;
+
+mod test_anthropic;
+mod test_gemini;
+mod test_groq;
+mod test_helpers;
+mod test_openai;
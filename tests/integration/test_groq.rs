@@ -0,0 +1,26 @@
+use crate::support::Result;
+use crate::test_helpers;
+
+const MODEL: &str = "llama3-8b-8192";
+// Note: The groq base llama3 models are not reliable for tool calls, so using the "tool-use" version.
+const MODEL_TOOL: &str = "llama3-groq-8b-8192-tool-use-preview";
+
+#[tokio::test]
+async fn test_groq_chat_ok() -> Result<()> {
+	test_helpers::chat_simple_question_ok(MODEL).await
+}
+
+#[tokio::test]
+async fn test_groq_chat_stream_ok() -> Result<()> {
+	test_helpers::chat_stream_ok(MODEL).await
+}
+
+#[tokio::test]
+async fn test_groq_tool_ok() -> Result<()> {
+	test_helpers::tool_ok(MODEL_TOOL).await
+}
+
+#[tokio::test]
+async fn test_groq_invalid_api_key_err() -> Result<()> {
+	test_helpers::invalid_api_key_err(MODEL).await
+}
@@ -0,0 +1,60 @@
+use crate::support::{common_tests, Result};
+use genai::chat::ChatRequest;
+use genai::resolver::{AuthData, AuthResolver};
+use genai::{Client, Error, ModelIden};
+
+// region:    --- Fixtures
+
+pub fn simple_question() -> ChatRequest {
+	ChatRequest::from_user("What is 2+2?")
+}
+
+// endregion: --- Fixtures
+
+// region:    --- Tests
+
+pub async fn chat_simple_question_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+
+	// -- Exec
+	let chat_res = client.exec_chat(model, simple_question(), None).await?;
+
+	// -- Check
+	let content = chat_res.content_text_as_str().ok_or("Should have content")?;
+	assert!(content.contains('4'), "Should contain '4', but was: {content}");
+
+	Ok(())
+}
+
+pub async fn chat_stream_ok(model: &str) -> Result<()> {
+	common_tests::common_test_chat_stream_simple_ok(model).await
+}
+
+pub async fn tool_ok(model: &str) -> Result<()> {
+	common_tests::common_test_tool_simple_ok(model, true).await
+}
+
+pub async fn invalid_api_key_err(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let auth_resolver =
+		AuthResolver::from_resolver_fn(|_model_iden: ModelIden| Ok(Some(AuthData::from_single("invalid-api-key"))));
+	let client = Client::builder().with_auth_resolver(auth_resolver).build();
+
+	// -- Exec
+	let res = client.exec_chat(model, simple_question(), None).await;
+
+	// -- Check
+	let Err(Error::ApiError { api_error, .. }) = res else {
+		return Err(format!("Should have been an Error::ApiError, but was: {res:?}").into());
+	};
+	assert!(
+		(400..500).contains(&api_error.status_code),
+		"Should be a 4xx status, but was: {}",
+		api_error.status_code
+	);
+
+	Ok(())
+}
+
+// endregion: --- Tests
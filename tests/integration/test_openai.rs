@@ -0,0 +1,24 @@
+use crate::support::Result;
+use crate::test_helpers;
+
+const MODEL: &str = "gpt-4o-mini";
+
+#[tokio::test]
+async fn test_openai_chat_ok() -> Result<()> {
+	test_helpers::chat_simple_question_ok(MODEL).await
+}
+
+#[tokio::test]
+async fn test_openai_chat_stream_ok() -> Result<()> {
+	test_helpers::chat_stream_ok(MODEL).await
+}
+
+#[tokio::test]
+async fn test_openai_tool_ok() -> Result<()> {
+	test_helpers::tool_ok(MODEL).await
+}
+
+#[tokio::test]
+async fn test_openai_invalid_api_key_err() -> Result<()> {
+	test_helpers::invalid_api_key_err(MODEL).await
+}
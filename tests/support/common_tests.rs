@@ -227,6 +227,58 @@ pub async fn common_test_chat_stop_sequences_ok(model: &str) -> Result<()> {
 	Ok(())
 }
 
+pub async fn common_test_chat_request_id_ok(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let chat_req = seed_chat_req_simple();
+
+	// -- Exec
+	let chat_res = client.exec_chat(model, chat_req, None).await?;
+
+	// -- Check
+	let request_id = chat_res.request_id().ok_or("Should have a request_id")?;
+	assert!(!request_id.is_empty(), "request_id should not be empty");
+	assert!(chat_res.client_request_id.is_some(), "Should have a client_request_id");
+
+	Ok(())
+}
+
+pub async fn common_test_chat_extra_params_ok(model: &str, extra_params: Vec<(&str, Value)>) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let chat_req = seed_chat_req_simple();
+	let chat_options = extra_params.into_iter().fold(ChatOptions::default(), |options, (key, value)| {
+		options.with_extra_param(key, value)
+	});
+
+	// -- Exec
+	let chat_res = client.exec_chat(model, chat_req, Some(&chat_options)).await?;
+
+	// -- Check
+	assert!(
+		!chat_res.content_text_as_str().unwrap_or("").is_empty(),
+		"Content should not be empty"
+	);
+
+	Ok(())
+}
+
+/// Check that the extra params are in the payload (an unknown param should be rejected by the provider).
+pub async fn common_test_chat_extra_params_unknown_err(model: &str) -> Result<()> {
+	// -- Setup & Fixtures
+	let client = Client::default();
+	let chat_req = seed_chat_req_simple();
+	let chat_options = ChatOptions::default().with_extra_param("genai_unknown_param", 42);
+
+	// -- Exec
+	let res = client.exec_chat(model, chat_req, Some(&chat_options)).await;
+
+	// -- Check
+	assert!(res.is_err(), "Should have failed with the unknown extra param");
+
+	Ok(())
+}
+
 // endregion: --- Chat
 
 // region:    --- Chat Stream Tests
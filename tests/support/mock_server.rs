@@ -0,0 +1,397 @@
+//! A minimal local HTTP server returning canned JSON responses (OpenAI format),
+//! to test client flows without network access.
+
+use super::Result;
+use genai::adapter::AdapterKind;
+use genai::resolver::{AuthData, Endpoint, ServiceTargetResolver};
+use genai::{Client, ClientBuilder, ModelIden, ServiceTarget};
+use serde_json::{json, Value};
+use std::collections::VecDeque;
+use std::io::Read;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+pub struct MockServer {
+	base_url: String,
+	requests: Arc<Mutex<Vec<MockRequest>>>,
+}
+
+impl MockServer {
+	/// Start the server, which will answer the successive requests with the given responses
+	/// (the last response is repeated when the list is exhausted).
+	pub async fn start(responses: Vec<Value>) -> Result<Self> {
+		let listener = TcpListener::bind("127.0.0.1:0").await?;
+		let base_url = format!("http://{}/v1/", listener.local_addr()?);
+		let responses: Vec<MockResponse> = responses.into_iter().map(MockResponse::Json).collect();
+		Self::start_with_responses(listener, base_url, responses).await
+	}
+
+	/// Same as `start`, with the given response headers (e.g., `x-request-id`) added to all of the responses.
+	pub async fn start_with_headers(responses: Vec<Value>, headers: &[(&str, &str)]) -> Result<Self> {
+		let listener = TcpListener::bind("127.0.0.1:0").await?;
+		let base_url = format!("http://{}/v1/", listener.local_addr()?);
+		let headers: String = headers.iter().map(|(name, value)| format!("{name}: {value}\r\n")).collect();
+		let responses: Vec<MockResponse> = responses
+			.into_iter()
+			.map(|response| MockResponse::JsonWithHeaders(response, headers.clone()))
+			.collect();
+		Self::start_with_responses(listener, base_url, responses).await
+	}
+
+	/// Start a server which answers the successive requests with the given JSON responses, and then all of
+	/// the next requests with the given text body (e.g., a JSONL file content download).
+	pub async fn start_with_text(responses: Vec<Value>, text: impl Into<String>) -> Result<Self> {
+		let listener = TcpListener::bind("127.0.0.1:0").await?;
+		let base_url = format!("http://{}/v1/", listener.local_addr()?);
+		let mut responses: Vec<MockResponse> = responses.into_iter().map(MockResponse::Json).collect();
+		responses.push(MockResponse::Text(text.into()));
+		Self::start_with_responses(listener, base_url, responses).await
+	}
+
+	/// Start a server which answers with the event stream headers, but never sends any event.
+	pub async fn start_stalled_stream() -> Result<Self> {
+		let listener = TcpListener::bind("127.0.0.1:0").await?;
+		let base_url = format!("http://{}/v1/", listener.local_addr()?);
+		Self::start_with_responses(listener, base_url, vec![MockResponse::StalledStream]).await
+	}
+
+	/// Start a server which answers with the given newline delimited JSON lines (e.g., a Cohere chat stream).
+	pub async fn start_ndjson_stream(lines: Vec<Value>) -> Result<Self> {
+		let listener = TcpListener::bind("127.0.0.1:0").await?;
+		let base_url = format!("http://{}/v1/", listener.local_addr()?);
+		let body = lines.iter().map(|line| format!("{line}\n")).collect::<String>();
+		Self::start_with_responses(listener, base_url, vec![MockResponse::NdJson(body)]).await
+	}
+
+	/// Same as `start_ndjson_stream`, but the body is only sent `delay` after the response headers
+	/// (e.g., a model slow to start generating).
+	pub async fn start_delayed_ndjson_stream(lines: Vec<Value>, delay: Duration) -> Result<Self> {
+		let listener = TcpListener::bind("127.0.0.1:0").await?;
+		let base_url = format!("http://{}/v1/", listener.local_addr()?);
+		let body = lines.iter().map(|line| format!("{line}\n")).collect::<String>();
+		Self::start_with_responses(listener, base_url, vec![MockResponse::DelayedNdJson(delay, body)]).await
+	}
+
+	/// Start a server which answers with the given server-sent events (`data: {json}`), followed by `data: [DONE]`
+	/// (e.g., an OpenAI compatible chat stream).
+	pub async fn start_sse_stream(events: Vec<Value>) -> Result<Self> {
+		let listener = TcpListener::bind("127.0.0.1:0").await?;
+		let base_url = format!("http://{}/v1/", listener.local_addr()?);
+		let mut body = events.iter().map(|event| format!("data: {event}\n\n")).collect::<String>();
+		body.push_str("data: [DONE]\n\n");
+		Self::start_with_responses(listener, base_url, vec![MockResponse::Sse(body)]).await
+	}
+
+	/// Start a server which answers with the given body chunks, each written separately (e.g., a Gemini JSON array stream).
+	pub async fn start_chunked_stream(chunks: Vec<String>) -> Result<Self> {
+		let listener = TcpListener::bind("127.0.0.1:0").await?;
+		let base_url = format!("http://{}/v1/", listener.local_addr()?);
+		Self::start_with_responses(listener, base_url, vec![MockResponse::Chunked(chunks)]).await
+	}
+
+	/// Start a server which answers all of the requests with the given error status and JSON body.
+	pub async fn start_error(status_code: u16, body: Value) -> Result<Self> {
+		let listener = TcpListener::bind("127.0.0.1:0").await?;
+		let base_url = format!("http://{}/v1/", listener.local_addr()?);
+		Self::start_with_responses(listener, base_url, vec![MockResponse::Error(status_code, body)]).await
+	}
+
+	/// Same as `start`, but each response is only sent `delay` after the request (e.g., to have concurrent requests).
+	pub async fn start_delayed(responses: Vec<Value>, delay: Duration) -> Result<Self> {
+		let listener = TcpListener::bind("127.0.0.1:0").await?;
+		let base_url = format!("http://{}/v1/", listener.local_addr()?);
+		let responses: Vec<MockResponse> = responses
+			.into_iter()
+			.map(|response| MockResponse::Delayed(delay, Box::new(MockResponse::Json(response))))
+			.collect();
+		Self::start_with_responses(listener, base_url, responses).await
+	}
+
+	/// Same as `start_error`, but each response is only sent `delay` after the request.
+	pub async fn start_delayed_error(status_code: u16, body: Value, delay: Duration) -> Result<Self> {
+		let listener = TcpListener::bind("127.0.0.1:0").await?;
+		let base_url = format!("http://{}/v1/", listener.local_addr()?);
+		let response = MockResponse::Delayed(delay, Box::new(MockResponse::Error(status_code, body)));
+		Self::start_with_responses(listener, base_url, vec![response]).await
+	}
+
+	/// Start a server which never answers (e.g., to test the request timeouts).
+	pub async fn start_stalled() -> Result<Self> {
+		let listener = TcpListener::bind("127.0.0.1:0").await?;
+		let base_url = format!("http://{}/v1/", listener.local_addr()?);
+		Self::start_with_responses(listener, base_url, vec![MockResponse::Stalled]).await
+	}
+
+	async fn start_with_responses(
+		listener: TcpListener,
+		base_url: String,
+		responses: Vec<MockResponse>,
+	) -> Result<Self> {
+		let requests: Arc<Mutex<Vec<MockRequest>>> = Default::default();
+		let responses = Arc::new(Mutex::new(VecDeque::from(responses)));
+		let server_requests = requests.clone();
+		tokio::spawn(async move {
+			while let Ok((stream, _)) = listener.accept().await {
+				let response = {
+					let mut responses = responses.lock().unwrap();
+					if responses.len() > 1 {
+						responses.pop_front()
+					} else {
+						responses.front().cloned()
+					}
+				}
+				.unwrap_or(MockResponse::Json(Value::Null));
+				let requests = server_requests.clone();
+				tokio::spawn(async move {
+					let _ = handle_connection(stream, response, requests).await;
+				});
+			}
+		});
+
+		Ok(Self { base_url, requests })
+	}
+
+	/// A client resolving all of the models to the OpenAI adapter pointing to this server.
+	pub fn client(&self) -> Client {
+		self.client_for_adapter(AdapterKind::OpenAI)
+	}
+
+	/// A client resolving all of the models to the given adapter pointing to this server.
+	pub fn client_for_adapter(&self, adapter_kind: AdapterKind) -> Client {
+		self.client_builder_for_adapter(adapter_kind).build()
+	}
+
+	/// Same as `client_for_adapter`, but returning the builder (e.g., to add middlewares).
+	pub fn client_builder_for_adapter(&self, adapter_kind: AdapterKind) -> ClientBuilder {
+		let base_url = self.base_url.clone();
+		let target_resolver = ServiceTargetResolver::from_resolver_fn(
+			move |service_target: ServiceTarget| -> core::result::Result<ServiceTarget, genai::resolver::Error> {
+				let ServiceTarget { model, .. } = service_target;
+				Ok(ServiceTarget {
+					endpoint: Endpoint::from_owned(base_url.clone()),
+					auth: AuthData::from_single("mock-api-key"),
+					model: ModelIden::new(adapter_kind, model.model_name),
+				})
+			},
+		);
+		Client::builder().with_service_target_resolver(target_resolver)
+	}
+
+	/// The JSON bodies of the requests received so far (null if not JSON).
+	pub fn requests(&self) -> Vec<Value> {
+		self.requests.lock().unwrap().iter().map(|req| req.body.clone()).collect()
+	}
+
+	/// The raw (decompressed) bodies of the requests received so far (e.g., multipart uploads).
+	pub fn request_raw_bodies(&self) -> Vec<String> {
+		self.requests.lock().unwrap().iter().map(|req| req.raw_body.clone()).collect()
+	}
+
+	/// The paths of the requests received so far.
+	pub fn request_paths(&self) -> Vec<String> {
+		self.requests.lock().unwrap().iter().map(|req| req.path.clone()).collect()
+	}
+
+	/// The heads (request line and headers, lowercased) of the requests received so far.
+	pub fn request_heads(&self) -> Vec<String> {
+		self.requests.lock().unwrap().iter().map(|req| req.head.clone()).collect()
+	}
+
+	pub fn base_url(&self) -> &str {
+		&self.base_url
+	}
+}
+
+/// Build an OpenAI chat completion response body with the given assistant text.
+pub fn mock_openai_chat_response(content: &str) -> Value {
+	json!({
+		"id": "chatcmpl-mock",
+		"object": "chat.completion",
+		"model": "mock-model",
+		"choices": [{
+			"index": 0,
+			"message": { "role": "assistant", "content": content },
+			"finish_reason": "stop"
+		}],
+		"usage": { "prompt_tokens": 10, "completion_tokens": 10, "total_tokens": 20 }
+	})
+}
+
+// region:    --- Support
+
+/// A received request.
+struct MockRequest {
+	path: String,
+	/// The request line and headers, lowercased.
+	head: String,
+	raw_body: String,
+	/// The JSON body (null if not JSON).
+	body: Value,
+}
+
+#[derive(Clone)]
+enum MockResponse {
+	Json(Value),
+	/// The JSON response with the extra header lines (each ending with `\r\n`).
+	JsonWithHeaders(Value, String),
+	Text(String),
+	StalledStream,
+	Stalled,
+	NdJson(String),
+	DelayedNdJson(Duration, String),
+	Sse(String),
+	Chunked(Vec<String>),
+	Error(u16, Value),
+	/// The response, sent after the delay.
+	Delayed(Duration, Box<MockResponse>),
+}
+
+async fn handle_connection(
+	mut stream: TcpStream,
+	response: MockResponse,
+	requests: Arc<Mutex<Vec<MockRequest>>>,
+) -> Result<()> {
+	// -- Read the head
+	let mut data: Vec<u8> = Vec::new();
+	let mut buf = [0u8; 4096];
+	let head_end = loop {
+		let n = stream.read(&mut buf).await?;
+		if n == 0 {
+			return Ok(());
+		}
+		data.extend_from_slice(&buf[..n]);
+		if let Some(pos) = data.windows(4).position(|w| w == b"\r\n\r\n") {
+			break pos + 4;
+		}
+	};
+
+	// -- Read the body
+	let head = String::from_utf8_lossy(&data[..head_end]).to_lowercase();
+	let content_length = head
+		.lines()
+		.find_map(|line| line.strip_prefix("content-length:"))
+		.and_then(|v| v.trim().parse::<usize>().ok())
+		.unwrap_or(0);
+	while data.len() < head_end + content_length {
+		let n = stream.read(&mut buf).await?;
+		if n == 0 {
+			break;
+		}
+		data.extend_from_slice(&buf[..n]);
+	}
+	let path = head.split_whitespace().nth(1).unwrap_or_default().to_string();
+	let mut body_bytes = data[head_end..].to_vec();
+	if head.contains("content-encoding: gzip") {
+		let mut decoded = Vec::new();
+		flate2::read::GzDecoder::new(&body_bytes[..]).read_to_end(&mut decoded)?;
+		body_bytes = decoded;
+	}
+	let body: Value = serde_json::from_slice(&body_bytes).unwrap_or_default();
+	let raw_body = String::from_utf8_lossy(&body_bytes).to_string();
+	requests.lock().unwrap().push(MockRequest {
+		path,
+		head,
+		raw_body,
+		body,
+	});
+
+	// -- Write the response
+	let response = match response {
+		MockResponse::Delayed(delay, response) => {
+			tokio::time::sleep(delay).await;
+			*response
+		}
+		response => response,
+	};
+	match response {
+		MockResponse::Json(response) => {
+			let body = response.to_string();
+			let res = format!(
+				"HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+				body.len()
+			);
+			stream.write_all(res.as_bytes()).await?;
+			stream.shutdown().await?;
+		}
+		MockResponse::JsonWithHeaders(response, headers) => {
+			let body = response.to_string();
+			let res = format!(
+				"HTTP/1.1 200 OK\r\ncontent-type: application/json\r\n{headers}content-length: {}\r\nconnection: close\r\n\r\n{body}",
+				body.len()
+			);
+			stream.write_all(res.as_bytes()).await?;
+			stream.shutdown().await?;
+		}
+		MockResponse::Text(body) => {
+			let res = format!(
+				"HTTP/1.1 200 OK\r\ncontent-type: text/plain\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+				body.len()
+			);
+			stream.write_all(res.as_bytes()).await?;
+			stream.shutdown().await?;
+		}
+		MockResponse::Error(status_code, body) => {
+			let body = body.to_string();
+			let res = format!(
+				"HTTP/1.1 {status_code} Error\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+				body.len()
+			);
+			stream.write_all(res.as_bytes()).await?;
+			stream.shutdown().await?;
+		}
+		MockResponse::NdJson(body) => {
+			let res = format!(
+				"HTTP/1.1 200 OK\r\ncontent-type: application/x-ndjson\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+				body.len()
+			);
+			stream.write_all(res.as_bytes()).await?;
+			stream.shutdown().await?;
+		}
+		MockResponse::DelayedNdJson(delay, body) => {
+			// Note: Without content-length, the body ends with the connection.
+			let res = "HTTP/1.1 200 OK\r\ncontent-type: application/x-ndjson\r\nconnection: close\r\n\r\n";
+			stream.write_all(res.as_bytes()).await?;
+			stream.flush().await?;
+			tokio::time::sleep(delay).await;
+			stream.write_all(body.as_bytes()).await?;
+			stream.shutdown().await?;
+		}
+		MockResponse::Sse(body) => {
+			let res = format!(
+				"HTTP/1.1 200 OK\r\ncontent-type: text/event-stream\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+				body.len()
+			);
+			stream.write_all(res.as_bytes()).await?;
+			stream.shutdown().await?;
+		}
+		MockResponse::Chunked(chunks) => {
+			// Note: Without content-length, the body ends with the connection.
+			let res = "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\nconnection: close\r\n\r\n";
+			stream.write_all(res.as_bytes()).await?;
+			for chunk in chunks {
+				stream.write_all(chunk.as_bytes()).await?;
+				stream.flush().await?;
+				// so that each chunk is received separately
+				tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+			}
+			stream.shutdown().await?;
+		}
+		MockResponse::Stalled => {
+			tokio::time::sleep(std::time::Duration::from_secs(60)).await;
+		}
+		MockResponse::StalledStream => {
+			let res = "HTTP/1.1 200 OK\r\ncontent-type: text/event-stream\r\ncache-control: no-cache\r\n\r\n";
+			stream.write_all(res.as_bytes()).await?;
+			stream.flush().await?;
+			// keep the connection open without sending any event
+			tokio::time::sleep(std::time::Duration::from_secs(60)).await;
+		}
+		MockResponse::Delayed(..) => unreachable!("The delayed response is unwrapped above"),
+	}
+
+	Ok(())
+}
+
+// endregion: --- Support
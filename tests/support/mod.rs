@@ -8,10 +8,12 @@
 mod asserts;
 mod data;
 mod helpers;
+mod mock_server;
 mod seeders;
 
 pub use asserts::*;
 pub use helpers::*;
+pub use mock_server::*;
 pub use seeders::*;
 
 pub mod common_tests;
@@ -0,0 +1,102 @@
+mod support;
+
+use crate::support::{MockServer, Result};
+use genai::adapter::{AdapterConfig, AdapterKind, OpenAIResponseSchema};
+use genai::chat::ChatRequest;
+use genai::Error;
+use serde_json::json;
+
+fn delta_schema() -> OpenAIResponseSchema {
+	OpenAIResponseSchema {
+		finish_reason_path: "/choices/0/delta/finish_reason",
+		content_path: "/choices/0/delta/content",
+		..OpenAIResponseSchema::OPENAI
+	}
+}
+
+#[tokio::test]
+async fn test_adapter_config_response_schema_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let response = json!({
+		"id": "chatcmpl-mock",
+		"choices": [{
+			"index": 0,
+			"delta": { "role": "assistant", "content": "Hello from delta", "finish_reason": "length" }
+		}],
+		"usage": { "prompt_tokens": 10, "completion_tokens": 4, "total_tokens": 14 }
+	});
+	let server = MockServer::start(vec![response.clone(), response]).await?;
+	let delta_client = server
+		.client_builder_for_adapter(AdapterKind::DeepSeek)
+		.with_adapter_config(AdapterConfig::new(AdapterKind::DeepSeek).with_response_schema(delta_schema())?)
+		.build();
+	let default_client = server.client_for_adapter(AdapterKind::DeepSeek);
+
+	// -- Exec
+	let delta_res = delta_client
+		.exec_chat("deepseek-chat", ChatRequest::from_user("Hi"), None)
+		.await?;
+	let default_res = default_client
+		.exec_chat("deepseek-chat", ChatRequest::from_user("Hi"), None)
+		.await?;
+
+	// -- Check
+	assert_eq!(delta_res.content_text_as_str(), Some("Hello from delta"));
+	assert_eq!(delta_res.finish_reason(), Some("length"));
+	assert_eq!(delta_res.usage.total_tokens, Some(14));
+	// Note: The schema is per client, so the other client uses the OpenAI schema.
+	assert_eq!(default_res.content_text_as_str(), None);
+	assert_eq!(default_res.finish_reason(), None);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_adapter_config_response_schema_replaced_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![json!({
+		"id": "chatcmpl-mock",
+		"choices": [{
+			"index": 0,
+			"message": { "role": "assistant", "content": "Hello" },
+			"finish_reason": "stop"
+		}]
+	})])
+	.await?;
+	let client = server
+		.client_builder_for_adapter(AdapterKind::Xai)
+		.with_adapter_config(AdapterConfig::new(AdapterKind::Xai).with_response_schema(delta_schema())?)
+		.with_adapter_config(AdapterConfig::new(AdapterKind::Xai).with_response_schema(OpenAIResponseSchema::OPENAI)?)
+		.build();
+
+	// -- Exec
+	let res = client.exec_chat("grok-beta", ChatRequest::from_user("Hi"), None).await?;
+
+	// -- Check
+	assert_eq!(res.content_text_as_str(), Some("Hello"));
+	assert_eq!(res.finish_reason(), Some("stop"));
+
+	Ok(())
+}
+
+#[test]
+fn test_adapter_config_response_schema_not_openai_err() -> Result<()> {
+	// -- Exec
+	let config = AdapterConfig::new(AdapterKind::Anthropic);
+	let res = config.with_response_schema(OpenAIResponseSchema::OPENAI);
+
+	// -- Check
+	assert!(config.response_schema().is_none());
+	assert!(
+		matches!(
+			res,
+			Err(Error::AdapterConfigInvalid {
+				adapter_kind: AdapterKind::Anthropic,
+				..
+			})
+		),
+		"{res:?}"
+	);
+
+	Ok(())
+}
@@ -0,0 +1,123 @@
+mod support;
+
+use crate::support::{MockServer, Result};
+use genai::adapter::{AdapterError, AdapterKind, AnthropicError, GeminiError, OpenAIError};
+use genai::chat::ChatRequest;
+use genai::Error;
+use serde_json::json;
+
+#[tokio::test]
+async fn test_adapter_error_openai_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start_error(
+		429,
+		json!({"error": {
+			"message": "You exceeded your current quota",
+			"type": "insufficient_quota",
+			"param": null,
+			"code": "insufficient_quota"
+		}}),
+	)
+	.await?;
+	let client = server.client();
+
+	// -- Exec
+	let err = client
+		.exec_chat("gpt-4o-mini", ChatRequest::from_user("Hi"), None)
+		.await
+		.err()
+		.ok_or("Should have failed")?;
+
+	// -- Check
+	assert!(
+		matches!(
+			err,
+			Error::ApiError {
+				adapter_error: Some(_),
+				..
+			}
+		),
+		"{err:?}"
+	);
+	let openai_error = err.downcast_adapter_error::<OpenAIError>().ok_or("Should be an OpenAIError")?;
+	assert_eq!(openai_error.code.as_deref(), Some("insufficient_quota"));
+	assert_eq!(openai_error.type_, "insufficient_quota");
+	assert!(err.downcast_adapter_error::<AnthropicError>().is_none());
+	assert_eq!(err.api_error().map(|api_error| api_error.status_code), Some(429));
+	assert!(err.is_transient());
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_adapter_error_anthropic_gemini_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let anthropic_server = MockServer::start_error(
+		400,
+		json!({"type": "error", "error": {"type": "invalid_request_error", "message": "max_tokens: Field required"}}),
+	)
+	.await?;
+	let gemini_server = MockServer::start_error(
+		429,
+		json!([{"error": {"code": 429, "message": "Resource has been exhausted", "status": "RESOURCE_EXHAUSTED"}}]),
+	)
+	.await?;
+
+	// -- Exec
+	let anthropic_err = anthropic_server
+		.client_for_adapter(AdapterKind::Anthropic)
+		.exec_chat("claude-3-haiku-20240307", ChatRequest::from_user("Hi"), None)
+		.await
+		.err()
+		.ok_or("Should have failed")?;
+	let gemini_err = gemini_server
+		.client_for_adapter(AdapterKind::Gemini)
+		.exec_chat("gemini-1.5-flash", ChatRequest::from_user("Hi"), None)
+		.await
+		.err()
+		.ok_or("Should have failed")?;
+
+	// -- Check
+	let anthropic_error = anthropic_err
+		.downcast_adapter_error::<AnthropicError>()
+		.ok_or("Should be an AnthropicError")?;
+	assert_eq!(anthropic_error.error_type(), "invalid_request_error");
+	assert!(!anthropic_err.is_transient());
+	let gemini_error = gemini_err
+		.downcast_adapter_error::<GeminiError>()
+		.ok_or("Should be a GeminiError")?;
+	assert_eq!(gemini_error.code, 429);
+	assert_eq!(gemini_error.status, "RESOURCE_EXHAUSTED");
+	assert_eq!(gemini_error.message(), "Resource has been exhausted");
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_adapter_error_unstructured_body_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start_error(502, json!("Bad Gateway")).await?;
+
+	// -- Exec
+	let err = server
+		.client()
+		.exec_chat("gpt-4o-mini", ChatRequest::from_user("Hi"), None)
+		.await
+		.err()
+		.ok_or("Should have failed")?;
+
+	// -- Check
+	assert!(
+		matches!(
+			err,
+			Error::ApiError {
+				adapter_error: None,
+				..
+			}
+		),
+		"{err:?}"
+	);
+	assert!(err.downcast_adapter_error::<OpenAIError>().is_none());
+
+	Ok(())
+}
@@ -0,0 +1,107 @@
+mod support;
+
+use crate::support::{mock_openai_chat_response, MockServer, Result};
+use genai::adapter::AdapterKind;
+use genai::chat::{ChatMessage, ChatRequest, ContentPart, MessageContent};
+use serde_json::json;
+
+#[tokio::test]
+async fn test_anthropic_cache_control_and_usage_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![json!({
+		"content": [{"type": "text", "text": "It is about Rust."}],
+		"usage": {
+			"input_tokens": 20,
+			"output_tokens": 5,
+			"cache_read_input_tokens": 1000,
+			"cache_creation_input_tokens": 0
+		}
+	})])
+	.await?;
+	let client = server.client_for_adapter(AdapterKind::Anthropic);
+	let chat_req = ChatRequest::new(vec![ChatMessage::user_with_cached_document(
+		"What is this document about?",
+		"A long document about Rust.",
+	)]);
+
+	// -- Exec
+	let chat_res = client.exec_chat("claude-3-haiku-20240307", chat_req, None).await?;
+
+	// -- Check
+	let requests = server.requests();
+	let content = requests[0].pointer("/messages/0/content").ok_or("Should have content")?;
+	assert_eq!(content.pointer("/0/text"), Some(&json!("A long document about Rust.")));
+	assert_eq!(content.pointer("/0/cache_control"), Some(&json!({"type": "ephemeral"})));
+	assert_eq!(content.pointer("/1/text"), Some(&json!("What is this document about?")));
+	assert!(content.pointer("/1/cache_control").is_none());
+
+	assert_eq!(chat_res.usage.cache_read_input_tokens, Some(1000));
+	assert_eq!(chat_res.usage.cache_creation_input_tokens, Some(0));
+	assert_eq!(chat_res.usage.total_tokens, Some(25));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_anthropic_cacheable_image_url_err() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![json!({"content": [{"type": "text", "text": "Ok"}]})]).await?;
+	let client = server.client_for_adapter(AdapterKind::Anthropic);
+	let chat_req = ChatRequest::new(vec![ChatMessage::user(MessageContent::from_parts(vec![
+		ContentPart::from_image_url("image/png", "https://example.com/image.png").cacheable(),
+		ContentPart::from_text("What is in this image?"),
+	]))]);
+
+	// -- Exec
+	let res = client.exec_chat("claude-3-haiku-20240307", chat_req, None).await;
+
+	// -- Check
+	assert!(
+		matches!(res, Err(genai::Error::MessageContentTypeNotSupported { .. })),
+		"Should have been an Error::MessageContentTypeNotSupported, but was: {res:?}"
+	);
+	assert!(server.requests().is_empty(), "Should not have sent the request");
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_openai_cacheable_part_sent_as_is_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![mock_openai_chat_response("It is about Rust.")]).await?;
+	let client = server.client_for_adapter(AdapterKind::OpenAI);
+	let chat_req = ChatRequest::new(vec![ChatMessage::user_with_cached_document(
+		"What is this document about?",
+		"A long document about Rust.",
+	)]);
+
+	// -- Exec
+	client.exec_chat("gpt-4o-mini", chat_req, None).await?;
+
+	// -- Check
+	let requests = server.requests();
+	let content = requests[0].pointer("/messages/0/content").ok_or("Should have content")?;
+	assert_eq!(
+		content,
+		&json!([
+			{"type": "text", "text": "A long document about Rust."},
+			{"type": "text", "text": "What is this document about?"}
+		])
+	);
+
+	Ok(())
+}
+
+#[test]
+fn test_content_part_cacheable_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let part = ContentPart::from_text("Hello").cacheable().cacheable();
+
+	// -- Check
+	assert!(part.is_cacheable());
+	assert!(matches!(part.without_cache(), ContentPart::Text(text) if text == "Hello"));
+	let content = MessageContent::from_parts(vec![part]);
+	assert!(matches!(content, MessageContent::Parts(parts) if parts.len() == 1));
+
+	Ok(())
+}
@@ -0,0 +1,49 @@
+use genai::ApiError;
+
+type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+#[test]
+fn test_api_error_openai_body_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let body = r#"{"error": {"message": "Incorrect API key provided", "type": "invalid_request_error", "param": null, "code": "invalid_api_key"}}"#;
+
+	// -- Exec
+	let api_error = ApiError::from_status_body(401, body);
+
+	// -- Check
+	assert_eq!(api_error.status_code, 401);
+	assert_eq!(api_error.error_message, "Incorrect API key provided");
+	assert_eq!(api_error.error_type.as_deref(), Some("invalid_request_error"));
+	assert_eq!(api_error.error_code.as_deref(), Some("invalid_api_key"));
+	assert_eq!(api_error.to_string(), "HTTP 401: Incorrect API key provided");
+
+	Ok(())
+}
+
+#[test]
+fn test_api_error_gemini_array_body_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let body = r#"[{"error": {"code": 400, "message": "API key not valid.", "status": "INVALID_ARGUMENT"}}]"#;
+
+	// -- Exec
+	let api_error = ApiError::from_status_body(400, body);
+
+	// -- Check
+	assert_eq!(api_error.error_message, "API key not valid.");
+	assert_eq!(api_error.error_type.as_deref(), Some("INVALID_ARGUMENT"));
+	assert_eq!(api_error.error_code.as_deref(), Some("400"));
+
+	Ok(())
+}
+
+#[test]
+fn test_api_error_not_json_body_ok() -> Result<()> {
+	// -- Exec
+	let api_error = ApiError::from_status_body(502, "Bad Gateway");
+
+	// -- Check
+	assert_eq!(api_error.error_message, "Bad Gateway");
+	assert!(api_error.error_type.is_none());
+
+	Ok(())
+}
@@ -0,0 +1,152 @@
+mod support;
+
+use crate::support::{MockServer, Result};
+use genai::assistants::{AssistantRequest, Run, RunStatus};
+use genai::chat::{ChatMessage, Tool, ToolResponse, ToolSchemaRegistry};
+use serde_json::{json, Value};
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_assistants_run_with_tool_call_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let run = |status: &str| json!({"id": "run_1", "thread_id": "thread_1", "assistant_id": "asst_1", "status": status, "required_action": null});
+	let mut requires_action = run("requires_action");
+	requires_action["required_action"] = json!({
+		"type": "submit_tool_outputs",
+		"submit_tool_outputs": {"tool_calls": [{
+			"id": "call_1",
+			"type": "function",
+			"function": {"name": "get_weather", "arguments": "{\"city\": \"Paris\"}"}
+		}]}
+	});
+	let server = MockServer::start(vec![
+		json!({"id": "asst_1", "name": "Weather", "instructions": "Be concise", "tools": [], "model": "gpt-4o-mini"}),
+		json!({"id": "thread_1"}),
+		json!({"id": "msg_1", "thread_id": "thread_1", "role": "user", "content": [], "created_at": 1}),
+		run("queued"),
+		requires_action,
+		run("completed"),
+		json!({"data": [{
+			"id": "msg_2",
+			"thread_id": "thread_1",
+			"role": "assistant",
+			"content": [{"type": "text", "text": {"value": "21C in Paris.", "annotations": []}}],
+			"created_at": 2
+		}]}),
+	])
+	.await?;
+	let assistant_client = server.client().assistant_client();
+	let assistant_req = AssistantRequest::new("gpt-4o-mini")
+		.with_name("Weather")
+		.with_instructions("Be concise")
+		.append_tool(Tool::new("get_weather").with_schema(json!({"type": "object"})));
+
+	// -- Exec
+	let assistant = assistant_client.create_assistant(assistant_req).await?;
+	let thread = assistant_client.create_thread().await?;
+	assistant_client
+		.add_message_to_thread(&thread.id, ChatMessage::user("Weather in Paris?"))
+		.await?;
+	let run = assistant_client.run_thread(&thread.id, &assistant.id).await?;
+	let run = assistant_client.poll_run(&run).await?;
+	let tool_calls = run.tool_calls()?;
+	let run = assistant_client
+		.submit_tool_outputs(&run, vec![ToolResponse::new("call_1", r#"{"temperature": 21}"#)])
+		.await?;
+	let messages = assistant_client.list_thread_messages(&thread.id).await?;
+
+	// -- Check
+	assert_eq!(tool_calls.len(), 1);
+	assert_eq!(tool_calls[0].fn_arguments, json!({"city": "Paris"}));
+	assert_eq!(run.status, RunStatus::Completed);
+	assert!(run.status.is_terminal());
+	assert_eq!(messages[0].text(), "21C in Paris.");
+	assert_eq!(
+		server.request_paths(),
+		vec![
+			"/v1/assistants",
+			"/v1/threads",
+			"/v1/threads/thread_1/messages",
+			"/v1/threads/thread_1/runs",
+			"/v1/threads/thread_1/runs/run_1",
+			"/v1/threads/thread_1/runs/run_1/submit_tool_outputs",
+			"/v1/threads/thread_1/messages",
+		]
+	);
+	let requests = server.requests();
+	assert_eq!(
+		requests[0].pointer("/tools/0/function/name"),
+		Some(&json!("get_weather"))
+	);
+	assert_eq!(requests[2], json!({"role": "user", "content": "Weather in Paris?"}));
+	assert_eq!(
+		requests[5],
+		json!({"tool_outputs": [{"tool_call_id": "call_1", "output": "{\"temperature\": 21}"}]})
+	);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_assistants_run_until_done_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let run_json = |status: &str| json!({"id": "run_1", "thread_id": "thread_1", "assistant_id": "asst_1", "status": status, "required_action": null});
+	let mut requires_action = run_json("requires_action");
+	requires_action["required_action"] = json!({
+		"type": "submit_tool_outputs",
+		"submit_tool_outputs": {"tool_calls": [
+			{
+				"id": "call_1",
+				"type": "function",
+				"function": {"name": "get_weather", "arguments": "{\"city\": \"Paris\"}"}
+			},
+			{
+				"id": "call_2",
+				"type": "function",
+				"function": {"name": "get_time", "arguments": "{}"}
+			}
+		]}
+	});
+	let server = MockServer::start(vec![requires_action, run_json("in_progress"), run_json("completed")]).await?;
+	let assistant_client = server.client().assistant_client();
+	let mut registry = ToolSchemaRegistry::new();
+	registry.register_from_schema(
+		json!({
+			"type": "function",
+			"function": {"name": "get_weather", "parameters": {"type": "object", "properties": {"city": {"type": "string"}}}}
+		}),
+		|args| {
+			let city = args.and_then(|args| args["city"].as_str()).unwrap_or_default();
+			format!(r#"{{"city": "{city}", "temperature": 21}}"#)
+		},
+	)?;
+	let run: Run = serde_json::from_value(run_json("queued"))?;
+
+	// -- Exec
+	let run = assistant_client
+		.run_until_done(run, &registry, Duration::from_millis(10))
+		.await?;
+
+	// -- Check
+	assert_eq!(run.status, RunStatus::Completed);
+	assert_eq!(
+		server.request_paths(),
+		vec![
+			"/v1/threads/thread_1/runs/run_1",
+			"/v1/threads/thread_1/runs/run_1/submit_tool_outputs",
+			"/v1/threads/thread_1/runs/run_1",
+		]
+	);
+	let tool_outputs = &server.requests()[1]["tool_outputs"];
+	assert_eq!(tool_outputs[0]["tool_call_id"], "call_1");
+	assert_eq!(tool_outputs[0]["output"], r#"{"city": "Paris", "temperature": 21}"#);
+	// The unknown tool is answered with an error output (not failing the run).
+	assert_eq!(tool_outputs[1]["tool_call_id"], "call_2");
+	let unknown_output: Value = serde_json::from_str(tool_outputs[1]["output"].as_str().unwrap_or_default())?;
+	assert!(unknown_output["error"].is_string());
+	for head in server.request_heads() {
+		assert!(head.contains("openai-beta: assistants=v2"));
+	}
+
+	Ok(())
+}
@@ -0,0 +1,82 @@
+mod support;
+
+use crate::support::{MockServer, Result};
+use genai::adapter::AdapterKind;
+use genai::audio::TranscriptionRequest;
+use genai::resolver::{AuthData, Endpoint, ServiceTargetResolver};
+use genai::{Client, ServiceTarget};
+use serde_json::json;
+
+// region:    --- Support
+
+/// A client keeping the default adapter endpoint as a path prefix on the mock server
+/// (e.g., `https://api.groq.com/openai/v1/` -> `{mock_root}/api.groq.com/openai/v1/`).
+fn client_with_endpoint_path(server: &MockServer) -> Client {
+	let mock_root = server.base_url().trim_end_matches("v1/").to_string();
+	let target_resolver = ServiceTargetResolver::from_resolver_fn(
+		move |service_target: ServiceTarget| -> core::result::Result<ServiceTarget, genai::resolver::Error> {
+			let ServiceTarget { endpoint, model, .. } = service_target;
+			let endpoint_path = endpoint.base_url().trim_start_matches("https://").to_string();
+			Ok(ServiceTarget {
+				endpoint: Endpoint::from_owned(format!("{mock_root}{endpoint_path}")),
+				auth: AuthData::from_single("mock-api-key"),
+				model,
+			})
+		},
+	);
+	Client::builder().with_service_target_resolver(target_resolver).build()
+}
+
+// endregion: --- Support
+
+#[tokio::test]
+async fn test_transcribe_with_adapter_endpoints_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![json!({"text": "Hello world"})]).await?;
+	let client = client_with_endpoint_path(&server);
+	let audio = b"fake-audio-content".to_vec();
+
+	// -- Exec
+	let groq_res = client
+		.transcribe_with_adapter(
+			AdapterKind::Groq,
+			TranscriptionRequest::new("whisper-large-v3", "audio.mp3", audio.clone()),
+		)
+		.await?;
+	let openai_res = client
+		.transcribe_with_adapter(
+			AdapterKind::OpenAI,
+			TranscriptionRequest::new("whisper-1", "audio.mp3", audio),
+		)
+		.await?;
+
+	// -- Check
+	assert_eq!(groq_res.text, "Hello world");
+	assert_eq!(groq_res.model_iden.adapter_kind, AdapterKind::Groq);
+	assert_eq!(openai_res.model_iden.adapter_kind, AdapterKind::OpenAI);
+	assert_eq!(
+		server.request_paths(),
+		vec![
+			"/api.groq.com/openai/v1/audio/transcriptions",
+			"/api.openai.com/v1/audio/transcriptions"
+		]
+	);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_transcribe_with_adapter_not_supported_err() -> Result<()> {
+	// -- Exec
+	let res = Client::default()
+		.transcribe_with_adapter(
+			AdapterKind::Anthropic,
+			TranscriptionRequest::new("claude-3-haiku-20240307", "audio.mp3", vec![]),
+		)
+		.await;
+
+	// -- Check
+	assert!(matches!(res, Err(genai::Error::TranscriptionNotSupported { .. })));
+
+	Ok(())
+}
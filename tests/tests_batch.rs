@@ -0,0 +1,148 @@
+mod support;
+
+use crate::support::{mock_openai_chat_response, MockServer, Result};
+use genai::batch::{BatchRequest, BatchStatus};
+use genai::chat::{ChatOptions, ChatRequest};
+use serde_json::{json, Value};
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_batch_submit_poll_results_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let batch = |status: &str| {
+		json!({
+			"id": "batch_1",
+			"object": "batch",
+			"status": status,
+			"output_file_id": if status == "completed" { json!("file-out") } else { Value::Null },
+			"error_file_id": null,
+			"request_counts": {"total": 2, "completed": 1, "failed": 1}
+		})
+	};
+	let output_lines = [
+		json!({
+			"id": "batch_req_1",
+			"custom_id": "req-1",
+			"response": {"status_code": 200, "request_id": "req_abc", "body": mock_openai_chat_response("Hello!")},
+			"error": null
+		}),
+		json!({
+			"id": "batch_req_2",
+			"custom_id": "req-2",
+			"response": {"status_code": 400, "body": {"error": {"message": "Bad request", "type": "invalid_request_error"}}},
+			"error": null
+		}),
+	];
+	let output_content = output_lines.iter().map(|line| line.to_string()).collect::<Vec<_>>().join("\n");
+	let server = MockServer::start_with_text(
+		vec![
+			json!({"id": "file-in", "object": "file", "purpose": "batch"}),
+			batch("validating"),
+			batch("in_progress"),
+			batch("completed"),
+		],
+		output_content,
+	)
+	.await?;
+	let batch_client = server.client().batch_client();
+	let requests = vec![
+		BatchRequest::new("req-1", "gpt-4o-mini", ChatRequest::from_user("Say hello")),
+		BatchRequest::new("req-2", "gpt-4o-mini", ChatRequest::from_user("Say bye"))
+			.with_options(ChatOptions::default().with_temperature(0.5)),
+	];
+
+	// -- Exec
+	let job = batch_client.submit_batch(requests).await?;
+	let results = batch_client.wait_for_batch(job, Duration::from_millis(1)).await?;
+
+	// -- Check
+	assert_eq!(
+		server.request_paths(),
+		vec![
+			"/v1/files",
+			"/v1/batches",
+			"/v1/batches/batch_1",
+			"/v1/batches/batch_1",
+			"/v1/files/file-out/content",
+		]
+	);
+	// upload file
+	let heads = server.request_heads();
+	assert!(heads[0].contains("content-type: multipart/form-data"));
+	let upload_body = &server.request_raw_bodies()[0];
+	assert!(upload_body.contains("name=\"purpose\"\r\n\r\nbatch"));
+	let lines: Vec<Value> = upload_body
+		.lines()
+		.filter(|line| line.starts_with('{'))
+		.map(serde_json::from_str)
+		.collect::<core::result::Result<_, _>>()?;
+	assert_eq!(lines.len(), 2);
+	assert_eq!(lines[0]["custom_id"], "req-1");
+	assert_eq!(lines[0]["method"], "POST");
+	assert_eq!(lines[0]["url"], "/v1/chat/completions");
+	assert_eq!(lines[0]["body"]["model"], "gpt-4o-mini");
+	assert_eq!(
+		lines[0]["body"]["messages"],
+		json!([{"role": "user", "content": "Say hello"}])
+	);
+	assert_eq!(lines[1]["body"]["temperature"], 0.5);
+	// create batch
+	assert_eq!(
+		server.requests()[1],
+		json!({"input_file_id": "file-in", "endpoint": "/v1/chat/completions", "completion_window": "24h"})
+	);
+	// results
+	assert_eq!(results.len(), 2);
+	let res_1 = &results[0];
+	assert_eq!(res_1.custom_id, "req-1");
+	assert!(res_1.error.is_none());
+	let chat_res = res_1.chat_response.as_ref().ok_or("Should have a chat response")?;
+	assert_eq!(chat_res.content_text_as_str(), Some("Hello!"));
+	assert_eq!(chat_res.request_id.as_deref(), Some("req_abc"));
+	let res_2 = &results[1];
+	assert_eq!(res_2.custom_id, "req-2");
+	assert!(res_2.chat_response.is_none());
+	assert_eq!(
+		res_2.error.as_ref().and_then(|error| error.get("message")),
+		Some(&json!("Bad request"))
+	);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_batch_results_not_completed_err() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![json!({"id": "batch_1", "status": "failed"})]).await?;
+	let batch_client = server.client().batch_client();
+	let job = serde_json::from_value(json!({"id": "batch_1", "status": "in_progress"}))?;
+
+	// -- Exec
+	let job = batch_client.poll_batch(&job).await?;
+	let res = batch_client.get_batch_results(&job).await;
+
+	// -- Check
+	assert_eq!(job.status, BatchStatus::Failed);
+	assert!(
+		matches!(res, Err(genai::Error::BatchNotCompleted { ref batch_id, status: BatchStatus::Failed }) if batch_id == "batch_1")
+	);
+	assert_eq!(server.request_paths(), vec!["/v1/batches/batch_1"]);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_batch_submit_empty_err() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![]).await?;
+	let batch_client = server.client().batch_client();
+
+	// -- Exec
+	let res = batch_client.submit_batch(Vec::new()).await;
+
+	// -- Check
+	assert!(matches!(res, Err(genai::Error::BatchHasNoRequests)));
+	assert!(server.request_paths().is_empty());
+
+	Ok(())
+}
@@ -0,0 +1,62 @@
+mod support;
+
+use crate::support::{mock_openai_chat_response, MockServer, Result};
+use genai::adapter::AdapterKind;
+use genai::bench::{BenchmarkRunner, BenchmarkTask};
+use genai::chat::ChatRequest;
+use serde_json::json;
+
+#[tokio::test]
+async fn test_bench_runner_run_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![mock_openai_chat_response("Paris is the capital of France")]).await?;
+	let tasks = [
+		BenchmarkTask::new("capital", ChatRequest::from_user("Capital of France?")).with_expected_keywords(&["paris"]),
+		BenchmarkTask::new("country", ChatRequest::from_user("Where is Paris?"))
+			.with_expected_keywords(&["France", "Europe"])
+			.with_score_fn(|text| if text.len() < 50 { 1.0 } else { 0.5 }),
+	];
+	let runner = BenchmarkRunner::new(server.client());
+
+	// -- Exec
+	let report = runner.run(&tasks, &["gpt-4o-mini"]).await;
+
+	// -- Check
+	let result = report.result("gpt-4o-mini").ok_or("Should have the model result")?;
+	// (1.0 + 0.5) / 2
+	assert_eq!(result.avg_quality_score, 0.75);
+	assert_eq!(result.input_tokens, 20);
+	assert_eq!(result.output_tokens, 20);
+	// gpt-4o-mini price: 0.15 / 0.6 USD per million tokens
+	assert!((result.total_cost_usd - 0.000015).abs() < 1e-12);
+	assert!(result.avg_latency_ms > 0.0);
+	assert_eq!(result.failed_tasks, 0);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_bench_runner_model_client_and_failures_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let ok_server = MockServer::start(vec![mock_openai_chat_response("Hello")]).await?;
+	let err_server = MockServer::start_error(500, json!({"error": {"message": "boom"}})).await?;
+	let tasks = [BenchmarkTask::new("hello", ChatRequest::from_user("Say hello"))];
+	let runner = BenchmarkRunner::new(ok_server.client())
+		.with_model_client("deepseek-chat", err_server.client_for_adapter(AdapterKind::DeepSeek));
+
+	// -- Exec
+	let report = runner.run(&tasks, &["gpt-4o-mini", "deepseek-chat"]).await;
+
+	// -- Check
+	let models: Vec<&str> = report.results.iter().map(|result| result.model.as_str()).collect();
+	assert_eq!(models, vec!["gpt-4o-mini", "deepseek-chat"]);
+	let failed = report.result("deepseek-chat").ok_or("Should have the deepseek result")?;
+	assert_eq!(failed.failed_tasks, 1);
+	assert_eq!(failed.avg_quality_score, 0.0);
+	assert_eq!(
+		report.best_quality().map(|result| result.model.as_str()),
+		Some("gpt-4o-mini")
+	);
+
+	Ok(())
+}
@@ -0,0 +1,97 @@
+use genai::adapter::AdapterKind;
+use genai::chat::{
+	analyze_tool_usage, ChatMessage, ChatRequest, ChatResponse, MessageContent, MetaUsage, Tool, ToolCall, ToolResponse,
+};
+use genai::ModelIden;
+use serde_json::json;
+
+type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+#[test]
+fn test_chat_analytics_stats_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let chat_req = ChatRequest::new(vec![
+		ChatMessage::system("Be nice"),
+		ChatMessage::user("Hello"),
+		ChatMessage::assistant("Hi there!"),
+		ChatMessage::user("Weather in Paris?"),
+		ChatMessage::from(vec![
+			tool_call("call_1", "get_weather"),
+			tool_call("call_2", "get_time"),
+		]),
+		ChatMessage::from(ToolResponse::new("call_1", "Sunny")),
+		ChatMessage::assistant("It is sunny."),
+	])
+	.with_system("You are an assistant");
+
+	// -- Exec
+	let stats = chat_req.stats();
+
+	// -- Check
+	assert_eq!(stats.turn_count, 6);
+	assert_eq!(stats.user_message_count, 2);
+	assert_eq!(stats.assistant_message_count, 3);
+	assert_eq!(stats.tool_call_count, 2);
+	assert_eq!(stats.system_char_count, 27);
+	// "Hello" (5) + "Hi there!" (9) + "Weather in Paris?" (17) + "It is sunny." (12)
+	assert_eq!(stats.total_char_count, 27 + 43);
+	assert_eq!(stats.avg_message_len, 43.0 / 6.0);
+
+	Ok(())
+}
+
+#[test]
+fn test_chat_analytics_tool_usage_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let history = vec![
+		response(MessageContent::from(vec![
+			tool_call("call_1", "get_weather"),
+			tool_call("call_2", "get_time"),
+		])),
+		response(MessageContent::from_text("It is sunny.")),
+		response(MessageContent::from(vec![tool_call("call_3", "get_weather")])),
+	];
+	let chat_req = ChatRequest::from_user("Weather in Paris?").with_tools(vec![
+		Tool::new("get_weather"),
+		Tool::new("get_time"),
+		Tool::new("send_email"),
+	]);
+
+	// -- Exec
+	let stats = analyze_tool_usage(&history);
+	let request_stats = chat_req.tool_usage(&history);
+
+	// -- Check
+	assert_eq!(stats.total_calls, 3);
+	assert_eq!(stats.calls_per_tool.get("get_weather"), Some(&2));
+	assert_eq!(stats.calls_per_tool.get("get_time"), Some(&1));
+	assert!(stats.tools_never_called.is_empty());
+	assert_eq!(request_stats.calls_per_tool, stats.calls_per_tool);
+	assert_eq!(request_stats.tools_never_called, vec!["send_email".to_string()]);
+
+	Ok(())
+}
+
+// region:    --- Support
+
+fn tool_call(call_id: &str, fn_name: &str) -> ToolCall {
+	ToolCall {
+		call_id: call_id.to_string(),
+		fn_name: fn_name.to_string(),
+		fn_arguments: json!({}),
+	}
+}
+
+fn response(content: MessageContent) -> ChatResponse {
+	ChatResponse {
+		content: Some(content),
+		model_iden: ModelIden::new(AdapterKind::OpenAI, "gpt-4o-mini"),
+		usage: MetaUsage::default(),
+		request_id: None,
+		client_request_id: None,
+		adapter_meta: None,
+		citations: Vec::new(),
+	}
+}
+
+// endregion: --- Support
@@ -0,0 +1,59 @@
+mod support;
+
+use crate::support::{MockServer, Result};
+use genai::adapter::AdapterKind;
+use genai::chat::{AudioFormat, ChatMessage, ChatRequest};
+use genai::Error;
+use serde_json::json;
+
+#[tokio::test]
+async fn test_chat_audio_openai_input_output_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![json!({
+		"choices": [{
+			"index": 0,
+			"message": {
+				"role": "assistant",
+				"content": null,
+				"audio": {"id": "audio_1", "data": "AQID", "expires_at": 1729000000, "transcript": "Hello there!"}
+			},
+			"finish_reason": "stop"
+		}],
+		"usage": {"prompt_tokens": 10, "completion_tokens": 10, "total_tokens": 20}
+	})])
+	.await?;
+	let client = server.client();
+	let chat_req = ChatRequest::new(vec![ChatMessage::user_with_audio(&[1, 2, 3], AudioFormat::Wav)]);
+
+	// -- Exec
+	let chat_res = client.exec_chat("gpt-4o-audio-preview", chat_req, None).await?;
+
+	// -- Check
+	assert_eq!(
+		server.requests()[0].pointer("/messages/0/content/0"),
+		Some(&json!({"type": "input_audio", "input_audio": {"data": "AQID", "format": "wav"}}))
+	);
+	assert_eq!(chat_res.content_text_as_str(), Some("Hello there!"));
+	assert_eq!(chat_res.audio_bytes(), Some(vec![1, 2, 3]));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_chat_audio_anthropic_not_supported_err() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![]).await?;
+	let client = server.client_for_adapter(AdapterKind::Anthropic);
+	let chat_req = ChatRequest::new(vec![ChatMessage::user_with_audio(&[1, 2, 3], AudioFormat::Mp3)]);
+
+	// -- Exec
+	let res = client.exec_chat("claude-3-haiku-20240307", chat_req, None).await;
+
+	// -- Check
+	assert!(
+		matches!(res, Err(Error::MessageContentTypeNotSupported { .. })),
+		"Should not be supported: {res:?}"
+	);
+
+	Ok(())
+}
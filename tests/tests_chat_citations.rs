@@ -0,0 +1,183 @@
+mod support;
+
+use crate::support::{mock_openai_chat_response, MockServer, Result};
+use genai::adapter::AdapterKind;
+use genai::chat::ChatRequest;
+use serde_json::json;
+
+#[tokio::test]
+async fn test_chat_citations_gemini_grounding_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let response = json!({
+		"candidates": [{
+			"content": {"parts": [{"text": "Rust 1.0 was released in 2015."}], "role": "model"},
+			"finishReason": "STOP",
+			"groundingMetadata": {
+				"groundingChunks": [
+					{"web": {"uri": "https://blog.rust-lang.org/2015/05/15/Rust-1.0.html", "title": "rust-lang.org"}}
+				],
+				"groundingSupports": [{
+					"segment": {"endIndex": 30, "text": "Rust 1.0 was released in 2015"},
+					"groundingChunkIndices": [0]
+				}]
+			}
+		}],
+		"usageMetadata": {"promptTokenCount": 2, "candidatesTokenCount": 8, "totalTokenCount": 10}
+	});
+	let server = MockServer::start(vec![response]).await?;
+	let client = server.client_for_adapter(AdapterKind::Gemini);
+
+	// -- Exec
+	let chat_res = client
+		.exec_chat("gemini-1.5-flash", ChatRequest::from_user("Rust 1.0?"), None)
+		.await?;
+
+	// -- Check
+	assert_eq!(chat_res.citations.len(), 1);
+	let citation = &chat_res.citations[0];
+	assert_eq!(citation.url, "https://blog.rust-lang.org/2015/05/15/Rust-1.0.html");
+	assert_eq!(citation.title.as_deref(), Some("rust-lang.org"));
+	assert_eq!(citation.snippet.as_deref(), Some("Rust 1.0 was released in 2015"));
+	assert_eq!((citation.start_index, citation.end_index), (Some(0), Some(30)));
+	assert_eq!(
+		chat_res.content_with_inline_citations(),
+		"Rust 1.0 was released in 2015. [https://blog.rust-lang.org/2015/05/15/Rust-1.0.html]"
+	);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_chat_citations_anthropic_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let response = json!({
+		"id": "msg_01",
+		"type": "message",
+		"role": "assistant",
+		"content": [
+			{"type": "text", "text": "Here is what I found."},
+			{
+				"type": "text",
+				"text": "Rust is memory safe",
+				"citations": [{
+					"type": "web_search_result_location",
+					"url": "https://www.rust-lang.org",
+					"title": "Rust Programming Language",
+					"cited_text": "Rust's rich type system and ownership model guarantee memory-safety"
+				}]
+			}
+		],
+		"usage": {"input_tokens": 5, "output_tokens": 10}
+	});
+	let server = MockServer::start(vec![response]).await?;
+	let client = server.client_for_adapter(AdapterKind::Anthropic);
+
+	// -- Exec
+	let chat_res = client
+		.exec_chat("claude-3-haiku-20240307", ChatRequest::from_user("Rust?"), None)
+		.await?;
+
+	// -- Check
+	assert_eq!(
+		chat_res.content_text_as_str(),
+		Some("Here is what I found.\nRust is memory safe")
+	);
+	assert_eq!(chat_res.citations.len(), 1);
+	let citation = &chat_res.citations[0];
+	assert_eq!(citation.url, "https://www.rust-lang.org");
+	assert_eq!(citation.title.as_deref(), Some("Rust Programming Language"));
+	assert!(citation
+		.snippet
+		.as_deref()
+		.is_some_and(|snippet| snippet.contains("memory-safety")));
+	assert_eq!((citation.start_index, citation.end_index), (Some(22), Some(41)));
+	assert_eq!(
+		chat_res.content_with_inline_citations(),
+		"Here is what I found.\nRust is memory safe [https://www.rust-lang.org]"
+	);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_chat_citations_openai_markers_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut response = mock_openai_chat_response("Rust has no GC [1], and a borrow checker [2].");
+	response["choices"][0]["message"]["annotations"] = json!([
+		{
+			"type": "url_citation",
+			"url_citation": {"url": "https://doc.rust-lang.org/book", "title": "The Book", "start_index": 15, "end_index": 18}
+		},
+		{
+			"type": "url_citation",
+			"url_citation": {"url": "https://rustc-dev-guide.rust-lang.org", "start_index": 41, "end_index": 44}
+		},
+		{"type": "file_citation", "file_citation": {"file_id": "file_01"}}
+	]);
+	let server = MockServer::start(vec![response]).await?;
+	let client = server.client();
+
+	// -- Exec
+	let chat_res = client.exec_chat("gpt-4o-mini", ChatRequest::from_user("Rust?"), None).await?;
+
+	// -- Check
+	assert_eq!(chat_res.citations.len(), 2);
+	assert_eq!(chat_res.citations[0].title.as_deref(), Some("The Book"));
+	assert_eq!(chat_res.citations[1].title, None);
+	assert_eq!(chat_res.citations[1].start_index, Some(41));
+	assert_eq!(
+		chat_res.content_with_inline_citations(),
+		"Rust has no GC [https://doc.rust-lang.org/book], and a borrow checker [https://rustc-dev-guide.rust-lang.org]."
+	);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_chat_citations_openai_non_ascii_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	// Note: The OpenAI indexes are character offsets (the byte offsets are 24 and 44).
+	let mut response = mock_openai_chat_response("Le café est crème 🦀, et Rust est rapide.");
+	response["choices"][0]["message"]["annotations"] = json!([
+		{
+			"type": "url_citation",
+			"url_citation": {"url": "https://www.rust-lang.org", "start_index": 18, "end_index": 19}
+		},
+		{
+			"type": "url_citation",
+			"url_citation": {"url": "https://doc.rust-lang.org/book", "start_index": 24, "end_index": 39}
+		}
+	]);
+	let server = MockServer::start(vec![response]).await?;
+	let client = server.client();
+
+	// -- Exec
+	let chat_res = client.exec_chat("gpt-4o-mini", ChatRequest::from_user("Rust?"), None).await?;
+
+	// -- Check
+	assert_eq!(chat_res.citations[0].start_index, Some(20));
+	assert_eq!(chat_res.citations[0].end_index, Some(24));
+	assert_eq!(chat_res.citations[1].end_index, Some(44));
+	assert_eq!(
+		chat_res.content_with_inline_citations(),
+		"Le café est crème 🦀 [https://www.rust-lang.org], et Rust est rapide [https://doc.rust-lang.org/book]."
+	);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_chat_citations_none_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![mock_openai_chat_response("Hello [world]")]).await?;
+	let client = server.client();
+
+	// -- Exec
+	let chat_res = client.exec_chat("gpt-4o-mini", ChatRequest::from_user("Hi"), None).await?;
+
+	// -- Check
+	assert!(chat_res.citations.is_empty());
+	assert_eq!(chat_res.content_with_inline_citations(), "Hello [world]");
+
+	Ok(())
+}
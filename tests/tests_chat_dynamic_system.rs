@@ -0,0 +1,62 @@
+mod support;
+
+use crate::support::{mock_openai_chat_response, MockServer, Result};
+use genai::chat::ChatRequest;
+use serde_json::json;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+#[tokio::test]
+async fn test_chat_dynamic_system_evaluated_at_request_time_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![mock_openai_chat_response("Ok")]).await?;
+	let client = server.client();
+	let calls = Arc::new(AtomicUsize::new(0));
+	let fn_calls = calls.clone();
+	let chat_req = ChatRequest::from_user("What day is it?")
+		.with_system("Static system")
+		.with_dynamic_system(move || {
+			let call = fn_calls.fetch_add(1, Ordering::SeqCst) + 1;
+			format!("Dynamic system #{call}")
+		});
+
+	// -- Exec
+	let calls_before_exec = calls.load(Ordering::SeqCst);
+	client.exec_chat("gpt-4o-mini", chat_req.clone(), None).await?;
+	client.exec_chat("gpt-4o-mini", chat_req, None).await?;
+
+	// -- Check
+	assert_eq!(calls_before_exec, 0);
+	assert_eq!(calls.load(Ordering::SeqCst), 2);
+	let requests = server.requests();
+	assert_eq!(
+		requests[0].pointer("/messages/0"),
+		Some(&json!({"role": "system", "content": "Static system\n\nDynamic system #1"}))
+	);
+	assert_eq!(
+		requests[1].pointer("/messages/0"),
+		Some(&json!({"role": "system", "content": "Static system\n\nDynamic system #2"}))
+	);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_chat_dynamic_system_only_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![mock_openai_chat_response("Ok")]).await?;
+	let client = server.client();
+	let chat_req = ChatRequest::from_user("What day is it?").with_dynamic_system(|| "Dynamic system".to_string());
+
+	// -- Exec
+	client.exec_chat("gpt-4o-mini", chat_req, None).await?;
+
+	// -- Check
+	let requests = server.requests();
+	assert_eq!(
+		requests[0].pointer("/messages/0"),
+		Some(&json!({"role": "system", "content": "Dynamic system"}))
+	);
+
+	Ok(())
+}
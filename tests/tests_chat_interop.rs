@@ -0,0 +1,206 @@
+//! Requires the `interop` feature: `cargo test --features interop --test tests_chat_interop`
+
+#![cfg(feature = "interop")]
+
+use genai::chat::{
+	ChatMessage, ChatRequest, ChatRole, ContentPart, ImageSource, MessageContent, ToolCall, ToolResponse,
+};
+use serde_json::json;
+
+type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+#[test]
+fn test_chat_interop_langchain_from_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let messages = json!([
+		{"type": "system", "content": "Be concise"},
+		{"type": "human", "content": [
+			{"type": "text", "text": "What is this?"},
+			{"type": "image_url", "image_url": {"url": "data:image/jpeg;base64,AAAA"}}
+		]},
+		{"type": "ai", "data": {"content": "A cat."}}
+	]);
+
+	// -- Exec
+	let chat_req = ChatRequest::from_langchain_messages(&messages)?;
+
+	// -- Check
+	let roles: Vec<&ChatRole> = chat_req.messages.iter().map(|m| &m.role).collect();
+	assert_eq!(roles, vec![&ChatRole::System, &ChatRole::User, &ChatRole::Assistant]);
+	let MessageContent::Parts(parts) = &chat_req.messages[1].content else {
+		return Err("Should be MessageContent::Parts".into());
+	};
+	assert!(matches!(&parts[0], ContentPart::Text(text) if text == "What is this?"));
+	assert!(matches!(
+		&parts[1],
+		ContentPart::Image { content_type, source: ImageSource::Base64(data), .. }
+			if content_type == "image/jpeg" && data.as_ref() == "AAAA"
+	));
+	assert_eq!(chat_req.messages[2].content.text_as_str(), Some("A cat."));
+
+	Ok(())
+}
+
+#[test]
+fn test_chat_interop_langchain_to_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let chat_req = ChatRequest::new(vec![
+		ChatMessage::user("Weather in Paris?"),
+		ChatMessage::from(vec![ToolCall {
+			call_id: "call_1".to_string(),
+			fn_name: "get_weather".to_string(),
+			fn_arguments: json!({"city": "Paris"}),
+		}]),
+		ChatMessage::from(ToolResponse::new("call_1", "Sunny")),
+		ChatMessage::assistant("It is sunny."),
+	])
+	.with_system("Be concise");
+
+	// -- Exec
+	let messages = chat_req.to_langchain_messages();
+
+	// -- Check
+	assert_eq!(
+		messages,
+		json!([
+			{"type": "system", "content": "Be concise"},
+			{"type": "human", "content": "Weather in Paris?"},
+			{"type": "ai", "content": "", "tool_calls": [{"name": "get_weather", "args": {"city": "Paris"}, "id": "call_1"}]},
+			{"type": "tool", "content": "Sunny", "tool_call_id": "call_1"},
+			{"type": "ai", "content": "It is sunny."}
+		])
+	);
+	// The round trip keeps the messages (the `.system` becomes a system message).
+	let round_trip = ChatRequest::from_langchain_messages(&messages)?;
+	assert_eq!(round_trip.to_langchain_messages(), messages);
+
+	Ok(())
+}
+
+#[test]
+fn test_chat_interop_llamaindex_round_trip_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let messages = json!([
+		{"role": "system", "content": "Be concise", "additional_kwargs": {}},
+		{"role": "user", "content": "Weather in Paris?", "additional_kwargs": {}},
+		{"role": "assistant", "content": null, "additional_kwargs": {"tool_calls": [{
+			"id": "call_1",
+			"type": "function",
+			"function": {"name": "get_weather", "arguments": "{\"city\":\"Paris\"}"}
+		}]}},
+		{"role": "tool", "content": "Sunny", "additional_kwargs": {"tool_call_id": "call_1"}},
+		{"role": "user", "blocks": [
+			{"block_type": "text", "text": "And this?"},
+			{"block_type": "image", "url": "https://example.com/cat.png", "image_mimetype": "image/png"}
+		], "additional_kwargs": {}}
+	]);
+
+	// -- Exec
+	let chat_req = ChatRequest::from_llamaindex_messages(&messages)?;
+
+	// -- Check
+	assert_eq!(chat_req.messages.len(), 5);
+	let MessageContent::ToolCalls(tool_calls) = &chat_req.messages[2].content else {
+		return Err("Should be MessageContent::ToolCalls".into());
+	};
+	assert_eq!(tool_calls[0].fn_arguments, json!({"city": "Paris"}));
+	assert_eq!(chat_req.to_llamaindex_messages(), messages);
+
+	Ok(())
+}
+
+#[test]
+fn test_chat_interop_langchain_ai_content_and_tool_calls_round_trip_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let messages = json!([
+		{"type": "human", "content": "Weather in Paris?"},
+		{"type": "ai", "content": "Let me check.", "tool_calls": [
+			{"name": "get_weather", "args": {"city": "Paris"}, "id": "call_1"}
+		]},
+		{"type": "tool", "content": "Sunny", "tool_call_id": "call_1"}
+	]);
+
+	// -- Exec
+	let chat_req = ChatRequest::from_langchain_messages(&messages)?;
+
+	// -- Check
+	// The ai content is kept as an assistant message before the tool calls.
+	assert_eq!(chat_req.messages.len(), 4);
+	assert_eq!(chat_req.messages[1].role, ChatRole::Assistant);
+	assert_eq!(chat_req.messages[1].content.text_as_str(), Some("Let me check."));
+	let MessageContent::ToolCalls(tool_calls) = &chat_req.messages[2].content else {
+		return Err("Should be MessageContent::ToolCalls".into());
+	};
+	assert_eq!(tool_calls[0].call_id, "call_1");
+	assert_eq!(chat_req.to_langchain_messages(), messages);
+
+	Ok(())
+}
+
+#[test]
+fn test_chat_interop_llamaindex_assistant_content_and_tool_calls_round_trip_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let messages = json!([
+		{"role": "user", "content": "Weather in Paris?", "additional_kwargs": {}},
+		{"role": "assistant", "content": "Let me check.", "additional_kwargs": {"tool_calls": [{
+			"id": "call_1",
+			"type": "function",
+			"function": {"name": "get_weather", "arguments": "{\"city\":\"Paris\"}"}
+		}]}},
+		{"role": "tool", "content": "Sunny", "additional_kwargs": {"tool_call_id": "call_1"}}
+	]);
+
+	// -- Exec
+	let chat_req = ChatRequest::from_llamaindex_messages(&messages)?;
+
+	// -- Check
+	assert_eq!(chat_req.messages.len(), 4);
+	assert_eq!(chat_req.messages[1].content.text_as_str(), Some("Let me check."));
+	assert!(matches!(chat_req.messages[2].content, MessageContent::ToolCalls(_)));
+	assert_eq!(chat_req.to_llamaindex_messages(), messages);
+
+	Ok(())
+}
+
+#[test]
+fn test_chat_interop_tool_message_without_call_id_skipped_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let tool_text = ChatMessage {
+		role: ChatRole::Tool,
+		content: MessageContent::from_text("Sunny"),
+	};
+	let chat_req = ChatRequest::new(vec![ChatMessage::user("Weather in Paris?"), tool_text]);
+
+	// -- Exec
+	let langchain_messages = chat_req.to_langchain_messages();
+	let llamaindex_messages = chat_req.to_llamaindex_messages();
+
+	// -- Check
+	assert_eq!(
+		langchain_messages,
+		json!([{"type": "human", "content": "Weather in Paris?"}])
+	);
+	assert_eq!(
+		llamaindex_messages,
+		json!([{"role": "user", "content": "Weather in Paris?", "additional_kwargs": {}}])
+	);
+	// The converted messages can be parsed back.
+	ChatRequest::from_langchain_messages(&langchain_messages)?;
+	ChatRequest::from_llamaindex_messages(&llamaindex_messages)?;
+
+	Ok(())
+}
+
+#[test]
+fn test_chat_interop_invalid_type_err() -> Result<()> {
+	// -- Exec
+	let res = ChatRequest::from_langchain_messages(&json!([{"type": "robot", "content": "Hi"}]));
+
+	// -- Check
+	assert!(
+		matches!(res, Err(genai::Error::InteropInvalidMessages { .. })),
+		"Should be InteropInvalidMessages, but was: {res:?}"
+	);
+
+	Ok(())
+}
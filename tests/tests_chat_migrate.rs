@@ -0,0 +1,122 @@
+use genai::chat::{migrate_chat_request, ChatMessage, ChatRequest, ChatRequestVersion, Tool, ToolType};
+use genai::Error;
+use serde_json::{json, Value};
+
+type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+#[test]
+fn test_chat_migrate_v1_to_v2_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut json = v1_chat_request_json();
+
+	// -- Exec
+	migrate_chat_request(&mut json, 1, 2)?;
+
+	// -- Check
+	assert_eq!(json["_schema_version"], 2);
+	assert_eq!(json["json_mode"], false);
+	assert_eq!(json["temperature"], Value::Null);
+	assert_eq!(json["max_tokens"], Value::Null);
+	assert_eq!(json.pointer("/tools/0/tool_type"), Some(&json!("Function")));
+	assert_eq!(json["system"], "Be concise");
+
+	Ok(())
+}
+
+#[test]
+fn test_chat_migrate_v1_to_v3_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut json = v1_chat_request_json();
+
+	// -- Exec
+	migrate_chat_request(&mut json, 1, 3)?;
+
+	// -- Check
+	assert_eq!(json["_schema_version"], 3);
+	assert_eq!(json["json_mode"], false);
+	assert_eq!(json["disabled_tools"], json!([]));
+
+	Ok(())
+}
+
+#[test]
+fn test_chat_migrate_invalid_versions_err() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut json = v1_chat_request_json();
+
+	// -- Exec
+	let downgrade_res = migrate_chat_request(&mut json, 2, 1);
+	let unknown_res = migrate_chat_request(&mut json, 1, 99);
+
+	// -- Check
+	assert!(matches!(downgrade_res, Err(Error::ChatRequestMigration { .. })));
+	assert!(matches!(unknown_res, Err(Error::ChatRequestMigration { .. })));
+	assert_eq!(json, v1_chat_request_json(), "Should not be modified on error");
+
+	Ok(())
+}
+
+#[test]
+fn test_chat_migrate_load_from_file_v1_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let path = temp_path("v1");
+	std::fs::write(&path, v1_chat_request_json().to_string())?;
+
+	// -- Exec
+	let res = ChatRequest::load_from_file(&path);
+	std::fs::remove_file(&path)?;
+	let chat_req = res?;
+
+	// -- Check
+	assert_eq!(chat_req.system.as_deref(), Some("Be concise"));
+	assert_eq!(chat_req.messages.len(), 1);
+	assert!(!chat_req.json_mode());
+	assert!(chat_req.disabled_tools().is_empty());
+	let tools = chat_req.tools.ok_or("Should have tools")?;
+	assert!(matches!(tools[0].tool_type, ToolType::Function));
+
+	Ok(())
+}
+
+#[test]
+fn test_chat_migrate_save_load_current_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let path = temp_path("current");
+	let mut chat_req = ChatRequest::new(vec![ChatMessage::user("Hi")])
+		.with_tools(vec![Tool::new("get_weather"), Tool::new("search_web")])
+		.with_max_tokens(100);
+	chat_req.disable_tool("search_web");
+
+	// -- Exec
+	chat_req.save_to_file(&path)?;
+	let saved: Value = serde_json::from_str(&std::fs::read_to_string(&path)?)?;
+	let res = ChatRequest::load_from_file(&path);
+	std::fs::remove_file(&path)?;
+	let loaded = res?;
+
+	// -- Check
+	assert_eq!(saved["_schema_version"], ChatRequestVersion::CURRENT.as_u32());
+	assert_eq!(loaded.max_tokens(), Some(100));
+	assert!(loaded.has_tool("get_weather"));
+	assert_eq!(loaded.disabled_tools().len(), 1);
+	assert_eq!(loaded.messages[0].content.text_as_str(), Some("Hi"));
+
+	Ok(())
+}
+
+// region:    --- Support
+
+/// A `ChatRequest` as saved before the request-level options (no `_schema_version`).
+fn v1_chat_request_json() -> Value {
+	json!({
+		"system": "Be concise",
+		"messages": [{"role": "User", "content": {"Text": "Hi"}}],
+		"tools": [{"name": "get_weather", "description": null, "schema": null}]
+	})
+}
+
+fn temp_path(name: &str) -> std::path::PathBuf {
+	std::env::temp_dir().join(format!("genai-tests-chat-req-{name}-{}.json", std::process::id()))
+}
+
+// endregion: --- Support
@@ -0,0 +1,105 @@
+use genai::adapter::AdapterKind;
+use genai::chat::{ChatOptions, ChatOptionsError, ReasoningEffort};
+use serde_json::json;
+
+type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+#[test]
+fn test_chat_options_builder_ok() -> Result<()> {
+	// -- Exec
+	let options = ChatOptions::builder().temperature(0.7)?.top_p(0.9)?.max_tokens(100)?.build();
+
+	// -- Check
+	assert_eq!(options.temperature, Some(0.7));
+	assert_eq!(options.top_p, Some(0.9));
+	assert_eq!(options.max_tokens, Some(100));
+
+	Ok(())
+}
+
+#[test]
+fn test_chat_options_builder_invalid_temperature_err() -> Result<()> {
+	// -- Exec
+	let res = ChatOptions::builder().temperature(5.0);
+
+	// -- Check
+	let Err(ChatOptionsError::InvalidTemperature { value, max, .. }) = res else {
+		return Err("Should have been an InvalidTemperature error".into());
+	};
+	assert_eq!(value, 5.0);
+	assert_eq!(max, 2.0);
+
+	Ok(())
+}
+
+#[test]
+fn test_chat_options_validate_for_adapter_anthropic_err() -> Result<()> {
+	// -- Setup & Fixtures
+	let options = ChatOptions::default().with_temperature(1.5).with_top_p(0.9);
+
+	// -- Exec
+	let openai_res = options.validate_for_adapter(AdapterKind::OpenAI);
+	let anthropic_res = options.validate_for_adapter(AdapterKind::Anthropic);
+
+	// -- Check
+	assert!(openai_res.is_ok());
+	let errors = anthropic_res.err().ok_or("Should have Anthropic errors")?;
+	assert_eq!(
+		errors.len(),
+		2,
+		"Should have the temperature range and the conflicting options errors"
+	);
+
+	Ok(())
+}
+
+#[test]
+fn test_chat_options_merge_patch_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let options = ChatOptions::default()
+		.with_temperature(0.7)
+		.with_max_tokens(100)
+		.with_reasoning_effort(ReasoningEffort::Low)
+		.with_extra_param("seed", 42);
+	let patch = json!({
+		"temperature": 0.2,
+		"max_tokens": null,
+		"stop_sequences": ["END"],
+		"extra_params": {"user": "jen"}
+	});
+
+	// -- Exec
+	let options = options.merge_patch(&patch)?;
+
+	// -- Check
+	assert_eq!(options.temperature, Some(0.2));
+	assert_eq!(options.max_tokens, None);
+	assert_eq!(options.reasoning_effort, Some(ReasoningEffort::Low));
+	assert_eq!(options.stop_sequences, vec!["END".to_string()]);
+	let extra_params = options.extra_params.ok_or("Should have extra params")?;
+	assert_eq!(extra_params.get("seed"), Some(&json!(42)));
+	assert_eq!(extra_params.get("user"), Some(&json!("jen")));
+
+	Ok(())
+}
+
+#[test]
+fn test_chat_options_add_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let config_options = ChatOptions::default()
+		.with_temperature(0.7)
+		.with_max_tokens(100)
+		.with_stop_sequences(vec!["END".to_string()]);
+	let call_options = ChatOptions::default().with_temperature(0.2).with_top_p(0.9);
+
+	// -- Exec
+	let options = config_options + call_options;
+
+	// -- Check
+	assert_eq!(options.temperature, Some(0.2));
+	assert_eq!(options.max_tokens, Some(100));
+	assert_eq!(options.top_p, Some(0.9));
+	assert_eq!(options.stop_sequences, vec!["END".to_string()]);
+
+	Ok(())
+}
@@ -0,0 +1,132 @@
+use genai::chat::{ChatMessage, ChatRequest, ChatRole, Tool, ToolCall, ToolResponse};
+use serde_json::json;
+
+type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+#[test]
+fn test_chat_request_prepend_message_after_systems_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let chat_req = ChatRequest::new(vec![
+		ChatMessage::system("Be concise"),
+		ChatMessage::user("Why is the sky blue?"),
+	]);
+
+	// -- Exec
+	let chat_req = chat_req.prepend_message(ChatMessage::user("Hello"));
+
+	// -- Check
+	let texts: Vec<&str> = chat_req.messages.iter().filter_map(|msg| msg.content.text_as_str()).collect();
+	assert_eq!(texts, vec!["Be concise", "Hello", "Why is the sky blue?"]);
+	assert_eq!(chat_req.message_count(), 3);
+	assert_eq!(chat_req.system_message_count(), 1);
+	assert_eq!(chat_req.messages_excluding_system().count(), 2);
+
+	Ok(())
+}
+
+#[test]
+fn test_chat_request_insert_remove_replace_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let chat_req = ChatRequest::from_user("one").append_message(ChatMessage::assistant("three"));
+
+	// -- Exec
+	let chat_req = chat_req
+		.insert_message(1, ChatMessage::user("two"))
+		.insert_message(99, ChatMessage::user("four"))
+		.replace_message(0, ChatMessage::user("ONE"))
+		.remove_message(2)
+		.remove_message(99);
+
+	// -- Check
+	let texts: Vec<&str> = chat_req.messages.iter().filter_map(|msg| msg.content.text_as_str()).collect();
+	assert_eq!(texts, vec!["ONE", "two", "four"]);
+
+	Ok(())
+}
+
+#[test]
+fn test_chat_request_iter_helpers_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let chat_req = ChatRequest::from_system("Be concise").append_messages(vec![
+		ChatMessage::user("Hi"),
+		ChatMessage::assistant("Hello"),
+		ChatMessage::user("Weather in Paris?"),
+		ChatMessage::assistant("Il fait beau à Paris"),
+	]);
+
+	// -- Exec & Check
+	assert_eq!(chat_req.messages_by_role(ChatRole::User).count(), 2);
+	assert_eq!(
+		chat_req.last_user_message().and_then(|msg| msg.content.text_as_str()),
+		Some("Weather in Paris?")
+	);
+	assert_eq!(
+		chat_req.last_assistant_message().and_then(|msg| msg.content.text_as_str()),
+		Some("Il fait beau à Paris")
+	);
+	assert_eq!(chat_req.message_pairs().count(), 2);
+	// Note: The lengths are in chars ("à" is 2 bytes).
+	assert_eq!(chat_req.total_text_len(), 10 + 2 + 5 + 17 + 20);
+	assert_eq!(chat_req.total_text_len(), chat_req.stats().total_char_count);
+
+	Ok(())
+}
+
+#[test]
+fn test_chat_request_contains_tool_response_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let tool_call = ToolCall {
+		call_id: "call_1".to_string(),
+		fn_name: "get_weather".to_string(),
+		fn_arguments: json!({"city": "Paris"}),
+	};
+	let chat_req = ChatRequest::from_user("Weather in Paris?")
+		.append_message(vec![tool_call])
+		.append_message(ToolResponse::new("call_1", r#"{"temperature": 21}"#));
+
+	// -- Exec & Check
+	assert!(chat_req.contains_tool_response("get_weather"));
+	assert!(!chat_req.contains_tool_response("get_time"));
+
+	Ok(())
+}
+
+#[test]
+fn test_chat_request_disable_enable_remove_tool_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let chat_req = ChatRequest::from_user("Hi")
+		.append_tool(Tool::new("search_web"))
+		.append_tool(Tool::new("get_weather"));
+
+	// -- Exec
+	let mut disabled_req = chat_req.clone();
+	let search_disabled = disabled_req.disable_tool("search_web");
+	let unknown_disabled = disabled_req.disable_tool("unknown");
+	let mut all_disabled_req = disabled_req.clone();
+	all_disabled_req.disable_tool("get_weather");
+	let mut enabled_req = disabled_req.clone();
+	let search_enabled = enabled_req.enable_tool("search_web");
+	let unknown_enabled = enabled_req.enable_tool("unknown");
+	let mut removed_req = chat_req.clone();
+	let removed = removed_req.remove_tool("get_weather");
+
+	// -- Check
+	assert!(search_disabled && !unknown_disabled);
+	assert!(search_enabled && !unknown_enabled);
+	assert!(chat_req.has_tool("search_web"));
+	assert!(!disabled_req.has_tool("search_web"));
+	assert!(disabled_req.has_tool("get_weather"));
+	assert_eq!(disabled_req.disabled_tools().len(), 1);
+	// No empty tool list once all disabled
+	assert!(all_disabled_req.tools.is_none());
+	assert_eq!(all_disabled_req.disabled_tools().len(), 2);
+	// Enabled back, at the end of the tools
+	let names: Vec<&str> = enabled_req.tools.iter().flatten().map(|tool| tool.name.as_str()).collect();
+	assert_eq!(names, vec!["get_weather", "search_web"]);
+	assert!(enabled_req.disabled_tools().is_empty());
+	assert_eq!(removed.map(|tool| tool.name), Some("get_weather".to_string()));
+	assert!(!removed_req.has_tool("get_weather"));
+	assert!(removed_req.remove_tool("get_weather").is_none());
+
+	Ok(())
+}
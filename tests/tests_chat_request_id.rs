@@ -0,0 +1,93 @@
+mod support;
+
+use crate::support::{mock_openai_chat_response, MockServer, Result};
+use genai::chat::ChatRequest;
+use serde_json::json;
+
+#[tokio::test]
+async fn test_chat_request_id_from_header_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start_with_headers(
+		vec![mock_openai_chat_response("Hello!")],
+		&[("x-request-id", "req_mock_123")],
+	)
+	.await?;
+
+	// -- Exec
+	let chat_res = server
+		.client()
+		.exec_chat("gpt-4o-mini", ChatRequest::from_user("Hi"), None)
+		.await?;
+
+	// -- Check
+	assert_eq!(chat_res.request_id(), Some("req_mock_123"));
+	let client_request_id = chat_res.client_request_id.as_deref().ok_or("Should have a client_request_id")?;
+	assert!(server.request_heads()[0].contains(&format!("x-client-request-id: {client_request_id}")));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_chat_request_id_anthropic_header_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	// Note: Anthropic returns the `request-id` header (no `x-` prefix).
+	let server = MockServer::start_with_headers(
+		vec![mock_openai_chat_response("Hello!")],
+		&[("request-id", "req_anthropic_456")],
+	)
+	.await?;
+
+	// -- Exec
+	let chat_res = server
+		.client()
+		.exec_chat("gpt-4o-mini", ChatRequest::from_user("Hi"), None)
+		.await?;
+
+	// -- Check
+	assert_eq!(chat_res.request_id(), Some("req_anthropic_456"));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_chat_request_id_none_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![mock_openai_chat_response("Hello!")]).await?;
+
+	// -- Exec
+	let chat_res = server
+		.client()
+		.exec_chat("gpt-4o-mini", ChatRequest::from_user("Hi"), None)
+		.await?;
+
+	// -- Check
+	assert_eq!(chat_res.request_id(), None);
+	assert!(chat_res.client_request_id.is_some());
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_chat_stream_client_request_id_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start_sse_stream(vec![
+		json!({"choices": [{"index": 0, "delta": {"content": "Hello"}, "finish_reason": null}]}),
+	])
+	.await?;
+
+	// -- Exec
+	let stream_res = server
+		.client()
+		.exec_chat_stream("gpt-4o-mini", ChatRequest::from_user("Hi"), None)
+		.await?;
+	let _ = stream_res.stream.collect_with_usage().await?;
+
+	// -- Check
+	let client_request_id = stream_res
+		.client_request_id
+		.as_deref()
+		.ok_or("Should have a client_request_id")?;
+	assert!(server.request_heads()[0].contains(&format!("x-client-request-id: {client_request_id}")));
+
+	Ok(())
+}
@@ -0,0 +1,149 @@
+mod support;
+
+use crate::support::{mock_openai_chat_response, MockServer, Result};
+use genai::adapter::AdapterKind;
+use genai::chat::{ChatOptions, ChatRequest, ReasoningEffort};
+use serde_json::json;
+
+#[tokio::test]
+async fn test_chat_request_json_mode_and_options_merge_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![mock_openai_chat_response(r#"{"ok": true}"#)]).await?;
+	let chat_req = ChatRequest::from_user("Answer in JSON")
+		.with_json_mode()
+		.with_temperature(0.2)
+		.with_max_tokens(64);
+	let options = ChatOptions::default().with_temperature(0.9).with_max_tokens(1000);
+	let (json_mode, temperature, max_tokens) = (chat_req.json_mode(), chat_req.temperature(), chat_req.max_tokens());
+
+	// -- Exec
+	server.client().exec_chat("gpt-4o-mini", chat_req, Some(&options)).await?;
+
+	// -- Check
+	assert!(json_mode);
+	assert_eq!(temperature, Some(0.2));
+	assert_eq!(max_tokens, Some(64));
+	let requests = server.requests();
+	let payload = &requests[0];
+	assert_eq!(payload.get("response_format"), Some(&json!({"type": "json_object"})));
+	assert_eq!(payload.get("temperature"), Some(&json!(0.2)));
+	assert_eq!(payload.get("max_tokens"), Some(&json!(64)));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_chat_options_reasoning_effort_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![mock_openai_chat_response("Ok")]).await?;
+	let client = server.client();
+	let options = ChatOptions::default().with_reasoning_effort(ReasoningEffort::High);
+
+	// -- Exec
+	client.exec_chat("o1", ChatRequest::from_user("Hi"), Some(&options)).await?;
+	client.exec_chat("gpt-4o", ChatRequest::from_user("Hi"), Some(&options)).await?;
+
+	// -- Check
+	let requests = server.requests();
+	assert_eq!(requests[0].get("reasoning_effort"), Some(&json!("high")));
+	assert_eq!(requests[1].get("reasoning_effort"), None);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_chat_options_prediction_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut response = mock_openai_chat_response("fn main() {}");
+	response["usage"]["completion_tokens_details"] = json!({
+		"accepted_prediction_tokens": 4,
+		"rejected_prediction_tokens": 1
+	});
+	let server = MockServer::start(vec![response]).await?;
+	let options = ChatOptions::default().with_prediction("fn main() {}");
+
+	// -- Exec
+	let chat_res = server
+		.client()
+		.exec_chat("gpt-4o", ChatRequest::from_user("Rename the function"), Some(&options))
+		.await?;
+
+	// -- Check
+	assert_eq!(
+		server.requests()[0].get("prediction"),
+		Some(&json!({"type": "content", "content": "fn main() {}"}))
+	);
+	assert_eq!(chat_res.usage.accepted_prediction_tokens, Some(4));
+	assert_eq!(chat_res.usage.rejected_prediction_tokens, Some(1));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_chat_options_extra_params_payload_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![mock_openai_chat_response("Ok")]).await?;
+	let options = ChatOptions::default()
+		.with_extra_param("seed", 42)
+		.with_extra_param("logit_bias", json!({"50256": -100}));
+
+	// -- Exec
+	server
+		.client()
+		.exec_chat("gpt-4o-mini", ChatRequest::from_user("Hi"), Some(&options))
+		.await?;
+
+	// -- Check
+	let requests = server.requests();
+	assert_eq!(requests[0]["seed"], 42);
+	assert_eq!(requests[0]["logit_bias"], json!({"50256": -100}));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_chat_options_extra_params_chat_overrides_client_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![mock_openai_chat_response("Ok")]).await?;
+	let client_options = ChatOptions::default().with_extra_param("seed", 7);
+	let client = server
+		.client_builder_for_adapter(AdapterKind::OpenAI)
+		.with_chat_options(client_options)
+		.build();
+	let options = ChatOptions::default().with_extra_param("seed", 42);
+
+	// -- Exec
+	client.exec_chat("gpt-4o-mini", ChatRequest::from_user("Hi"), None).await?;
+	client
+		.exec_chat("gpt-4o-mini", ChatRequest::from_user("Hi"), Some(&options))
+		.await?;
+
+	// -- Check
+	let requests = server.requests();
+	assert_eq!(requests[0]["seed"], 7);
+	assert_eq!(requests[1]["seed"], 42);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_chat_options_extra_param_pointer_key_err() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![mock_openai_chat_response("Ok")]).await?;
+	let options = ChatOptions::default().with_extra_param("/generationConfig/seed", 42);
+
+	// -- Exec
+	let res = server
+		.client()
+		.exec_chat("gpt-4o-mini", ChatRequest::from_user("Hi"), Some(&options))
+		.await;
+
+	// -- Check
+	assert!(
+		matches!(res, Err(genai::Error::ExtraParamInvalid { ref key, .. }) if key == "/generationConfig/seed"),
+		"Should have been an Error::ExtraParamInvalid, but was: {res:?}"
+	);
+	assert!(server.requests().is_empty(), "Should not have sent the request");
+
+	Ok(())
+}
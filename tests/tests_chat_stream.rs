@@ -0,0 +1,315 @@
+mod support;
+
+use crate::support::{MockServer, Result};
+use genai::adapter::AdapterKind;
+use genai::chat::{ChatOptions, ChatRequest, StreamLogger};
+use genai::Error;
+use serde_json::json;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio_stream::StreamExt;
+
+#[tokio::test]
+async fn test_chat_stream_timeout_first_chunk_err() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start_stalled_stream().await?;
+	let chat_req = ChatRequest::from_user("Why is the sky blue?");
+	let client = server.client_for_adapter(AdapterKind::Cohere);
+	let stream_res = client.exec_chat_stream("command-r", chat_req, None).await?;
+
+	// -- Exec
+	let mut stream = stream_res.stream.timeout_first_chunk(Duration::from_millis(200));
+	let mut timeout_err: Option<Error> = None;
+	while let Some(event) = stream.next().await {
+		if let Err(err) = event {
+			timeout_err = Some(err);
+		}
+	}
+
+	// -- Check
+	let Some(Error::FirstChunkTimeout { duration_ms, .. }) = timeout_err else {
+		return Err(format!("Should have been a FirstChunkTimeout error, but was: {timeout_err:?}").into());
+	};
+	assert_eq!(duration_ms, 200);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_chat_stream_timeout_first_chunk_delayed_poll_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start_delayed_ndjson_stream(
+		vec![
+			json!({"is_finished": false, "event_type": "stream-start"}),
+			json!({"is_finished": false, "event_type": "text-generation", "text": "Hello"}),
+			json!({"is_finished": true, "event_type": "stream-end", "response": {}}),
+		],
+		Duration::from_millis(100),
+	)
+	.await?;
+	let chat_req = ChatRequest::from_user("Why is the sky blue?");
+	let client = server.client_for_adapter(AdapterKind::Cohere);
+	let stream_res = client.exec_chat_stream("command-r", chat_req, None).await?;
+	let stream = stream_res.stream.timeout_first_chunk(Duration::from_millis(300));
+
+	// -- Exec
+	// The first poll is later than the timeout duration (the timer must start at the first poll, not before).
+	tokio::time::sleep(Duration::from_millis(500)).await;
+	let (content, _) = stream.collect_with_usage().await?;
+
+	// -- Check
+	assert_eq!(content, "Hello");
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_chat_stream_tap_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start_ndjson_stream(vec![
+		json!({"is_finished": false, "event_type": "stream-start"}),
+		json!({"is_finished": false, "event_type": "text-generation", "text": "Hello"}),
+		json!({"is_finished": false, "event_type": "text-generation", "text": " world"}),
+		json!({"is_finished": true, "event_type": "stream-end", "response": {}}),
+	])
+	.await?;
+	let client = server.client_for_adapter(AdapterKind::Cohere);
+	let events: Arc<Mutex<Vec<String>>> = Default::default();
+	let texts: Arc<Mutex<Vec<String>>> = Default::default();
+	let log = SharedWriter::default();
+
+	// -- Exec
+	let chat_res = client.exec_chat_stream("command-r", ChatRequest::from_user("Hi"), None).await?;
+	let tap_events = events.clone();
+	let tap_texts = texts.clone();
+	let mut stream = chat_res
+		.stream
+		.tap(move |event| tap_events.lock().unwrap().push(format!("{event:?}")))
+		.tap_text(move |text| tap_texts.lock().unwrap().push(text.to_string()))
+		.tap(StreamLogger::new(log.clone()).into_tap());
+	let mut consumed = 0;
+	while let Some(event) = stream.next().await {
+		event?;
+		consumed += 1;
+	}
+
+	// -- Check
+	assert_eq!(consumed, 4);
+	assert_eq!(events.lock().unwrap().len(), 4);
+	assert_eq!(*texts.lock().unwrap(), vec!["Hello", " world"]);
+	let log_content = String::from_utf8(log.0.lock().unwrap().clone())?;
+	let log_lines: Vec<&str> = log_content.lines().collect();
+	assert_eq!(log_lines.len(), 4);
+	assert_eq!(log_lines[1], r#"{"Chunk":{"content":"Hello"}}"#);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_chat_stream_pipe_to_writer_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start_ndjson_stream(cohere_stream_lines()).await?;
+	let client = server.client_for_adapter(AdapterKind::Cohere);
+	let options = ChatOptions::default().with_capture_usage(true);
+	let mut buffer: Vec<u8> = Vec::new();
+
+	// -- Exec
+	let chat_res = client
+		.exec_chat_stream("command-r", ChatRequest::from_user("Hi"), Some(&options))
+		.await?;
+	let usage = chat_res.stream.pipe_to_writer(&mut buffer).await?;
+
+	// -- Check
+	assert_eq!(String::from_utf8(buffer)?, "Hello world");
+	assert_eq!(usage.input_tokens, Some(3));
+	assert_eq!(usage.output_tokens, Some(2));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_chat_stream_with_buffer_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start_ndjson_stream(cohere_stream_lines()).await?;
+	let client = server.client_for_adapter(AdapterKind::Cohere);
+	let options = ChatOptions::default().with_capture_usage(true);
+	let mut buffer: Vec<u8> = Vec::new();
+
+	// -- Exec
+	let chat_res = client
+		.exec_chat_stream("command-r", ChatRequest::from_user("Hi"), Some(&options))
+		.await?
+		.with_buffer(1);
+	let usage = chat_res.stream.pipe_to_writer(&mut buffer).await?;
+
+	// -- Check
+	assert_eq!(String::from_utf8(buffer)?, "Hello world");
+	assert_eq!(usage.output_tokens, Some(2));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_chat_stream_groq_usage_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let x_groq = json!({"id": "req_01", "usage": {"prompt_tokens": 12, "completion_tokens": 2, "total_tokens": 14}});
+	let mut finish_chunk = groq_stream_chunk(json!({}), Some("stop"));
+	finish_chunk["x_groq"] = x_groq.clone();
+	// The usage in the `finish_reason` chunk, and in a trailing chunk without choices
+	let streams = vec![
+		vec![groq_stream_chunk(json!({"content": "Hello"}), None), finish_chunk],
+		vec![
+			groq_stream_chunk(json!({"content": "Hello"}), None),
+			groq_stream_chunk(json!({}), Some("stop")),
+			json!({"id": "chatcmpl-mock", "object": "chat.completion.chunk", "choices": [], "x_groq": x_groq}),
+		],
+	];
+	let options = ChatOptions::default().with_capture_usage(true);
+
+	for events in streams {
+		let server = MockServer::start_sse_stream(events).await?;
+		let client = server.client_for_adapter(AdapterKind::Groq);
+
+		// -- Exec
+		let (content, usage) = client
+			.exec_chat_stream("llama-3.1-8b-instant", ChatRequest::from_user("Hi"), Some(&options))
+			.await?
+			.collect_with_usage()
+			.await?;
+
+		// -- Check
+		assert_eq!(content, "Hello");
+		assert_eq!(usage.input_tokens, Some(12));
+		assert_eq!(usage.output_tokens, Some(2));
+		assert_eq!(usage.total_tokens, Some(14));
+	}
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_chat_stream_gemini_usage_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server =
+		MockServer::start_chunked_stream(gemini_stream_chunks("./tests/data/gemini-stream-chunks.json")?).await?;
+	let client = server.client_for_adapter(AdapterKind::Gemini);
+	let options = ChatOptions::default().with_capture_usage(true);
+
+	// -- Exec
+	let (content, usage) = client
+		.exec_chat_stream("gemini-1.5-flash", ChatRequest::from_user("Hi"), Some(&options))
+		.await?
+		.collect_with_usage()
+		.await?;
+
+	// -- Check
+	// The usage of the last chunk (without content), not the sum of the chunks usages
+	assert_eq!(content, "Hello world, how are you?");
+	assert_eq!(usage.input_tokens, Some(8));
+	assert_eq!(usage.output_tokens, Some(8));
+	assert_eq!(usage.total_tokens, Some(16));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_chat_stream_to_file_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start_ndjson_stream(cohere_stream_lines()).await?;
+	let client = server.client_for_adapter(AdapterKind::Cohere);
+	let path = std::env::temp_dir().join(format!("genai-tests-stream-{}.txt", std::process::id()));
+
+	// -- Exec
+	// Note: No `capture_usage` option, as it is forced.
+	let usage = client
+		.exec_chat_stream_to_file("command-r", ChatRequest::from_user("Hi"), None, &path)
+		.await?;
+	let content = std::fs::read_to_string(&path)?;
+	std::fs::remove_file(&path)?;
+
+	// -- Check
+	assert_eq!(content, "Hello world");
+	assert_eq!(usage.input_tokens, Some(3));
+	assert_eq!(usage.output_tokens, Some(2));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_chat_stream_to_file_request_err_file_kept_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start_error(401, json!({"message": "invalid api token"})).await?;
+	let client = server.client_for_adapter(AdapterKind::Cohere);
+	let path = std::env::temp_dir().join(format!("genai-tests-stream-err-{}.txt", std::process::id()));
+	std::fs::write(&path, "previous content")?;
+
+	// -- Exec
+	let res = client
+		.exec_chat_stream_to_file("command-r", ChatRequest::from_user("Hi"), None, &path)
+		.await;
+	let content = std::fs::read_to_string(&path)?;
+	std::fs::remove_file(&path)?;
+
+	// -- Check
+	assert!(res.is_err(), "Should have failed: {res:?}");
+	assert_eq!(
+		content, "previous content",
+		"The file should not be truncated on a failed request"
+	);
+
+	Ok(())
+}
+
+// region:    --- Support
+
+fn cohere_stream_lines() -> Vec<serde_json::Value> {
+	vec![
+		json!({"is_finished": false, "event_type": "stream-start"}),
+		json!({"is_finished": false, "event_type": "text-generation", "text": "Hello"}),
+		json!({"is_finished": false, "event_type": "text-generation", "text": " world"}),
+		json!({"is_finished": true, "event_type": "stream-end", "response": {
+			"meta": {"tokens": {"input_tokens": 3, "output_tokens": 2}}
+		}}),
+	]
+}
+
+fn groq_stream_chunk(delta: serde_json::Value, finish_reason: Option<&str>) -> serde_json::Value {
+	json!({
+		"id": "chatcmpl-mock",
+		"object": "chat.completion.chunk",
+		"model": "llama-3.1-8b-instant",
+		"choices": [{"index": 0, "delta": delta, "finish_reason": finish_reason}]
+	})
+}
+
+/// The recorded Gemini stream items of the file, in the `streamGenerateContent` wire format
+/// (a pretty JSON array, with one item per body chunk).
+fn gemini_stream_chunks(path: &str) -> Result<Vec<String>> {
+	let items: Vec<serde_json::Value> = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+	let mut chunks: Vec<String> = items
+		.iter()
+		.enumerate()
+		.map(|(idx, item)| {
+			let prefix = if idx == 0 { "[" } else { ",\r\n" };
+			format!("{prefix}{}\n", serde_json::to_string_pretty(item).unwrap_or_default())
+		})
+		.collect();
+	chunks.push("]".to_string());
+	Ok(chunks)
+}
+
+#[derive(Clone, Default)]
+struct SharedWriter(Arc<Mutex<Vec<u8>>>);
+
+impl std::io::Write for SharedWriter {
+	fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+		self.0.lock().unwrap().extend_from_slice(buf);
+		Ok(buf.len())
+	}
+
+	fn flush(&mut self) -> std::io::Result<()> {
+		Ok(())
+	}
+}
+
+// endregion: --- Support
@@ -0,0 +1,141 @@
+mod support;
+
+use crate::support::{mock_openai_chat_response, MockServer, Result};
+use genai::chat::{ChatRequest, Tool, ToolSchemaRegistry};
+use genai::Error;
+use serde_json::{json, Value};
+
+#[tokio::test]
+async fn test_chat_tool_loop_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![
+		mock_openai_tool_call_response("call_1", "get_weather", r#"{"city":"Paris"}"#),
+		mock_openai_chat_response("It is sunny in Paris."),
+	])
+	.await?;
+	let client = server.client();
+	let registry = registry();
+	let chat_req = ChatRequest::from_user("Weather in Paris?").with_tools(registry.tool_schemas());
+
+	// -- Exec
+	let chat_res = client.exec_chat_with_tools("gpt-4o-mini", chat_req, None, &registry, 3).await?;
+
+	// -- Check
+	assert_eq!(chat_res.content_text_as_str(), Some("It is sunny in Paris."));
+	let requests = server.requests();
+	assert_eq!(requests.len(), 2);
+	assert_eq!(
+		requests[1].pointer("/messages/1/tool_calls/0/id"),
+		Some(&json!("call_1"))
+	);
+	assert_eq!(
+		requests[1].pointer("/messages/2"),
+		Some(&json!({"role": "tool", "tool_call_id": "call_1", "content": "Sunny in Paris"}))
+	);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_chat_tool_loop_exceeded_err() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![
+		mock_openai_tool_call_response("call_1", "get_weather", r#"{"city":"Paris"}"#),
+		mock_openai_tool_call_response("call_2", "get_weather", r#"{"city":"Lyon"}"#),
+	])
+	.await?;
+	let client = server.client();
+	let registry = registry();
+	let chat_req = ChatRequest::from_user("Weather in Paris?").with_tools(registry.tool_schemas());
+
+	// -- Exec
+	let res = client.exec_chat_with_tools("gpt-4o-mini", chat_req, None, &registry, 1).await;
+
+	// -- Check
+	assert!(
+		matches!(res, Err(Error::ToolCallLoopExceeded { rounds: 1 })),
+		"Should be ToolCallLoopExceeded, but was: {res:?}"
+	);
+	assert_eq!(server.requests().len(), 2);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_chat_tool_loop_disabled_tool_not_in_payload_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![mock_openai_chat_response("Hello")]).await?;
+	let client = server.client();
+	let mut chat_req = ChatRequest::from_user("Weather in Paris?")
+		.with_tools(registry().tool_schemas())
+		.append_tool(Tool::new("search_web").with_schema(json!({"type": "object", "properties": {}})));
+	chat_req.disable_tool("search_web");
+
+	// -- Exec
+	client.exec_chat("gpt-4o-mini", chat_req.clone(), None).await?;
+	chat_req.disable_tool("get_weather");
+	client.exec_chat("gpt-4o-mini", chat_req, None).await?;
+
+	// -- Check
+	let requests = server.requests();
+	let tool_names: Vec<&str> = requests[0]["tools"]
+		.as_array()
+		.ok_or("Should have tools")?
+		.iter()
+		.filter_map(|tool| tool.pointer("/function/name")?.as_str())
+		.collect();
+	assert_eq!(tool_names, vec!["get_weather"]);
+	assert!(requests[1].get("tools").is_none());
+
+	Ok(())
+}
+
+// region:    --- Support
+
+fn registry() -> ToolSchemaRegistry {
+	let mut registry = ToolSchemaRegistry::new();
+	registry
+		.register_from_schema(
+			json!({
+				"type": "function",
+				"function": {
+					"name": "get_weather",
+					"description": "Get the weather of a city",
+					"parameters": {"type": "object", "properties": {"city": {"type": "string"}}}
+				}
+			}),
+			|args| {
+				let city = args
+					.and_then(|args| args.get("city"))
+					.and_then(|c| c.as_str())
+					.unwrap_or_default();
+				format!("Sunny in {city}")
+			},
+		)
+		.expect("Should be a valid tool schema");
+	registry
+}
+
+fn mock_openai_tool_call_response(call_id: &str, fn_name: &str, arguments: &str) -> Value {
+	json!({
+		"id": "chatcmpl-mock",
+		"object": "chat.completion",
+		"model": "mock-model",
+		"choices": [{
+			"index": 0,
+			"message": {
+				"role": "assistant",
+				"content": null,
+				"tool_calls": [{
+					"id": call_id,
+					"type": "function",
+					"function": { "name": fn_name, "arguments": arguments }
+				}]
+			},
+			"finish_reason": "tool_calls"
+		}],
+		"usage": { "prompt_tokens": 10, "completion_tokens": 10, "total_tokens": 20 }
+	})
+}
+
+// endregion: --- Support
@@ -0,0 +1,53 @@
+mod support;
+
+use crate::support::{MockServer, Result};
+use genai::adapter::AdapterKind;
+use genai::chat::ChatRequest;
+use genai::{Client, Error};
+use std::time::{Duration, Instant};
+
+#[tokio::test]
+async fn test_client_config_with_timeout_err() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start_stalled().await?;
+	let client = server
+		.client_builder_for_adapter(AdapterKind::OpenAI)
+		.with_timeout(Duration::from_millis(200))
+		.build();
+
+	// -- Exec
+	let start = Instant::now();
+	let res = client.exec_chat("gpt-4o-mini", ChatRequest::from_user("Hi"), None).await;
+
+	// -- Check
+	assert!(
+		matches!(res, Err(Error::WebModelCall { .. })),
+		"Should have timed out: {res:?}"
+	);
+	let elapsed = start.elapsed();
+	assert!(elapsed >= Duration::from_millis(200) && elapsed < Duration::from_secs(5));
+
+	Ok(())
+}
+
+#[test]
+fn test_client_config_with_proxy_invalid_err() -> Result<()> {
+	// -- Exec
+	let res = Client::builder().with_proxy("http://[::1");
+
+	// -- Check
+	let Err(Error::ProxyUrlInvalid { url, .. }) = res else {
+		return Err("Should have been a ProxyUrlInvalid error".into());
+	};
+	assert_eq!(url, "http://[::1");
+
+	Ok(())
+}
+
+#[test]
+fn test_client_config_with_proxy_ok() -> Result<()> {
+	// -- Exec & Check
+	Client::builder().with_proxy("http://127.0.0.1:8080")?.build();
+
+	Ok(())
+}
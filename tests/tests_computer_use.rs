@@ -0,0 +1,73 @@
+mod support;
+
+use crate::support::{MockServer, Result};
+use genai::adapter::AdapterKind;
+use genai::chat::{ChatRequest, ComputerToolKind, ComputerUseTool, Tool};
+use genai::Error;
+use serde_json::json;
+
+#[tokio::test]
+async fn test_computer_use_anthropic_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![json!({
+		"content": [
+			{"type": "text", "text": "Let me take a screenshot."},
+			{"type": "tool_use", "id": "toolu_01", "name": "computer", "input": {"action": "screenshot"}}
+		],
+		"usage": {"input_tokens": 10, "output_tokens": 5}
+	})])
+	.await?;
+	let client = server.client_for_adapter(AdapterKind::Anthropic);
+	let chat_req = ChatRequest::from_user("Open the browser")
+		.append_tool(Tool::computer_use(
+			ComputerUseTool::new(ComputerToolKind::Computer).with_display_size(1024, 768),
+		))
+		.append_tool(Tool::computer_use(ComputerUseTool::new(ComputerToolKind::Bash)));
+
+	// -- Exec
+	let chat_res = client.exec_chat("claude-3-5-sonnet-20241022", chat_req, None).await?;
+
+	// -- Check
+	let request = &server.requests()[0];
+	assert_eq!(
+		request.pointer("/tools/0"),
+		Some(
+			&json!({"type": "computer_20241022", "name": "computer", "display_width_px": 1024, "display_height_px": 768})
+		)
+	);
+	assert_eq!(
+		request.pointer("/tools/1"),
+		Some(&json!({"type": "bash_20241022", "name": "bash"}))
+	);
+	assert!(server.request_heads()[0].contains("anthropic-beta: computer-use-2024-10-22"));
+
+	let tool_calls = chat_res.into_tool_calls().ok_or("Should have tool calls")?;
+	assert_eq!(
+		ComputerToolKind::from_tool_name(&tool_calls[0].fn_name),
+		Some(ComputerToolKind::Computer)
+	);
+	assert_eq!(tool_calls[0].fn_arguments, json!({"action": "screenshot"}));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_computer_use_openai_not_supported_err() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![]).await?;
+	let client = server.client();
+	let chat_req = ChatRequest::from_user("Open the browser")
+		.append_tool(Tool::computer_use(ComputerUseTool::new(ComputerToolKind::Bash)));
+
+	// -- Exec
+	let res = client.exec_chat("gpt-4o-mini", chat_req, None).await;
+
+	// -- Check
+	assert!(
+		matches!(&res, Err(Error::ToolTypeNotSupported { tool_name, .. }) if tool_name == "bash"),
+		"Should be ToolTypeNotSupported: {res:?}"
+	);
+	assert!(server.requests().is_empty());
+
+	Ok(())
+}
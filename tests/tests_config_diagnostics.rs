@@ -0,0 +1,111 @@
+mod support;
+
+use crate::support::{MockServer, Result};
+use genai::adapter::AdapterKind;
+use genai::resolver::{AuthData, Endpoint, ServiceTargetResolver};
+use genai::{check_adapter_config, check_adapter_reachability, Client, ServiceTarget, Severity};
+
+// Note: Each test uses its own adapter kind, since the environment variables are process-wide.
+
+#[test]
+fn test_config_diagnostics_missing_env_err() -> Result<()> {
+	// -- Setup & Fixtures
+	std::env::remove_var("XAI_API_KEY");
+
+	// -- Exec
+	let warnings = check_adapter_config(AdapterKind::Xai);
+
+	// -- Check
+	let warning = warnings
+		.iter()
+		.find(|w| w.severity == Severity::Error)
+		.ok_or("Should have an error")?;
+	assert_eq!(warning.message, "XAI_API_KEY is not set.");
+	assert_eq!(warning.fix_hint, "Set it with `export XAI_API_KEY=xai-...`");
+
+	Ok(())
+}
+
+#[test]
+fn test_config_diagnostics_quoted_key_warning() -> Result<()> {
+	// -- Setup & Fixtures
+	std::env::set_var("DEEPSEEK_API_KEY", "\"sk-123\"\n");
+
+	// -- Exec
+	let warnings = check_adapter_config(AdapterKind::DeepSeek);
+
+	// -- Check
+	let messages: Vec<&str> = warnings.iter().map(|w| w.message.as_str()).collect();
+	assert_eq!(
+		messages,
+		vec![
+			"DEEPSEEK_API_KEY has leading or trailing whitespace.",
+			"DEEPSEEK_API_KEY is quoted."
+		]
+	);
+	assert!(warnings.iter().all(|w| w.severity == Severity::Warning));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_config_diagnostics_reachability_not_local_ok() -> Result<()> {
+	// -- Exec
+	// Note: The provider endpoints are not local, so no network call is made.
+	let warnings = check_adapter_reachability(AdapterKind::Anthropic).await;
+
+	// -- Check
+	assert!(warnings.is_empty(), "Should have no warnings: {warnings:?}");
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_config_diagnostics_client_diagnose_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![]).await?;
+	let client = server.client_for_adapter(AdapterKind::OpenAI);
+
+	// -- Exec
+	let warnings = client.diagnose().await;
+
+	// -- Check
+	assert!(warnings.is_empty(), "Should have no warnings: {warnings:?}");
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_config_diagnostics_client_diagnose_base_url_err() -> Result<()> {
+	// -- Setup & Fixtures
+	let target_resolver = ServiceTargetResolver::from_resolver_fn(
+		|service_target: ServiceTarget| -> core::result::Result<ServiceTarget, genai::resolver::Error> {
+			Ok(ServiceTarget {
+				endpoint: Endpoint::from_static("http://127.0.0.1:1/api"),
+				auth: AuthData::from_single("sk-123"),
+				..service_target
+			})
+		},
+	);
+	let client = Client::builder().with_service_target_resolver(target_resolver).build();
+
+	// -- Exec
+	let warnings = client.diagnose().await;
+
+	// -- Check
+	let ollama_messages: Vec<&str> = warnings
+		.iter()
+		.filter(|w| w.adapter_kind == AdapterKind::Ollama)
+		.map(|w| w.message.as_str())
+		.collect();
+	assert_eq!(
+		ollama_messages,
+		vec![
+			"Base URL 'http://127.0.0.1:1/api' does not end with '/'.",
+			"The server is not reachable at 'http://127.0.0.1:1/api'."
+		]
+	);
+	assert_eq!(warnings.len(), AdapterKind::ALL.len() * 2);
+
+	Ok(())
+}
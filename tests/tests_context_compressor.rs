@@ -0,0 +1,142 @@
+mod support;
+
+use crate::support::{mock_openai_chat_response, MockServer, Result};
+use genai::chat::{
+	count_request_tokens, CharTokenCounter, ChatMessage, ChatRequest, ChatRole, ContextCompressor, MessageContent,
+	ToolCall, ToolResponse,
+};
+use serde_json::json;
+
+const MODEL: &str = "gpt-4o-mini";
+
+#[tokio::test]
+async fn test_context_compressor_compress_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![mock_openai_chat_response("User asked about the sky and the sea.")]).await?;
+	let compressor = ContextCompressor::new(server.client(), MODEL, "Summarize these messages");
+	let long_text = "word ".repeat(100);
+	let mut chat_req = ChatRequest::from_system("Be concise").append_messages(vec![
+		ChatMessage::user(format!("Why is the sky blue? {long_text}")),
+		ChatMessage::assistant(format!("Because of scattering. {long_text}")),
+		ChatMessage::user(format!("Why is the sea blue? {long_text}")),
+		ChatMessage::assistant(format!("Mostly absorption. {long_text}")),
+		ChatMessage::user("And the grass?"),
+	]);
+
+	// -- Exec
+	compressor.compress(&mut chat_req, 200, &CharTokenCounter).await?;
+
+	// -- Check
+	let first_msg = chat_req.messages.first().ok_or("Should have messages")?;
+	assert!(matches!(first_msg.role, ChatRole::System));
+	assert_eq!(
+		first_msg.content.text_as_str(),
+		Some("Summary of earlier conversation: User asked about the sky and the sea.")
+	);
+	let last_msg = chat_req.messages.last().ok_or("Should have messages")?;
+	assert_eq!(last_msg.content.text_as_str(), Some("And the grass?"));
+	assert!(count_request_tokens(&chat_req, &CharTokenCounter) <= 200);
+	// the summary request was sent with the summary prompt
+	let requests = server.requests();
+	assert_eq!(requests.len(), 1);
+	assert_eq!(
+		requests[0].pointer("/messages/0/content").and_then(|v| v.as_str()),
+		Some("Summarize these messages")
+	);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_context_compressor_within_budget_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![mock_openai_chat_response("Should not be called")]).await?;
+	let compressor = ContextCompressor::new(server.client(), MODEL, "Summarize these messages");
+	let mut chat_req = ChatRequest::from_user("Why is the sky blue?");
+
+	// -- Exec
+	compressor.compress(&mut chat_req, 1000, &CharTokenCounter).await?;
+
+	// -- Check
+	assert_eq!(chat_req.messages.len(), 1);
+	assert!(server.requests().is_empty());
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_context_compressor_keeps_tool_round_together_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![mock_openai_chat_response("User asked about the weather.")]).await?;
+	let compressor = ContextCompressor::new(server.client(), MODEL, "Summarize these messages");
+	let long_text = "word ".repeat(100);
+	let tool_call = ToolCall {
+		call_id: "call_1".to_string(),
+		fn_name: "get_weather".to_string(),
+		fn_arguments: json!({"city": "Paris"}),
+	};
+	// The budget is reached after the tool calls message, so the tool round is at the cut point.
+	let mut chat_req = ChatRequest::default().append_messages(vec![
+		ChatMessage::user(format!("What is the weather in Paris? {long_text}")),
+		ChatMessage::from(vec![tool_call]),
+		ChatMessage::from(ToolResponse::new(
+			"call_1",
+			format!("{{\"weather\": \"sunny\", \"note\": \"{long_text}\"}}"),
+		)),
+		ChatMessage::assistant("It is sunny in Paris."),
+		ChatMessage::user("And tomorrow?"),
+	]);
+
+	// -- Exec
+	compressor.compress(&mut chat_req, 150, &CharTokenCounter).await?;
+
+	// -- Check
+	// the tool calls and tool responses were summarized together
+	assert_eq!(chat_req.messages.len(), 3);
+	assert!(matches!(chat_req.messages[0].role, ChatRole::System));
+	assert_eq!(
+		chat_req.messages[1].content.text_as_str(),
+		Some("It is sunny in Paris.")
+	);
+	let transcript = server.requests()[0]
+		.pointer("/messages/1/content")
+		.and_then(|v| v.as_str())
+		.map(|v| v.to_string())
+		.ok_or("Should have a transcript")?;
+	assert!(transcript.contains("call_1"));
+	assert!(transcript.contains("sunny"));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_context_compressor_keeps_last_tool_round_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![mock_openai_chat_response("User asked about Paris.")]).await?;
+	let compressor = ContextCompressor::new(server.client(), MODEL, "Summarize these messages");
+	let long_text = "word ".repeat(100);
+	let tool_call = ToolCall {
+		call_id: "call_1".to_string(),
+		fn_name: "get_weather".to_string(),
+		fn_arguments: json!({"city": "Paris"}),
+	};
+	let mut chat_req = ChatRequest::default().append_messages(vec![
+		ChatMessage::user(format!("Tell me about Paris. {long_text}")),
+		ChatMessage::assistant(format!("Paris is the capital of France. {long_text}")),
+		ChatMessage::user("What is the weather there?"),
+		ChatMessage::from(vec![tool_call]),
+		ChatMessage::from(ToolResponse::new("call_1", format!("{{\"weather\": \"{long_text}\"}}"))),
+	]);
+
+	// -- Exec
+	compressor.compress(&mut chat_req, 10, &CharTokenCounter).await?;
+
+	// -- Check
+	// the last tool round (ending with the last message) is never summarized
+	assert_eq!(chat_req.messages.len(), 3);
+	assert!(matches!(chat_req.messages[0].role, ChatRole::System));
+	assert!(matches!(chat_req.messages[1].content, MessageContent::ToolCalls(_)));
+	assert!(matches!(chat_req.messages[2].content, MessageContent::ToolResponses(_)));
+
+	Ok(())
+}
@@ -0,0 +1,54 @@
+mod support;
+
+use crate::support::{mock_openai_chat_response, MockServer, Result};
+use genai::chat::ChatRequest;
+use genai::middleware::{ConversationRecorder, ConversationReplayer};
+use genai::Error;
+
+#[tokio::test]
+async fn test_conversation_record_replay_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![
+		mock_openai_chat_response("Blue, because of Rayleigh scattering."),
+		mock_openai_chat_response("Hello!"),
+	])
+	.await?;
+	let path = std::env::temp_dir().join(format!("genai-tests-replay-{}.jsonl", std::process::id()));
+	let recorder = ConversationRecorder::new(server.client(), &path);
+	recorder
+		.exec_chat("gpt-4o-mini", ChatRequest::from_user("Why is the sky blue?"), None)
+		.await?;
+	recorder.exec_chat("gpt-4o-mini", ChatRequest::from_user("Hi"), None).await?;
+
+	let recorded_lines: Vec<serde_json::Value> = std::fs::read_to_string(&path)?
+		.lines()
+		.map(serde_json::from_str)
+		.collect::<serde_json::Result<_>>()?;
+
+	// -- Exec
+	let replay_client = ConversationReplayer::from_file(&path)?;
+	let hi_res = replay_client
+		.exec_chat("gpt-4o-mini", ChatRequest::from_user("Hi"), None)
+		.await?;
+	let sky_res = replay_client
+		.exec_chat("gpt-4o-mini", ChatRequest::from_user("Why is the sky blue?"), None)
+		.await?;
+	let unknown_res = replay_client
+		.exec_chat("gpt-4o-mini", ChatRequest::from_user("Not recorded"), None)
+		.await;
+	std::fs::remove_file(&path)?;
+
+	// -- Check
+	assert_eq!(hi_res.content_text_as_str(), Some("Hello!"));
+	assert_eq!(
+		sky_res.content_text_as_str(),
+		Some("Blue, because of Rayleigh scattering.")
+	);
+	assert!(matches!(unknown_res, Err(Error::NoRecordedResponse { .. })));
+	// One JSON line per response (appended).
+	assert_eq!(recorded_lines.len(), 2);
+	assert_eq!(recorded_lines[1]["request"]["messages"][0]["content"], "Hi");
+	assert_eq!(server.requests().len(), 2, "Replay should not call the server");
+
+	Ok(())
+}
@@ -0,0 +1,182 @@
+mod support;
+
+use crate::support::{mock_openai_chat_response, MockServer, Result};
+use genai::adapter::{AdapterKind, TextResponseParser};
+use genai::chat::ChatRequest;
+use genai::{CostBudget, Error};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use tokio_stream::StreamExt;
+
+#[tokio::test]
+async fn test_cost_budget_exceeded_err() -> Result<()> {
+	// -- Setup & Fixtures
+	// gpt-4o: 100k input tokens ($0.25) + 100k output tokens ($1.0) = $1.25 per call
+	let server = MockServer::start(vec![response_with_usage(100_000, 100_000)]).await?;
+	let client = server
+		.client_builder_for_adapter(AdapterKind::OpenAI)
+		.with_cost_budget(CostBudget::new(2.0, 1.0, 1.5))
+		.build();
+
+	// -- Exec
+	client.exec_chat("gpt-4o", ChatRequest::from_user("Hi"), None).await?;
+	client.exec_chat("gpt-4o", ChatRequest::from_user("Hi"), None).await?;
+	let res = client.exec_chat("gpt-4o", ChatRequest::from_user("Hi"), None).await;
+
+	// -- Check
+	assert_eq!(client.accumulated_cost(), 2.5);
+	match res {
+		Err(Error::CostBudgetExceeded { spent_usd, limit_usd }) => {
+			assert_eq!(spent_usd, 2.5);
+			assert_eq!(limit_usd, 2.0);
+		}
+		other => return Err(format!("Should be CostBudgetExceeded, but was {other:?}").into()),
+	}
+	// The last call should not reach the API
+	assert_eq!(server.requests().len(), 2);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_cost_budget_accumulated_cost_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	// gpt-4o-mini: 1M input tokens ($0.15) + 1M output tokens ($0.6) = $0.75
+	let server = MockServer::start(vec![response_with_usage(1_000_000, 1_000_000)]).await?;
+	let client = server.client_for_adapter(AdapterKind::OpenAI);
+	let client_clone = client.clone();
+
+	// -- Exec
+	client.exec_chat("gpt-4o-mini", ChatRequest::from_user("Hi"), None).await?;
+	client_clone
+		.exec_chat("gpt-4o-mini", ChatRequest::from_user("Hi"), None)
+		.await?;
+	// Unknown price, not counted
+	client.exec_chat("gpt-3.5-turbo", ChatRequest::from_user("Hi"), None).await?;
+
+	// -- Check
+	assert!((client.accumulated_cost() - 1.5).abs() < 1e-9);
+	assert_eq!(client.accumulated_cost(), client_clone.accumulated_cost());
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_cost_budget_stream_cost_added_at_end_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	// gpt-4o: 100k input tokens ($0.25) + 100k output tokens ($1.0) = $1.25
+	let server = MockServer::start_sse_stream(stream_with_usage(100_000, 100_000)).await?;
+	let client = server
+		.client_builder_for_adapter(AdapterKind::OpenAI)
+		.with_cost_budget(CostBudget::new(10.0, 2.0, 5.0))
+		.build();
+
+	// -- Exec
+	// Note: No `capture_usage` option, as it is forced with a budget.
+	let chat_res = client.exec_chat_stream("gpt-4o", ChatRequest::from_user("Hi"), None).await?;
+	let cost_before_end = client.accumulated_cost();
+	let mut stream = chat_res.stream;
+	while let Some(event) = stream.next().await {
+		event?;
+	}
+
+	// -- Check
+	assert_eq!(cost_before_end, 0.0);
+	assert_eq!(client.accumulated_cost(), 1.25);
+	assert_eq!(
+		server.requests()[0].pointer("/stream_options/include_usage"),
+		Some(&json!(true))
+	);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_cost_budget_stream_response_parser_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start_sse_stream(stream_with_usage(100_000, 100_000)).await?;
+	let client = server
+		.client_builder_for_adapter(AdapterKind::OpenAI)
+		.with_cost_budget(CostBudget::new(10.0, 2.0, 5.0))
+		.with_response_parser(
+			AdapterKind::OpenAI,
+			Arc::new(TextResponseParser::new(AdapterKind::OpenAI)),
+		)
+		.build();
+
+	// -- Exec
+	let chat_res = client.exec_chat_stream("gpt-4o", ChatRequest::from_user("Hi"), None).await?;
+	let (content, usage) = chat_res.collect_with_usage().await?;
+
+	// -- Check
+	// The custom parser stream still captures the usage, so the stream cost is added.
+	assert_eq!(content, "Hello");
+	assert_eq!(usage.total_tokens, Some(200_000));
+	assert_eq!(client.accumulated_cost(), 1.25);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_cost_budget_stream_exceeded_err() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start_sse_stream(stream_with_usage(100_000, 100_000)).await?;
+	let client = server
+		.client_builder_for_adapter(AdapterKind::OpenAI)
+		.with_cost_budget(CostBudget::new(1.0, 2.0, 0.5))
+		.build();
+	let chat_res = client.exec_chat_stream("gpt-4o", ChatRequest::from_user("Hi"), None).await?;
+	let mut stream = chat_res.stream;
+	while let Some(event) = stream.next().await {
+		event?;
+	}
+
+	// -- Exec
+	let res = client.exec_chat_stream("gpt-4o", ChatRequest::from_user("Hi"), None).await;
+
+	// -- Check
+	match res {
+		Err(Error::CostBudgetExceeded { spent_usd, limit_usd }) => {
+			assert_eq!(spent_usd, 1.25);
+			assert_eq!(limit_usd, 1.0);
+		}
+		Err(other) => return Err(format!("Should be CostBudgetExceeded, but was {other:?}").into()),
+		Ok(_) => return Err("Should be CostBudgetExceeded, but was Ok".into()),
+	}
+	// The second stream should not reach the API
+	assert_eq!(server.requests().len(), 1);
+
+	Ok(())
+}
+
+// region:    --- Support
+
+fn response_with_usage(prompt_tokens: i32, completion_tokens: i32) -> Value {
+	let mut response = mock_openai_chat_response("Hello");
+	response["usage"] = json!({
+		"prompt_tokens": prompt_tokens,
+		"completion_tokens": completion_tokens,
+		"total_tokens": prompt_tokens + completion_tokens
+	});
+	response
+}
+
+fn stream_with_usage(prompt_tokens: i32, completion_tokens: i32) -> Vec<Value> {
+	vec![
+		json!({
+			"id": "chatcmpl-mock",
+			"object": "chat.completion.chunk",
+			"choices": [{ "index": 0, "delta": { "content": "Hello" }, "finish_reason": null }]
+		}),
+		json!({
+			"choices": [],
+			"usage": {
+				"prompt_tokens": prompt_tokens,
+				"completion_tokens": completion_tokens,
+				"total_tokens": prompt_tokens + completion_tokens
+			}
+		}),
+	]
+}
+
+// endregion: --- Support
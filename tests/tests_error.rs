@@ -0,0 +1,79 @@
+mod support;
+
+use crate::support::{MockServer, Result};
+use genai::adapter::AdapterKind;
+use genai::chat::ChatRequest;
+use genai::{ApiError, Error, ModelIden};
+use std::error::Error as _;
+use std::time::Duration;
+
+#[test]
+fn test_error_api_error_transient_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let api_error = |status_code| Error::ApiError {
+		model_iden: ModelIden::new(AdapterKind::OpenAI, "gpt-4o-mini"),
+		api_error: Box::new(ApiError::from_status_body(status_code, "Too Many Requests")),
+		adapter_error: None,
+	};
+
+	// -- Exec & Check
+	assert!(api_error(429).is_transient());
+	assert!(api_error(503).is_transient());
+	assert!(!api_error(400).is_transient());
+	assert_eq!(
+		api_error(429).to_string(),
+		"API error from openai/gpt-4o-mini: HTTP 429: Too Many Requests"
+	);
+
+	Ok(())
+}
+
+#[test]
+fn test_error_stream_parse_source_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let serde_error = serde_json::from_str::<serde_json::Value>("{not json").unwrap_err();
+	let error = Error::StreamParse {
+		model_iden: ModelIden::new(AdapterKind::Anthropic, "claude-3-haiku-20240307"),
+		serde_error,
+	};
+
+	// -- Exec
+	let source = error.source().ok_or("Should have a source")?;
+
+	// -- Check
+	assert!(source.downcast_ref::<serde_json::Error>().is_some());
+	// Note: The inner error text is only in the source.
+	assert_eq!(
+		error.to_string(),
+		"Cannot parse the stream event of anthropic/claude-3-haiku-20240307"
+	);
+	assert!(!error.is_transient());
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_error_web_call_timeout_transient_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start_stalled().await?;
+	let client = server
+		.client_builder_for_adapter(AdapterKind::OpenAI)
+		.with_timeout(Duration::from_millis(200))
+		.build();
+
+	// -- Exec
+	let error = client
+		.exec_chat("gpt-4o-mini", ChatRequest::from_user("Hi"), None)
+		.await
+		.err()
+		.ok_or("Should have failed")?;
+
+	// -- Check
+	assert!(error.is_transient(), "Should be transient: {error:?}");
+	let source = error.source().ok_or("Should have a source")?;
+	assert!(source.downcast_ref::<genai::webc::Error>().is_some());
+	assert_eq!(error.to_string(), "Web call failed for openai/gpt-4o-mini");
+	assert!(!source.to_string().is_empty());
+
+	Ok(())
+}
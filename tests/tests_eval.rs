@@ -0,0 +1,72 @@
+use genai::adapter::AdapterKind;
+use genai::chat::{ChatResponse, MessageContent, MetaUsage};
+use genai::eval::{compare_usages, diff_responses, TokenPrice};
+use genai::ModelIden;
+
+type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+#[test]
+fn test_eval_diff_responses_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let a = response("The sky is blue.\nIt is due to Rayleigh scattering.\nThe end.");
+	let b = response("The sky is blue.\nIt is due to Mie scattering.\nThe end.");
+
+	// -- Exec
+	let diff = diff_responses(&a, &b);
+
+	// -- Check
+	assert_eq!(diff.removed_lines, vec!["It is due to Rayleigh scattering."]);
+	assert_eq!(diff.added_lines, vec!["It is due to Mie scattering."]);
+	// 11 unique tokens, 9 in common
+	assert!(
+		(diff.similarity - 9.0 / 11.0).abs() < 1e-6,
+		"similarity: {}",
+		diff.similarity
+	);
+
+	Ok(())
+}
+
+#[test]
+fn test_eval_compare_usages_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let a = usage(100, 50);
+	let b = usage(100, 150);
+
+	// -- Exec
+	let comparison = compare_usages(&a, &b, Some(TokenPrice::new(1.0, 10.0)));
+	let no_price_comparison = compare_usages(&a, &b, None);
+
+	// -- Check
+	assert_eq!(comparison.token_delta, 100);
+	assert!((comparison.cost_delta_usd - 0.001).abs() < 1e-12);
+	assert_eq!(no_price_comparison.token_delta, 100);
+	assert_eq!(no_price_comparison.cost_delta_usd, 0.0);
+
+	Ok(())
+}
+
+// region:    --- Support
+
+fn response(text: &str) -> ChatResponse {
+	ChatResponse {
+		content: Some(MessageContent::from_text(text)),
+		model_iden: ModelIden::new(AdapterKind::OpenAI, "gpt-4o-mini"),
+		usage: MetaUsage::default(),
+		request_id: None,
+		client_request_id: None,
+		adapter_meta: None,
+		citations: Vec::new(),
+	}
+}
+
+fn usage(input_tokens: i32, output_tokens: i32) -> MetaUsage {
+	MetaUsage {
+		input_tokens: Some(input_tokens),
+		output_tokens: Some(output_tokens),
+		total_tokens: Some(input_tokens + output_tokens),
+		..Default::default()
+	}
+}
+
+// endregion: --- Support
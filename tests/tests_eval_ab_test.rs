@@ -0,0 +1,43 @@
+mod support;
+
+use crate::support::{mock_openai_chat_response, MockServer, Result};
+use genai::chat::ChatRequest;
+use genai::eval::PromptABTest;
+use serde_json::json;
+
+#[tokio::test]
+async fn test_eval_prompt_ab_test_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![mock_openai_chat_response("positive")]).await?;
+	let ab_test = PromptABTest::new(
+		server.client(),
+		ChatRequest::from_user("I love it!"),
+		"Classify the sentiment",
+		"Answer with positive or negative",
+	)
+	.with_scorer(|text| if text == "positive" { 1.0 } else { 0.0 });
+
+	// -- Exec
+	let result = ab_test.run("gpt-4o-mini", 3).await?;
+
+	// -- Check
+	assert_eq!(result.variant_a_responses.len(), 3);
+	assert_eq!(result.variant_b_responses.len(), 3);
+	assert_eq!(result.metrics.variant_a.avg_tokens, 20.0);
+	assert_eq!(result.metrics.variant_b.avg_score, Some(1.0));
+	assert!(result.metrics.variant_a.avg_latency_ms > 0.0);
+	let systems: Vec<_> = server
+		.requests()
+		.iter()
+		.map(|req| req.pointer("/messages/0/content").cloned())
+		.collect();
+	assert_eq!(systems.len(), 6);
+	let count_a = systems.iter().filter(|s| *s == &Some(json!("Classify the sentiment"))).count();
+	let count_b = systems
+		.iter()
+		.filter(|s| *s == &Some(json!("Answer with positive or negative")))
+		.count();
+	assert_eq!((count_a, count_b), (3, 3));
+
+	Ok(())
+}
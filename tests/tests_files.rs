@@ -0,0 +1,57 @@
+mod support;
+
+use crate::support::{MockServer, Result};
+use genai::files::FilePurpose;
+use serde_json::json;
+
+#[tokio::test]
+async fn test_files_upload_list_get_delete_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let file_json = json!({
+		"id": "file-abc123",
+		"object": "file",
+		"bytes": 11,
+		"created_at": 1700000000,
+		"filename": "train.jsonl",
+		"purpose": "fine-tune"
+	});
+	let server = MockServer::start(vec![
+		file_json.clone(),
+		json!({"object": "list", "data": [file_json.clone()]}),
+		file_json,
+		json!({"id": "file-abc123", "object": "file", "deleted": true}),
+	])
+	.await?;
+	let file_client = server.client().file_client();
+	let path = std::env::temp_dir().join(format!("genai-tests-files-{}.jsonl", std::process::id()));
+	std::fs::write(&path, "{\"a\": 1}\n")?;
+
+	// -- Exec
+	let uploaded = file_client.upload_file(&path, FilePurpose::FineTune).await?;
+	let files = file_client.list_files(Some(FilePurpose::FineTune)).await?;
+	let file = file_client.get_file("file-abc123").await?;
+	file_client.delete_file("file-abc123").await?;
+	std::fs::remove_file(&path)?;
+
+	// -- Check
+	assert_eq!(uploaded.id, "file-abc123");
+	assert_eq!(uploaded.bytes, 11);
+	assert_eq!(files.len(), 1);
+	assert_eq!(file.purpose, "fine-tune");
+	assert_eq!(
+		server.request_paths(),
+		vec![
+			"/v1/files",
+			"/v1/files?purpose=fine-tune",
+			"/v1/files/file-abc123",
+			"/v1/files/file-abc123"
+		]
+	);
+	// The DELETE is sent without a body (not a `null` JSON body).
+	let delete_head = &server.request_heads()[3];
+	assert!(delete_head.starts_with("delete "));
+	assert!(!delete_head.contains("content-type"));
+	assert_eq!(server.request_raw_bodies()[3], "");
+
+	Ok(())
+}
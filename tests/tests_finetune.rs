@@ -0,0 +1,125 @@
+mod support;
+
+use crate::support::{MockServer, Result};
+use genai::chat::ChatMessage;
+use genai::finetune::{FineTuneOptions, FineTuneStatus, TrainingSample};
+use serde_json::{json, Value};
+
+#[tokio::test]
+async fn test_finetune_create_list_cancel_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let job = |status: &str| {
+		json!({
+			"id": "ftjob-1",
+			"object": "fine_tuning.job",
+			"model": "gpt-4o-mini-2024-07-18",
+			"status": status,
+			"training_file": "file-train",
+			"fine_tuned_model": null,
+			"trained_tokens": null,
+			"created_at": 1700000000,
+			"finished_at": null
+		})
+	};
+	let server = MockServer::start(vec![
+		json!({"id": "file-train", "object": "file", "purpose": "fine-tune"}),
+		job("validating_files"),
+		json!({"object": "list", "data": [job("running")], "has_more": false}),
+		job("running"),
+		job("cancelled"),
+	])
+	.await?;
+	let finetune_client = server.client().finetune_client();
+	let samples = vec![TrainingSample::from(vec![
+		ChatMessage::system("You are a pirate"),
+		ChatMessage::user("Hello"),
+		ChatMessage::assistant("Ahoy!"),
+	])];
+	let options = FineTuneOptions::default().with_n_epochs(3).with_suffix("pirate");
+
+	// -- Exec
+	let file_id = finetune_client.upload_training_file(samples).await?;
+	let created = finetune_client
+		.create_fine_tuning_job("gpt-4o-mini-2024-07-18", &file_id, options)
+		.await?;
+	let jobs = finetune_client.list_fine_tuning_jobs().await?;
+	let fetched = finetune_client.get_fine_tuning_job("ftjob-1").await?;
+	let cancelled = finetune_client.cancel_fine_tuning_job("ftjob-1").await?;
+
+	// -- Check
+	assert_eq!(file_id, "file-train");
+	assert_eq!(created.status, FineTuneStatus::ValidatingFiles);
+	assert_eq!(jobs.len(), 1);
+	assert_eq!(jobs[0].status, FineTuneStatus::Running);
+	assert_eq!(fetched.id, "ftjob-1");
+	assert_eq!(cancelled.status, FineTuneStatus::Cancelled);
+	assert_eq!(
+		server.request_paths(),
+		vec![
+			"/v1/files",
+			"/v1/fine_tuning/jobs",
+			"/v1/fine_tuning/jobs",
+			"/v1/fine_tuning/jobs/ftjob-1",
+			"/v1/fine_tuning/jobs/ftjob-1/cancel",
+		]
+	);
+	// upload file
+	let upload_body = &server.request_raw_bodies()[0];
+	assert!(upload_body.contains("name=\"purpose\"\r\n\r\nfine-tune"));
+	let line: Value = upload_body
+		.lines()
+		.find(|line| line.starts_with('{'))
+		.map(serde_json::from_str)
+		.ok_or("Should have a JSONL line")??;
+	assert_eq!(
+		line,
+		json!({"messages": [
+			{"role": "system", "content": "You are a pirate"},
+			{"role": "user", "content": "Hello"},
+			{"role": "assistant", "content": "Ahoy!"}
+		]})
+	);
+	// create job (only the set options)
+	let requests = server.requests();
+	assert_eq!(
+		requests[1],
+		json!({
+			"model": "gpt-4o-mini-2024-07-18",
+			"training_file": "file-train",
+			"hyperparameters": {"n_epochs": 3},
+			"suffix": "pirate"
+		})
+	);
+	assert!(server.request_heads()[2].starts_with("get "));
+	assert!(server.request_heads()[4].starts_with("post "));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_finetune_create_no_options_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![json!({
+		"id": "ftjob-2",
+		"model": "gpt-4o-mini-2024-07-18",
+		"status": "queued",
+		"training_file": "file-train",
+		"created_at": 1700000000
+	})])
+	.await?;
+	let finetune_client = server.client().finetune_client();
+
+	// -- Exec
+	let job = finetune_client
+		.create_fine_tuning_job("gpt-4o-mini-2024-07-18", "file-train", FineTuneOptions::default())
+		.await?;
+
+	// -- Check
+	assert_eq!(job.status, FineTuneStatus::Queued);
+	assert_eq!(
+		server.requests()[0],
+		json!({"model": "gpt-4o-mini-2024-07-18", "training_file": "file-train"})
+	);
+
+	Ok(())
+}
@@ -0,0 +1,138 @@
+mod support;
+
+use crate::support::{MockServer, Result};
+use genai::adapter::AdapterKind;
+use genai::chat::{ChatOptions, ChatRequest, HarmBlockMode};
+use genai::Error;
+use serde_json::{json, Value};
+use tokio_stream::StreamExt;
+
+fn gemini_response(finish_reason: &str, blocked: bool) -> Value {
+	gemini_response_with_rating_blocked(finish_reason, blocked, blocked)
+}
+
+fn gemini_response_with_rating_blocked(finish_reason: &str, blocked: bool, rating_blocked: bool) -> Value {
+	let mut candidate = json!({
+		"finishReason": finish_reason,
+		"safetyRatings": [
+			{"category": "HARM_CATEGORY_HARASSMENT", "probability": "NEGLIGIBLE"},
+			{"category": "HARM_CATEGORY_DANGEROUS_CONTENT", "probability": "HIGH", "blocked": rating_blocked}
+		]
+	});
+	if !blocked {
+		candidate["content"] = json!({"parts": [{"text": "Hello!"}], "role": "model"});
+	}
+	json!({
+		"candidates": [candidate],
+		"usageMetadata": {"promptTokenCount": 2, "candidatesTokenCount": 2, "totalTokenCount": 4},
+		"modelVersion": "gemini-1.5-flash-002"
+	})
+}
+
+#[tokio::test]
+async fn test_gemini_response_meta_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![gemini_response("STOP", false)]).await?;
+	let client = server.client_for_adapter(AdapterKind::Gemini);
+
+	// -- Exec
+	let chat_res = client.exec_chat("gemini-1.5-flash", ChatRequest::from_user("Hi"), None).await?;
+
+	// -- Check
+	assert_eq!(chat_res.content_text_as_str(), Some("Hello!"));
+	let meta = chat_res.gemini_meta().ok_or("Should have gemini meta")?;
+	assert_eq!(meta.model_version.as_deref(), Some("gemini-1.5-flash-002"));
+	assert_eq!(meta.safety_ratings.len(), 2);
+	assert_eq!(meta.safety_ratings[1].probability, "HIGH");
+	assert!(!meta.is_blocked());
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_gemini_response_meta_blocked_err() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![gemini_response("SAFETY", true)]).await?;
+	let client = server.client_for_adapter(AdapterKind::Gemini);
+	let options = ChatOptions::default().with_harm_block_mode(HarmBlockMode::Error);
+
+	// -- Exec
+	let ignored_res = client.exec_chat("gemini-1.5-flash", ChatRequest::from_user("Hi"), None).await?;
+	let blocked_res = client
+		.exec_chat("gemini-1.5-flash", ChatRequest::from_user("Hi"), Some(&options))
+		.await;
+
+	// -- Check
+	assert!(ignored_res.content.is_none());
+	assert!(ignored_res.gemini_meta().is_some_and(|meta| meta.is_blocked()));
+	let Err(Error::ResponseSafetyBlocked {
+		block_reason,
+		safety_ratings,
+		..
+	}) = blocked_res
+	else {
+		return Err(format!("Should have been a ResponseSafetyBlocked, but was: {blocked_res:?}").into());
+	};
+	assert_eq!(block_reason.as_deref(), Some("SAFETY"));
+	assert!(safety_ratings[1].blocked);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_gemini_response_meta_block_reason_only_err() -> Result<()> {
+	// -- Setup & Fixtures
+	// Note: The `SAFETY` finish reason without any rating flagged as `blocked`.
+	let server = MockServer::start(vec![gemini_response_with_rating_blocked("SAFETY", true, false)]).await?;
+	let client = server.client_for_adapter(AdapterKind::Gemini);
+	let options = ChatOptions::default().with_harm_block_mode(HarmBlockMode::Error);
+
+	// -- Exec
+	let res = client
+		.exec_chat("gemini-1.5-flash", ChatRequest::from_user("Hi"), Some(&options))
+		.await;
+
+	// -- Check
+	let Err(Error::ResponseSafetyBlocked { block_reason, .. }) = res else {
+		return Err(format!("Should have been a ResponseSafetyBlocked, but was: {res:?}").into());
+	};
+	assert_eq!(block_reason.as_deref(), Some("SAFETY"));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_gemini_response_meta_stream_blocked_err() -> Result<()> {
+	// -- Setup & Fixtures
+	let chunks = vec![format!("[{}\n", gemini_response("SAFETY", true)), "]".to_string()];
+	let server = MockServer::start_chunked_stream(chunks).await?;
+	let client = server.client_for_adapter(AdapterKind::Gemini);
+	let options = ChatOptions::default().with_harm_block_mode(HarmBlockMode::Error);
+
+	// -- Exec
+	let mut stream = client
+		.exec_chat_stream("gemini-1.5-flash", ChatRequest::from_user("Hi"), Some(&options))
+		.await?
+		.stream;
+	let mut blocked_err = None;
+	while let Some(event) = stream.next().await {
+		if let Err(err) = event {
+			blocked_err = Some(err);
+			break;
+		}
+	}
+
+	// -- Check
+	let Some(Error::ResponseSafetyBlocked {
+		block_reason,
+		safety_ratings,
+		..
+	}) = blocked_err
+	else {
+		return Err(format!("Should have been a ResponseSafetyBlocked, but was: {blocked_err:?}").into());
+	};
+	assert_eq!(block_reason.as_deref(), Some("SAFETY"));
+	assert!(safety_ratings[1].blocked);
+
+	Ok(())
+}
@@ -0,0 +1,215 @@
+//! Requires the `grpc` feature: `cargo test --features grpc --test tests_grpc`
+
+#![cfg(feature = "grpc")]
+
+use bytes::{Buf, BufMut, Bytes};
+use futures::StreamExt;
+use genai::adapter::{AdapterKind, GrpcChatClient, GrpcChatServiceConfig};
+use genai::chat::{ChatRequest, ChatResponse, MessageContent, MetaUsage};
+use genai::{Error, ModelIden};
+use std::convert::Infallible;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use tonic::codec::{Codec, DecodeBuf, Decoder, EncodeBuf, Encoder};
+use tonic::codegen::tokio_stream::wrappers::TcpListenerStream;
+use tonic::codegen::{empty_body, http, Body, BoxFuture, Context, Poll, Service, StdError};
+use tonic::server::{NamedService, UnaryService};
+use tonic::Status;
+
+type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+// region:    --- Test Server
+
+/// A chat service answering `echo: <message>` to the `Complete` method.
+#[derive(Debug, Clone)]
+struct EchoChatService;
+
+impl NamedService for EchoChatService {
+	const NAME: &'static str = "test.ChatService";
+}
+
+impl<B> Service<http::Request<B>> for EchoChatService
+where
+	B: Body + Send + 'static,
+	B::Error: Into<StdError> + Send + 'static,
+{
+	type Response = http::Response<tonic::body::BoxBody>;
+	type Error = Infallible;
+	type Future = BoxFuture<Self::Response, Self::Error>;
+
+	fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<core::result::Result<(), Self::Error>> {
+		Poll::Ready(Ok(()))
+	}
+
+	fn call(&mut self, req: http::Request<B>) -> Self::Future {
+		match req.uri().path() {
+			"/test.ChatService/Complete" => Box::pin(async move {
+				let mut grpc = tonic::server::Grpc::new(TestBytesCodec);
+				Ok(grpc.unary(CompleteMethod, req).await)
+			}),
+			_ => Box::pin(async move {
+				// Note: 12 is the `Unimplemented` gRPC status code.
+				let response = http::Response::builder()
+					.header("grpc-status", "12")
+					.header("content-type", "application/grpc")
+					.body(empty_body())
+					.expect("Should build the response");
+				Ok(response)
+			}),
+		}
+	}
+}
+
+struct CompleteMethod;
+
+impl UnaryService<Bytes> for CompleteMethod {
+	type Response = Bytes;
+	type Future = BoxFuture<tonic::Response<Bytes>, Status>;
+
+	fn call(&mut self, request: tonic::Request<Bytes>) -> Self::Future {
+		let message = request.into_inner();
+		Box::pin(async move {
+			let mut answer = b"echo: ".to_vec();
+			answer.extend_from_slice(&message);
+			Ok(tonic::Response::new(Bytes::from(answer)))
+		})
+	}
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct TestBytesCodec;
+
+impl Codec for TestBytesCodec {
+	type Encode = Bytes;
+	type Decode = Bytes;
+	type Encoder = TestBytesCodec;
+	type Decoder = TestBytesCodec;
+
+	fn encoder(&mut self) -> Self::Encoder {
+		TestBytesCodec
+	}
+
+	fn decoder(&mut self) -> Self::Decoder {
+		TestBytesCodec
+	}
+}
+
+impl Encoder for TestBytesCodec {
+	type Item = Bytes;
+	type Error = Status;
+
+	fn encode(&mut self, item: Self::Item, dst: &mut EncodeBuf<'_>) -> core::result::Result<(), Self::Error> {
+		dst.put(item);
+		Ok(())
+	}
+}
+
+impl Decoder for TestBytesCodec {
+	type Item = Bytes;
+	type Error = Status;
+
+	fn decode(&mut self, src: &mut DecodeBuf<'_>) -> core::result::Result<Option<Self::Item>, Self::Error> {
+		Ok(Some(src.copy_to_bytes(src.remaining())))
+	}
+}
+
+/// Start the test server, returning its endpoint (e.g., `http://127.0.0.1:50051`)
+/// and the count of the accepted connections.
+async fn start_server() -> Result<(String, Arc<AtomicUsize>)> {
+	let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+	let endpoint = format!("http://{}", listener.local_addr()?);
+	let connections = Arc::new(AtomicUsize::new(0));
+	let incoming = TcpListenerStream::new(listener).inspect({
+		let connections = connections.clone();
+		move |_| {
+			connections.fetch_add(1, Ordering::SeqCst);
+		}
+	});
+	tokio::spawn(
+		tonic::transport::Server::builder()
+			.add_service(EchoChatService)
+			.serve_with_incoming(incoming),
+	);
+	Ok((endpoint, connections))
+}
+
+// endregion: --- Test Server
+
+// region:    --- Transformers
+
+fn last_user_text(chat_req: ChatRequest) -> Bytes {
+	let text = chat_req
+		.messages
+		.into_iter()
+		.last()
+		.and_then(|message| message.content.text_into_string())
+		.unwrap_or_default();
+	Bytes::from(text)
+}
+
+fn echo_chat_response(bytes: Bytes) -> genai::Result<ChatResponse> {
+	let text = String::from_utf8(bytes.to_vec()).map_err(|_| Error::InvalidJsonResponseElement {
+		info: "the response is not UTF-8",
+	})?;
+	Ok(ChatResponse {
+		content: Some(MessageContent::from_text(text)),
+		model_iden: ModelIden::new(AdapterKind::Ollama, "echo"),
+		usage: MetaUsage::default(),
+		request_id: None,
+		client_request_id: None,
+		adapter_meta: None,
+		citations: Vec::new(),
+	})
+}
+
+fn service_config(endpoint: String, method_name: &str) -> GrpcChatServiceConfig {
+	GrpcChatServiceConfig {
+		endpoint,
+		service_name: "test.ChatService".to_string(),
+		method_name: method_name.to_string(),
+		request_transformer: last_user_text,
+		response_transformer: echo_chat_response,
+	}
+}
+
+// endregion: --- Transformers
+
+#[tokio::test]
+async fn test_grpc_exec_chat_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let (endpoint, connections) = start_server().await?;
+	let client = GrpcChatClient::connect(service_config(endpoint, "Complete")).await?;
+
+	// -- Exec
+	let hello_res = client.exec_chat(ChatRequest::from_user("Hello")).await?;
+	let world_res = client.clone().exec_chat(ChatRequest::from_user("World")).await?;
+
+	// -- Check
+	assert_eq!(client.config().method_path(), "/test.ChatService/Complete");
+	assert_eq!(hello_res.content_text_as_str(), Some("echo: Hello"));
+	assert_eq!(world_res.content_text_as_str(), Some("echo: World"));
+	// The channel is connected once, and reused by the calls (and the clones).
+	assert_eq!(connections.load(Ordering::SeqCst), 1);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_grpc_exec_chat_err() -> Result<()> {
+	// -- Setup & Fixtures
+	let (endpoint, _) = start_server().await?;
+	let unknown_method_client = GrpcChatClient::connect(service_config(endpoint, "Unknown")).await?;
+
+	// -- Exec
+	let unknown_method_res = unknown_method_client.exec_chat(ChatRequest::from_user("Hello")).await;
+	let invalid_endpoint_res = GrpcChatClient::connect(service_config("not a url".to_string(), "Complete")).await;
+
+	// -- Check
+	match unknown_method_res {
+		Err(Error::Grpc { cause }) => assert!(cause.contains("Unimplemented"), "cause: {cause}"),
+		other => return Err(format!("Expected Error::Grpc, got {other:?}").into()),
+	}
+	assert!(matches!(invalid_endpoint_res, Err(Error::Grpc { .. })));
+
+	Ok(())
+}
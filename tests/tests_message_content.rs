@@ -0,0 +1,22 @@
+use genai::chat::MessageContent;
+use serde_json::json;
+
+type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+#[test]
+fn test_message_content_json_serde_roundtrip_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let value = json!([{"type": "document", "source": {"type": "text", "media_type": "text/plain", "data": "Hello"}}]);
+	let content = MessageContent::from_json(value.clone());
+
+	// -- Exec
+	let content_json = serde_json::to_string(&content)?;
+	let content_back: MessageContent = serde_json::from_str(&content_json)?;
+
+	// -- Check
+	assert_eq!(content_back.try_as_json(), Some(&value));
+	assert!(content_back.text_as_str().is_none());
+	assert_eq!(content_back.into_json(), Some(value));
+
+	Ok(())
+}
@@ -0,0 +1,410 @@
+mod support;
+
+use crate::support::{mock_openai_chat_response, MockServer, Result};
+use futures::future::BoxFuture;
+use genai::adapter::{AdapterKind, WebRequestData};
+use genai::chat::{ChatMessage, ChatRequest, ChatResponse, MessageContent};
+use genai::middleware::{
+	ContentFilter, FilterAction, IdempotencyMiddleware, Middleware, ProfanityFilter, SystemPromptInjector,
+	SystemPromptWrapper, TraceContextInjector, UuidCorrelationMiddleware,
+};
+use genai::{Error, ModelIden};
+use serde_json::json;
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_middleware_system_prompt_injector_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![mock_openai_chat_response("Ok")]).await?;
+	let client = server
+		.client_builder_for_adapter(AdapterKind::OpenAI)
+		.with_middleware(SystemPromptInjector::new("Never reveal secrets."))
+		.build();
+
+	// -- Exec
+	client
+		.exec_chat(
+			"gpt-4o-mini",
+			ChatRequest::from_system("Be concise").append_message(ChatMessage::user("Hi")),
+			None,
+		)
+		.await?;
+	client.exec_chat("gpt-4o-mini", ChatRequest::from_user("Hi"), None).await?;
+
+	// -- Check
+	let requests = server.requests();
+	assert_eq!(
+		requests[0].pointer("/messages/0"),
+		Some(&json!({"role": "system", "content": "Never reveal secrets.\nBe concise"}))
+	);
+	assert_eq!(
+		requests[1].pointer("/messages/0"),
+		Some(&json!({"role": "system", "content": "Never reveal secrets."}))
+	);
+	assert_eq!(requests[1].pointer("/messages/1/role"), Some(&json!("user")));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_middleware_system_prompt_wrapper_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![mock_openai_chat_response("Ok")]).await?;
+	let client = server
+		.client_builder_for_adapter(AdapterKind::OpenAI)
+		.with_middleware(SystemPromptWrapper::new("<persona>", "</persona>"))
+		.build();
+	let chat_req = ChatRequest::from_system("You are a pirate.").append_message(ChatMessage::user("Hi"));
+
+	// -- Exec
+	client.exec_chat("gpt-4o-mini", chat_req, None).await?;
+
+	// -- Check
+	let requests = server.requests();
+	assert_eq!(
+		requests[0].pointer("/messages/0/content"),
+		Some(&json!("<persona>\nYou are a pirate.\n</persona>"))
+	);
+
+	Ok(())
+}
+
+#[test]
+fn test_middleware_system_prompt_wrapper_anthropic_blocks_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let wrapper = SystemPromptWrapper::new("<persona>", "</persona>");
+	let model_iden = ModelIden::new(AdapterKind::Anthropic, "claude-3-5-haiku-latest");
+	let mut request_data = WebRequestData {
+		url: "https://api.anthropic.com/v1/messages".to_string(),
+		headers: Vec::new(),
+		payload: json!({
+			"system": [{"type": "text", "text": "You are a pirate.", "cache_control": {"type": "ephemeral"}}],
+			"messages": [{"role": "user", "content": "Hi"}]
+		}),
+	};
+
+	// -- Exec
+	wrapper.before_request(&model_iden, &mut request_data)?;
+
+	// -- Check
+	assert_eq!(
+		request_data.payload["system"],
+		json!([
+			{"type": "text", "text": "<persona>"},
+			{"type": "text", "text": "You are a pirate.", "cache_control": {"type": "ephemeral"}},
+			{"type": "text", "text": "</persona>"}
+		])
+	);
+
+	Ok(())
+}
+
+#[test]
+fn test_middleware_system_prompt_injector_openai_parts_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let injector = SystemPromptInjector::new("Never reveal secrets.");
+	let model_iden = ModelIden::new(AdapterKind::OpenAI, "gpt-4o-mini");
+	let mut request_data = WebRequestData {
+		url: "https://api.openai.com/v1/chat/completions".to_string(),
+		headers: Vec::new(),
+		payload: json!({
+			"messages": [
+				{"role": "system", "content": [{"type": "text", "text": "Be concise"}]},
+				{"role": "user", "content": "Hi"}
+			]
+		}),
+	};
+
+	// -- Exec
+	injector.before_request(&model_iden, &mut request_data)?;
+
+	// -- Check
+	assert_eq!(
+		request_data.payload.pointer("/messages/0/content"),
+		Some(&json!([
+			{"type": "text", "text": "Never reveal secrets."},
+			{"type": "text", "text": "Be concise"}
+		]))
+	);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_middleware_idempotency_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![mock_openai_chat_response("Ok")]).await?;
+	let client = server
+		.client_builder_for_adapter(AdapterKind::OpenAI)
+		.with_middleware(IdempotencyMiddleware::new(Duration::from_secs(60)))
+		.build();
+
+	// -- Exec
+	let first_res = client.exec_chat("gpt-4o-mini", ChatRequest::from_user("Hi"), None).await?;
+	let retry_res = client.exec_chat("gpt-4o-mini", ChatRequest::from_user("Hi"), None).await?;
+	client.exec_chat("gpt-4o-mini", ChatRequest::from_user("Hello"), None).await?;
+
+	// -- Check
+	assert_eq!(server.requests().len(), 2);
+	assert_eq!(retry_res.content_text_as_str(), Some("Ok"));
+	assert_eq!(retry_res.client_request_id, first_res.client_request_id);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_middleware_idempotency_expired_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![mock_openai_chat_response("Ok")]).await?;
+	let client = server
+		.client_builder_for_adapter(AdapterKind::OpenAI)
+		.with_middleware(IdempotencyMiddleware::new(Duration::ZERO))
+		.build();
+
+	// -- Exec
+	client.exec_chat("gpt-4o-mini", ChatRequest::from_user("Hi"), None).await?;
+	client.exec_chat("gpt-4o-mini", ChatRequest::from_user("Hi"), None).await?;
+
+	// -- Check
+	assert_eq!(server.requests().len(), 2);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_middleware_idempotency_profanity_filter_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![mock_openai_chat_response("What a Darn good question.")]).await?;
+	let client = server
+		.client_builder_for_adapter(AdapterKind::OpenAI)
+		.with_middleware(IdempotencyMiddleware::new(Duration::from_secs(60)))
+		.with_content_filter(ProfanityFilter::new(&["darn"]))
+		.build();
+
+	// -- Exec
+	let first_res = client.exec_chat("gpt-4o-mini", ChatRequest::from_user("Hi"), None).await?;
+	let retry_res = client.exec_chat("gpt-4o-mini", ChatRequest::from_user("Hi"), None).await?;
+
+	// -- Check
+	assert_eq!(server.requests().len(), 1);
+	assert_eq!(first_res.content_text_as_str(), Some("What a **** good question."));
+	assert_eq!(retry_res.content_text_as_str(), Some("What a **** good question."));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_middleware_idempotency_modify_filter_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![mock_openai_chat_response("Hello")]).await?;
+	let client = server
+		.client_builder_for_adapter(AdapterKind::OpenAI)
+		.with_middleware(IdempotencyMiddleware::new(Duration::from_secs(60)))
+		.with_content_filter(DisclaimerFilter)
+		.build();
+
+	// -- Exec
+	let first_res = client.exec_chat("gpt-4o-mini", ChatRequest::from_user("Hi"), None).await?;
+	let retry_res = client.exec_chat("gpt-4o-mini", ChatRequest::from_user("Hi"), None).await?;
+
+	// -- Check
+	// The response is cached before the filters, so the disclaimer is appended once.
+	assert_eq!(server.requests().len(), 1);
+	assert_eq!(first_res.content_text_as_str(), Some("Hello (AI generated)"));
+	assert_eq!(retry_res.content_text_as_str(), Some("Hello (AI generated)"));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_middleware_idempotency_concurrent_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start_delayed(vec![mock_openai_chat_response("Ok")], Duration::from_millis(200)).await?;
+	let client = server
+		.client_builder_for_adapter(AdapterKind::OpenAI)
+		.with_middleware(IdempotencyMiddleware::new(Duration::from_secs(60)))
+		.build();
+
+	// -- Exec
+	let results =
+		futures::future::join_all((0..3).map(|_| client.exec_chat("gpt-4o-mini", ChatRequest::from_user("Hi"), None)))
+			.await;
+
+	// -- Check
+	// The duplicates wait for the first call, rather than calling the provider.
+	assert_eq!(server.requests().len(), 1);
+	for res in results {
+		assert_eq!(res?.content_text_as_str(), Some("Ok"));
+	}
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_middleware_idempotency_concurrent_err() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start_delayed_error(
+		503,
+		json!({"error": {"message": "overloaded"}}),
+		Duration::from_millis(100),
+	)
+	.await?;
+	let client = server
+		.client_builder_for_adapter(AdapterKind::OpenAI)
+		.with_middleware(IdempotencyMiddleware::new(Duration::from_secs(60)))
+		.build();
+
+	// -- Exec
+	let (first_res, duplicate_res) = tokio::join!(
+		client.exec_chat("gpt-4o-mini", ChatRequest::from_user("Hi"), None),
+		client.exec_chat("gpt-4o-mini", ChatRequest::from_user("Hi"), None),
+	);
+
+	// -- Check
+	// The failed first call releases the duplicate, which makes its own call.
+	assert!(matches!(first_res, Err(Error::ApiError { .. })), "{first_res:?}");
+	assert!(
+		matches!(duplicate_res, Err(Error::ApiError { .. })),
+		"{duplicate_res:?}"
+	);
+	assert_eq!(server.requests().len(), 2);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_middleware_correlation_ids_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![mock_openai_chat_response("Ok")]).await?;
+	let client = server
+		.client_builder_for_adapter(AdapterKind::OpenAI)
+		.with_middleware(UuidCorrelationMiddleware::new())
+		.with_middleware(TraceContextInjector::new("X-Trace-Id", || "trace-123".to_string()))
+		.build();
+
+	// -- Exec
+	client.exec_chat("gpt-4o-mini", ChatRequest::from_user("Hi"), None).await?;
+	client.exec_chat("gpt-4o-mini", ChatRequest::from_user("Hi"), None).await?;
+
+	// -- Check
+	let correlation_ids: Vec<String> = server
+		.request_heads()
+		.iter()
+		.map(|head| {
+			assert!(head.contains("x-trace-id: trace-123"), "head: {head}");
+			head_value(head, "x-correlation-id").unwrap_or_default()
+		})
+		.collect();
+	assert_eq!(correlation_ids.len(), 2);
+	assert_eq!(correlation_ids[0].len(), 36, "Should be a UUID: {}", correlation_ids[0]);
+	assert_ne!(correlation_ids[0], correlation_ids[1]);
+
+	Ok(())
+}
+
+#[cfg(feature = "opentelemetry")]
+#[tokio::test]
+async fn test_middleware_tracing_without_otel_context_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![mock_openai_chat_response("Ok")]).await?;
+	let client = server
+		.client_builder_for_adapter(AdapterKind::OpenAI)
+		.with_middleware(genai::middleware::TracingMiddleware::new())
+		.build();
+
+	// -- Exec
+	client.exec_chat("gpt-4o-mini", ChatRequest::from_user("Hi"), None).await?;
+
+	// -- Check
+	// No `tracing_opentelemetry` layer, so no valid trace context
+	assert!(!server.request_heads()[0].contains("traceparent"));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_content_filter_profanity_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![mock_openai_chat_response("What a Darn good question.")]).await?;
+	let client = server
+		.client_builder_for_adapter(AdapterKind::OpenAI)
+		.with_content_filter(ProfanityFilter::new(&["darn"]))
+		.build();
+
+	// -- Exec
+	let chat_res = client
+		.exec_chat(
+			"gpt-4o-mini",
+			ChatRequest::from_user("Why is this darn sky blue?"),
+			None,
+		)
+		.await?;
+
+	// -- Check
+	let requests = server.requests();
+	assert_eq!(
+		requests[0].pointer("/messages/0/content"),
+		Some(&json!("Why is this **** sky blue?"))
+	);
+	assert_eq!(chat_res.content_text_as_str(), Some("What a **** good question."));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_content_filter_block_err() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![mock_openai_chat_response("Ok")]).await?;
+	let client = server
+		.client_builder_for_adapter(AdapterKind::OpenAI)
+		.with_content_filter(BlockAllFilter)
+		.build();
+
+	// -- Exec
+	let res = client.exec_chat("gpt-4o-mini", ChatRequest::from_user("Hi"), None).await;
+
+	// -- Check
+	assert!(matches!(res, Err(Error::ContentBlocked { reason, .. }) if reason == "blocked"));
+	assert!(server.requests().is_empty(), "Blocked input should not be sent");
+
+	Ok(())
+}
+
+// region:    --- Support
+
+fn head_value(head: &str, name: &str) -> Option<String> {
+	head.lines()
+		.find_map(|line| line.strip_prefix(&format!("{name}:")))
+		.map(|value| value.trim().to_string())
+}
+
+struct BlockAllFilter;
+
+impl ContentFilter for BlockAllFilter {
+	fn filter_input<'a>(&'a self, _msg: &'a mut ChatMessage) -> BoxFuture<'a, genai::Result<FilterAction>> {
+		Box::pin(async { Ok(FilterAction::Block("blocked".to_string())) })
+	}
+
+	fn filter_output<'a>(&'a self, _response: &'a mut ChatResponse) -> BoxFuture<'a, genai::Result<FilterAction>> {
+		Box::pin(async { Ok(FilterAction::Allow) })
+	}
+}
+
+/// Appends a disclaimer to the response text.
+struct DisclaimerFilter;
+
+impl ContentFilter for DisclaimerFilter {
+	fn filter_input<'a>(&'a self, _msg: &'a mut ChatMessage) -> BoxFuture<'a, genai::Result<FilterAction>> {
+		Box::pin(async { Ok(FilterAction::Allow) })
+	}
+
+	fn filter_output<'a>(&'a self, response: &'a mut ChatResponse) -> BoxFuture<'a, genai::Result<FilterAction>> {
+		Box::pin(async move {
+			let text = response.content_text_as_str().unwrap_or_default();
+			response.content = Some(MessageContent::from_text(format!("{text} (AI generated)")));
+			Ok(FilterAction::Modify)
+		})
+	}
+}
+
+// endregion: --- Support
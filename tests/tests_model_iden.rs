@@ -0,0 +1,32 @@
+use genai::adapter::AdapterKind;
+use genai::ModelIden;
+
+type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+#[test]
+fn test_adapter_kind_from_str_ok() -> Result<()> {
+	// -- Exec & Check
+	assert_eq!("openai".parse::<AdapterKind>()?, AdapterKind::OpenAI);
+	assert_eq!("Anthropic".parse::<AdapterKind>()?, AdapterKind::Anthropic);
+	assert_eq!("GEMINI".parse::<AdapterKind>()?, AdapterKind::Gemini);
+	assert!("not-an-adapter".parse::<AdapterKind>().is_err());
+
+	Ok(())
+}
+
+#[test]
+fn test_model_iden_from_str_ok() -> Result<()> {
+	// -- Exec
+	let with_prefix: ModelIden = "anthropic/claude-3-5-sonnet-latest".parse()?;
+	let without_prefix: ModelIden = "gpt-4o".parse()?;
+	let ollama_path: ModelIden = "hf.co/some-user/some-model".parse()?;
+
+	// -- Check
+	assert_eq!(with_prefix.adapter_kind, AdapterKind::Anthropic);
+	assert_eq!(&*with_prefix.model_name, "claude-3-5-sonnet-latest");
+	assert_eq!(without_prefix.adapter_kind, AdapterKind::OpenAI);
+	assert_eq!(ollama_path.adapter_kind, AdapterKind::Ollama);
+	assert_eq!(&*ollama_path.model_name, "hf.co/some-user/some-model");
+
+	Ok(())
+}
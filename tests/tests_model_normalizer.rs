@@ -0,0 +1,56 @@
+mod support;
+
+use crate::support::{MockServer, Result};
+use genai::adapter::AdapterKind;
+use genai::chat::ChatRequest;
+use genai::ModelNormalizer;
+use serde_json::json;
+
+#[test]
+fn test_model_normalizer_normalize_ok() -> Result<()> {
+	// -- Exec & Check
+	assert_eq!(
+		ModelNormalizer::normalize("sonnet-3-5", AdapterKind::Anthropic),
+		Some("claude-3-5-sonnet-20241022")
+	);
+	assert_eq!(
+		ModelNormalizer::normalize("llama-3-1-8b", AdapterKind::Ollama),
+		Some("llama3.1:8b")
+	);
+	assert_eq!(
+		ModelNormalizer::normalize("gemini-1.5-flash", AdapterKind::Gemini),
+		Some("gemini-1.5-flash")
+	);
+	assert_eq!(ModelNormalizer::normalize("sonnet-3-5", AdapterKind::OpenAI), None);
+	assert_eq!(
+		ModelNormalizer::canonical_name("llama-3.1-70b-versatile", AdapterKind::Groq),
+		Some("llama-3-1-70b")
+	);
+	assert_eq!(
+		ModelNormalizer::canonical_name("unknown-model", AdapterKind::Groq),
+		None
+	);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_model_normalizer_exec_chat_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![json!({
+		"content": [{"type": "text", "text": "Hello!"}],
+		"usage": {"input_tokens": 10, "output_tokens": 5}
+	})])
+	.await?;
+	let client = server.client_for_adapter(AdapterKind::Anthropic);
+
+	// -- Exec
+	let chat_res = client.exec_chat("sonnet-3-5", ChatRequest::from_user("Hi"), None).await?;
+
+	// -- Check
+	assert_eq!(server.requests()[0]["model"], "claude-3-5-sonnet-20241022");
+	assert_eq!(&*chat_res.model_iden.model_name, "claude-3-5-sonnet-20241022");
+	assert_eq!(client.default_model("sonnet-3-5")?.adapter_kind, AdapterKind::Anthropic);
+
+	Ok(())
+}
@@ -0,0 +1,158 @@
+mod support;
+
+use crate::support::{mock_openai_chat_response, MockServer, Result};
+use genai::adapter::AdapterKind;
+use genai::chat::ChatRequest;
+use genai::eval::TokenPrice;
+use genai::resolver::{AuthData, Endpoint, ServiceTargetResolver};
+use genai::{
+	Client, Error, LatencyPriority, ModelCapabilities, ModelIden, ModelSelector, ServiceTarget, TaskRequirements,
+};
+
+#[test]
+fn test_model_selector_select_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let selector = ModelSelector::default();
+
+	// -- Exec & Check
+	assert_eq!(
+		selector.select(&TaskRequirements::default()),
+		Some("llama-3.1-8b-instant")
+	);
+	let vision_tools = TaskRequirements {
+		needs_vision: true,
+		needs_tools: true,
+		..Default::default()
+	};
+	assert_eq!(selector.select(&vision_tools), Some("gpt-4o-mini"));
+	let anthropic_vision = TaskRequirements {
+		needs_vision: true,
+		preferred_providers: vec![AdapterKind::Anthropic],
+		..Default::default()
+	};
+	assert_eq!(selector.select(&anthropic_vision), Some("claude-3-haiku-20240307"));
+	let long_context = TaskRequirements {
+		min_context_tokens: 1_500_000,
+		..Default::default()
+	};
+	assert_eq!(selector.select(&long_context), Some("gemini-1.5-pro"));
+	let too_cheap = TaskRequirements {
+		needs_vision: true,
+		max_cost_per_1k_tokens: 0.00001,
+		..Default::default()
+	};
+	assert_eq!(selector.select(&too_cheap), None);
+
+	Ok(())
+}
+
+#[test]
+fn test_model_selector_latency_priority_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let candidate = |model_name: &'static str, price: TokenPrice, is_fast: bool| ModelCapabilities {
+		model_name,
+		adapter_kind: AdapterKind::OpenAI,
+		supports_vision: false,
+		supports_tools: true,
+		context_window_tokens: 128_000,
+		price,
+		is_fast,
+	};
+	let selector = ModelSelector::from_candidates(vec![
+		candidate("cheap-slow", TokenPrice::new(0.1, 0.1), false),
+		candidate("fast", TokenPrice::new(1.0, 1.0), true),
+	]);
+	let requirements = |latency_priority| TaskRequirements {
+		latency_priority,
+		..Default::default()
+	};
+
+	// -- Exec & Check
+	assert_eq!(selector.select(&requirements(LatencyPriority::Low)), Some("cheap-slow"));
+	assert_eq!(
+		selector.select(&requirements(LatencyPriority::Normal)),
+		Some("cheap-slow")
+	);
+	assert_eq!(selector.select(&requirements(LatencyPriority::High)), Some("fast"));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_model_selector_exec_chat_auto_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![mock_openai_chat_response("Hello!")]).await?;
+	let client = server.client();
+	let requirements = TaskRequirements {
+		needs_tools: true,
+		preferred_providers: vec![AdapterKind::OpenAI],
+		..Default::default()
+	};
+
+	// -- Exec
+	let chat_res = client.exec_chat_auto(ChatRequest::from_user("Hi"), requirements).await?;
+	let impossible_res = client
+		.exec_chat_auto(
+			ChatRequest::from_user("Hi"),
+			TaskRequirements {
+				min_context_tokens: u32::MAX,
+				..Default::default()
+			},
+		)
+		.await;
+
+	// -- Check
+	assert_eq!(chat_res.content_text_as_str(), Some("Hello!"));
+	assert_eq!(server.requests()[0]["model"], "gpt-4o-mini");
+	assert!(matches!(impossible_res, Err(Error::NoModelForRequirements { .. })));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_model_selector_exec_chat_auto_skip_unresolved_auth_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![mock_openai_chat_response("Hello!")]).await?;
+	let base_url = server.base_url().to_string();
+	// Note: The Groq models have an auth from an environment variable that is not set.
+	let target_resolver = ServiceTargetResolver::from_resolver_fn(
+		move |service_target: ServiceTarget| -> core::result::Result<ServiceTarget, genai::resolver::Error> {
+			let ServiceTarget { model, .. } = service_target;
+			let auth = match model.adapter_kind {
+				AdapterKind::Groq => AuthData::from_env("GENAI_TEST_NOT_SET_API_KEY"),
+				_ => AuthData::from_single("mock-api-key"),
+			};
+			Ok(ServiceTarget {
+				endpoint: Endpoint::from_owned(base_url.clone()),
+				auth,
+				model: ModelIden::new(AdapterKind::OpenAI, model.model_name),
+			})
+		},
+	);
+	let client = Client::builder().with_service_target_resolver(target_resolver).build();
+	let requirements = TaskRequirements::default();
+	let expected_model = ModelSelector::from_candidates(
+		ModelCapabilities::known()
+			.into_iter()
+			.filter(|caps| caps.adapter_kind != AdapterKind::Groq)
+			.collect(),
+	)
+	.select(&requirements)
+	.ok_or("Should have a model")?;
+
+	// -- Exec
+	let chat_res = client
+		.exec_chat_auto(ChatRequest::from_user("Hi"), requirements.clone())
+		.await?;
+
+	// -- Check
+	// Note: Without the auth check, the cheapest model is a Groq one.
+	assert_eq!(
+		ModelSelector::default().select(&requirements),
+		Some("llama-3.1-8b-instant")
+	);
+	assert_eq!(chat_res.content_text_as_str(), Some("Hello!"));
+	assert_eq!(server.requests()[0]["model"], expected_model);
+
+	Ok(())
+}
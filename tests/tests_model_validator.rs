@@ -0,0 +1,187 @@
+mod support;
+
+use crate::support::{mock_openai_chat_response, MockServer, Result};
+use futures::future::BoxFuture;
+use genai::adapter::{AdapterKind, WebRequestData};
+use genai::chat::{ChatRequest, ChatResponse, MessageContent, MetaUsage};
+use genai::middleware::Middleware;
+use genai::resolver::AuthData;
+use genai::{Client, Error, ModelIden, ModelValidation, ModelValidator, Result as GenaiResult};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Level, Metadata, Subscriber};
+
+#[test]
+fn test_model_validator_validate_model_name_ok() -> Result<()> {
+	// -- Exec & Check
+	assert_eq!(
+		ModelValidator::validate_model_name("gpt-4o-mini", AdapterKind::OpenAI),
+		ModelValidation::Known
+	);
+	assert_eq!(
+		ModelValidator::validate_model_name("gpt-4o-mni", AdapterKind::OpenAI),
+		ModelValidation::SimilarExists("gpt-4o-mini".to_string())
+	);
+	assert_eq!(
+		ModelValidator::validate_model_name("claude-3-5-sonet-20241022", AdapterKind::Anthropic),
+		ModelValidation::SimilarExists("claude-3-5-sonnet-20241022".to_string())
+	);
+	assert_eq!(
+		ModelValidator::validate_model_name("my-fine-tuned-model", AdapterKind::OpenAI),
+		ModelValidation::Unknown
+	);
+	// Ollama has no static model list (local models)
+	assert_eq!(
+		ModelValidator::validate_model_name("my-local-model", AdapterKind::Ollama),
+		ModelValidation::Known
+	);
+
+	Ok(())
+}
+
+#[test]
+fn test_model_validator_suggest_ok() -> Result<()> {
+	// -- Exec & Check
+	assert_eq!(
+		ModelValidator::suggest("deepsek-chat", AdapterKind::DeepSeek).as_deref(),
+		Some("deepseek-chat")
+	);
+	assert_eq!(
+		ModelValidator::suggest("gpt-3.5-turbo-instruct", AdapterKind::OpenAI),
+		None
+	);
+
+	Ok(())
+}
+
+// region:    --- Support
+
+/// Returns a canned response, so the default endpoint is not called.
+struct CannedResponse;
+
+impl Middleware for CannedResponse {
+	fn cached_response<'a>(
+		&'a self,
+		model_iden: &'a ModelIden,
+		_request_data: &'a WebRequestData,
+	) -> BoxFuture<'a, GenaiResult<Option<ChatResponse>>> {
+		Box::pin(async move {
+			Ok(Some(ChatResponse {
+				content: Some(MessageContent::from_text("Hello")),
+				model_iden: model_iden.clone(),
+				usage: MetaUsage::default(),
+				request_id: None,
+				client_request_id: None,
+				adapter_meta: None,
+				citations: Vec::new(),
+			}))
+		})
+	}
+}
+
+/// A client with the adapter default endpoints (and a canned response).
+fn default_endpoint_client(strict: bool) -> Client {
+	Client::builder()
+		.with_auth_resolver_fn(|_model_iden: ModelIden| Ok(Some(AuthData::from_single("sk-test"))))
+		.with_middleware(CannedResponse)
+		.with_strict_model_validation(strict)
+		.build()
+}
+
+/// Counts the warning events.
+#[derive(Clone, Default)]
+struct WarnCounter(Arc<AtomicUsize>);
+
+impl Subscriber for WarnCounter {
+	fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+		true
+	}
+	fn new_span(&self, _span: &Attributes<'_>) -> Id {
+		Id::from_u64(1)
+	}
+	fn record(&self, _span: &Id, _values: &Record<'_>) {}
+	fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+	fn event(&self, event: &Event<'_>) {
+		if *event.metadata().level() == Level::WARN {
+			self.0.fetch_add(1, Ordering::SeqCst);
+		}
+	}
+	fn enter(&self, _span: &Id) {}
+	fn exit(&self, _span: &Id) {}
+}
+
+// endregion: --- Support
+
+#[tokio::test]
+async fn test_model_validator_strict_err() -> Result<()> {
+	// -- Setup & Fixtures
+	let client = default_endpoint_client(true);
+
+	// -- Exec
+	let res = client.exec_chat("gpt-4o-mni", ChatRequest::from_user("Hi"), None).await;
+
+	// -- Check
+	match res {
+		Err(Error::ModelNameUnknown { suggestion, .. }) => assert_eq!(suggestion.as_deref(), Some("gpt-4o-mini")),
+		other => return Err(format!("Should be a ModelNameUnknown error: {other:?}").into()),
+	}
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_model_validator_strict_custom_endpoint_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![mock_openai_chat_response("Hello")]).await?;
+	let client = server
+		.client_builder_for_adapter(AdapterKind::OpenAI)
+		.with_strict_model_validation(true)
+		.build();
+
+	// -- Exec
+	let res = client.exec_chat("my-proxy-model", ChatRequest::from_user("Hi"), None).await?;
+
+	// -- Check
+	// The model names of a custom endpoint are not validated.
+	assert_eq!(res.content_text_as_str(), Some("Hello"));
+	assert_eq!(server.requests()[0]["model"], "my-proxy-model");
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_model_validator_warn_once_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let warn_counter = WarnCounter::default();
+	let _guard = tracing::subscriber::set_default(warn_counter.clone());
+	let client = default_endpoint_client(false);
+
+	// -- Exec
+	for _ in 0..3 {
+		client.exec_chat("gpt-4o-mni", ChatRequest::from_user("Hi"), None).await?;
+	}
+	client.exec_chat("gpt-4o-mimi", ChatRequest::from_user("Hi"), None).await?;
+	client.exec_chat("gpt-4o-mini", ChatRequest::from_user("Hi"), None).await?;
+
+	// -- Check
+	// One warning per unknown model name.
+	assert_eq!(warn_counter.0.load(Ordering::SeqCst), 2);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_model_validator_not_strict_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![mock_openai_chat_response("Hello")]).await?;
+	let client = server.client_for_adapter(AdapterKind::OpenAI);
+
+	// -- Exec
+	let res = client.exec_chat("gpt-4o-mni", ChatRequest::from_user("Hi"), None).await?;
+
+	// -- Check
+	assert_eq!(res.content_text_as_str(), Some("Hello"));
+
+	Ok(())
+}
@@ -0,0 +1,32 @@
+mod support;
+
+use crate::support::{MockServer, Result};
+use genai::adapter::AdapterKind;
+use serde_json::json;
+
+#[tokio::test]
+async fn test_ollama_manager_list_running_models_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![json!({
+		"models": [{
+			"name": "llama3.2:latest",
+			"model": "llama3.2:latest",
+			"size": 5137025024u64,
+			"digest": "a80c4f17acd55265feec403c7aef86be0c25983ab279d83f3bcd3abbcb5b8b72",
+			"expires_at": "2024-06-04T14:38:31.83753-07:00",
+			"size_vram": 5137025024u64
+		}]
+	})])
+	.await?;
+	let manager = server.client_for_adapter(AdapterKind::Ollama).ollama_manager();
+
+	// -- Exec
+	let models = manager.list_running_models().await?;
+
+	// -- Check
+	assert_eq!(models.len(), 1);
+	assert_eq!(models[0].name, "llama3.2:latest");
+	assert_eq!(models[0].size_vram, Some(5137025024));
+
+	Ok(())
+}
@@ -43,6 +43,11 @@ async fn test_chat_json_mode_ok() -> Result<()> {
 	common_tests::common_test_chat_json_mode_ok(MODEL, true).await
 }
 
+#[tokio::test]
+async fn test_chat_request_id_ok() -> Result<()> {
+	common_tests::common_test_chat_request_id_ok(MODEL).await
+}
+
 // endregion: --- Chat
 
 // region:    --- Chat Stream Tests
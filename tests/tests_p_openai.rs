@@ -3,6 +3,7 @@ mod support;
 use crate::support::common_tests;
 use genai::adapter::AdapterKind;
 use genai::resolver::AuthData;
+use serde_json::json;
 
 type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
 
@@ -40,6 +41,21 @@ async fn test_chat_stop_sequences_ok() -> Result<()> {
 	common_tests::common_test_chat_stop_sequences_ok(MODEL).await
 }
 
+#[tokio::test]
+async fn test_chat_request_id_ok() -> Result<()> {
+	common_tests::common_test_chat_request_id_ok(MODEL).await
+}
+
+#[tokio::test]
+async fn test_chat_extra_params_ok() -> Result<()> {
+	common_tests::common_test_chat_extra_params_ok(MODEL, vec![("seed", json!(42))]).await
+}
+
+#[tokio::test]
+async fn test_chat_extra_params_unknown_err() -> Result<()> {
+	common_tests::common_test_chat_extra_params_unknown_err(MODEL).await
+}
+
 // endregion: --- Chat
 
 // region:    --- Chat Stream Tests
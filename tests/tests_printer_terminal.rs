@@ -0,0 +1,159 @@
+//! Requires the `terminal` feature: `cargo test --features terminal --test tests_printer_terminal`
+
+#![cfg(feature = "terminal")]
+
+mod support;
+
+use crate::support::{MockServer, Result};
+use genai::chat::printer::{ColorScheme, TerminalStreamPrinter};
+use genai::chat::{ChatOptions, ChatRequest};
+use genai::Error;
+use serde_json::json;
+use std::io::Write;
+
+#[tokio::test]
+async fn test_printer_terminal_print_to_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start_sse_stream(vec![
+		stream_chunk("Hello"),
+		stream_chunk(" world"),
+		json!({"choices": [], "usage": {"prompt_tokens": 142, "completion_tokens": 38, "total_tokens": 180}}),
+	])
+	.await?;
+	let options = ChatOptions::default().with_capture_usage(true);
+	let chat_res = server
+		.client()
+		.exec_chat_stream("gpt-4o-mini", ChatRequest::from_user("Hi"), Some(&options))
+		.await?;
+	let printer = TerminalStreamPrinter::builder()
+		.typing_indicator(false)
+		.color_scheme(ColorScheme::monochrome())
+		.build();
+
+	// -- Exec
+	let mut output: Vec<u8> = Vec::new();
+	let usage = printer.print_to(&mut output, chat_res).await?;
+
+	// -- Check
+	let output = String::from_utf8(output)?;
+	assert_eq!(usage.input_tokens, Some(142));
+	assert!(output.contains("[Model: gpt-4o-mini]"), "{output:?}");
+	assert!(output.contains("Hello world"), "{output:?}");
+	assert!(output.contains("[Tokens: 142 in / 38 out]"), "{output:?}");
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_printer_terminal_no_header_footer_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start_sse_stream(vec![stream_chunk("Hello")]).await?;
+	let chat_res = server
+		.client()
+		.exec_chat_stream("gpt-4o-mini", ChatRequest::from_user("Hi"), None)
+		.await?;
+	let printer = TerminalStreamPrinter::builder()
+		.show_model_header(false)
+		.show_usage_footer(false)
+		.build();
+
+	// -- Exec
+	let mut output: Vec<u8> = Vec::new();
+	printer.print_to(&mut output, chat_res).await?;
+	printer.interrupt(&mut output, "[Tool call: get_weather]")?;
+
+	// -- Check
+	let output = String::from_utf8(output)?;
+	assert!(!output.contains("[Model:"), "{output:?}");
+	assert!(!output.contains("[Tokens:"), "{output:?}");
+	assert!(output.contains("[Tool call: get_weather]"), "{output:?}");
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_printer_terminal_write_err_style_reset_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start_sse_stream(vec![stream_chunk("Hello"), stream_chunk("FAIL")]).await?;
+	let chat_res = server
+		.client()
+		.exec_chat_stream("gpt-4o-mini", ChatRequest::from_user("Hi"), None)
+		.await?;
+	let printer = TerminalStreamPrinter::builder().build();
+
+	// -- Exec
+	let mut writer = FailingWriter::default();
+	let res = printer.print_to(&mut writer, chat_res).await;
+
+	// -- Check
+	assert!(matches!(res, Err(Error::StreamWrite { .. })), "{res:?}");
+	let output = String::from_utf8(writer.output)?;
+	assert!(output.contains("Hello"), "{output:?}");
+	// The footer (color reset and disable blinking) is still written after the write error.
+	let hello_idx = output.find("Hello").unwrap_or_default();
+	assert!(output[hello_idx..].contains("\x1b[0m"), "{output:?}");
+	assert!(output[hello_idx..].contains("\x1b[?12l"), "{output:?}");
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_printer_terminal_exec_chat_stream_to_terminal_usage_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start_sse_stream(vec![
+		stream_chunk(""),
+		json!({"choices": [], "usage": {"prompt_tokens": 142, "completion_tokens": 38, "total_tokens": 180}}),
+	])
+	.await?;
+	let printer = TerminalStreamPrinter::builder()
+		.show_model_header(false)
+		.show_usage_footer(false)
+		.typing_indicator(false)
+		.color_scheme(ColorScheme::monochrome())
+		.build();
+
+	// -- Exec
+	// Note: No `capture_usage` option, as it is forced.
+	let usage = server
+		.client()
+		.exec_chat_stream_to_terminal("gpt-4o-mini", ChatRequest::from_user("Hi"), None, &printer)
+		.await?;
+
+	// -- Check
+	assert_eq!(usage.input_tokens, Some(142));
+	assert_eq!(usage.output_tokens, Some(38));
+
+	Ok(())
+}
+
+// region:    --- Support
+
+/// A writer failing on the writes containing `FAIL`.
+#[derive(Default)]
+struct FailingWriter {
+	output: Vec<u8>,
+}
+
+impl Write for FailingWriter {
+	fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+		if String::from_utf8_lossy(buf).contains("FAIL") {
+			return Err(std::io::Error::other("write failed"));
+		}
+		self.output.extend_from_slice(buf);
+		Ok(buf.len())
+	}
+
+	fn flush(&mut self) -> std::io::Result<()> {
+		Ok(())
+	}
+}
+
+fn stream_chunk(content: &str) -> serde_json::Value {
+	json!({
+		"id": "chatcmpl-mock",
+		"object": "chat.completion.chunk",
+		"choices": [{ "index": 0, "delta": { "content": content }, "finish_reason": null }]
+	})
+}
+
+// endregion: --- Support
@@ -0,0 +1,35 @@
+//! Requires the `realtime` feature: `cargo test --features realtime --test tests_realtime`
+
+#![cfg(feature = "realtime")]
+
+use genai::realtime::RealtimeEvent;
+
+type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+#[test]
+fn test_realtime_event_parse_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let text_delta = r#"{"type": "response.text.delta", "event_id": "event_1", "response_id": "resp_1",
+		"item_id": "item_1", "output_index": 0, "content_index": 0, "delta": "Hello"}"#;
+	let speech_started = r#"{"type": "input_audio_buffer.speech_started", "event_id": "event_2",
+		"audio_start_ms": 1000, "item_id": "item_2"}"#;
+	let unknown = r#"{"type": "response.content_part.added", "event_id": "event_3"}"#;
+
+	// -- Exec
+	let text_delta = RealtimeEvent::from_json_str(text_delta)?;
+	let speech_started = RealtimeEvent::from_json_str(speech_started)?;
+	let unknown = RealtimeEvent::from_json_str(unknown)?;
+
+	// -- Check
+	assert!(matches!(text_delta, RealtimeEvent::ResponseTextDelta { delta, .. } if delta == "Hello"));
+	assert!(matches!(
+		speech_started,
+		RealtimeEvent::InputAudioBufferSpeechStarted {
+			audio_start_ms: 1000,
+			..
+		}
+	));
+	assert!(matches!(unknown, RealtimeEvent::Other));
+
+	Ok(())
+}
@@ -0,0 +1,90 @@
+mod support;
+
+use crate::support::{mock_openai_chat_response, MockServer, Result};
+use genai::chat::{ChatOptions, ChatRequest};
+use genai::ReproducibleChatSession;
+use serde_json::{json, Value};
+
+#[tokio::test]
+async fn test_reproducible_session_same_fingerprint_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![response_with_fingerprint("fp_44709d6fcb")]).await?;
+	let mut session = ReproducibleChatSession::new(server.client(), 42);
+	let options = ChatOptions::default().with_temperature(0.0).with_seed(7);
+
+	// -- Exec
+	let chat_res = session.exec_chat("gpt-4o-mini", ChatRequest::from_user("Hi"), None).await?;
+	session
+		.exec_chat("gpt-4o-mini", ChatRequest::from_user("Hi"), Some(&options))
+		.await?;
+
+	// -- Check
+	assert_eq!(chat_res.system_fingerprint(), Some("fp_44709d6fcb"));
+	assert!(session.verify_reproducibility());
+	let record = session.record();
+	assert_eq!(record.seed, 42);
+	assert_eq!(record.system_fingerprints, vec!["fp_44709d6fcb".to_string()]);
+	assert_eq!(record.call_count, 2);
+	// The session seed overrides the options seed (and the other options are kept)
+	let requests = server.requests();
+	assert_eq!(requests[0]["seed"], json!(42));
+	assert_eq!(requests[1]["seed"], json!(42));
+	assert_eq!(requests[1]["temperature"], json!(0.0));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_reproducible_session_fingerprint_changed_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![
+		response_with_fingerprint("fp_1"),
+		response_with_fingerprint("fp_2"),
+		response_with_fingerprint("fp_1"),
+	])
+	.await?;
+	let mut session = ReproducibleChatSession::new(server.client(), 42);
+
+	// -- Exec
+	for _ in 0..3 {
+		session.exec_chat("gpt-4o-mini", ChatRequest::from_user("Hi"), None).await?;
+	}
+
+	// -- Check
+	assert!(!session.verify_reproducibility());
+	assert_eq!(
+		session.record().system_fingerprints,
+		vec!["fp_1".to_string(), "fp_2".to_string()]
+	);
+	assert_eq!(session.record().call_count, 3);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_reproducible_session_no_fingerprint_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![mock_openai_chat_response("Hello")]).await?;
+	let mut session = ReproducibleChatSession::new(server.client(), 42);
+
+	// -- Exec
+	let chat_res = session.exec_chat("gpt-4o-mini", ChatRequest::from_user("Hi"), None).await?;
+
+	// -- Check
+	assert_eq!(chat_res.system_fingerprint(), None);
+	assert_eq!(chat_res.adapter_meta, Some(json!({"finish_reason": "stop"})));
+	assert!(session.record().system_fingerprints.is_empty());
+	assert!(session.verify_reproducibility());
+
+	Ok(())
+}
+
+// region:    --- Support
+
+fn response_with_fingerprint(system_fingerprint: &str) -> Value {
+	let mut response = mock_openai_chat_response("Hello");
+	response["system_fingerprint"] = json!(system_fingerprint);
+	response
+}
+
+// endregion: --- Support
@@ -0,0 +1,108 @@
+mod support;
+
+use crate::support::{mock_openai_chat_response, MockServer, Result};
+use genai::adapter::AdapterKind;
+use genai::chat::{ChatRequest, Tool};
+use genai::middleware::RequestCompressor;
+use serde_json::json;
+
+fn chat_req_with_tools(tool_count: usize) -> ChatRequest {
+	let tools = (0..tool_count)
+		.map(|i| {
+			Tool::new(format!("get_weather_{i}")).with_schema(json!({
+				"type": "object",
+				"properties": {
+					"city": {"type": "string", "description": "The city name"},
+					"unit": {"type": "string", "enum": ["celsius", "fahrenheit"]}
+				},
+				"required": ["city"]
+			}))
+		})
+		.collect();
+	ChatRequest::from_user("What is the weather?").with_tools(tools)
+}
+
+/// The `content-length` of a request head (i.e., the body size on the wire).
+fn content_length(head: &str) -> Option<usize> {
+	head.lines()
+		.find_map(|line| line.strip_prefix("content-length:"))
+		.and_then(|value| value.trim().parse().ok())
+}
+
+#[tokio::test]
+async fn test_request_compression_config_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![mock_openai_chat_response("Hello!")]).await?;
+	let client = server
+		.client_builder_for_adapter(AdapterKind::OpenAI)
+		.with_request_compression(true)
+		.build();
+
+	// -- Exec
+	let chat_res = client.exec_chat("gpt-4o-mini", ChatRequest::from_user("Hi"), None).await?;
+
+	// -- Check
+	assert_eq!(chat_res.content_text_as_str(), Some("Hello!"));
+	assert!(server.request_heads()[0].contains("content-encoding: gzip"));
+	// Note: The mock server decompresses the gzip bodies.
+	assert_eq!(server.requests()[0]["model"], "gpt-4o-mini");
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_request_compression_middleware_min_size_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![mock_openai_chat_response("Hello!")]).await?;
+	let client = server
+		.client_builder_for_adapter(AdapterKind::OpenAI)
+		.with_middleware(RequestCompressor::new(2048))
+		.build();
+
+	// -- Exec
+	client.exec_chat("gpt-4o-mini", ChatRequest::from_user("Hi"), None).await?;
+	client.exec_chat("gpt-4o-mini", chat_req_with_tools(20), None).await?;
+
+	// -- Check
+	let heads = server.request_heads();
+	assert!(!heads[0].contains("content-encoding"));
+	assert!(heads[1].contains("content-encoding: gzip"));
+	let requests = server.requests();
+	assert_eq!(requests[1]["tools"].as_array().map(|tools| tools.len()), Some(20));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_request_compression_tools_size_reduction_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![
+		mock_openai_chat_response("Hello!"),
+		mock_openai_chat_response("Hello!"),
+	])
+	.await?;
+	let raw_client = server.client_builder_for_adapter(AdapterKind::OpenAI).build();
+	let compressed_client = server
+		.client_builder_for_adapter(AdapterKind::OpenAI)
+		.with_request_compression(true)
+		.build();
+
+	// -- Exec
+	raw_client.exec_chat("gpt-4o-mini", chat_req_with_tools(20), None).await?;
+	compressed_client
+		.exec_chat("gpt-4o-mini", chat_req_with_tools(20), None)
+		.await?;
+
+	// -- Check
+	let heads = server.request_heads();
+	let raw_size = content_length(&heads[0]).ok_or("Should have the raw content-length")?;
+	let compressed_size = content_length(&heads[1]).ok_or("Should have the compressed content-length")?;
+	assert_eq!(server.requests()[0], server.requests()[1]);
+	// Note: The 20 tool schemas are very repetitive, so gzip reduces them to less than a tenth (~5.5 KB to ~0.3 KB).
+	assert!(
+		compressed_size * 10 < raw_size,
+		"compressed: {compressed_size} bytes, raw: {raw_size} bytes"
+	);
+
+	Ok(())
+}
@@ -0,0 +1,175 @@
+mod support;
+
+use crate::support::{mock_openai_chat_response, MockServer, Result};
+use genai::adapter::{AdapterKind, ResponseParser, TextResponseParser};
+use genai::chat::{ChatOptions, ChatRequest, ChatResponse, ChatStreamEvent, MetaUsage, StreamChunk, StreamEnd};
+use genai::ModelIden;
+use serde_json::{json, Value};
+use std::sync::Arc;
+
+#[tokio::test]
+async fn test_response_parser_exec_chat_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![json!({ "result": mock_openai_chat_response("Hello from proxy") })]).await?;
+	let client = server
+		.client_builder_for_adapter(AdapterKind::OpenAI)
+		.with_response_parser(AdapterKind::OpenAI, Arc::new(WrappedParser))
+		.build();
+
+	// -- Exec
+	let res = client.exec_chat("gpt-4o-mini", ChatRequest::from_user("Hi"), None).await?;
+
+	// -- Check
+	assert_eq!(res.content_text_as_str(), Some("Hello from proxy"));
+	assert_eq!(res.usage.total_tokens, Some(20));
+	assert!(res.client_request_id.is_some());
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_response_parser_exec_chat_stream_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start_sse_stream(vec![
+		json!({ "token": "Hello" }),
+		json!({ "token": " world" }),
+		json!({ "done": true, "total_tokens": 12 }),
+	])
+	.await?;
+	let client = server
+		.client_builder_for_adapter(AdapterKind::OpenAI)
+		.with_response_parser(AdapterKind::OpenAI, Arc::new(WrappedParser))
+		.build();
+
+	// -- Exec
+	let stream_res = client
+		.exec_chat_stream("gpt-4o-mini", ChatRequest::from_user("Hi"), None)
+		.await?;
+	let (content, usage) = stream_res.collect_with_usage().await?;
+
+	// -- Check
+	assert_eq!(content, "Hello world");
+	assert_eq!(usage.total_tokens, Some(12));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_response_parser_gemini_stream_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server =
+		MockServer::start_chunked_stream(gemini_stream_chunks("./tests/data/gemini-stream-chunks.json")?).await?;
+	let client = server
+		.client_builder_for_adapter(AdapterKind::Gemini)
+		.with_response_parser(
+			AdapterKind::Gemini,
+			Arc::new(TextResponseParser::new(AdapterKind::Gemini)),
+		)
+		.build();
+
+	let options = ChatOptions::default().with_capture_usage(true);
+
+	// -- Exec
+	let (content, usage) = client
+		.exec_chat_stream("gemini-1.5-flash", ChatRequest::from_user("Hi"), Some(&options))
+		.await?
+		.collect_with_usage()
+		.await?;
+
+	// -- Check
+	assert_eq!(content, "Hello world, how are you?");
+	// The usage of the last chunk (the Gemini usage is cumulative)
+	assert_eq!(usage.input_tokens, Some(8));
+	assert_eq!(usage.output_tokens, Some(8));
+	assert_eq!(usage.total_tokens, Some(16));
+
+	Ok(())
+}
+
+#[test]
+fn test_response_parser_text_stream_event_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let model_iden = ModelIden::new(AdapterKind::Anthropic, "claude-3-5-haiku-20241022");
+	let parser = TextResponseParser::new(AdapterKind::Anthropic);
+
+	// -- Exec
+	let chunk = parser.parse_stream_event(
+		&model_iden,
+		r#"{"type":"content_block_delta","delta":{"type":"text_delta","text":"Hi"}}"#,
+	)?;
+	let end = parser.parse_stream_event(&model_iden, r#"{"type":"message_stop"}"#)?;
+	let skipped = parser.parse_stream_event(&model_iden, r#"{"type":"ping"}"#)?;
+	let tool_call_skipped = parser.parse_stream_event(
+		&model_iden,
+		r#"{"type":"content_block_delta","delta":{"type":"input_json_delta","partial_json":"{\"city\""}}"#,
+	)?;
+
+	let start_usage = parser.parse_stream_usage(
+		&model_iden,
+		r#"{"type":"message_start","message":{"usage":{"input_tokens":12,"output_tokens":1}}}"#,
+	)?;
+	let delta_usage =
+		parser.parse_stream_usage(&model_iden, r#"{"type":"message_delta","usage":{"output_tokens":5}}"#)?;
+
+	// -- Check
+	assert!(matches!(chunk, Some(ChatStreamEvent::Chunk(StreamChunk { content })) if content == "Hi"));
+	let start_usage = start_usage.ok_or("Should have the message_start usage")?;
+	assert_eq!(start_usage.input_tokens, Some(12));
+	let delta_usage = delta_usage.ok_or("Should have the message_delta usage")?;
+	assert_eq!(delta_usage.output_tokens, Some(5));
+	assert!(matches!(end, Some(ChatStreamEvent::End(_))));
+	assert!(skipped.is_none());
+	// Text-only, the tool call chunks are skipped.
+	assert!(tool_call_skipped.is_none());
+
+	Ok(())
+}
+
+// region:    --- Support
+
+/// The Gemini pretty JSON array stream chunks of the items of the `path` JSON array (`[`, `,\r\n` separated items, `]`).
+fn gemini_stream_chunks(path: &str) -> Result<Vec<String>> {
+	let items: Vec<Value> = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+	let mut chunks: Vec<String> = items
+		.iter()
+		.enumerate()
+		.map(|(idx, item)| {
+			let prefix = if idx == 0 { "[" } else { ",\r\n" };
+			format!("{prefix}{}\n", serde_json::to_string_pretty(item).unwrap_or_default())
+		})
+		.collect();
+	chunks.push("]".to_string());
+	Ok(chunks)
+}
+
+/// Parse the `{"result": ...}` wrapped responses, and the `{"token": ...}` stream events.
+struct WrappedParser;
+
+impl ResponseParser for WrappedParser {
+	fn parse_chat_response(&self, model_iden: ModelIden, mut body: Value) -> genai::Result<ChatResponse> {
+		let body = body.get_mut("result").map(Value::take).unwrap_or_default();
+		TextResponseParser::new(AdapterKind::OpenAI).parse_chat_response(model_iden, body)
+	}
+
+	fn parse_stream_event(&self, _model_iden: &ModelIden, event: &str) -> genai::Result<Option<ChatStreamEvent>> {
+		let data: Value = serde_json::from_str(event).unwrap_or_default();
+		if let Some(token) = data.get("token").and_then(Value::as_str) {
+			return Ok(Some(ChatStreamEvent::Chunk(StreamChunk {
+				content: token.to_string(),
+			})));
+		}
+		if data.get("done").is_some() {
+			let usage = MetaUsage {
+				total_tokens: data.get("total_tokens").and_then(Value::as_i64).map(|t| t as i32),
+				..Default::default()
+			};
+			return Ok(Some(ChatStreamEvent::End(StreamEnd {
+				captured_usage: Some(usage),
+				captured_content: None,
+			})));
+		}
+		Ok(None)
+	}
+}
+
+// endregion: --- Support
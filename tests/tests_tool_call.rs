@@ -0,0 +1,36 @@
+use genai::chat::ToolCall;
+use serde_json::json;
+
+type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+#[test]
+fn test_tool_call_from_anthropic_value_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let value = json!({"type": "tool_use", "id": "toolu_01", "name": "get_weather", "input": {"city": "Paris"}});
+
+	// -- Exec
+	let tool_call = ToolCall::from_anthropic_value(value)?;
+
+	// -- Check
+	assert_eq!(tool_call.call_id, "toolu_01");
+	assert_eq!(tool_call.fn_name, "get_weather");
+	assert_eq!(tool_call.fn_arguments, json!({"city": "Paris"}));
+
+	Ok(())
+}
+
+#[test]
+fn test_tool_call_from_gemini_value_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let value = json!({"name": "get_weather", "args": {"city": "Paris"}});
+
+	// -- Exec
+	let tool_call = ToolCall::from_gemini_value(value)?;
+
+	// -- Check
+	assert_eq!(tool_call.fn_name, "get_weather");
+	assert_eq!(tool_call.call_id, "get_weather");
+	assert_eq!(tool_call.fn_arguments, json!({"city": "Paris"}));
+
+	Ok(())
+}
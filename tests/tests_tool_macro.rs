@@ -0,0 +1,178 @@
+//! Requires the `macros` feature: `cargo test --features macros --test tests_tool_macro`
+
+#![cfg(feature = "macros")]
+
+use genai::chat::{schema_for_fn_single_param_from_docs, tool, tool_doc, ToolCall, ToolFn, ToolSchemaRegistry};
+use genai::Error;
+use schemars::JsonSchema;
+use serde_json::{json, Value};
+
+type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+// region:    --- Tool Functions
+
+/// Get the current weather of a city.
+#[tool]
+fn get_current_weather(
+	/// The city name, e.g., "Paris"
+	city: String,
+	/// The temperature unit ("C" or "F"), Celsius by default
+	unit: Option<String>,
+) -> core::result::Result<String, String> {
+	Ok(format!("21{} in {city}", unit.unwrap_or("C".to_string())))
+}
+
+/// Add two numbers.
+#[tool]
+fn add(a: i64, b: i64) -> core::result::Result<Value, String> {
+	Ok(json!({ "sum": a + b }))
+}
+
+/// Get the current time.
+#[tool]
+fn get_time() -> core::result::Result<String, String> {
+	Ok("12:00".to_string())
+}
+
+/// Always fail.
+#[tool]
+fn always_fail(reason: String) -> core::result::Result<String, String> {
+	Err(reason)
+}
+
+/// The `#[tool_doc]` description takes precedence over this doc comment.
+#[allow(dead_code)]
+#[tool_doc("Get the population of a country.")]
+#[derive(JsonSchema)]
+struct GetPopulationParams {
+	/// The country name
+	country: String,
+}
+
+// endregion: --- Tool Functions
+
+fn tool_call(fn_name: &str, fn_arguments: Value) -> ToolCall {
+	ToolCall {
+		call_id: "call_1".to_string(),
+		fn_name: fn_name.to_string(),
+		fn_arguments,
+	}
+}
+
+#[test]
+fn test_tool_macro_schema_ok() -> Result<()> {
+	// -- Exec
+	let fn_schema = get_current_weather::schema();
+
+	// -- Check
+	assert_eq!(fn_schema["type"], "function");
+	assert_eq!(fn_schema["function"]["name"], "get_current_weather");
+	assert_eq!(
+		fn_schema["function"]["description"],
+		"Get the current weather of a city."
+	);
+	let parameters = &fn_schema["function"]["parameters"];
+	assert_eq!(parameters["properties"]["city"]["type"], "string");
+	assert_eq!(
+		parameters["properties"]["city"]["description"],
+		"The city name, e.g., \"Paris\""
+	);
+	assert_eq!(
+		parameters["properties"]["unit"]["description"],
+		"The temperature unit (\"C\" or \"F\"), Celsius by default"
+	);
+	assert_eq!(parameters["required"], json!(["city"]));
+	assert_eq!(<get_current_weather as ToolFn>::NAME, "get_current_weather");
+	assert_eq!(<get_current_weather as ToolFn>::schema(), fn_schema);
+
+	Ok(())
+}
+
+#[test]
+fn test_tool_macro_fn_kept_ok() -> Result<()> {
+	// -- Exec & Check
+	assert_eq!(get_current_weather("Paris".to_string(), None)?, "21C in Paris");
+	assert_eq!(add(1, 2)?, json!({ "sum": 3 }));
+
+	Ok(())
+}
+
+#[test]
+fn test_tool_macro_invoke_ok() -> Result<()> {
+	// -- Exec
+	let weather_output = get_current_weather::invoke(json!({"city": "Oslo", "unit": "F"}))?;
+	let weather_default_output = get_current_weather::invoke(json!({"city": "Paris"}))?;
+	let add_output = add::invoke(json!({"a": 40, "b": 2}))?;
+	let time_output = get_time::invoke(Value::Null)?;
+
+	// -- Check
+	// Note: The output is serialized as JSON (a JSON string for a `String`).
+	assert_eq!(weather_output, r#""21F in Oslo""#);
+	assert_eq!(weather_default_output, r#""21C in Paris""#);
+	assert_eq!(add_output, r#"{"sum":42}"#);
+	assert_eq!(time_output, r#""12:00""#);
+
+	Ok(())
+}
+
+#[test]
+fn test_tool_macro_invoke_err() -> Result<()> {
+	// -- Exec
+	let invalid_args_res = add::invoke(json!({"a": "forty"}));
+	let fn_failed_res = always_fail::invoke(json!({"reason": "out of order"}));
+
+	// -- Check
+	assert!(matches!(invalid_args_res, Err(Error::ToolInvalidArgs { .. })));
+	match fn_failed_res {
+		Err(Error::ToolFnFailed { cause }) => assert_eq!(cause, "out of order"),
+		other => return Err(format!("Expected ToolFnFailed, got {other:?}").into()),
+	}
+
+	Ok(())
+}
+
+#[test]
+fn test_tool_macro_register_tool_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut registry = ToolSchemaRegistry::new();
+	registry.register_tool::<get_current_weather>().register_tool::<add>();
+
+	// -- Exec
+	let weather_output = registry.dispatch_versioned(&tool_call("get_current_weather", json!({"city": "Rome"})));
+	let add_output = registry.dispatch_versioned(&tool_call("add", json!({"a": 1, "b": 1})));
+	let tools = registry.tool_schemas();
+
+	// -- Check
+	assert_eq!(weather_output, r#""21C in Rome""#);
+	assert_eq!(add_output, r#"{"sum":2}"#);
+	let tool_names: Vec<&str> = tools.iter().map(|tool| tool.name.as_str()).collect();
+	assert_eq!(tool_names, vec!["get_current_weather", "add"]);
+	assert_eq!(
+		tools[0].description.as_deref(),
+		Some("Get the current weather of a city.")
+	);
+	assert_eq!(
+		tools[0]
+			.schema
+			.as_ref()
+			.and_then(|schema| schema.pointer("/properties/city/type")),
+		Some(&json!("string"))
+	);
+
+	Ok(())
+}
+
+#[test]
+fn test_tool_macro_tool_doc_ok() -> Result<()> {
+	// -- Exec
+	let fn_schema = schema_for_fn_single_param_from_docs::<GetPopulationParams>("get_population")?;
+
+	// -- Check
+	assert_eq!(fn_schema["function"]["name"], "get_population");
+	assert_eq!(fn_schema["function"]["description"], "Get the population of a country.");
+	let parameters = &fn_schema["function"]["parameters"];
+	assert!(parameters.get("description").is_none());
+	assert_eq!(parameters["properties"]["country"]["description"], "The country name");
+
+	Ok(())
+}
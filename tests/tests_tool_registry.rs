@@ -0,0 +1,236 @@
+use genai::chat::{invoke_with_args, CompatibilityWarning, ToolCall, ToolSchemaRegistry};
+use genai::Error;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+#[derive(Deserialize)]
+struct WeatherV1 {
+	city: String,
+}
+
+#[derive(Deserialize)]
+struct WeatherV2 {
+	city: String,
+	unit: String,
+}
+
+fn weather_v1_params() -> Value {
+	json!({
+		"type": "object",
+		"properties": { "city": { "type": "string" } },
+		"required": ["city"]
+	})
+}
+
+fn weather_v2_params() -> Value {
+	json!({
+		"type": "object",
+		"properties": { "city": { "type": "string" }, "unit": { "type": "string" } },
+		"required": ["city", "unit"]
+	})
+}
+
+fn registry() -> ToolSchemaRegistry {
+	let mut registry = ToolSchemaRegistry::new();
+	registry
+		.register_versioned("get_weather", "v1", weather_v1_params(), |args| {
+			invoke_with_args(|p: WeatherV1| Ok::<_, String>(format!("v1 {}", p.city)), args, None)
+		})
+		.register_versioned("get_weather", "v2", weather_v2_params(), |args| {
+			invoke_with_args(
+				|p: WeatherV2| Ok::<_, String>(format!("v2 {} {}", p.city, p.unit)),
+				args,
+				None,
+			)
+		});
+	registry
+}
+
+fn tool_call(fn_name: &str, fn_arguments: Value) -> ToolCall {
+	ToolCall {
+		call_id: "call_1".to_string(),
+		fn_name: fn_name.to_string(),
+		fn_arguments,
+	}
+}
+
+fn tool_names(registry: &ToolSchemaRegistry) -> Vec<String> {
+	registry.tool_schemas().into_iter().map(|tool| tool.name).collect()
+}
+
+#[test]
+fn test_tool_registry_dispatch_versioned_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let registry = registry();
+
+	// -- Exec
+	let v1_output = registry.dispatch_versioned(&tool_call("get_weather_v1", json!({"city": "Paris"})));
+	let v2_output = registry.dispatch_versioned(&tool_call("get_weather_v2", json!({"city": "Paris", "unit": "C"})));
+	let latest_output = registry.dispatch_versioned(&tool_call("get_weather", json!({"city": "Oslo", "unit": "F"})));
+	let unknown_output = registry.dispatch_versioned(&tool_call("get_time_v1", json!({})));
+	let invalid_output = registry.dispatch_versioned(&tool_call("get_weather_v2", json!({"city": "Paris"})));
+
+	// -- Check
+	assert_eq!(v1_output, "v1 Paris");
+	assert_eq!(v2_output, "v2 Paris C");
+	assert_eq!(latest_output, "v2 Oslo F");
+	let unknown_output: Value = serde_json::from_str(&unknown_output)?;
+	assert!(unknown_output["error"].as_str().is_some_and(|e| e.contains("get_time_v1")));
+	let invalid_output: Value = serde_json::from_str(&invalid_output)?;
+	assert!(invalid_output["error"].is_string());
+	let tool_names: Vec<String> = registry.tool_schemas().into_iter().map(|tool| tool.name).collect();
+	assert_eq!(tool_names, vec!["get_weather_v1", "get_weather_v2"]);
+	assert_eq!(registry.versions("get_weather"), vec!["v1", "v2"]);
+
+	Ok(())
+}
+
+#[test]
+fn test_tool_registry_compatibility_report_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let path = std::env::temp_dir().join(format!("genai-tests-tool-snapshot-{}.json", std::process::id()));
+	registry().save_snapshot(&path)?;
+	let mut registry = ToolSchemaRegistry::new();
+	registry.register_versioned(
+		"get_weather",
+		"v2",
+		json!({
+			"type": "object",
+			"properties": { "city": { "type": "integer" }, "country": { "type": "string" } },
+			"required": ["city", "country"]
+		}),
+		|_| Ok("".to_string()),
+	);
+
+	// -- Exec
+	let warnings = registry.compatibility_report(&path)?;
+	std::fs::remove_file(&path)?;
+
+	// -- Check
+	assert_eq!(
+		warnings,
+		vec![
+			CompatibilityWarning::ToolRemoved {
+				tool_name: "get_weather_v1".to_string()
+			},
+			CompatibilityWarning::RequiredParamRemoved {
+				tool_name: "get_weather_v2".to_string(),
+				param: "unit".to_string()
+			},
+			CompatibilityWarning::RequiredParamAdded {
+				tool_name: "get_weather_v2".to_string(),
+				param: "country".to_string()
+			},
+			CompatibilityWarning::ParamTypeChanged {
+				tool_name: "get_weather_v2".to_string(),
+				param: "city".to_string(),
+				old_type: json!("string"),
+				new_type: json!("integer")
+			},
+		]
+	);
+
+	Ok(())
+}
+
+#[test]
+fn test_tool_registry_register_from_schema_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let schema = json!({
+		"type": "function",
+		"function": {
+			"name": "get_time",
+			"description": "Get the current time of a city",
+			"parameters": {
+				"type": "object",
+				"properties": { "city": { "type": "string" } },
+				"required": ["city"]
+			}
+		}
+	});
+	let mut registry = ToolSchemaRegistry::new();
+	registry.register_from_schema(schema, |args| {
+		let city = args.and_then(|args| args["city"].as_str()).unwrap_or("nowhere");
+		format!("12:00 in {city}")
+	})?;
+
+	// -- Exec
+	let output = registry.dispatch_versioned(&tool_call("get_time", json!({"city": "Paris"})));
+	let no_args_output = registry.dispatch_versioned(&tool_call("get_time", Value::Null));
+
+	// -- Check
+	assert_eq!(output, "12:00 in Paris");
+	assert_eq!(no_args_output, "12:00 in nowhere");
+	let tools = registry.tool_schemas();
+	assert_eq!(tools[0].name, "get_time");
+	assert_eq!(tools[0].description.as_deref(), Some("Get the current time of a city"));
+	assert_eq!(
+		tools[0].schema.as_ref().and_then(|s| s.pointer("/required/0")),
+		Some(&json!("city"))
+	);
+
+	Ok(())
+}
+
+#[test]
+fn test_tool_registry_register_from_schema_invalid_err() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut registry = ToolSchemaRegistry::new();
+	let invalid_schemas = [
+		json!({"type": "object", "properties": {}}),
+		json!({"type": "function", "function": {"description": "No name"}}),
+		json!({"type": "function", "function": {"name": "get time"}}),
+		json!({"type": "function", "function": {"name": "get_time", "parameters": {"type": "string"}}}),
+	];
+
+	// -- Exec & Check
+	for schema in invalid_schemas {
+		let res = registry.register_from_schema(schema.clone(), |_| "".to_string());
+		assert!(
+			matches!(res, Err(Error::ToolInvalidSchema { .. })),
+			"Should be invalid: {schema}"
+		);
+	}
+	assert!(registry.tool_schemas().is_empty());
+
+	Ok(())
+}
+
+#[test]
+fn test_tool_registry_typed_results_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let registry = ToolSchemaRegistry::new();
+	registry.store_result("call_1", 21.5_f64);
+
+	// -- Exec
+	let wrong_type = registry.take_result::<String>("call_1");
+	let temperature = registry.take_result::<f64>("call_1");
+	let taken_again = registry.take_result::<f64>("call_1");
+
+	// -- Check
+	assert_eq!(wrong_type, None);
+	assert_eq!(temperature, Some(21.5));
+	assert_eq!(taken_again, None);
+
+	Ok(())
+}
+
+#[test]
+fn test_tool_registry_disable_enable_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut registry = registry();
+
+	// -- Exec
+	let all_disabled = tool_names(registry.disable("get_weather"));
+	let v1_disabled = tool_names(registry.enable("get_weather").disable("get_weather_v1"));
+	let all_enabled = tool_names(registry.enable("get_weather_v1"));
+
+	// -- Check
+	assert!(all_disabled.is_empty());
+	assert_eq!(v1_disabled, vec!["get_weather_v2"]);
+	assert_eq!(all_enabled, vec!["get_weather_v1", "get_weather_v2"]);
+
+	Ok(())
+}
@@ -0,0 +1,292 @@
+use genai::chat::{
+	invoke_typed_with_args, invoke_with_args, invoke_with_typed_args, normalize_schema,
+	schema_for_fn_single_param_from_docs, validate_json_value, SchemaOptions, TypedToolResult,
+};
+use genai::Error;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+#[derive(Deserialize)]
+struct GetWeatherParams {
+	city: String,
+}
+
+/// Get the current weather of a city.
+#[allow(dead_code)]
+#[derive(JsonSchema)]
+struct DocWeatherParams {
+	/// The city name
+	city: String,
+}
+
+#[allow(dead_code)]
+#[derive(JsonSchema)]
+#[schemars(description = "Get the current time of a timezone.")]
+struct AttrTimeParams {
+	/// The IANA timezone, e.g., "Europe/Paris"
+	timezone: String,
+}
+
+#[allow(dead_code)]
+#[derive(JsonSchema)]
+struct NoDocParams {
+	city: String,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Weather {
+	city: String,
+	temperature: f64,
+}
+
+fn weather_output_schema() -> Value {
+	json!({
+		"type": "object",
+		"properties": {
+			"city": { "type": "string" },
+			"temperature": { "type": "number" }
+		},
+		"required": ["city", "temperature"]
+	})
+}
+
+#[test]
+fn test_tool_schema_invoke_with_args_output_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let output_schema = weather_output_schema();
+	let get_weather = |params: GetWeatherParams| -> core::result::Result<String, String> {
+		Ok(json!({"city": params.city, "temperature": 21.5}).to_string())
+	};
+
+	// -- Exec
+	let output = invoke_with_args(get_weather, json!({"city": "Paris"}), Some(&output_schema))?;
+
+	// -- Check
+	let output: Value = serde_json::from_str(&output)?;
+	assert_eq!(output["city"], "Paris");
+
+	Ok(())
+}
+
+#[test]
+fn test_tool_schema_invoke_with_args_output_invalid_err() -> Result<()> {
+	// -- Setup & Fixtures
+	let output_schema = weather_output_schema();
+	let get_weather = |params: GetWeatherParams| -> core::result::Result<String, String> {
+		Ok(json!({"city": params.city, "temperature": "warm"}).to_string())
+	};
+
+	// -- Exec
+	let res = invoke_with_args(get_weather, json!({"city": "Paris"}), Some(&output_schema));
+
+	// -- Check
+	let Err(Error::ToolInvalidOutput { cause }) = res else {
+		return Err("Should have been a ToolInvalidOutput error".into());
+	};
+	assert!(cause.contains("/temperature"), "cause: {cause}");
+
+	Ok(())
+}
+
+#[test]
+fn test_tool_schema_invoke_with_typed_args_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let get_weather = |params: GetWeatherParams| -> core::result::Result<Weather, String> {
+		Ok(Weather {
+			city: params.city,
+			temperature: 21.5,
+		})
+	};
+
+	// -- Exec
+	let output = invoke_with_typed_args(get_weather, json!({"city": "Paris"}))?;
+
+	// -- Check
+	let output: Value = serde_json::from_str(&output)?;
+	validate_json_value(&output, &weather_output_schema())?;
+	assert_eq!(output["temperature"], 21.5);
+
+	Ok(())
+}
+
+#[test]
+fn test_tool_schema_invoke_typed_with_args_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let get_weather = |params: GetWeatherParams| -> core::result::Result<Weather, String> {
+		Ok(Weather {
+			city: params.city,
+			temperature: 21.5,
+		})
+	};
+
+	// -- Exec
+	let result = invoke_typed_with_args(get_weather, Some(&json!({"city": "Paris"})))?;
+	let tool_response = result.to_tool_response("call_1");
+	let parsed: TypedToolResult<Weather> = TypedToolResult::from_output(tool_response.content)?;
+
+	// -- Check
+	assert_eq!(result.value.city, "Paris");
+	assert_eq!(result.output, r#"{"city":"Paris","temperature":21.5}"#);
+	assert_eq!(tool_response.call_id, "call_1");
+	assert_eq!(parsed, result);
+
+	Ok(())
+}
+
+#[test]
+fn test_tool_schema_normalize_schema_strip_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	// as generated by schemars
+	let schema = json!({
+		"$schema": "http://json-schema.org/draft-07/schema#",
+		"title": "GetWeatherParams",
+		"type": "object",
+		"required": ["city"],
+		"properties": {
+			"city": { "type": "string" }
+		},
+		"definitions": {}
+	});
+
+	// -- Exec
+	let schema = normalize_schema(schema, &SchemaOptions::default());
+
+	// -- Check
+	for field in ["$schema", "title", "definitions"] {
+		assert!(schema.get(field).is_none(), "'{field}' should have been stripped");
+	}
+	assert_eq!(schema["additionalProperties"], false);
+	validate_json_value(&json!({"city": "Paris"}), &schema)?;
+	assert!(validate_json_value(&json!({"city": "Paris", "country": "France"}), &schema).is_err());
+
+	Ok(())
+}
+
+#[test]
+fn test_tool_schema_normalize_schema_optional_not_required_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	// `{ pub location: String, pub format: Option<TemperatureUnits> }` (with a `required` including `format`)
+	let schema = json!({
+		"title": "GetCurrentWeatherParams",
+		"type": "object",
+		"required": ["location", "format"],
+		"properties": {
+			"location": { "type": "string" },
+			"format": {
+				"anyOf": [
+					{ "type": "string", "enum": ["Celsius", "Fahrenheit"] },
+					{ "type": "null" }
+				]
+			}
+		}
+	});
+
+	// -- Exec
+	let schema = normalize_schema(schema, &SchemaOptions::default());
+
+	// -- Check
+	assert_eq!(schema["required"], json!(["location"]));
+	validate_json_value(&json!({"location": "Paris"}), &schema)?;
+
+	Ok(())
+}
+
+#[test]
+fn test_tool_schema_normalize_schema_properties_field_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	// `{ pub properties: Properties, pub layout: Option<String> }`, with a schema-like `default` data value
+	let schema = json!({
+		"type": "object",
+		"required": ["properties", "layout"],
+		"properties": {
+			"properties": {
+				"type": "object",
+				"required": ["name"],
+				"properties": {
+					"name": { "type": "string" }
+				}
+			},
+			"layout": {
+				"type": ["object", "null"],
+				"default": { "properties": {}, "required": ["grid"] }
+			}
+		}
+	});
+
+	// -- Exec
+	let schema = normalize_schema(schema, &SchemaOptions::default());
+
+	// -- Check
+	let property_names: Vec<&String> = schema["properties"]
+		.as_object()
+		.ok_or("Should have properties")?
+		.keys()
+		.collect();
+	assert_eq!(property_names, vec!["layout", "properties"]);
+	assert_eq!(schema["required"], json!(["properties"]));
+	assert_eq!(schema["properties"]["properties"]["additionalProperties"], false);
+	assert_eq!(
+		schema["properties"]["layout"]["default"],
+		json!({ "properties": {}, "required": ["grid"] })
+	);
+
+	Ok(())
+}
+
+#[test]
+fn test_tool_schema_from_docs_doc_comment_ok() -> Result<()> {
+	// -- Exec
+	let fn_schema = schema_for_fn_single_param_from_docs::<DocWeatherParams>("get_weather")?;
+
+	// -- Check
+	assert_eq!(fn_schema["type"], "function");
+	assert_eq!(fn_schema["function"]["name"], "get_weather");
+	assert_eq!(
+		fn_schema["function"]["description"],
+		"Get the current weather of a city."
+	);
+	let parameters = &fn_schema["function"]["parameters"];
+	// The root description is moved to the function description (the property descriptions are kept).
+	assert!(parameters.get("description").is_none());
+	assert_eq!(parameters["properties"]["city"]["description"], "The city name");
+	assert_eq!(parameters["required"], json!(["city"]));
+
+	Ok(())
+}
+
+#[test]
+fn test_tool_schema_from_docs_schemars_description_ok() -> Result<()> {
+	// -- Exec
+	let fn_schema = schema_for_fn_single_param_from_docs::<AttrTimeParams>("get_time")?;
+
+	// -- Check
+	assert_eq!(
+		fn_schema["function"]["description"],
+		"Get the current time of a timezone."
+	);
+	let parameters = &fn_schema["function"]["parameters"];
+	assert!(parameters.get("description").is_none());
+	assert_eq!(
+		parameters["properties"]["timezone"]["description"],
+		"The IANA timezone, e.g., \"Europe/Paris\""
+	);
+
+	Ok(())
+}
+
+#[test]
+fn test_tool_schema_from_docs_no_description_err() -> Result<()> {
+	// -- Exec
+	let res = schema_for_fn_single_param_from_docs::<NoDocParams>("get_weather");
+
+	// -- Check
+	match res {
+		Err(Error::ToolSchemaNoDescription { fn_name }) => assert_eq!(fn_name, "get_weather"),
+		other => return Err(format!("Expected ToolSchemaNoDescription, got {other:?}").into()),
+	}
+
+	Ok(())
+}
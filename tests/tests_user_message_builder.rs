@@ -0,0 +1,57 @@
+mod support;
+
+use crate::support::{mock_openai_chat_response, MockServer, Result};
+use genai::chat::{ChatRequest, ContentPart, MessageContent, UserMessageBuilder};
+use serde_json::json;
+
+const PNG_BYTES: &[u8] = &[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, 0x00];
+
+#[test]
+fn test_user_message_builder_image_bytes_media_type_ok() -> Result<()> {
+	// -- Exec
+	let msg = UserMessageBuilder::new()
+		.text("What is in this image?")
+		.image_bytes(PNG_BYTES, "application/octet-stream")
+		.build();
+
+	// -- Check
+	let MessageContent::Parts(parts) = msg.content else {
+		return Err("Should be MessageContent::Parts".into());
+	};
+	assert_eq!(parts.len(), 2);
+	let ContentPart::Image { content_type, .. } = &parts[1] else {
+		return Err("Should be ContentPart::Image".into());
+	};
+	assert_eq!(content_type, "image/png");
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_user_message_builder_openai_serialization_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let server = MockServer::start(vec![mock_openai_chat_response("A duck.")]).await?;
+	let chat_req = ChatRequest::default()
+		.append_user_message_builder()
+		.text("What is in these images?")
+		.image_url_with_detail("https://example.com/duck.png", "low")
+		.image_bytes(PNG_BYTES, "image/png")
+		.end_message();
+
+	// -- Exec
+	server.client().exec_chat("gpt-4o-mini", chat_req, None).await?;
+
+	// -- Check
+	let requests = server.requests();
+	let content = requests[0].pointer("/messages/0/content").ok_or("Should have content")?;
+	assert_eq!(
+		content,
+		&json!([
+			{"type": "text", "text": "What is in these images?"},
+			{"type": "image_url", "image_url": {"url": "https://example.com/duck.png", "detail": "low"}},
+			{"type": "image_url", "image_url": {"url": "data:image/png;base64,iVBORw0KGgoA"}}
+		])
+	);
+
+	Ok(())
+}
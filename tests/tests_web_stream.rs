@@ -0,0 +1,42 @@
+mod support;
+
+use crate::support::{MockServer, Result};
+use genai::adapter::AdapterKind;
+use genai::chat::{ChatRequest, ChatStreamEvent};
+use serde_json::json;
+use tokio_stream::StreamExt;
+
+#[tokio::test]
+async fn test_web_stream_delimited_several_messages_per_chunk_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	// Note: All of the lines are sent in a single body chunk, so a single buffer has several delimited messages.
+	let server = MockServer::start_ndjson_stream(vec![
+		json!({"is_finished": false, "event_type": "stream-start"}),
+		json!({"is_finished": false, "event_type": "text-generation", "text": "one"}),
+		json!({"is_finished": false, "event_type": "text-generation", "text": " two"}),
+		json!({"is_finished": false, "event_type": "text-generation", "text": " three"}),
+		json!({"is_finished": false, "event_type": "text-generation", "text": " four"}),
+		json!({"is_finished": true, "event_type": "stream-end", "response": {}}),
+	])
+	.await?;
+	let client = server.client_for_adapter(AdapterKind::Cohere);
+
+	// -- Exec
+	let chat_res = client.exec_chat_stream("command-r", ChatRequest::from_user("Hi"), None).await?;
+	let mut stream = chat_res.stream;
+	let mut chunks: Vec<String> = Vec::new();
+	let mut end_count = 0;
+	while let Some(event) = stream.next().await {
+		match event? {
+			ChatStreamEvent::Chunk(chunk) => chunks.push(chunk.content),
+			ChatStreamEvent::End(_) => end_count += 1,
+			ChatStreamEvent::Start => (),
+		}
+	}
+
+	// -- Check
+	assert_eq!(chunks, vec!["one", " two", " three", " four"]);
+	assert_eq!(end_count, 1);
+
+	Ok(())
+}